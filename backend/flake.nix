@@ -36,6 +36,7 @@
             WorkingDir = "/";  # Set working directory to root so relative paths work
             Env = [
               "SSL_CERT_FILE=${pkgs.cacert}/etc/ssl/certs/ca-bundle.crt"
+              "OG_IMAGE_FONT_DIR=${pkgs.dejavu_fonts}/share/fonts/truetype"
             ];
           };
         };
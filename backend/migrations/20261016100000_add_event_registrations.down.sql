@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS registrations;
+
+ALTER TABLE events
+    DROP COLUMN IF EXISTS max_participants;
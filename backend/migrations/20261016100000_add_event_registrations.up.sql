@@ -0,0 +1,13 @@
+ALTER TABLE events
+    ADD COLUMN max_participants INTEGER CHECK (max_participants IS NULL OR max_participants > 0);
+
+CREATE TABLE registrations (
+    id BIGSERIAL PRIMARY KEY,
+    event_id BIGINT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    email TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_registrations_event_email ON registrations (event_id, LOWER(email));
+CREATE INDEX idx_registrations_event_id ON registrations (event_id);
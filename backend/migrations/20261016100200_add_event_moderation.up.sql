@@ -0,0 +1,5 @@
+ALTER TYPE event_status ADD VALUE IF NOT EXISTS 'PENDING_REVIEW';
+ALTER TYPE event_status ADD VALUE IF NOT EXISTS 'REJECTED';
+
+ALTER TABLE organizers
+    ADD COLUMN needs_review BOOLEAN NOT NULL DEFAULT false;
@@ -0,0 +1,8 @@
+CREATE TABLE api_token_usage (
+    token_id BIGINT NOT NULL REFERENCES api_tokens(id) ON DELETE CASCADE,
+    usage_date DATE NOT NULL,
+    request_count BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (token_id, usage_date)
+);
+
+CREATE INDEX api_token_usage_usage_date_idx ON api_token_usage (usage_date);
@@ -0,0 +1,14 @@
+CREATE TABLE short_links (
+    id BIGSERIAL PRIMARY KEY,
+    alias TEXT NOT NULL UNIQUE CHECK (alias ~ '^[a-z0-9]([a-z0-9-]*[a-z0-9])?$'),
+    target_url TEXT NOT NULL,
+    event_id BIGINT REFERENCES events(id) ON DELETE SET NULL,
+    organizer_id BIGINT REFERENCES organizers(id) ON DELETE CASCADE,
+    created_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    click_count BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX short_links_organizer_id_idx ON short_links (organizer_id);
+CREATE INDEX short_links_event_id_idx ON short_links (event_id);
@@ -0,0 +1,3 @@
+ALTER TABLE events DROP COLUMN visibility;
+
+DROP TYPE event_visibility;
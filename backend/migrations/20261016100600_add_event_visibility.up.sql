@@ -0,0 +1,4 @@
+CREATE TYPE event_visibility AS ENUM ('PUBLIC', 'UNLISTED');
+
+ALTER TABLE events
+    ADD COLUMN visibility event_visibility NOT NULL DEFAULT 'PUBLIC';
@@ -0,0 +1,14 @@
+CREATE TABLE event_agenda_items (
+    id BIGSERIAL PRIMARY KEY,
+    event_id BIGINT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    start_time TIMESTAMPTZ NOT NULL,
+    end_time TIMESTAMPTZ,
+    title TEXT NOT NULL,
+    speaker TEXT,
+    room TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_time IS NULL OR end_time >= start_time)
+);
+
+CREATE INDEX idx_event_agenda_items_event_start ON event_agenda_items (event_id, start_time);
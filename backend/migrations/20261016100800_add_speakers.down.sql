@@ -0,0 +1,3 @@
+DROP TABLE event_speakers;
+
+DROP TABLE speakers;
@@ -0,0 +1,21 @@
+CREATE TABLE speakers (
+    id BIGSERIAL PRIMARY KEY,
+    organizer_id BIGINT NOT NULL REFERENCES organizers(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    bio TEXT,
+    photo_url TEXT,
+    links TEXT[] NOT NULL DEFAULT '{}',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_speakers_organizer_id ON speakers (organizer_id);
+
+CREATE TABLE event_speakers (
+    event_id BIGINT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    speaker_id BIGINT NOT NULL REFERENCES speakers(id) ON DELETE CASCADE,
+    position INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (event_id, speaker_id)
+);
+
+CREATE INDEX idx_event_speakers_speaker_id ON event_speakers (speaker_id);
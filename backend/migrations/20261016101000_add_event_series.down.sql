@@ -0,0 +1,3 @@
+ALTER TABLE events DROP COLUMN series_id;
+
+DROP TABLE event_series;
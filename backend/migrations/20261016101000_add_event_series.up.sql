@@ -0,0 +1,17 @@
+CREATE TABLE event_series (
+    id BIGSERIAL PRIMARY KEY,
+    organizer_id BIGINT NOT NULL REFERENCES organizers(id) ON DELETE CASCADE,
+    title_de TEXT NOT NULL,
+    title_en TEXT NOT NULL,
+    description_de TEXT,
+    description_en TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_event_series_organizer_id ON event_series (organizer_id);
+
+ALTER TABLE events
+    ADD COLUMN series_id BIGINT REFERENCES event_series(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_events_series_id ON events (series_id) WHERE series_id IS NOT NULL;
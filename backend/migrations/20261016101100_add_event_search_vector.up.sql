@@ -0,0 +1,9 @@
+ALTER TABLE events
+    ADD COLUMN search_vector tsvector GENERATED ALWAYS AS (
+        setweight(to_tsvector('german', coalesce(title_de, '')), 'A') ||
+        setweight(to_tsvector('english', coalesce(title_en, '')), 'A') ||
+        setweight(to_tsvector('german', coalesce(description_de, '')), 'B') ||
+        setweight(to_tsvector('english', coalesce(description_en, '')), 'B')
+    ) STORED;
+
+CREATE INDEX idx_events_search_vector ON events USING GIN (search_vector);
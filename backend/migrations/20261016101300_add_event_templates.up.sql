@@ -0,0 +1,22 @@
+CREATE TABLE event_templates (
+    id BIGSERIAL PRIMARY KEY,
+    organizer_id BIGINT NOT NULL REFERENCES organizers(id) ON DELETE CASCADE,
+    name TEXT NOT NULL,
+    title_de TEXT NOT NULL,
+    title_en TEXT NOT NULL,
+    description_de TEXT,
+    description_en TEXT,
+    event_url TEXT,
+    location TEXT,
+    duration_minutes INTEGER CHECK (duration_minutes IS NULL OR duration_minutes > 0),
+    publish_app BOOLEAN NOT NULL DEFAULT TRUE,
+    publish_newsletter BOOLEAN NOT NULL DEFAULT TRUE,
+    publish_in_ical BOOLEAN NOT NULL DEFAULT TRUE,
+    publish_web BOOLEAN NOT NULL DEFAULT TRUE,
+    max_participants INTEGER,
+    visibility event_visibility NOT NULL DEFAULT 'PUBLIC',
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_event_templates_organizer_id ON event_templates (organizer_id);
@@ -0,0 +1,3 @@
+ALTER TABLE organizers
+    DROP COLUMN event_footer_en,
+    DROP COLUMN event_footer_de;
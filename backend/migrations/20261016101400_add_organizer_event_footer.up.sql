@@ -0,0 +1,3 @@
+ALTER TABLE organizers
+    ADD COLUMN event_footer_de TEXT,
+    ADD COLUMN event_footer_en TEXT;
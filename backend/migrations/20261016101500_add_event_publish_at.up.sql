@@ -0,0 +1,3 @@
+ALTER TABLE events ADD COLUMN publish_at TIMESTAMPTZ;
+
+CREATE INDEX idx_events_publish_at ON events (publish_at) WHERE publish_at IS NOT NULL;
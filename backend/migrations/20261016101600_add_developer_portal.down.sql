@@ -0,0 +1,4 @@
+DROP TABLE IF EXISTS developer_api_key_usage;
+DROP TABLE IF EXISTS developer_api_keys;
+DROP TABLE IF EXISTS developer_accounts;
+DROP TYPE IF EXISTS developer_status;
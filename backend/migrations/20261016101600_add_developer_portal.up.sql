@@ -0,0 +1,41 @@
+CREATE TYPE developer_status AS ENUM ('PENDING', 'APPROVED', 'REJECTED', 'SUSPENDED');
+
+CREATE TABLE developer_accounts (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    email TEXT NOT NULL,
+    organization TEXT,
+    use_case TEXT NOT NULL,
+    status developer_status NOT NULL DEFAULT 'PENDING',
+    daily_quota INTEGER NOT NULL DEFAULT 1000 CHECK (daily_quota > 0),
+    portal_token_hmac BYTEA NOT NULL UNIQUE,
+    reviewed_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    reviewed_at TIMESTAMPTZ,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_developer_accounts_email ON developer_accounts (LOWER(email));
+CREATE INDEX idx_developer_accounts_status ON developer_accounts (status);
+
+CREATE TABLE developer_api_keys (
+    id BIGSERIAL PRIMARY KEY,
+    developer_id BIGINT NOT NULL REFERENCES developer_accounts(id) ON DELETE CASCADE,
+    key_hmac BYTEA NOT NULL UNIQUE,
+    label TEXT NOT NULL DEFAULT '',
+    key_last_four TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ,
+    revoked_at TIMESTAMPTZ
+);
+
+CREATE INDEX idx_developer_api_keys_developer_id ON developer_api_keys (developer_id);
+
+CREATE TABLE developer_api_key_usage (
+    key_id BIGINT NOT NULL REFERENCES developer_api_keys(id) ON DELETE CASCADE,
+    usage_date DATE NOT NULL,
+    request_count BIGINT NOT NULL DEFAULT 0,
+    PRIMARY KEY (key_id, usage_date)
+);
+
+CREATE INDEX developer_api_key_usage_usage_date_idx ON developer_api_key_usage (usage_date);
@@ -0,0 +1,4 @@
+ALTER TABLE events DROP COLUMN IF EXISTS custom_fields;
+
+DROP TABLE IF EXISTS custom_field_definitions;
+DROP TYPE IF EXISTS custom_field_type;
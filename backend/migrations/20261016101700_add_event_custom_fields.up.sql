@@ -0,0 +1,15 @@
+CREATE TYPE custom_field_type AS ENUM ('TEXT', 'NUMBER', 'BOOLEAN', 'DATE', 'DATE_TIME', 'URL');
+
+CREATE TABLE custom_field_definitions (
+    id BIGSERIAL PRIMARY KEY,
+    key TEXT NOT NULL UNIQUE CHECK (key ~ '^[a-z][a-z0-9_]{0,49}$'),
+    label_de TEXT NOT NULL,
+    label_en TEXT NOT NULL,
+    field_type custom_field_type NOT NULL,
+    position INTEGER NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE events
+    ADD COLUMN custom_fields JSONB NOT NULL DEFAULT '{}'::jsonb;
@@ -0,0 +1,6 @@
+DROP INDEX IF EXISTS idx_events_organizer_created_at;
+
+ALTER TABLE organizers
+    DROP COLUMN IF EXISTS max_events_per_day,
+    DROP COLUMN IF EXISTS max_events_per_week,
+    DROP COLUMN IF EXISTS max_future_events;
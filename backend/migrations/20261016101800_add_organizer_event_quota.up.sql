@@ -0,0 +1,6 @@
+ALTER TABLE organizers
+    ADD COLUMN max_events_per_day INTEGER CHECK (max_events_per_day >= 0),
+    ADD COLUMN max_events_per_week INTEGER CHECK (max_events_per_week >= 0),
+    ADD COLUMN max_future_events INTEGER CHECK (max_future_events >= 0);
+
+CREATE INDEX IF NOT EXISTS idx_events_organizer_created_at ON events (organizer_id, created_at);
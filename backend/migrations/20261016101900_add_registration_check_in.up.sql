@@ -0,0 +1,12 @@
+ALTER TABLE registrations
+    ADD COLUMN check_in_code TEXT,
+    ADD COLUMN checked_in_at TIMESTAMPTZ,
+    ADD COLUMN checked_in_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL;
+
+UPDATE registrations
+SET check_in_code = md5(random()::text || id::text || clock_timestamp()::text);
+
+ALTER TABLE registrations
+    ALTER COLUMN check_in_code SET NOT NULL;
+
+CREATE UNIQUE INDEX idx_registrations_check_in_code ON registrations (check_in_code);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS newsletter_issues;
+DROP TYPE IF EXISTS newsletter_ordering;
+DROP TYPE IF EXISTS newsletter_grouping;
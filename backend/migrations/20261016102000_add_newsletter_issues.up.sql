@@ -0,0 +1,11 @@
+CREATE TYPE newsletter_grouping AS ENUM ('DAY', 'ORGANIZER');
+CREATE TYPE newsletter_ordering AS ENUM ('START_TIME', 'TITLE', 'ORGANIZER');
+
+CREATE TABLE newsletter_issues (
+    week_start DATE PRIMARY KEY CHECK (EXTRACT(ISODOW FROM week_start) = 1),
+    grouping newsletter_grouping NOT NULL DEFAULT 'DAY',
+    ordering newsletter_ordering NOT NULL DEFAULT 'START_TIME',
+    updated_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS organizer_onboarding_milestones;
+DROP TYPE IF EXISTS onboarding_milestone;
@@ -0,0 +1,42 @@
+CREATE TYPE onboarding_milestone AS ENUM (
+    'ACCOUNT_INITIALIZED',
+    'PROFILE_COMPLETED',
+    'FIRST_EVENT_CREATED',
+    'FIRST_NEWSLETTER_INCLUSION'
+);
+
+CREATE TABLE organizer_onboarding_milestones (
+    organizer_id BIGINT NOT NULL REFERENCES organizers(id) ON DELETE CASCADE,
+    milestone onboarding_milestone NOT NULL,
+    reached_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (organizer_id, milestone)
+);
+
+INSERT INTO organizer_onboarding_milestones (organizer_id, milestone, reached_at)
+SELECT organizer_id, 'ACCOUNT_INITIALIZED', MIN(updated_at)
+FROM accounts
+WHERE organizer_id IS NOT NULL AND password_hash IS NOT NULL
+GROUP BY organizer_id;
+
+INSERT INTO organizer_onboarding_milestones (organizer_id, milestone, reached_at)
+SELECT id, 'PROFILE_COMPLETED', updated_at
+FROM organizers
+WHERE NULLIF(TRIM(description_de), '') IS NOT NULL
+  AND NULLIF(TRIM(description_en), '') IS NOT NULL
+  AND COALESCE(NULLIF(TRIM(website_url), ''), NULLIF(TRIM(instagram_url), ''), NULLIF(TRIM(linkedin_url), '')) IS NOT NULL;
+
+INSERT INTO organizer_onboarding_milestones (organizer_id, milestone, reached_at)
+SELECT organizer_id, 'FIRST_EVENT_CREATED', MIN(created_at)
+FROM events
+GROUP BY organizer_id;
+
+INSERT INTO organizer_onboarding_milestones (organizer_id, milestone, reached_at)
+SELECT e.organizer_id, 'FIRST_NEWSLETTER_INCLUSION', MIN(e.start_date_time)
+FROM events e
+JOIN organizers o ON o.id = e.organizer_id
+WHERE e.publish_newsletter = true
+  AND e.status = 'PUBLISHED'
+  AND e.visibility = 'PUBLIC'
+  AND o.organizer_kind = 'STUDENT_ASSOCIATION'
+  AND e.start_date_time < NOW()
+GROUP BY e.organizer_id;
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_events_free_entry;
+
+ALTER TABLE events
+    DROP CONSTRAINT IF EXISTS events_free_entry_price_check,
+    DROP COLUMN IF EXISTS free_entry,
+    DROP COLUMN IF EXISTS currency,
+    DROP COLUMN IF EXISTS price_cents;
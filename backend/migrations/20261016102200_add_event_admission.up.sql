@@ -0,0 +1,7 @@
+ALTER TABLE events
+    ADD COLUMN price_cents INTEGER CHECK (price_cents IS NULL OR price_cents >= 0),
+    ADD COLUMN currency TEXT NOT NULL DEFAULT 'EUR' CHECK (currency ~ '^[A-Z]{3}$'),
+    ADD COLUMN free_entry BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD CONSTRAINT events_free_entry_price_check CHECK (NOT free_entry OR COALESCE(price_cents, 0) = 0);
+
+CREATE INDEX idx_events_free_entry ON events (free_entry) WHERE free_entry;
@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS organizer_feed_tombstone_events;
+DROP TABLE IF EXISTS organizer_feed_tombstones;
@@ -0,0 +1,19 @@
+CREATE TABLE organizer_feed_tombstones (
+    organizer_id BIGINT PRIMARY KEY,
+    organizer_name TEXT NOT NULL,
+    deleted_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE TABLE organizer_feed_tombstone_events (
+    organizer_id BIGINT NOT NULL REFERENCES organizer_feed_tombstones(organizer_id) ON DELETE CASCADE,
+    event_id BIGINT NOT NULL,
+    title_de TEXT NOT NULL,
+    title_en TEXT NOT NULL,
+    start_date_time TIMESTAMPTZ NOT NULL,
+    end_date_time TIMESTAMPTZ NOT NULL,
+    location TEXT,
+    PRIMARY KEY (organizer_id, event_id)
+);
+
+CREATE INDEX idx_organizer_feed_tombstones_expires_at ON organizer_feed_tombstones (expires_at);
@@ -0,0 +1,7 @@
+DROP INDEX IF EXISTS idx_events_audience;
+
+ALTER TABLE events
+    DROP CONSTRAINT IF EXISTS events_audience_not_empty,
+    DROP COLUMN IF EXISTS audience;
+
+DROP TYPE IF EXISTS event_audience;
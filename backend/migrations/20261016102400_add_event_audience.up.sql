@@ -0,0 +1,7 @@
+CREATE TYPE event_audience AS ENUM ('STUDENTS', 'STAFF', 'ALUMNI', 'PUBLIC');
+
+ALTER TABLE events
+    ADD COLUMN audience event_audience[] NOT NULL DEFAULT ARRAY['STUDENTS', 'STAFF', 'ALUMNI', 'PUBLIC']::event_audience[],
+    ADD CONSTRAINT events_audience_not_empty CHECK (cardinality(audience) > 0);
+
+CREATE INDEX idx_events_audience ON events USING GIN (audience);
@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS email_log;
+DROP TYPE IF EXISTS email_send_status;
+DROP TYPE IF EXISTS email_template;
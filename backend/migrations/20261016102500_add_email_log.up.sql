@@ -0,0 +1,25 @@
+CREATE TYPE email_template AS ENUM (
+    'ORGANIZER_INVITE',
+    'ADMIN_INVITE',
+    'WELCOME',
+    'PASSWORD_RESET',
+    'NEWSLETTER_PREVIEW',
+    'EVENT_REVIEW_DECISION',
+    'EVENT_CHANGE_NOTIFICATION'
+);
+
+CREATE TYPE email_send_status AS ENUM ('SENT', 'FAILED');
+
+CREATE TABLE email_log (
+    id BIGSERIAL PRIMARY KEY,
+    recipient_hash TEXT NOT NULL,
+    template email_template NOT NULL,
+    status email_send_status NOT NULL,
+    message_id TEXT,
+    provider_message_id TEXT,
+    error TEXT,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_email_log_recipient_hash ON email_log (recipient_hash, created_at DESC);
+CREATE INDEX idx_email_log_created_at ON email_log (created_at DESC);
@@ -0,0 +1,20 @@
+CREATE TABLE venues (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    building TEXT,
+    room TEXT,
+    latitude DOUBLE PRECISION,
+    longitude DOUBLE PRECISION,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT venues_name_not_empty CHECK (length(trim(name)) > 0),
+    CONSTRAINT venues_coordinates_pair CHECK ((latitude IS NULL) = (longitude IS NULL)),
+    CONSTRAINT venues_latitude_range CHECK (latitude BETWEEN -90 AND 90),
+    CONSTRAINT venues_longitude_range CHECK (longitude BETWEEN -180 AND 180)
+);
+
+CREATE UNIQUE INDEX idx_venues_identity ON venues (lower(name), lower(COALESCE(building, '')), lower(COALESCE(room, '')));
+
+ALTER TABLE events ADD COLUMN venue_id BIGINT REFERENCES venues(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_events_venue_id ON events (venue_id);
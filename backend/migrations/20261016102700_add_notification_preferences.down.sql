@@ -0,0 +1,12 @@
+DELETE FROM email_log WHERE status = 'SKIPPED';
+
+ALTER TABLE email_log ALTER COLUMN status TYPE TEXT;
+DROP TYPE email_send_status;
+CREATE TYPE email_send_status AS ENUM ('SENT', 'FAILED');
+ALTER TABLE email_log ALTER COLUMN status TYPE email_send_status USING status::email_send_status;
+
+ALTER TABLE accounts
+    DROP COLUMN IF EXISTS notify_broadcast,
+    DROP COLUMN IF EXISTS notify_audit_alerts,
+    DROP COLUMN IF EXISTS notify_digests,
+    DROP COLUMN IF EXISTS notify_reminders;
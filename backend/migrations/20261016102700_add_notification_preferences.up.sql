@@ -0,0 +1,7 @@
+ALTER TABLE accounts
+    ADD COLUMN notify_reminders BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN notify_digests BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN notify_audit_alerts BOOLEAN NOT NULL DEFAULT TRUE,
+    ADD COLUMN notify_broadcast BOOLEAN NOT NULL DEFAULT TRUE;
+
+ALTER TYPE email_send_status ADD VALUE IF NOT EXISTS 'SKIPPED';
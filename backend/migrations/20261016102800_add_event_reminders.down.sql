@@ -0,0 +1,16 @@
+DROP TABLE IF EXISTS event_reminders;
+
+DELETE FROM email_log WHERE template = 'EVENT_REMINDER';
+
+ALTER TABLE email_log ALTER COLUMN template TYPE TEXT;
+DROP TYPE email_template;
+CREATE TYPE email_template AS ENUM (
+    'ORGANIZER_INVITE',
+    'ADMIN_INVITE',
+    'WELCOME',
+    'PASSWORD_RESET',
+    'NEWSLETTER_PREVIEW',
+    'EVENT_REVIEW_DECISION',
+    'EVENT_CHANGE_NOTIFICATION'
+);
+ALTER TABLE email_log ALTER COLUMN template TYPE email_template USING template::email_template;
@@ -0,0 +1,8 @@
+ALTER TYPE email_template ADD VALUE IF NOT EXISTS 'EVENT_REMINDER';
+
+CREATE TABLE event_reminders (
+    event_id BIGINT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    lead_days INTEGER NOT NULL CHECK (lead_days > 0),
+    sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (event_id, lead_days)
+);
@@ -0,0 +1,15 @@
+CREATE TABLE newsletter_sponsors (
+    id BIGSERIAL PRIMARY KEY,
+    name TEXT NOT NULL,
+    logo_url TEXT,
+    website_url TEXT,
+    position INTEGER NOT NULL DEFAULT 0,
+    active_from DATE,
+    active_until DATE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT newsletter_sponsors_name_not_empty CHECK (length(trim(name)) > 0),
+    CONSTRAINT newsletter_sponsors_active_window CHECK (
+        active_from IS NULL OR active_until IS NULL OR active_until >= active_from
+    )
+);
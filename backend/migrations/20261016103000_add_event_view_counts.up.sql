@@ -0,0 +1,6 @@
+CREATE TABLE event_view_counts (
+    event_id BIGINT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    view_date DATE NOT NULL,
+    views BIGINT NOT NULL DEFAULT 0 CHECK (views >= 0),
+    PRIMARY KEY (event_id, view_date)
+);
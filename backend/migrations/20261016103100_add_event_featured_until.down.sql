@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_events_featured_until;
+
+ALTER TABLE events DROP COLUMN IF EXISTS featured_until;
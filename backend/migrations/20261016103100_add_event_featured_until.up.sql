@@ -0,0 +1,3 @@
+ALTER TABLE events ADD COLUMN featured_until TIMESTAMPTZ;
+
+CREATE INDEX idx_events_featured_until ON events (featured_until) WHERE featured_until IS NOT NULL;
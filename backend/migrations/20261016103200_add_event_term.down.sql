@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_events_term;
+
+ALTER TABLE events DROP COLUMN IF EXISTS term;
@@ -0,0 +1,21 @@
+ALTER TABLE events ADD COLUMN term TEXT;
+
+UPDATE events e
+SET term = CASE
+    WHEN local.day < make_date(local.year, 3, 15)
+        THEN 'WS' || lpad(((local.year - 1) % 100)::text, 2, '0') || '/' || lpad((local.year % 100)::text, 2, '0')
+    WHEN local.day < make_date(local.year, 10, 1)
+        THEN 'SS' || lpad((local.year % 100)::text, 2, '0')
+    ELSE 'WS' || lpad((local.year % 100)::text, 2, '0') || '/' || lpad(((local.year + 1) % 100)::text, 2, '0')
+END
+FROM (
+    SELECT id,
+           (start_date_time AT TIME ZONE 'Europe/Berlin')::date AS day,
+           EXTRACT(YEAR FROM start_date_time AT TIME ZONE 'Europe/Berlin')::int AS year
+    FROM events
+) AS local
+WHERE local.id = e.id;
+
+ALTER TABLE events ALTER COLUMN term SET NOT NULL;
+
+CREATE INDEX idx_events_term ON events (term);
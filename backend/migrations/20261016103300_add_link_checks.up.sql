@@ -0,0 +1,9 @@
+CREATE TABLE link_checks (
+    url TEXT PRIMARY KEY,
+    ok BOOLEAN NOT NULL,
+    status_code INTEGER,
+    error TEXT,
+    checked_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_link_checks_broken ON link_checks (checked_at) WHERE NOT ok;
@@ -0,0 +1,5 @@
+ALTER TABLE venues
+    DROP CONSTRAINT IF EXISTS venues_close_requires_capacity,
+    DROP CONSTRAINT IF EXISTS venues_capacity_positive,
+    DROP COLUMN IF EXISTS close_registration_at_capacity,
+    DROP COLUMN IF EXISTS capacity;
@@ -0,0 +1,5 @@
+ALTER TABLE venues
+    ADD COLUMN capacity INTEGER,
+    ADD COLUMN close_registration_at_capacity BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD CONSTRAINT venues_capacity_positive CHECK (capacity IS NULL OR capacity > 0),
+    ADD CONSTRAINT venues_close_requires_capacity CHECK (NOT close_registration_at_capacity OR capacity IS NOT NULL);
@@ -0,0 +1,6 @@
+ALTER TABLE organizers
+    DROP CONSTRAINT IF EXISTS organizers_calendar_refresh_range,
+    DROP CONSTRAINT IF EXISTS organizers_calendar_color_hex,
+    DROP COLUMN IF EXISTS calendar_refresh_minutes,
+    DROP COLUMN IF EXISTS calendar_color,
+    DROP COLUMN IF EXISTS calendar_name;
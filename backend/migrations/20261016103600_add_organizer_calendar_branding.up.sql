@@ -0,0 +1,6 @@
+ALTER TABLE organizers
+    ADD COLUMN calendar_name TEXT,
+    ADD COLUMN calendar_color TEXT,
+    ADD COLUMN calendar_refresh_minutes INTEGER,
+    ADD CONSTRAINT organizers_calendar_color_hex CHECK (calendar_color IS NULL OR calendar_color ~ '^#[0-9A-F]{6}$'),
+    ADD CONSTRAINT organizers_calendar_refresh_range CHECK (calendar_refresh_minutes IS NULL OR calendar_refresh_minutes BETWEEN 15 AND 10080);
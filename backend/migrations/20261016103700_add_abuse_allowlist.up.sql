@@ -0,0 +1,6 @@
+CREATE TABLE abuse_allowlist (
+    ip TEXT PRIMARY KEY,
+    note TEXT,
+    created_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
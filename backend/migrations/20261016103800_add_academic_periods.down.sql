@@ -0,0 +1,2 @@
+DROP TABLE IF EXISTS academic_periods;
+DROP TYPE IF EXISTS academic_period_kind;
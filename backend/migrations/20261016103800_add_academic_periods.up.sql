@@ -0,0 +1,16 @@
+CREATE TYPE academic_period_kind AS ENUM ('LECTURE_BREAK', 'EXAM_PERIOD', 'HOLIDAY');
+
+CREATE TABLE academic_periods (
+    id BIGSERIAL PRIMARY KEY,
+    kind academic_period_kind NOT NULL,
+    name_de TEXT NOT NULL,
+    name_en TEXT NOT NULL,
+    starts_on DATE NOT NULL,
+    ends_on DATE NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CONSTRAINT academic_periods_names_not_empty CHECK (length(trim(name_de)) > 0 AND length(trim(name_en)) > 0),
+    CONSTRAINT academic_periods_range CHECK (ends_on >= starts_on)
+);
+
+CREATE INDEX idx_academic_periods_range ON academic_periods (starts_on, ends_on);
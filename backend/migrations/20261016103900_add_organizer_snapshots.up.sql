@@ -0,0 +1,9 @@
+CREATE TABLE organizer_snapshots (
+    id BIGSERIAL PRIMARY KEY,
+    organizer_id BIGINT NOT NULL REFERENCES organizers(id) ON DELETE CASCADE,
+    account_id BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    data JSONB NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_organizer_snapshots_organizer ON organizer_snapshots (organizer_id, created_at DESC);
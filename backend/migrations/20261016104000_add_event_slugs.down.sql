@@ -0,0 +1,3 @@
+DROP TABLE IF EXISTS event_slug_history;
+DROP INDEX IF EXISTS idx_events_slug;
+ALTER TABLE events DROP COLUMN IF EXISTS slug;
@@ -0,0 +1,25 @@
+ALTER TABLE events ADD COLUMN slug TEXT;
+
+UPDATE events
+SET slug = concat_ws(
+    '-',
+    NULLIF(
+        trim(both '-' from left(regexp_replace(
+            replace(replace(replace(replace(lower(title_de), 'ä', 'ae'), 'ö', 'oe'), 'ü', 'ue'), 'ß', 'ss'),
+            '[^a-z0-9]+', '-', 'g'
+        ), 60)),
+        ''
+    ),
+    to_char(start_date_time AT TIME ZONE 'Europe/Berlin', 'YYYY'),
+    id::text
+);
+
+CREATE UNIQUE INDEX idx_events_slug ON events (slug);
+
+CREATE TABLE event_slug_history (
+    slug TEXT PRIMARY KEY,
+    event_id BIGINT NOT NULL REFERENCES events(id) ON DELETE CASCADE,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_event_slug_history_event ON event_slug_history (event_id);
@@ -0,0 +1,4 @@
+DROP INDEX IF EXISTS idx_email_log_request_id;
+ALTER TABLE email_log
+    DROP COLUMN IF EXISTS account_id,
+    DROP COLUMN IF EXISTS request_id;
@@ -0,0 +1,5 @@
+ALTER TABLE email_log
+    ADD COLUMN request_id TEXT,
+    ADD COLUMN account_id BIGINT REFERENCES accounts(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_email_log_request_id ON email_log (request_id) WHERE request_id IS NOT NULL;
@@ -0,0 +1,23 @@
+ALTER TABLE newsletter_issues
+    DROP COLUMN IF EXISTS failed_count,
+    DROP COLUMN IF EXISTS recipient_count,
+    DROP COLUMN IF EXISTS sent_subject,
+    DROP COLUMN IF EXISTS sent_by,
+    DROP COLUMN IF EXISTS sent_at,
+    DROP COLUMN IF EXISTS send_started_at;
+
+DELETE FROM email_log WHERE template = 'NEWSLETTER';
+
+ALTER TABLE email_log ALTER COLUMN template TYPE TEXT;
+DROP TYPE email_template;
+CREATE TYPE email_template AS ENUM (
+    'ORGANIZER_INVITE',
+    'ADMIN_INVITE',
+    'WELCOME',
+    'PASSWORD_RESET',
+    'NEWSLETTER_PREVIEW',
+    'EVENT_REVIEW_DECISION',
+    'EVENT_CHANGE_NOTIFICATION',
+    'EVENT_REMINDER'
+);
+ALTER TABLE email_log ALTER COLUMN template TYPE email_template USING template::email_template;
@@ -0,0 +1,9 @@
+ALTER TYPE email_template ADD VALUE IF NOT EXISTS 'NEWSLETTER';
+
+ALTER TABLE newsletter_issues
+    ADD COLUMN send_started_at TIMESTAMPTZ,
+    ADD COLUMN sent_at TIMESTAMPTZ,
+    ADD COLUMN sent_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    ADD COLUMN sent_subject TEXT,
+    ADD COLUMN recipient_count INTEGER,
+    ADD COLUMN failed_count INTEGER;
@@ -0,0 +1,4 @@
+CREATE TABLE newsletter_unsubscribes (
+    recipient_hash TEXT PRIMARY KEY,
+    unsubscribed_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
@@ -0,0 +1,19 @@
+ALTER TABLE newsletter_issues
+    DROP COLUMN IF EXISTS scheduled_run_at;
+
+DELETE FROM email_log WHERE template = 'NEWSLETTER_SCHEDULE_REPORT';
+
+ALTER TABLE email_log ALTER COLUMN template TYPE TEXT;
+DROP TYPE email_template;
+CREATE TYPE email_template AS ENUM (
+    'ORGANIZER_INVITE',
+    'ADMIN_INVITE',
+    'WELCOME',
+    'PASSWORD_RESET',
+    'NEWSLETTER_PREVIEW',
+    'EVENT_REVIEW_DECISION',
+    'EVENT_CHANGE_NOTIFICATION',
+    'EVENT_REMINDER',
+    'NEWSLETTER'
+);
+ALTER TABLE email_log ALTER COLUMN template TYPE email_template USING template::email_template;
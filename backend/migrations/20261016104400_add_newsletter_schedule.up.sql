@@ -0,0 +1,4 @@
+ALTER TYPE email_template ADD VALUE IF NOT EXISTS 'NEWSLETTER_SCHEDULE_REPORT';
+
+ALTER TABLE newsletter_issues
+    ADD COLUMN scheduled_run_at TIMESTAMPTZ;
@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_newsletter_issues_sent_at;
+
+ALTER TABLE newsletter_issues
+    DROP COLUMN IF EXISTS sent_event_ids,
+    DROP COLUMN IF EXISTS sent_html;
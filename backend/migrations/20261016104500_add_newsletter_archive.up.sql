@@ -0,0 +1,5 @@
+ALTER TABLE newsletter_issues
+    ADD COLUMN sent_html TEXT,
+    ADD COLUMN sent_event_ids BIGINT[] NOT NULL DEFAULT '{}';
+
+CREATE INDEX idx_newsletter_issues_sent_at ON newsletter_issues (sent_at DESC) WHERE sent_at IS NOT NULL;
@@ -0,0 +1,33 @@
+CREATE TABLE newsletter_templates (
+    version SERIAL PRIMARY KEY,
+    header_title TEXT NOT NULL,
+    greeting TEXT NOT NULL,
+    intro TEXT NOT NULL,
+    events_heading TEXT NOT NULL,
+    outlook_heading TEXT NOT NULL,
+    no_events_text TEXT NOT NULL,
+    no_outlook_text TEXT NOT NULL,
+    sponsors_heading TEXT NOT NULL,
+    footer_title TEXT NOT NULL,
+    footer_tagline TEXT NOT NULL,
+    footer_note TEXT NOT NULL,
+    created_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO newsletter_templates (
+    header_title, greeting, intro, events_heading, outlook_heading, no_events_text,
+    no_outlook_text, sponsors_heading, footer_title, footer_tagline, footer_note
+) VALUES (
+    'Campus Life Newsletter',
+    'Hallo zusammen!',
+    'Hier sind die kommenden Veranstaltungen für euch zusammengestellt. Viel Spaß bei den Events!',
+    'Events der Organisationen',
+    'Ausblick Kalenderwoche',
+    'Keine Veranstaltungen diese Woche.',
+    'Keine Veranstaltungen geplant.',
+    'Unterstützt von',
+    'Campus Life Events',
+    'Der Newsletter für studentische Veranstaltungen',
+    E'Den Campus Life Newsletter nicht mehr empfangen? Melden Sie sich unter https://sympa.thi.de/ an (THI-Login rechts oben). Dann auf Meine Listen (links) → students-campuslife → Abbestellen (links) → Bestätigen.\n\nNo longer receiving the Campus Life Newsletter? Log in at https://sympa.thi.de/ (THI login at the top right). Then go to My lists (left) → students-campuslife → Unsubscribe (left) → Confirm.'
+);
@@ -0,0 +1,5 @@
+ALTER TABLE newsletter_issues
+    DROP COLUMN IF EXISTS outro_en,
+    DROP COLUMN IF EXISTS outro_de,
+    DROP COLUMN IF EXISTS intro_en,
+    DROP COLUMN IF EXISTS intro_de;
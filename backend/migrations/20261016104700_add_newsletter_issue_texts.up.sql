@@ -0,0 +1,5 @@
+ALTER TABLE newsletter_issues
+    ADD COLUMN intro_de TEXT,
+    ADD COLUMN intro_en TEXT,
+    ADD COLUMN outro_de TEXT,
+    ADD COLUMN outro_en TEXT;
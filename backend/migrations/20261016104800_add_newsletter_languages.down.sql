@@ -0,0 +1,11 @@
+ALTER TABLE newsletter_issues
+    DROP COLUMN IF EXISTS sent_html_en;
+
+DELETE FROM newsletter_templates WHERE language = 'EN';
+
+DROP INDEX IF EXISTS idx_newsletter_templates_language_version;
+
+ALTER TABLE newsletter_templates
+    DROP COLUMN IF EXISTS language;
+
+DROP TYPE IF EXISTS newsletter_language;
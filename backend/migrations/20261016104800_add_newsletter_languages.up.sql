@@ -0,0 +1,27 @@
+CREATE TYPE newsletter_language AS ENUM ('DE', 'EN');
+
+ALTER TABLE newsletter_templates
+    ADD COLUMN language newsletter_language NOT NULL DEFAULT 'DE';
+
+CREATE INDEX idx_newsletter_templates_language_version ON newsletter_templates (language, version DESC);
+
+INSERT INTO newsletter_templates (
+    language, header_title, greeting, intro, events_heading, outlook_heading, no_events_text,
+    no_outlook_text, sponsors_heading, footer_title, footer_tagline, footer_note
+) VALUES (
+    'EN',
+    'Campus Life Newsletter',
+    'Hi everyone!',
+    'Here are the upcoming events we put together for you. Have fun!',
+    'Events by student organizations',
+    'Preview calendar week',
+    'No events this week.',
+    'No events planned yet.',
+    'Supported by',
+    'Campus Life Events',
+    'The newsletter for student events',
+    E'No longer want to receive the Campus Life Newsletter? Log in at https://sympa.thi.de/ (THI login at the top right). Then go to My lists (left) → students-campuslife → Unsubscribe (left) → Confirm.'
+);
+
+ALTER TABLE newsletter_issues
+    ADD COLUMN sent_html_en TEXT;
@@ -0,0 +1,15 @@
+CREATE TABLE newsletter_issue_events (
+    week_start DATE NOT NULL REFERENCES newsletter_issues(week_start) ON DELETE CASCADE,
+    event_id BIGINT NOT NULL,
+    title_de TEXT NOT NULL,
+    title_en TEXT NOT NULL,
+    start_date_time TIMESTAMPTZ NOT NULL,
+    end_date_time TIMESTAMPTZ NOT NULL,
+    PRIMARY KEY (week_start, event_id)
+);
+
+INSERT INTO newsletter_issue_events (week_start, event_id, title_de, title_en, start_date_time, end_date_time)
+SELECT i.week_start, e.id, e.title_de, e.title_en, e.start_date_time, e.end_date_time
+FROM newsletter_issues i
+JOIN events e ON e.id = ANY(i.sent_event_ids)
+WHERE i.sent_at IS NOT NULL;
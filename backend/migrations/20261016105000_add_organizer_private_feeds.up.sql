@@ -0,0 +1,8 @@
+CREATE TABLE organizer_private_feeds (
+    organizer_id BIGINT PRIMARY KEY REFERENCES organizers(id) ON DELETE CASCADE,
+    token_hmac BYTEA NOT NULL UNIQUE,
+    token_last_four TEXT NOT NULL,
+    created_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    last_used_at TIMESTAMPTZ
+);
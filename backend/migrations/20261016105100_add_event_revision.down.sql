@@ -0,0 +1,3 @@
+ALTER TABLE organizer_feed_tombstone_events DROP COLUMN IF EXISTS revision;
+
+ALTER TABLE events DROP COLUMN IF EXISTS revision;
@@ -0,0 +1,3 @@
+ALTER TABLE events ADD COLUMN revision INTEGER NOT NULL DEFAULT 0;
+
+ALTER TABLE organizer_feed_tombstone_events ADD COLUMN revision INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,6 @@
+CREATE TABLE organizer_ical_tokens (
+    organizer_id BIGINT PRIMARY KEY REFERENCES organizers(id) ON DELETE CASCADE,
+    version INTEGER NOT NULL DEFAULT 1,
+    created_by BIGINT REFERENCES accounts(id) ON DELETE SET NULL,
+    created_at TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
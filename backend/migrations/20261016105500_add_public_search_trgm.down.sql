@@ -0,0 +1,5 @@
+DROP INDEX IF EXISTS idx_organizers_name_trgm;
+DROP INDEX IF EXISTS idx_events_description_en_trgm;
+DROP INDEX IF EXISTS idx_events_description_de_trgm;
+DROP INDEX IF EXISTS idx_events_title_en_trgm;
+DROP INDEX IF EXISTS idx_events_title_de_trgm;
@@ -0,0 +1,7 @@
+CREATE EXTENSION IF NOT EXISTS pg_trgm;
+
+CREATE INDEX idx_events_title_de_trgm ON events USING GIN (title_de gin_trgm_ops);
+CREATE INDEX idx_events_title_en_trgm ON events USING GIN (title_en gin_trgm_ops);
+CREATE INDEX idx_events_description_de_trgm ON events USING GIN (description_de gin_trgm_ops);
+CREATE INDEX idx_events_description_en_trgm ON events USING GIN (description_en gin_trgm_ops);
+CREATE INDEX idx_organizers_name_trgm ON organizers USING GIN (name gin_trgm_ops);
@@ -0,0 +1,23 @@
+ALTER TABLE accounts
+    DROP COLUMN IF EXISTS email_verification_attempts,
+    DROP COLUMN IF EXISTS email_verification_expires_at,
+    DROP COLUMN IF EXISTS email_verification_code_hash,
+    DROP COLUMN IF EXISTS email_verified_at;
+
+DELETE FROM email_log WHERE template = 'EMAIL_VERIFICATION';
+
+ALTER TABLE email_log ALTER COLUMN template TYPE TEXT;
+DROP TYPE email_template;
+CREATE TYPE email_template AS ENUM (
+    'ORGANIZER_INVITE',
+    'ADMIN_INVITE',
+    'WELCOME',
+    'PASSWORD_RESET',
+    'NEWSLETTER_PREVIEW',
+    'EVENT_REVIEW_DECISION',
+    'EVENT_CHANGE_NOTIFICATION',
+    'EVENT_REMINDER',
+    'NEWSLETTER',
+    'NEWSLETTER_SCHEDULE_REPORT'
+);
+ALTER TABLE email_log ALTER COLUMN template TYPE email_template USING template::email_template;
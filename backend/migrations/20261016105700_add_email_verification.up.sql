@@ -0,0 +1,11 @@
+ALTER TYPE email_template ADD VALUE IF NOT EXISTS 'EMAIL_VERIFICATION';
+
+ALTER TABLE accounts
+    ADD COLUMN email_verified_at TIMESTAMPTZ,
+    ADD COLUMN email_verification_code_hash TEXT,
+    ADD COLUMN email_verification_expires_at TIMESTAMPTZ,
+    ADD COLUMN email_verification_attempts INTEGER NOT NULL DEFAULT 0;
+
+UPDATE accounts
+SET email_verified_at = created_at
+WHERE password_hash IS NOT NULL;
@@ -0,0 +1,5 @@
+ALTER TABLE api_tokens
+    DROP COLUMN IF EXISTS organizer_id,
+    DROP COLUMN IF EXISTS scopes;
+
+DROP TYPE IF EXISTS api_token_scope;
@@ -0,0 +1,5 @@
+CREATE TYPE api_token_scope AS ENUM ('FULL', 'ICAL_READ', 'EVENTS_READ', 'EVENTS_WRITE');
+
+ALTER TABLE api_tokens
+    ADD COLUMN scopes api_token_scope[] NOT NULL DEFAULT '{FULL}',
+    ADD COLUMN organizer_id BIGINT REFERENCES organizers(id) ON DELETE CASCADE;
@@ -0,0 +1,22 @@
+ALTER TABLE event_subscriptions
+    DROP COLUMN IF EXISTS confirmation_sent_at,
+    DROP COLUMN IF EXISTS confirmed_at;
+
+DELETE FROM email_log WHERE template = 'EVENT_SUBSCRIPTION_CONFIRMATION';
+
+ALTER TABLE email_log ALTER COLUMN template TYPE TEXT;
+DROP TYPE email_template;
+CREATE TYPE email_template AS ENUM (
+    'ORGANIZER_INVITE',
+    'ADMIN_INVITE',
+    'WELCOME',
+    'PASSWORD_RESET',
+    'NEWSLETTER_PREVIEW',
+    'EVENT_REVIEW_DECISION',
+    'EVENT_CHANGE_NOTIFICATION',
+    'EVENT_REMINDER',
+    'NEWSLETTER',
+    'NEWSLETTER_SCHEDULE_REPORT',
+    'EMAIL_VERIFICATION'
+);
+ALTER TABLE email_log ALTER COLUMN template TYPE email_template USING template::email_template;
@@ -0,0 +1,5 @@
+ALTER TYPE email_template ADD VALUE IF NOT EXISTS 'EVENT_SUBSCRIPTION_CONFIRMATION';
+
+ALTER TABLE event_subscriptions
+    ADD COLUMN confirmed_at TIMESTAMPTZ,
+    ADD COLUMN confirmation_sent_at TIMESTAMPTZ NOT NULL DEFAULT NOW();
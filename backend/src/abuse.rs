@@ -0,0 +1,127 @@
+use std::{
+    env,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+
+use axum::{extract::ConnectInfo, http::HeaderMap};
+
+const DEFAULT_WINDOW_SECONDS: u64 = 60;
+const DEFAULT_TARPIT_THRESHOLD: u64 = 300;
+const DEFAULT_BLOCK_THRESHOLD: u64 = 1200;
+const DEFAULT_TARPIT_DELAY_MS: u64 = 1000;
+const DEFAULT_BLOCK_SECONDS: u64 = 600;
+
+#[derive(Debug, Clone, Copy)]
+pub struct AbuseSettings {
+    pub window: Duration,
+    pub tarpit_threshold: Option<u64>,
+    pub block_threshold: Option<u64>,
+    pub tarpit_delay: Duration,
+    pub block_duration: Duration,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbuseVerdict {
+    Allow,
+    Tarpit(Duration),
+    Block(Duration),
+}
+
+impl AbuseSettings {
+    pub fn from_env() -> Self {
+        let number = |name: &str| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let threshold = |name: &str, default: u64| match number(name) {
+            Some(0) => None,
+            Some(value) => Some(value),
+            None => Some(default),
+        };
+
+        Self {
+            window: Duration::from_secs(
+                number("ABUSE_WINDOW_SECONDS")
+                    .filter(|seconds| *seconds > 0)
+                    .unwrap_or(DEFAULT_WINDOW_SECONDS),
+            ),
+            tarpit_threshold: threshold("ABUSE_TARPIT_THRESHOLD", DEFAULT_TARPIT_THRESHOLD),
+            block_threshold: threshold("ABUSE_BLOCK_THRESHOLD", DEFAULT_BLOCK_THRESHOLD),
+            tarpit_delay: Duration::from_millis(
+                number("ABUSE_TARPIT_DELAY_MS").unwrap_or(DEFAULT_TARPIT_DELAY_MS),
+            ),
+            block_duration: Duration::from_secs(
+                number("ABUSE_BLOCK_SECONDS")
+                    .filter(|seconds| *seconds > 0)
+                    .unwrap_or(DEFAULT_BLOCK_SECONDS),
+            ),
+        }
+    }
+
+    pub fn enabled(&self) -> bool {
+        self.tarpit_threshold.is_some() || self.block_threshold.is_some()
+    }
+
+    pub fn verdict(&self, requests_in_window: u64) -> AbuseVerdict {
+        if self
+            .block_threshold
+            .is_some_and(|threshold| requests_in_window > threshold)
+        {
+            AbuseVerdict::Block(self.block_duration)
+        } else if self
+            .tarpit_threshold
+            .is_some_and(|threshold| requests_in_window > threshold)
+        {
+            AbuseVerdict::Tarpit(self.tarpit_delay)
+        } else {
+            AbuseVerdict::Allow
+        }
+    }
+}
+
+pub fn normalize_ip(raw: &str) -> Option<IpAddr> {
+    let ip: IpAddr = raw.trim().parse().ok()?;
+    Some(ip.to_canonical())
+}
+
+pub fn client_ip(headers: &HeaderMap, peer: Option<&ConnectInfo<SocketAddr>>) -> Option<IpAddr> {
+    headers
+        .get("x-forwarded-for")
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.rsplit(',').next())
+        .and_then(normalize_ip)
+        .or_else(|| {
+            headers
+                .get("x-real-ip")
+                .and_then(|value| value.to_str().ok())
+                .and_then(normalize_ip)
+        })
+        .or_else(|| peer.map(|ConnectInfo(addr)| addr.ip().to_canonical()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn verdict_escalates_from_tarpit_to_block() {
+        let settings = AbuseSettings {
+            window: Duration::from_secs(60),
+            tarpit_threshold: Some(10),
+            block_threshold: Some(20),
+            tarpit_delay: Duration::from_millis(500),
+            block_duration: Duration::from_secs(300),
+        };
+        assert_eq!(settings.verdict(10), AbuseVerdict::Allow);
+        assert_eq!(
+            settings.verdict(11),
+            AbuseVerdict::Tarpit(Duration::from_millis(500))
+        );
+        assert_eq!(
+            settings.verdict(21),
+            AbuseVerdict::Block(Duration::from_secs(300))
+        );
+    }
+}
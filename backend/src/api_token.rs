@@ -3,10 +3,18 @@ use hmac::{Hmac, Mac};
 use rand_core::{OsRng, RngCore};
 use sha2::Sha256;
 
-use crate::{app_state::AppState, authed_user::AuthedUser, error::AppError, models::AccountType};
+use crate::{
+    app_state::AppState,
+    authed_user::AuthedUser,
+    error::AppError,
+    models::{AccountType, ApiTokenScope, DeveloperStatus},
+};
 
 type HmacSha256 = Hmac<Sha256>;
 
+pub const DEVELOPER_KEY_PREFIX: &str = "cle_pk_";
+pub const DEVELOPER_PORTAL_TOKEN_PREFIX: &str = "cle_dev_";
+
 pub fn derive_key(secret: &str) -> [u8; 32] {
     use sha2::Digest;
     let mut hasher = Sha256::new();
@@ -22,9 +30,13 @@ pub fn hash_raw_token(key: &[u8; 32], raw: &str) -> [u8; 32] {
 }
 
 pub fn generate_raw_token() -> String {
+    generate_prefixed_token("cle_")
+}
+
+pub fn generate_prefixed_token(prefix: &str) -> String {
     let mut bytes = [0u8; 32];
     OsRng.fill_bytes(&mut bytes);
-    format!("cle_{}", URL_SAFE_NO_PAD.encode(bytes))
+    format!("{prefix}{}", URL_SAFE_NO_PAD.encode(bytes))
 }
 
 pub fn token_last_four(raw: &str) -> String {
@@ -40,6 +52,7 @@ pub fn token_last_four(raw: &str) -> String {
 pub async fn authed_user_from_bearer(
     raw_token: &str,
     state: &AppState,
+    required_scope: ApiTokenScope,
 ) -> Result<AuthedUser, AppError> {
     let Some(key) = state.api_token_hmac_key.as_ref() else {
         return Err(AppError::unauthorized("invalid token"));
@@ -52,7 +65,8 @@ pub async fn authed_user_from_bearer(
     let digest = hash_raw_token(key, raw_token);
     let rec = sqlx::query!(
         r#"
-        SELECT t.id, a.id as account_id, a.account_type as "account_type: AccountType", a.organizer_id
+        SELECT t.id, a.id as account_id, a.account_type as "account_type: AccountType", a.organizer_id,
+               t.scopes as "scopes: Vec<ApiTokenScope>", t.organizer_id as token_organizer_id
         FROM api_tokens t
         JOIN accounts a ON a.id = t.account_id
         WHERE t.token_hmac = $1 AND t.expires_at > NOW()
@@ -66,6 +80,16 @@ pub async fn authed_user_from_bearer(
         return Err(AppError::unauthorized("invalid token"));
     };
 
+    let bound_to_organizer = row.token_organizer_id.is_some();
+    if (bound_to_organizer && required_scope == ApiTokenScope::Full)
+        || !row.scopes.iter().any(|scope| scope.grants(required_scope))
+    {
+        return Err(AppError::unauthorized(format!(
+            "token lacks the {} scope",
+            required_scope.as_str()
+        )));
+    }
+
     sqlx::query!(
         "UPDATE api_tokens SET last_used_at = NOW() WHERE id = $1",
         row.id
@@ -73,9 +97,95 @@ pub async fn authed_user_from_bearer(
     .execute(&state.db)
     .await?;
 
+    sqlx::query!(
+        r#"
+        INSERT INTO api_token_usage (token_id, usage_date, request_count)
+        VALUES ($1, CURRENT_DATE, 1)
+        ON CONFLICT (token_id, usage_date)
+        DO UPDATE SET request_count = api_token_usage.request_count + 1
+        "#,
+        row.id
+    )
+    .execute(&state.db)
+    .await?;
+
+    if let Some(organizer_id) = row.token_organizer_id {
+        return Ok(AuthedUser {
+            account_id: row.account_id,
+            account_type: AccountType::Organizer,
+            organizer_id: Some(organizer_id),
+        });
+    }
+
     Ok(AuthedUser {
         account_id: row.account_id,
         account_type: row.account_type,
         organizer_id: row.organizer_id,
     })
 }
+
+pub async fn authorize_developer_key(raw_key: &str, state: &AppState) -> Result<i64, AppError> {
+    let Some(key) = state.api_token_hmac_key.as_ref() else {
+        return Err(AppError::unauthorized("invalid API token"));
+    };
+
+    if !raw_key.starts_with(DEVELOPER_KEY_PREFIX) {
+        return Err(AppError::unauthorized("invalid API token"));
+    }
+
+    let digest = hash_raw_token(key, raw_key);
+    let rec = sqlx::query!(
+        r#"
+        SELECT
+            k.id,
+            d.id AS developer_id,
+            d.status AS "status: DeveloperStatus",
+            d.daily_quota,
+            COALESCE((
+                SELECT SUM(u.request_count)
+                FROM developer_api_key_usage u
+                JOIN developer_api_keys dk ON dk.id = u.key_id
+                WHERE dk.developer_id = d.id AND u.usage_date = CURRENT_DATE
+            ), 0)::BIGINT AS "requests_today!"
+        FROM developer_api_keys k
+        JOIN developer_accounts d ON d.id = k.developer_id
+        WHERE k.key_hmac = $1 AND k.revoked_at IS NULL
+        "#,
+        &digest[..]
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let Some(row) = rec else {
+        return Err(AppError::unauthorized("invalid API token"));
+    };
+
+    if row.status != DeveloperStatus::Approved {
+        return Err(AppError::unauthorized("developer account is not approved"));
+    }
+
+    if row.requests_today >= i64::from(row.daily_quota) {
+        return Err(AppError::too_many_requests("daily API quota exceeded"));
+    }
+
+    sqlx::query!(
+        "UPDATE developer_api_keys SET last_used_at = NOW() WHERE id = $1",
+        row.id
+    )
+    .execute(&state.db)
+    .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO developer_api_key_usage (key_id, usage_date, request_count)
+        VALUES ($1, CURRENT_DATE, 1)
+        ON CONFLICT (key_id, usage_date)
+        DO UPDATE SET request_count = developer_api_key_usage.request_count + 1
+        "#,
+        row.id
+    )
+    .execute(&state.db)
+    .await?;
+
+    Ok(row.developer_id)
+}
@@ -0,0 +1,53 @@
+use std::{env, sync::LazyLock};
+
+const ID_PLACEHOLDER: &str = "{id}";
+
+static APP_LINKS: LazyLock<AppLinkTemplates> = LazyLock::new(AppLinkTemplates::from_env);
+
+#[derive(Debug, Clone, Default)]
+pub struct AppLinkTemplates {
+    pub event: Option<String>,
+    pub organizer: Option<String>,
+}
+
+fn template_from_env(name: &str) -> Option<String> {
+    env::var(name)
+        .ok()
+        .map(|value| value.trim().to_string())
+        .filter(|value| value.contains(ID_PLACEHOLDER))
+}
+
+impl AppLinkTemplates {
+    pub fn from_env() -> Self {
+        Self {
+            event: template_from_env("APP_EVENT_LINK_TEMPLATE"),
+            organizer: template_from_env("APP_ORGANIZER_LINK_TEMPLATE"),
+        }
+    }
+
+    fn render(template: Option<&str>, id: i64) -> Option<String> {
+        template.map(|template| template.replace(ID_PLACEHOLDER, &id.to_string()))
+    }
+}
+
+pub fn event_app_link(event_id: i64) -> Option<String> {
+    AppLinkTemplates::render(APP_LINKS.event.as_deref(), event_id)
+}
+
+pub fn organizer_app_link(organizer_id: i64) -> Option<String> {
+    AppLinkTemplates::render(APP_LINKS.organizer.as_deref(), organizer_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_every_id_placeholder() {
+        assert_eq!(
+            AppLinkTemplates::render(Some("neuland://events/{id}?ref={id}"), 42).as_deref(),
+            Some("neuland://events/42?ref=42")
+        );
+        assert_eq!(AppLinkTemplates::render(None, 42), None);
+    }
+}
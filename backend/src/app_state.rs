@@ -1,11 +1,25 @@
 use sqlx::postgres::PgPool;
+use tokio::sync::broadcast;
 
-use crate::{cache::CacheService, email::EmailClient};
+use crate::{
+    abuse::AbuseSettings, cache::CacheService, captcha::CaptchaVerifier, email::EmailClient,
+    event_quota::EventQuotaLimits, ldap::LdapAuthenticator, password::PasswordHashing,
+    pwned_passwords::PwnedPasswordChecker, responses::PublicEventChangeMessage,
+    translation::TranslationClient,
+};
 
 #[derive(Clone)]
 pub struct AppState {
     pub db: PgPool,
     pub email: Option<EmailClient>,
     pub cache: Option<CacheService>,
+    pub translator: Option<TranslationClient>,
+    pub ldap: Option<LdapAuthenticator>,
+    pub captcha: Option<CaptchaVerifier>,
+    pub passwords: PasswordHashing,
+    pub pwned_passwords: Option<PwnedPasswordChecker>,
     pub api_token_hmac_key: Option<[u8; 32]>,
+    pub event_quota: EventQuotaLimits,
+    pub abuse: AbuseSettings,
+    pub public_changes: broadcast::Sender<PublicEventChangeMessage>,
 }
@@ -1,8 +1,21 @@
-use std::sync::Arc;
+use std::{
+    future::Future,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
 use redis::{AsyncCommands, Client, aio::MultiplexedConnection};
 use serde::{Serialize, de::DeserializeOwned};
 use thiserror::Error;
+use tracing::{info, warn};
+
+use crate::timings::{Timed, TimingPhase};
+
+const DEFAULT_BREAKER_FAILURE_THRESHOLD: u32 = 3;
+const DEFAULT_BREAKER_COOLDOWN_SECONDS: u64 = 30;
 
 #[derive(Debug, Error)]
 pub enum CacheError {
@@ -17,6 +30,137 @@ pub struct CacheService {
     client: Arc<Client>,
     ttl_seconds: u64,
     prefix: String,
+    breaker: Arc<CircuitBreaker>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CacheHealth {
+    Ok,
+    Degraded,
+}
+
+impl CacheHealth {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            CacheHealth::Ok => "ok",
+            CacheHealth::Degraded => "degraded",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CircuitBreakerSnapshot {
+    pub health: CacheHealth,
+    pub failure_threshold: u32,
+    pub cooldown_seconds: u64,
+    pub consecutive_failures: u32,
+    pub trips_total: u64,
+    pub bypassed_total: u64,
+    pub pending_flush: bool,
+}
+
+#[derive(Debug)]
+struct CircuitBreaker {
+    failure_threshold: u32,
+    cooldown: Duration,
+    started: Instant,
+    consecutive_failures: AtomicU32,
+    open_until_ms: AtomicU64,
+    trips_total: AtomicU64,
+    bypassed_total: AtomicU64,
+    needs_flush: AtomicBool,
+}
+
+impl CircuitBreaker {
+    fn new(failure_threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            failure_threshold: failure_threshold.max(1),
+            cooldown,
+            started: Instant::now(),
+            consecutive_failures: AtomicU32::new(0),
+            open_until_ms: AtomicU64::new(0),
+            trips_total: AtomicU64::new(0),
+            bypassed_total: AtomicU64::new(0),
+            needs_flush: AtomicBool::new(false),
+        }
+    }
+
+    fn now_ms(&self) -> u64 {
+        u64::try_from(self.started.elapsed().as_millis())
+            .unwrap_or(u64::MAX)
+            .max(1)
+    }
+
+    fn reopen_deadline(&self) -> u64 {
+        let cooldown_ms = u64::try_from(self.cooldown.as_millis()).unwrap_or(u64::MAX);
+        self.now_ms().saturating_add(cooldown_ms)
+    }
+
+    fn allow_request(&self) -> bool {
+        let open_until = self.open_until_ms.load(Ordering::Acquire);
+        if open_until == 0 {
+            return true;
+        }
+        if self.now_ms() < open_until {
+            self.bypassed_total.fetch_add(1, Ordering::Relaxed);
+            return false;
+        }
+        let trial = self
+            .open_until_ms
+            .compare_exchange(
+                open_until,
+                self.reopen_deadline(),
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            )
+            .is_ok();
+        if !trial {
+            self.bypassed_total.fetch_add(1, Ordering::Relaxed);
+        }
+        trial
+    }
+
+    fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+        if self.open_until_ms.swap(0, Ordering::AcqRel) != 0 {
+            info!(
+                target: "cache",
+                action = "circuit_closed",
+                "Redis reachable again; cache re-enabled"
+            );
+        }
+    }
+
+    fn record_failure(&self, err: &CacheError) {
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        let was_open = self.open_until_ms.load(Ordering::Acquire) != 0;
+        if !was_open && failures < self.failure_threshold {
+            return;
+        }
+
+        self.open_until_ms
+            .store(self.reopen_deadline(), Ordering::Release);
+        if !was_open {
+            let trips_total = self.trips_total.fetch_add(1, Ordering::Relaxed) + 1;
+            warn!(
+                target: "cache",
+                action = "circuit_open",
+                consecutive_failures = failures,
+                cooldown_seconds = self.cooldown.as_secs(),
+                trips_total,
+                %err,
+                "Redis unavailable; bypassing cache"
+            );
+        }
+    }
+
+    fn health(&self) -> CacheHealth {
+        if self.open_until_ms.load(Ordering::Acquire) == 0 {
+            CacheHealth::Ok
+        } else {
+            CacheHealth::Degraded
+        }
+    }
 }
 
 impl CacheService {
@@ -30,11 +174,61 @@ impl CacheService {
             client: Arc::new(client),
             ttl_seconds: ttl_seconds.max(1),
             prefix: prefix.into(),
+            breaker: Arc::new(CircuitBreaker::new(
+                DEFAULT_BREAKER_FAILURE_THRESHOLD,
+                Duration::from_secs(DEFAULT_BREAKER_COOLDOWN_SECONDS),
+            )),
         };
         service.ping().await?;
         Ok(service)
     }
 
+    pub fn with_circuit_breaker(mut self, failure_threshold: u32, cooldown: Duration) -> Self {
+        self.breaker = Arc::new(CircuitBreaker::new(failure_threshold, cooldown));
+        self
+    }
+
+    pub fn health(&self) -> CacheHealth {
+        self.breaker.health()
+    }
+
+    pub fn breaker_snapshot(&self) -> CircuitBreakerSnapshot {
+        CircuitBreakerSnapshot {
+            health: self.breaker.health(),
+            failure_threshold: self.breaker.failure_threshold,
+            cooldown_seconds: self.breaker.cooldown.as_secs(),
+            consecutive_failures: self.breaker.consecutive_failures.load(Ordering::Relaxed),
+            trips_total: self.breaker.trips_total.load(Ordering::Relaxed),
+            bypassed_total: self.breaker.bypassed_total.load(Ordering::Relaxed),
+            pending_flush: self.breaker.needs_flush.load(Ordering::Relaxed),
+        }
+    }
+
+    async fn guarded<T>(
+        &self,
+        fallback: T,
+        operation: impl Future<Output = Result<T, CacheError>>,
+    ) -> Result<T, CacheError> {
+        if !self.breaker.allow_request() {
+            return Ok(fallback);
+        }
+
+        if self.breaker.needs_flush.swap(false, Ordering::AcqRel)
+            && let Err(err) = self.scan_and_delete("").await
+        {
+            self.breaker.needs_flush.store(true, Ordering::Release);
+            self.breaker.record_failure(&err);
+            return Err(err);
+        }
+
+        let result = operation.timed(TimingPhase::Cache).await;
+        match &result {
+            Err(err @ CacheError::Redis(_)) => self.breaker.record_failure(err),
+            _ => self.breaker.record_success(),
+        }
+        result
+    }
+
     async fn ping(&self) -> Result<(), CacheError> {
         let mut connection = self.connection().await?;
         let _: () = redis::cmd("PING").query_async(&mut connection).await?;
@@ -53,8 +247,7 @@ impl CacheService {
     where
         T: DeserializeOwned,
     {
-        let mut connection = self.connection().await?;
-        let payload: Option<String> = connection.get(self.namespaced_key(key)).await?;
+        let payload = self.get_string(key).await?;
         match payload {
             Some(payload) => Ok(Some(serde_json::from_str(&payload)?)),
             None => Ok(None),
@@ -66,33 +259,130 @@ impl CacheService {
         T: Serialize,
     {
         let payload = serde_json::to_string(value)?;
-        let ttl = self.effective_ttl();
-        let mut connection = self.connection().await?;
-        let _: () = connection
-            .set_ex(self.namespaced_key(key), payload, ttl)
-            .await?;
-        Ok(())
+        self.set_string(key, &payload).await
     }
 
     pub async fn get_string(&self, key: &str) -> Result<Option<String>, CacheError> {
-        let mut connection = self.connection().await?;
-        let payload: Option<String> = connection.get(self.namespaced_key(key)).await?;
-        Ok(payload)
+        self.guarded(None, async {
+            let mut connection = self.connection().await?;
+            let payload: Option<String> = connection.get(self.namespaced_key(key)).await?;
+            Ok(payload)
+        })
+        .await
     }
 
     pub async fn set_string(&self, key: &str, value: &str) -> Result<(), CacheError> {
         let ttl = self.effective_ttl();
-        let mut connection = self.connection().await?;
-        let _: () = connection
-            .set_ex(self.namespaced_key(key), value, ttl)
-            .await?;
-        Ok(())
+        self.guarded((), async {
+            let mut connection = self.connection().await?;
+            let _: () = connection
+                .set_ex(self.namespaced_key(key), value, ttl)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    pub async fn get_bytes(&self, key: &str) -> Result<Option<Vec<u8>>, CacheError> {
+        self.guarded(None, async {
+            let mut connection = self.connection().await?;
+            let payload: Option<Vec<u8>> = connection.get(self.namespaced_key(key)).await?;
+            Ok(payload)
+        })
+        .await
+    }
+
+    pub async fn set_bytes(&self, key: &str, value: &[u8]) -> Result<(), CacheError> {
+        let ttl = self.effective_ttl();
+        self.guarded((), async {
+            let mut connection = self.connection().await?;
+            let _: () = connection
+                .set_ex(self.namespaced_key(key), value, ttl)
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    pub async fn set_string_with_ttl(
+        &self,
+        key: &str,
+        value: &str,
+        ttl: Duration,
+    ) -> Result<(), CacheError> {
+        self.guarded((), async {
+            let mut connection = self.connection().await?;
+            let _: () = connection
+                .set_ex(self.namespaced_key(key), value, ttl.as_secs().max(1))
+                .await?;
+            Ok(())
+        })
+        .await
+    }
+
+    pub async fn increment_with_ttl(&self, key: &str, ttl: Duration) -> Result<u64, CacheError> {
+        self.guarded(0, async {
+            let mut connection = self.connection().await?;
+            let key = self.namespaced_key(key);
+            let count: u64 = connection.incr(&key, 1).await?;
+            if count == 1 {
+                let _: () = connection.expire(&key, ttl.as_secs().max(1) as i64).await?;
+            }
+            Ok(count)
+        })
+        .await
+    }
+
+    pub async fn delete(&self, key: &str) -> Result<(), CacheError> {
+        self.guarded((), async {
+            let mut connection = self.connection().await?;
+            let _: () = connection.del(self.namespaced_key(key)).await?;
+            Ok(())
+        })
+        .await
+    }
+
+    pub async fn increment_counter(&self, key: &str, field: &str) -> Result<(), CacheError> {
+        self.guarded((), async {
+            let mut connection = self.connection().await?;
+            let _: i64 = connection.hincr(self.counter_key(key), field, 1).await?;
+            Ok(())
+        })
+        .await
     }
 
-    pub async fn purge_prefix(&self, prefix: &str) -> Result<(), CacheError> {
+    pub async fn drain_counters(&self, key: &str) -> Result<Vec<(String, i64)>, CacheError> {
+        self.guarded(Vec::new(), async {
+            let mut connection = self.connection().await?;
+            let counter_key = self.counter_key(key);
+            let (counters, _): (Vec<(String, i64)>, i64) = redis::pipe()
+                .atomic()
+                .hgetall(&counter_key)
+                .del(&counter_key)
+                .query_async(&mut connection)
+                .await?;
+            Ok(counters)
+        })
+        .await
+    }
+
+    fn counter_key(&self, key: &str) -> String {
+        format!("{}-counters:{key}", self.prefix)
+    }
+
+    pub async fn purge_prefix(&self, prefix: &str) -> Result<u64, CacheError> {
+        let result = self.guarded(0, self.scan_and_delete(prefix)).await;
+        if result.is_err() || self.breaker.health() == CacheHealth::Degraded {
+            self.breaker.needs_flush.store(true, Ordering::Release);
+        }
+        result
+    }
+
+    async fn scan_and_delete(&self, prefix: &str) -> Result<u64, CacheError> {
         let mut connection = self.connection().await?;
         let pattern = format!("{}:{}*", self.prefix, prefix);
         let mut cursor: u64 = 0;
+        let mut deleted: u64 = 0;
         loop {
             let (next_cursor, keys): (u64, Vec<String>) = redis::cmd("SCAN")
                 .arg(cursor)
@@ -103,17 +393,18 @@ impl CacheService {
                 .query_async(&mut connection)
                 .await?;
             if !keys.is_empty() {
-                let _: () = redis::cmd("DEL")
+                let removed: u64 = redis::cmd("DEL")
                     .arg(keys)
                     .query_async(&mut connection)
                     .await?;
+                deleted += removed;
             }
             if next_cursor == 0 {
                 break;
             }
             cursor = next_cursor;
         }
-        Ok(())
+        Ok(deleted)
     }
 
     fn effective_ttl(&self) -> u64 {
@@ -0,0 +1,149 @@
+use std::{env, str::FromStr, time::Duration};
+
+use serde::Deserialize;
+use thiserror::Error;
+
+const TURNSTILE_VERIFY_URL: &str = "https://challenges.cloudflare.com/turnstile/v0/siteverify";
+const HCAPTCHA_VERIFY_URL: &str = "https://api.hcaptcha.com/siteverify";
+const DEFAULT_CAPTCHA_TIMEOUT_SECONDS: u64 = 10;
+const DEFAULT_LOGIN_FAILURE_THRESHOLD: u64 = 3;
+const DEFAULT_LOGIN_FAILURE_WINDOW_SECONDS: u64 = 900;
+pub const CAPTCHA_TOKEN_HEADER: &str = "x-captcha-token";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CaptchaProvider {
+    Turnstile,
+    HCaptcha,
+}
+
+impl FromStr for CaptchaProvider {
+    type Err = CaptchaError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "turnstile" | "cloudflare" => Ok(CaptchaProvider::Turnstile),
+            "hcaptcha" => Ok(CaptchaProvider::HCaptcha),
+            other => Err(CaptchaError::InvalidConfig(format!(
+                "unsupported CAPTCHA_PROVIDER {other}"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum CaptchaError {
+    #[error("Invalid CAPTCHA configuration: {0}")]
+    InvalidConfig(String),
+    #[error("CAPTCHA verification request failed: {0}")]
+    Request(#[from] reqwest::Error),
+}
+
+#[derive(Debug, Deserialize)]
+struct SiteVerifyResponse {
+    success: bool,
+}
+
+#[derive(Clone)]
+pub struct CaptchaVerifier {
+    http: reqwest::Client,
+    provider: CaptchaProvider,
+    secret: String,
+    site_key: String,
+    login_failure_threshold: u64,
+    login_failure_window: Duration,
+}
+
+impl CaptchaVerifier {
+    pub fn from_env() -> Result<Option<Self>, CaptchaError> {
+        let Some(secret) = env::var("CAPTCHA_SECRET_KEY")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+
+        let site_key = env::var("CAPTCHA_SITE_KEY")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .ok_or_else(|| {
+                CaptchaError::InvalidConfig(
+                    "CAPTCHA_SITE_KEY is required when CAPTCHA_SECRET_KEY is set".to_string(),
+                )
+            })?;
+        let provider = env::var("CAPTCHA_PROVIDER")
+            .unwrap_or_else(|_| "turnstile".to_string())
+            .parse::<CaptchaProvider>()?;
+        let number = |name: &str| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let timeout = number("CAPTCHA_TIMEOUT_SECONDS")
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(DEFAULT_CAPTCHA_TIMEOUT_SECONDS);
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout))
+            .build()?;
+
+        Ok(Some(Self {
+            http,
+            provider,
+            secret: secret.trim().to_string(),
+            site_key,
+            login_failure_threshold: number("CAPTCHA_LOGIN_FAILURE_THRESHOLD")
+                .unwrap_or(DEFAULT_LOGIN_FAILURE_THRESHOLD),
+            login_failure_window: Duration::from_secs(
+                number("CAPTCHA_LOGIN_FAILURE_WINDOW_SECONDS")
+                    .filter(|seconds| *seconds > 0)
+                    .unwrap_or(DEFAULT_LOGIN_FAILURE_WINDOW_SECONDS),
+            ),
+        }))
+    }
+
+    pub fn provider_name(&self) -> &'static str {
+        match self.provider {
+            CaptchaProvider::Turnstile => "turnstile",
+            CaptchaProvider::HCaptcha => "hcaptcha",
+        }
+    }
+
+    pub fn site_key(&self) -> &str {
+        &self.site_key
+    }
+
+    pub fn login_failure_window(&self) -> Duration {
+        self.login_failure_window
+    }
+
+    pub fn login_requires_captcha(&self, recent_failures: u64) -> bool {
+        recent_failures >= self.login_failure_threshold
+    }
+
+    pub async fn verify(&self, token: &str, remote_ip: Option<&str>) -> Result<bool, CaptchaError> {
+        let token = token.trim();
+        if token.is_empty() {
+            return Ok(false);
+        }
+
+        let url = match self.provider {
+            CaptchaProvider::Turnstile => TURNSTILE_VERIFY_URL,
+            CaptchaProvider::HCaptcha => HCAPTCHA_VERIFY_URL,
+        };
+        let mut form = vec![("secret", self.secret.as_str()), ("response", token)];
+        if let Some(remote_ip) = remote_ip {
+            form.push(("remoteip", remote_ip));
+        }
+
+        let response: SiteVerifyResponse = self
+            .http
+            .post(url)
+            .form(&form)
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+        Ok(response.success)
+    }
+}
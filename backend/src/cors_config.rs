@@ -1,7 +1,12 @@
-use axum::http::{HeaderValue, Method, header};
+use axum::http::{HeaderName, HeaderValue, Method, header};
 use tower_http::cors::{AllowOrigin, CorsLayer};
 use tracing::{info, warn};
 
+use crate::{
+    captcha::CAPTCHA_TOKEN_HEADER, pagination::TOTAL_COUNT_HEADER,
+    request_context::REQUEST_ID_HEADER, routes::debug_timings::DEBUG_TIMINGS_HEADER,
+};
+
 pub fn build_cors_layer() -> CorsLayer {
     let raw_allowed_origins = std::env::var("ALLOWED_ORIGINS")
         .unwrap_or_else(|_| "http://localhost:3000,https://localhost:3000".to_string());
@@ -60,7 +65,20 @@ pub fn build_cors_layer() -> CorsLayer {
             Method::DELETE,
             Method::OPTIONS,
         ])
-        .allow_headers([header::CONTENT_TYPE, header::AUTHORIZATION, header::COOKIE])
+        .allow_headers([
+            header::CONTENT_TYPE,
+            header::AUTHORIZATION,
+            header::COOKIE,
+            HeaderName::from_static(DEBUG_TIMINGS_HEADER),
+            HeaderName::from_static(REQUEST_ID_HEADER),
+            HeaderName::from_static(CAPTCHA_TOKEN_HEADER),
+        ])
+        .expose_headers([
+            HeaderName::from_static(DEBUG_TIMINGS_HEADER),
+            HeaderName::from_static(REQUEST_ID_HEADER),
+            HeaderName::from_static(TOTAL_COUNT_HEADER),
+            header::LINK,
+        ])
         .allow_credentials(true);
 
     layer = if allowed_suffixes.is_empty() {
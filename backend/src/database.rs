@@ -0,0 +1,105 @@
+use std::{env, str::FromStr, time::Duration};
+
+use axum::{
+    extract::{MatchedPath, Request},
+    middleware::Next,
+    response::Response,
+};
+use log::LevelFilter;
+use sqlx::{
+    ConnectOptions, Connection, PgConnection,
+    postgres::{PgConnectOptions, PgPool, PgPoolOptions},
+};
+use tracing::{Instrument, info, info_span};
+
+use crate::request_context::RequestContext;
+
+const DEFAULT_MAX_CONNECTIONS: u32 = 10;
+const DEFAULT_STATEMENT_TIMEOUT_MS: u64 = 10_000;
+const DEFAULT_SLOW_QUERY_THRESHOLD_MS: u64 = 500;
+
+#[derive(Debug, Clone, Copy)]
+pub struct DatabaseSettings {
+    pub max_connections: u32,
+    pub statement_timeout: Option<Duration>,
+    pub slow_query_threshold: Option<Duration>,
+}
+
+fn millis_from_env(name: &str, default: u64) -> Option<Duration> {
+    let millis = env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<u64>().ok())
+        .unwrap_or(default);
+    (millis > 0).then(|| Duration::from_millis(millis))
+}
+
+impl DatabaseSettings {
+    pub fn from_env() -> Self {
+        Self {
+            max_connections: env::var("DB_MAX_CONNECTIONS")
+                .ok()
+                .and_then(|value| value.trim().parse::<u32>().ok())
+                .filter(|value| *value > 0)
+                .unwrap_or(DEFAULT_MAX_CONNECTIONS),
+            statement_timeout: millis_from_env(
+                "DB_STATEMENT_TIMEOUT_MS",
+                DEFAULT_STATEMENT_TIMEOUT_MS,
+            ),
+            slow_query_threshold: millis_from_env(
+                "DB_SLOW_QUERY_THRESHOLD_MS",
+                DEFAULT_SLOW_QUERY_THRESHOLD_MS,
+            ),
+        }
+    }
+
+    fn connect_options(&self, database_url: &str) -> Result<PgConnectOptions, sqlx::Error> {
+        let mut options = PgConnectOptions::from_str(database_url)?;
+        options = match self.slow_query_threshold {
+            Some(threshold) => options.log_slow_statements(LevelFilter::Warn, threshold),
+            None => options.log_slow_statements(LevelFilter::Off, Duration::MAX),
+        };
+        if let Some(timeout) = self.statement_timeout {
+            options = options.options([("statement_timeout", timeout.as_millis().to_string())]);
+        }
+        Ok(options)
+    }
+
+    pub async fn connect(&self, database_url: &str) -> Result<PgPool, sqlx::Error> {
+        let pool = PgPoolOptions::new()
+            .max_connections(self.max_connections)
+            .connect_with(self.connect_options(database_url)?)
+            .await?;
+
+        info!(
+            target: "startup",
+            component = "database",
+            action = "configure",
+            max_connections = self.max_connections,
+            statement_timeout_ms = self.statement_timeout.map(|timeout| timeout.as_millis() as u64),
+            slow_query_threshold_ms = self.slow_query_threshold.map(|threshold| threshold.as_millis() as u64),
+            "Database pool configured"
+        );
+
+        Ok(pool)
+    }
+}
+
+pub async fn run_migrations(database_url: &str) -> Result<(), sqlx::migrate::MigrateError> {
+    let mut connection = PgConnection::connect(database_url).await?;
+    sqlx::migrate!("./migrations").run(&mut connection).await?;
+    connection.close().await?;
+    Ok(())
+}
+
+pub async fn with_route_context(request: Request, next: Next) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string())
+        .unwrap_or_else(|| request.uri().path().to_string());
+    let request_id = RequestContext::current()
+        .map(|context| context.request_id)
+        .unwrap_or_default();
+    let span = info_span!("request", method = %request.method(), route = %route, %request_id);
+    next.run(request).instrument(span).await
+}
@@ -1,8 +1,16 @@
-use chrono::{DateTime, Utc};
-use serde::Deserialize;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use utoipa::{IntoParams, ToSchema};
 
-use crate::models::OrganizerKind;
+use crate::{
+    models::{
+        AcademicPeriodKind, ApiTokenScope, CustomFieldType, DeveloperStatus, EmailSendStatus,
+        EmailTemplate, EventAudience, EventStatus, EventVisibility, NewsletterGrouping,
+        NewsletterLanguage, NewsletterOrdering, OrganizerKind, WebhookEventType,
+    },
+    translation::TranslationLanguage,
+};
 
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
@@ -31,6 +39,11 @@ pub struct UpdateOrganizerRequest {
     pub linkedin_url: Option<String>,
     pub registration_number: Option<String>,
     pub non_profit: Option<bool>,
+    pub event_footer_de: Option<String>,
+    pub event_footer_en: Option<String>,
+    pub calendar_name: Option<String>,
+    pub calendar_color: Option<String>,
+    pub calendar_refresh_minutes: Option<i32>,
 }
 
 impl UpdateOrganizerRequest {
@@ -44,6 +57,11 @@ impl UpdateOrganizerRequest {
             || self.linkedin_url.is_some()
             || self.registration_number.is_some()
             || self.non_profit.is_some()
+            || self.event_footer_de.is_some()
+            || self.event_footer_en.is_some()
+            || self.calendar_name.is_some()
+            || self.calendar_color.is_some()
+            || self.calendar_refresh_minutes.is_some()
     }
 }
 
@@ -52,6 +70,15 @@ impl UpdateOrganizerRequest {
 pub struct UpdateOrganizerPermissionsRequest {
     pub newsletter: bool,
     pub organizer_kind: OrganizerKind,
+    pub needs_review: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateEventQuotaRequest {
+    pub max_events_per_day: Option<i32>,
+    pub max_events_per_week: Option<i32>,
+    pub max_future_events: Option<i32>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -65,6 +92,8 @@ pub struct UpdateAccountEmailRequest {
 pub struct SendNewsletterPreviewRequest {
     pub subject: String,
     pub html: String,
+    #[serde(default)]
+    pub text: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema, IntoParams)]
@@ -72,10 +101,90 @@ pub struct SendNewsletterPreviewRequest {
 #[into_params(parameter_in = Query)]
 pub struct NewsletterDataQuery {
     pub week_start: Option<String>,
+    pub grouping: Option<NewsletterGrouping>,
+    pub ordering: Option<NewsletterOrdering>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SendNewsletterRequest {
+    pub week_start: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct NewsletterTemplateQuery {
+    pub language: Option<NewsletterLanguage>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateNewsletterTemplateRequest {
+    #[serde(default)]
+    pub language: NewsletterLanguage,
+    pub header_title: String,
+    #[serde(default)]
+    pub greeting: String,
+    #[serde(default)]
+    pub intro: String,
+    pub events_heading: String,
+    pub outlook_heading: String,
+    pub no_events_text: String,
+    pub no_outlook_text: String,
+    pub sponsors_heading: String,
+    pub footer_title: String,
+    #[serde(default)]
+    pub footer_tagline: String,
+    #[serde(default)]
+    pub footer_note: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct NewsletterArchiveQuery {
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct NewsletterChangesQuery {
+    pub week_start: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateNewsletterIssueRequest {
+    pub grouping: NewsletterGrouping,
+    pub ordering: NewsletterOrdering,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateNewsletterIssueTextsRequest {
+    pub intro_de: Option<String>,
+    pub intro_en: Option<String>,
+    pub outro_de: Option<String>,
+    pub outro_en: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
 #[serde(deny_unknown_fields)]
+pub struct NewsletterSponsorRequest {
+    pub name: String,
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    #[serde(default)]
+    pub position: i32,
+    pub active_from: Option<NaiveDate>,
+    pub active_until: Option<NaiveDate>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
 pub struct CreateEventRequest {
     pub title_de: String,
     pub title_en: String,
@@ -93,6 +202,31 @@ pub struct CreateEventRequest {
     pub publish_in_ical: bool,
     #[serde(default = "default_true")]
     pub publish_web: bool,
+    pub max_participants: Option<i32>,
+    #[serde(default)]
+    pub status: EventStatus,
+    #[serde(default)]
+    pub visibility: EventVisibility,
+    pub series_id: Option<i64>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub custom_fields: Option<Value>,
+    pub price_cents: Option<i32>,
+    pub currency: Option<String>,
+    #[serde(default)]
+    pub free_entry: bool,
+    pub audience: Option<Vec<EventAudience>>,
+    pub venue_id: Option<i64>,
+    pub term: Option<String>,
+    #[serde(default)]
+    pub all_day: bool,
+}
+
+fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<Option<Option<T>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    Option::<T>::deserialize(deserializer).map(Some)
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -110,6 +244,21 @@ pub struct UpdateEventRequest {
     pub publish_newsletter: Option<bool>,
     pub publish_in_ical: Option<bool>,
     pub publish_web: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_nullable")]
+    #[schema(value_type = Option<i32>)]
+    pub max_participants: Option<Option<i32>>,
+    pub status: Option<EventStatus>,
+    pub visibility: Option<EventVisibility>,
+    pub series_id: Option<i64>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub custom_fields: Option<Value>,
+    pub price_cents: Option<i32>,
+    pub currency: Option<String>,
+    pub free_entry: Option<bool>,
+    pub audience: Option<Vec<EventAudience>>,
+    pub venue_id: Option<i64>,
+    pub term: Option<String>,
+    pub all_day: Option<bool>,
 }
 
 impl UpdateEventRequest {
@@ -126,24 +275,404 @@ impl UpdateEventRequest {
             || self.publish_newsletter.is_some()
             || self.publish_in_ical.is_some()
             || self.publish_web.is_some()
+            || self.max_participants.is_some()
+            || self.status.is_some()
+            || self.visibility.is_some()
+            || self.series_id.is_some()
+            || self.publish_at.is_some()
+            || self.custom_fields.is_some()
+            || self.price_cents.is_some()
+            || self.currency.is_some()
+            || self.free_entry.is_some()
+            || self.audience.is_some()
+            || self.venue_id.is_some()
+            || self.term.is_some()
+            || self.all_day.is_some()
     }
 }
 
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateNotificationPreferencesRequest {
+    pub reminders: Option<bool>,
+    pub digests: Option<bool>,
+    pub audit_alerts: Option<bool>,
+    pub broadcast: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct VenueRequest {
+    pub name: String,
+    pub building: Option<String>,
+    pub room: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub capacity: Option<i32>,
+    #[serde(default)]
+    pub close_registration_at_capacity: bool,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct LocationSuggestQuery {
+    pub q: String,
+    pub organizer_id: Option<i64>,
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AcademicPeriodRequest {
+    pub kind: AcademicPeriodKind,
+    pub name_de: String,
+    pub name_en: String,
+    pub starts_on: NaiveDate,
+    pub ends_on: NaiveDate,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct AcademicPeriodQuery {
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AbuseAllowlistRequest {
+    pub ip: String,
+    pub note: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CustomFieldDefinitionRequest {
+    pub key: String,
+    pub label_de: String,
+    pub label_en: String,
+    pub field_type: CustomFieldType,
+    #[serde(default)]
+    pub position: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RejectEventRequest {
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct FeatureEventRequest {
+    pub featured_until: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateWebhookRequest {
+    pub url: String,
+    pub event_types: Vec<WebhookEventType>,
+    pub organizer_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateWebhookRequest {
+    pub url: Option<String>,
+    pub event_types: Option<Vec<WebhookEventType>>,
+    pub active: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateShortLinkRequest {
+    pub alias: String,
+    pub target_url: Option<String>,
+    pub event_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct ImportEventsRequest {
+    pub events: Vec<CreateEventRequest>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct BulkCloneEventsRequest {
+    pub event_ids: Vec<i64>,
+    pub shift_weeks: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct EventTemplateRequest {
+    pub name: String,
+    pub title_de: String,
+    pub title_en: String,
+    pub description_de: Option<String>,
+    pub description_en: Option<String>,
+    pub event_url: Option<String>,
+    pub location: Option<String>,
+    pub duration_minutes: Option<i32>,
+    #[serde(default = "default_true")]
+    pub publish_app: bool,
+    #[serde(default = "default_true")]
+    pub publish_newsletter: bool,
+    #[serde(default = "default_true")]
+    pub publish_in_ical: bool,
+    #[serde(default = "default_true")]
+    pub publish_web: bool,
+    pub max_participants: Option<i32>,
+    #[serde(default)]
+    pub visibility: EventVisibility,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct InstantiateEventTemplateRequest {
+    pub start_date_time: DateTime<Utc>,
+    pub end_date_time: Option<DateTime<Utc>>,
+    pub title_de: Option<String>,
+    pub title_en: Option<String>,
+    #[serde(default)]
+    pub status: EventStatus,
+    pub series_id: Option<i64>,
+    pub publish_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateEventSeriesRequest {
+    pub title_de: String,
+    pub title_en: String,
+    pub description_de: Option<String>,
+    pub description_en: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PriceTierRequest {
+    pub label: String,
+    pub price_cents: i32,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub position: i32,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct AgendaItemRequest {
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub title: String,
+    pub speaker: Option<String>,
+    pub room: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SpeakerRequest {
+    pub name: String,
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+    #[serde(default)]
+    pub links: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct SetEventSpeakersRequest {
+    pub speaker_ids: Vec<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct OrganizerFilterQuery {
+    pub organizer_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateEventSubscriptionRequest {
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateRegistrationRequest {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CheckInRequest {
+    pub code: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventConflictQuery {
+    pub start_date_time: DateTime<Utc>,
+    pub end_date_time: DateTime<Utc>,
+    pub location: Option<String>,
+    pub exclude_event_id: Option<i64>,
+    pub organizer_id: Option<i64>,
+}
+
 #[derive(Debug, Deserialize, ToSchema, IntoParams)]
 #[into_params(parameter_in = Query)]
 pub struct ListEventsQuery {
+    pub q: Option<String>,
     pub organizer_id: Option<i64>,
+    pub series_id: Option<i64>,
     pub upcoming_only: Option<bool>,
+    pub start_after: Option<DateTime<Utc>>,
+    pub start_before: Option<DateTime<Utc>>,
     pub limit: Option<i64>,
     pub offset: Option<i64>,
     pub organizer_kind: Option<OrganizerKind>,
+    pub free_entry: Option<bool>,
+    pub audience: Option<EventAudience>,
+    pub term: Option<String>,
+    pub channel: Option<PublicChannel>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum PublicChannel {
+    #[default]
+    App,
+    Web,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ChannelQuery {
+    pub channel: Option<PublicChannel>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct ApiUsageQuery {
+    pub days: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct EmailStatusQuery {
+    pub verify: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct SuggestTranslationQuery {
+    pub target_language: Option<TranslationLanguage>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct TranslationReportQuery {
+    pub weeks: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct OnboardingReportQuery {
+    pub organizer_kind: Option<OrganizerKind>,
+    pub stalled_only: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EmailLogQuery {
+    pub email: Option<String>,
+    pub template: Option<EmailTemplate>,
+    pub status: Option<EmailSendStatus>,
+    pub request_id: Option<String>,
+    pub limit: Option<i64>,
+    pub offset: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventViewStatsQuery {
+    pub days: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct TermStatsQuery {
+    pub organizer_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct CapacityWarningQuery {
+    pub organizer_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventChangesQuery {
+    pub since: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventSnapshotQuery {
+    pub hash: Option<String>,
+    pub organizer_id: Option<i64>,
+    pub organizer_kind: Option<OrganizerKind>,
 }
 
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct EventArchiveQuery {
+    pub year: Option<i32>,
+    pub month: Option<u32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct IcalFeedQuery {
+    pub window_days: Option<u32>,
+    pub past_days: Option<u32>,
+    pub future_days: Option<u32>,
+}
+
+#[allow(dead_code)]
 #[derive(Debug, Deserialize, ToSchema, IntoParams)]
 #[serde(deny_unknown_fields)]
 #[into_params(parameter_in = Query)]
 pub struct ListPublicOrganizersQuery {
     pub organizer_kind: Option<OrganizerKind>,
+    pub lang: Option<String>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct PublicSearchQuery {
+    pub q: String,
+    pub limit: Option<i64>,
+    pub channel: Option<PublicChannel>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct LanguageQuery {
+    pub lang: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema, IntoParams)]
@@ -155,6 +684,30 @@ pub struct ListAuditLogsQuery {
     pub offset: Option<i64>,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    #[default]
+    Ndjson,
+    Csv,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportAuditLogsQuery {
+    pub event_id: Option<i64>,
+    pub organizer_id: Option<i64>,
+    #[serde(default)]
+    pub format: ExportFormat,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ExportEventsQuery {
+    pub organizer_id: Option<i64>,
+    pub term: Option<String>,
+}
+
 const fn default_true() -> bool {
     true
 }
@@ -169,6 +722,7 @@ pub struct LoginRequest {
 pub struct InitAccountRequest {
     pub token: String,
     pub password: String,
+    pub verification_code: Option<String>,
 }
 
 #[derive(Debug, Deserialize, ToSchema)]
@@ -201,4 +755,66 @@ pub struct ResetPasswordRequest {
 pub struct CreateApiTokenRequest {
     #[serde(default)]
     pub label: String,
+    pub scopes: Option<Vec<ApiTokenScope>>,
+    pub expires_in_days: Option<i64>,
+    pub organizer_id: Option<i64>,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct DeveloperApplicationRequest {
+    pub name: String,
+    pub email: String,
+    pub organization: Option<String>,
+    pub use_case: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct CreateDeveloperApiKeyRequest {
+    #[serde(default)]
+    pub label: String,
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct UpdateDeveloperAccountRequest {
+    pub status: Option<DeveloperStatus>,
+    pub daily_quota: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, ToSchema, IntoParams)]
+#[serde(deny_unknown_fields)]
+#[into_params(parameter_in = Query)]
+pub struct DeveloperAccountQuery {
+    pub status: Option<DeveloperStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub enum CacheNamespace {
+    #[serde(rename = "public:events")]
+    PublicEvents,
+    #[serde(rename = "public:organizers")]
+    PublicOrganizers,
+    #[serde(rename = "ical")]
+    Ical,
+    #[serde(rename = "all")]
+    All,
+}
+
+impl CacheNamespace {
+    pub fn key_prefix(self) -> &'static str {
+        match self {
+            CacheNamespace::PublicEvents => "public:events",
+            CacheNamespace::PublicOrganizers => "public:organizers",
+            CacheNamespace::Ical => "ical",
+            CacheNamespace::All => "",
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, ToSchema)]
+#[serde(deny_unknown_fields)]
+pub struct PurgeCacheRequest {
+    pub namespaces: Vec<CacheNamespace>,
 }
@@ -1,15 +1,34 @@
-use std::{env, str::FromStr};
+use std::{
+    env,
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, AtomicU64, Ordering},
+    },
+    time::{Duration, Instant},
+};
 
+use chrono::{DateTime, Utc};
+use chrono_tz::Europe::Berlin;
 use lettre::{
     AsyncSmtpTransport, AsyncTransport, Tokio1Executor,
-    message::{Mailbox, Message, header::ContentType},
+    message::{
+        Mailbox, Message, MultiPart,
+        header::{ContentType, HeaderName, HeaderValue},
+    },
+    transport::smtp::PoolConfig,
     transport::smtp::authentication::Credentials,
     transport::smtp::client::{Tls, TlsParameters},
 };
+use sha2::{Digest, Sha256};
+use sqlx::PgPool;
 use thiserror::Error;
-use tracing::warn;
+use tracing::{error, info, warn};
 
-use crate::models::{AccountType, OrganizerKind};
+use crate::{
+    models::{AccountType, EmailSendStatus, EmailTemplate, OrganizerKind},
+    request_context::RequestContext,
+};
 
 const DEFAULT_BASE_URL: &str = "http://localhost:3000";
 const INVITE_SUBJECT: &str = "Willkommen bei Campus Life Events";
@@ -19,12 +38,87 @@ const WELCOME_SUBJECT: &str = "Willkommen bei Campus Life Events – Dein Konto
 const WELCOME_SUBJECT_THI_ORGANIZER: &str =
     "Willkommen bei Campus Life Events (THI Services) – Ihr Konto ist aktiviert!";
 const PASSWORD_RESET_SUBJECT: &str = "Passwort zurücksetzen - Campus Life Events";
+const EMAIL_VERIFICATION_SUBJECT: &str = "Bestätigungscode - Campus Life Events";
+const EVENT_APPROVED_SUBJECT: &str = "Veranstaltung freigegeben - Campus Life Events";
+const EVENT_REJECTED_SUBJECT: &str = "Veranstaltung nicht freigegeben - Campus Life Events";
+const EVENT_CHANGED_SUBJECT_PREFIX: &str = "Änderung an Veranstaltung";
+const SUBSCRIPTION_CONFIRMATION_SUBJECT_PREFIX: &str = "Benachrichtigungen bestätigen";
+const EVENT_REMINDER_SUBJECT_PREFIX: &str = "Erinnerung: Angaben fehlen für";
+const NEWSLETTER_SCHEDULE_SUBJECT: &str = "Automatischer Newsletter-Versand - Campus Life Events";
+const DEFAULT_POOL_MAX_SIZE: u32 = 10;
+const DEFAULT_POOL_IDLE_TIMEOUT_SECONDS: u64 = 60;
+const DEFAULT_SMTP_TIMEOUT_SECONDS: u64 = 30;
+const REQUEST_ID_EMAIL_HEADER: &str = "X-CLE-Request-Id";
 
 #[derive(Clone)]
 pub struct EmailClient {
     mailer: AsyncSmtpTransport<Tokio1Executor>,
     from: Mailbox,
     base_url: String,
+    pool_max_size: u32,
+    metrics: Arc<EmailMetrics>,
+    db: Option<PgPool>,
+}
+
+#[derive(Debug, Default)]
+struct EmailMetrics {
+    reachable: AtomicBool,
+    sent_total: AtomicU64,
+    failed_total: AtomicU64,
+    latency_total_ms: AtomicU64,
+    last_latency_ms: AtomicU64,
+    max_latency_ms: AtomicU64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmailHealth {
+    Ok,
+    Unreachable,
+}
+
+impl EmailHealth {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EmailHealth::Ok => "ok",
+            EmailHealth::Unreachable => "unreachable",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EmailMetricsSnapshot {
+    pub health: EmailHealth,
+    pub pool_max_size: u32,
+    pub sent_total: u64,
+    pub failed_total: u64,
+    pub average_latency_ms: Option<u64>,
+    pub last_latency_ms: Option<u64>,
+    pub max_latency_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NotificationCategory {
+    Reminders,
+    AuditAlerts,
+}
+
+impl NotificationCategory {
+    fn for_template(template: EmailTemplate) -> Option<Self> {
+        match template {
+            EmailTemplate::EventReminder => Some(Self::Reminders),
+            EmailTemplate::EventReviewDecision => Some(Self::AuditAlerts),
+            EmailTemplate::OrganizerInvite
+            | EmailTemplate::AdminInvite
+            | EmailTemplate::Welcome
+            | EmailTemplate::PasswordReset
+            | EmailTemplate::NewsletterPreview
+            | EmailTemplate::Newsletter
+            | EmailTemplate::NewsletterScheduleReport
+            | EmailTemplate::EmailVerification
+            | EmailTemplate::EventSubscriptionConfirmation
+            | EmailTemplate::EventChangeNotification => None,
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -39,10 +133,28 @@ pub enum EmailClientError {
     Build(#[from] lettre::error::Error),
     #[error("Failed to send email: {0}")]
     Transport(#[from] lettre::transport::smtp::Error),
+    #[error("SMTP server did not respond to connection check")]
+    Unreachable,
+}
+
+impl EmailMetrics {
+    fn record_send(&self, elapsed: Duration, success: bool) {
+        let elapsed_ms = u64::try_from(elapsed.as_millis()).unwrap_or(u64::MAX);
+        if success {
+            self.sent_total.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.failed_total.fetch_add(1, Ordering::Relaxed);
+        }
+        self.latency_total_ms
+            .fetch_add(elapsed_ms, Ordering::Relaxed);
+        self.last_latency_ms.store(elapsed_ms, Ordering::Relaxed);
+        self.max_latency_ms.fetch_max(elapsed_ms, Ordering::Relaxed);
+        self.reachable.store(success, Ordering::Relaxed);
+    }
 }
 
 impl EmailClient {
-    pub fn from_env() -> Result<Option<Self>, EmailClientError> {
+    pub async fn from_env() -> Result<Option<Self>, EmailClientError> {
         let host = env::var("SMTP_HOST").ok();
         let username = env::var("SMTP_USERNAME").ok();
         let password = env::var("SMTP_PASSWORD").ok();
@@ -92,6 +204,13 @@ impl EmailClient {
         let from = Mailbox::from_str(&sender_spec)
             .map_err(|_| EmailClientError::InvalidConfig("SMTP_FROM_EMAIL is invalid".into()))?;
 
+        let pool_max_size = parse_env_number("SMTP_POOL_MAX_SIZE", DEFAULT_POOL_MAX_SIZE)?.max(1);
+        let pool_idle_timeout = parse_env_number(
+            "SMTP_POOL_IDLE_TIMEOUT_SECONDS",
+            DEFAULT_POOL_IDLE_TIMEOUT_SECONDS,
+        )?;
+        let timeout = parse_env_number("SMTP_TIMEOUT_SECONDS", DEFAULT_SMTP_TIMEOUT_SECONDS)?;
+
         let builder = AsyncSmtpTransport::<Tokio1Executor>::relay(&host)
             .map_err(|err| EmailClientError::InvalidConfig(err.to_string()))?;
 
@@ -99,13 +218,244 @@ impl EmailClient {
             .port(parsed_port)
             .credentials(Credentials::new(username, password))
             .tls(Tls::Required(TlsParameters::new(host.clone())?))
+            .timeout(Some(Duration::from_secs(timeout)))
+            .pool_config(
+                PoolConfig::new()
+                    .max_size(pool_max_size)
+                    .idle_timeout(Duration::from_secs(pool_idle_timeout)),
+            )
             .build();
 
-        Ok(Some(Self {
+        let client = Self {
             mailer,
             from,
             base_url,
-        }))
+            pool_max_size,
+            metrics: Arc::new(EmailMetrics::default()),
+            db: None,
+        };
+
+        match client.verify_connection().await {
+            Ok(()) => info!(
+                target: "startup",
+                component = "email",
+                action = "verify",
+                host = %host,
+                port = parsed_port,
+                pool_max_size,
+                "SMTP connection verified"
+            ),
+            Err(err) => error!(
+                target: "startup",
+                component = "email",
+                action = "verify",
+                host = %host,
+                port = parsed_port,
+                %err,
+                "SMTP connection check failed; check SMTP_HOST, SMTP_PORT and credentials"
+            ),
+        }
+
+        Ok(Some(client))
+    }
+
+    pub fn with_database(mut self, db: PgPool) -> Self {
+        self.db = Some(db);
+        self
+    }
+
+    pub async fn verify_connection(&self) -> Result<(), EmailClientError> {
+        let result = self.mailer.test_connection().await;
+        let reachable = matches!(result, Ok(true));
+        self.metrics.reachable.store(reachable, Ordering::Relaxed);
+
+        match result {
+            Ok(true) => Ok(()),
+            Ok(false) => Err(EmailClientError::Unreachable),
+            Err(err) => Err(EmailClientError::Transport(err)),
+        }
+    }
+
+    pub fn health(&self) -> EmailHealth {
+        if self.metrics.reachable.load(Ordering::Relaxed) {
+            EmailHealth::Ok
+        } else {
+            EmailHealth::Unreachable
+        }
+    }
+
+    pub fn metrics_snapshot(&self) -> EmailMetricsSnapshot {
+        let sent_total = self.metrics.sent_total.load(Ordering::Relaxed);
+        let failed_total = self.metrics.failed_total.load(Ordering::Relaxed);
+        let attempts = sent_total + failed_total;
+        let latency_total_ms = self.metrics.latency_total_ms.load(Ordering::Relaxed);
+
+        EmailMetricsSnapshot {
+            health: self.health(),
+            pool_max_size: self.pool_max_size,
+            sent_total,
+            failed_total,
+            average_latency_ms: (attempts > 0).then(|| latency_total_ms / attempts),
+            last_latency_ms: (attempts > 0)
+                .then(|| self.metrics.last_latency_ms.load(Ordering::Relaxed)),
+            max_latency_ms: (attempts > 0)
+                .then(|| self.metrics.max_latency_ms.load(Ordering::Relaxed)),
+        }
+    }
+
+    async fn deliver(
+        &self,
+        mut message: Message,
+        recipient_email: &str,
+        template: EmailTemplate,
+    ) -> Result<(), EmailClientError> {
+        if let Some(context) = RequestContext::current() {
+            message.headers_mut().insert_raw(HeaderValue::new(
+                HeaderName::new_from_ascii_str(REQUEST_ID_EMAIL_HEADER),
+                context.request_id,
+            ));
+        }
+        let message_id = message
+            .headers()
+            .get_raw("Message-ID")
+            .map(|value| value.trim().to_string());
+
+        if let Some(category) = NotificationCategory::for_template(template)
+            && !self.recipient_accepts(recipient_email, category).await
+        {
+            info!(
+                target: "email",
+                action = "skip",
+                template = ?template,
+                category = ?category,
+                "Skipped email due to recipient notification preferences"
+            );
+            self.record_send_log(
+                recipient_email,
+                template,
+                EmailSendStatus::Skipped,
+                message_id,
+                None,
+                None,
+            )
+            .await;
+            return Ok(());
+        }
+
+        let started = Instant::now();
+        let result = self.mailer.send(message).await;
+        let elapsed = started.elapsed();
+        self.metrics.record_send(elapsed, result.is_ok());
+
+        let (status, provider_message_id, error) = match &result {
+            Ok(response) => (
+                EmailSendStatus::Sent,
+                Some(response.message().collect::<Vec<_>>().join(" ")),
+                None,
+            ),
+            Err(err) => (EmailSendStatus::Failed, None, Some(err.to_string())),
+        };
+        self.record_send_log(
+            recipient_email,
+            template,
+            status,
+            message_id,
+            provider_message_id,
+            error,
+        )
+        .await;
+
+        if let Err(err) = &result {
+            warn!(
+                target: "email",
+                action = "send",
+                latency_ms = elapsed.as_millis() as u64,
+                %err,
+                "Failed to deliver email"
+            );
+        }
+
+        result.map(|_| ()).map_err(EmailClientError::Transport)
+    }
+
+    async fn recipient_accepts(
+        &self,
+        recipient_email: &str,
+        category: NotificationCategory,
+    ) -> bool {
+        let Some(db) = &self.db else {
+            return true;
+        };
+
+        let result = sqlx::query!(
+            r#"
+            SELECT notify_reminders, notify_audit_alerts
+            FROM accounts
+            WHERE lower(email) = lower($1)
+            "#,
+            recipient_email.trim()
+        )
+        .fetch_optional(db)
+        .await;
+
+        match result {
+            Ok(Some(preferences)) => match category {
+                NotificationCategory::Reminders => preferences.notify_reminders,
+                NotificationCategory::AuditAlerts => preferences.notify_audit_alerts,
+            },
+            Ok(None) => true,
+            Err(err) => {
+                warn!(
+                    target: "email",
+                    action = "preferences",
+                    %err,
+                    "Failed to load notification preferences; sending anyway"
+                );
+                true
+            }
+        }
+    }
+
+    async fn record_send_log(
+        &self,
+        recipient_email: &str,
+        template: EmailTemplate,
+        status: EmailSendStatus,
+        message_id: Option<String>,
+        provider_message_id: Option<String>,
+        error: Option<String>,
+    ) {
+        let Some(db) = &self.db else {
+            return;
+        };
+        let context = RequestContext::current();
+
+        let result = sqlx::query!(
+            r#"
+            INSERT INTO email_log (recipient_hash, template, status, message_id, provider_message_id, error, request_id, account_id)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+            "#,
+            recipient_hash(recipient_email),
+            template as EmailTemplate,
+            status as EmailSendStatus,
+            message_id,
+            provider_message_id.filter(|value| !value.trim().is_empty()),
+            error,
+            context.as_ref().map(|context| context.request_id.as_str()),
+            context.as_ref().and_then(RequestContext::account_id)
+        )
+        .execute(db)
+        .await;
+
+        if let Err(err) = result {
+            warn!(
+                target: "email",
+                action = "log",
+                template = ?template,
+                %err,
+                "Failed to record email send"
+            );
+        }
     }
 
     pub async fn send_new_organizer_invite(
@@ -132,15 +482,13 @@ impl EmailClient {
 
         let message = Message::builder()
             .from(self.from.clone())
+            .message_id(None)
             .to(recipient)
             .subject(subject)
             .body(body)?;
 
-        self.mailer
-            .send(message)
+        self.deliver(message, recipient_email, EmailTemplate::OrganizerInvite)
             .await
-            .map(|_| ())
-            .map_err(EmailClientError::Transport)
     }
 
     pub async fn send_new_admin_invite(
@@ -157,15 +505,13 @@ impl EmailClient {
 
         let message = Message::builder()
             .from(self.from.clone())
+            .message_id(None)
             .to(recipient)
             .subject(INVITE_SUBJECT)
             .body(body)?;
 
-        self.mailer
-            .send(message)
+        self.deliver(message, recipient_email, EmailTemplate::AdminInvite)
             .await
-            .map(|_| ())
-            .map_err(EmailClientError::Transport)
     }
 
     pub async fn send_welcome_email(
@@ -188,15 +534,13 @@ impl EmailClient {
 
         let message = Message::builder()
             .from(self.from.clone())
+            .message_id(None)
             .to(recipient)
             .subject(subject)
             .body(body)?;
 
-        self.mailer
-            .send(message)
+        self.deliver(message, recipient_email, EmailTemplate::Welcome)
             .await
-            .map(|_| ())
-            .map_err(EmailClientError::Transport)
     }
 
     pub async fn send_password_reset_email(
@@ -213,15 +557,36 @@ impl EmailClient {
 
         let message = Message::builder()
             .from(self.from.clone())
+            .message_id(None)
             .to(recipient)
             .subject(PASSWORD_RESET_SUBJECT)
             .body(body)?;
 
-        self.mailer
-            .send(message)
+        self.deliver(message, recipient_email, EmailTemplate::PasswordReset)
+            .await
+    }
+
+    pub async fn send_email_verification_code(
+        &self,
+        recipient_email: &str,
+        display_name: &str,
+        code: &str,
+        valid_minutes: i64,
+    ) -> Result<(), EmailClientError> {
+        let recipient = Mailbox::from_str(recipient_email)
+            .map_err(|_| EmailClientError::InvalidRecipient(recipient_email.to_string()))?;
+
+        let body = self.render_email_verification_template(display_name, code, valid_minutes);
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .message_id(None)
+            .to(recipient)
+            .subject(EMAIL_VERIFICATION_SUBJECT)
+            .body(body)?;
+
+        self.deliver(message, recipient_email, EmailTemplate::EmailVerification)
             .await
-            .map(|_| ())
-            .map_err(EmailClientError::Transport)
     }
 
     pub async fn send_newsletter_preview_email(
@@ -229,22 +594,225 @@ impl EmailClient {
         recipient_email: &str,
         subject: &str,
         html_body: &str,
+        text_body: Option<&str>,
+    ) -> Result<(), EmailClientError> {
+        let recipient = Mailbox::from_str(recipient_email)
+            .map_err(|_| EmailClientError::InvalidRecipient(recipient_email.to_string()))?;
+
+        let builder = Message::builder()
+            .from(self.from.clone())
+            .message_id(None)
+            .to(recipient)
+            .subject(subject);
+        let message = match text_body {
+            Some(text_body) => builder.multipart(MultiPart::alternative_plain_html(
+                text_body.to_string(),
+                html_body.to_string(),
+            ))?,
+            None => builder
+                .header(ContentType::TEXT_HTML)
+                .body(html_body.to_string())?,
+        };
+
+        self.deliver(message, recipient_email, EmailTemplate::NewsletterPreview)
+            .await
+    }
+
+    pub async fn send_newsletter_email(
+        &self,
+        recipient_email: &str,
+        subject: &str,
+        html_body: &str,
+        text_body: &str,
+        unsubscribe_token: Option<&str>,
+    ) -> Result<(), EmailClientError> {
+        let recipient = Mailbox::from_str(recipient_email)
+            .map_err(|_| EmailClientError::InvalidRecipient(recipient_email.to_string()))?;
+
+        let unsubscribe_url = unsubscribe_token.map(|token| self.newsletter_unsubscribe_url(token));
+        let (text_body, html_body) = match unsubscribe_url.as_deref() {
+            Some(url) => (
+                format!(
+                    "{}\n\nDu möchtest den Newsletter nicht mehr erhalten? Hier abmelden:\n{url}\n",
+                    text_body.trim_end()
+                ),
+                with_newsletter_unsubscribe_footer(html_body, url),
+            ),
+            None => (text_body.to_string(), html_body.to_string()),
+        };
+
+        let mut message = Message::builder()
+            .from(self.from.clone())
+            .message_id(None)
+            .to(recipient)
+            .subject(subject)
+            .multipart(MultiPart::alternative_plain_html(text_body, html_body))?;
+        if let Some(url) = unsubscribe_url {
+            let headers = message.headers_mut();
+            headers.insert_raw(HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe"),
+                format!("<{url}>"),
+            ));
+            headers.insert_raw(HeaderValue::new(
+                HeaderName::new_from_ascii_str("List-Unsubscribe-Post"),
+                "List-Unsubscribe=One-Click".to_string(),
+            ));
+        }
+
+        self.deliver(message, recipient_email, EmailTemplate::Newsletter)
+            .await
+    }
+
+    pub async fn send_event_review_decision(
+        &self,
+        recipient_email: &str,
+        organizer_name: &str,
+        event_title: &str,
+        approved: bool,
+        reason: Option<&str>,
+    ) -> Result<(), EmailClientError> {
+        let recipient = Mailbox::from_str(recipient_email)
+            .map_err(|_| EmailClientError::InvalidRecipient(recipient_email.to_string()))?;
+
+        let subject = if approved {
+            EVENT_APPROVED_SUBJECT
+        } else {
+            EVENT_REJECTED_SUBJECT
+        };
+        let body = self.render_event_review_template(organizer_name, event_title, approved, reason);
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .message_id(None)
+            .to(recipient)
+            .subject(subject)
+            .body(body)?;
+
+        self.deliver(message, recipient_email, EmailTemplate::EventReviewDecision)
+            .await
+    }
+
+    pub async fn send_event_change_notification(
+        &self,
+        recipient_email: &str,
+        event_id: i64,
+        event_title: &str,
+        changes: &[String],
+        unsubscribe_token: &str,
     ) -> Result<(), EmailClientError> {
         let recipient = Mailbox::from_str(recipient_email)
             .map_err(|_| EmailClientError::InvalidRecipient(recipient_email.to_string()))?;
 
+        let subject = format!("{EVENT_CHANGED_SUBJECT_PREFIX}: {event_title}");
+        let body =
+            self.render_event_change_template(event_id, event_title, changes, unsubscribe_token);
+
         let message = Message::builder()
             .from(self.from.clone())
+            .message_id(None)
             .to(recipient)
             .subject(subject)
-            .header(ContentType::TEXT_HTML)
-            .body(html_body.to_string())?;
+            .body(body)?;
+
+        self.deliver(
+            message,
+            recipient_email,
+            EmailTemplate::EventChangeNotification,
+        )
+        .await
+    }
 
-        self.mailer
-            .send(message)
+    pub async fn send_event_subscription_confirmation(
+        &self,
+        recipient_email: &str,
+        event_id: i64,
+        event_title: &str,
+        subscription_token: &str,
+    ) -> Result<(), EmailClientError> {
+        let recipient = Mailbox::from_str(recipient_email)
+            .map_err(|_| EmailClientError::InvalidRecipient(recipient_email.to_string()))?;
+
+        let subject = format!("{SUBSCRIPTION_CONFIRMATION_SUBJECT_PREFIX}: {event_title}");
+        let body = self.render_subscription_confirmation_template(
+            event_id,
+            event_title,
+            subscription_token,
+        );
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .message_id(None)
+            .to(recipient)
+            .subject(subject)
+            .body(body)?;
+
+        self.deliver(
+            message,
+            recipient_email,
+            EmailTemplate::EventSubscriptionConfirmation,
+        )
+        .await
+    }
+
+    pub async fn send_event_reminder(
+        &self,
+        recipient_email: &str,
+        organizer_name: &str,
+        event_title: &str,
+        start_date_time: DateTime<Utc>,
+        missing_fields: &[&str],
+    ) -> Result<(), EmailClientError> {
+        let recipient = Mailbox::from_str(recipient_email)
+            .map_err(|_| EmailClientError::InvalidRecipient(recipient_email.to_string()))?;
+
+        let subject = format!("{EVENT_REMINDER_SUBJECT_PREFIX} {event_title}");
+        let body = self.render_event_reminder_template(
+            organizer_name,
+            event_title,
+            start_date_time,
+            missing_fields,
+        );
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .message_id(None)
+            .to(recipient)
+            .subject(subject)
+            .body(body)?;
+
+        self.deliver(message, recipient_email, EmailTemplate::EventReminder)
             .await
-            .map(|_| ())
-            .map_err(EmailClientError::Transport)
+    }
+
+    pub async fn send_newsletter_schedule_report(
+        &self,
+        recipient_email: &str,
+        display_name: &str,
+        summary: &str,
+    ) -> Result<(), EmailClientError> {
+        let recipient = Mailbox::from_str(recipient_email)
+            .map_err(|_| EmailClientError::InvalidRecipient(recipient_email.to_string()))?;
+
+        let body = format!(
+            "Hallo {display_name},\n\n\
+            {summary}\n\n\
+            Viele Grüße\nDas Neuland Team\n\n\
+            Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolstadt e.V. entwickelt und betrieben."
+        );
+
+        let message = Message::builder()
+            .from(self.from.clone())
+            .message_id(None)
+            .to(recipient)
+            .subject(NEWSLETTER_SCHEDULE_SUBJECT)
+            .body(body)?;
+
+        self.deliver(
+            message,
+            recipient_email,
+            EmailTemplate::NewsletterScheduleReport,
+        )
+        .await
     }
 
     fn registration_url(&self, token: &str) -> String {
@@ -252,6 +820,31 @@ impl EmailClient {
         format!("{trimmed}/register?token={token}")
     }
 
+    fn events_url(&self) -> String {
+        let trimmed = self.base_url.trim_end_matches('/');
+        format!("{trimmed}/events")
+    }
+
+    fn public_event_url(&self, event_id: i64) -> String {
+        let trimmed = self.base_url.trim_end_matches('/');
+        format!("{trimmed}/e/{event_id}")
+    }
+
+    fn subscription_confirm_url(&self, token: &str) -> String {
+        let trimmed = self.base_url.trim_end_matches('/');
+        format!("{trimmed}/api/v1/public/subscriptions/{token}/confirm")
+    }
+
+    fn subscription_unsubscribe_url(&self, token: &str) -> String {
+        let trimmed = self.base_url.trim_end_matches('/');
+        format!("{trimmed}/api/v1/public/subscriptions/{token}/unsubscribe")
+    }
+
+    fn newsletter_unsubscribe_url(&self, token: &str) -> String {
+        let trimmed = self.base_url.trim_end_matches('/');
+        format!("{trimmed}/api/v1/newsletter/unsubscribe/{token}")
+    }
+
     fn reset_url(&self, token: &str) -> String {
         let trimmed = self.base_url.trim_end_matches('/');
         format!("{trimmed}/reset-password?token={token}")
@@ -349,6 +942,115 @@ Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolsta
         }
     }
 
+    fn render_event_review_template(
+        &self,
+        organizer_name: &str,
+        event_title: &str,
+        approved: bool,
+        reason: Option<&str>,
+    ) -> String {
+        let dashboard_url = self.events_url();
+
+        if approved {
+            return format!(
+                "Hallo {organizer_name},\n\n\
+                deine Veranstaltung „{event_title}“ wurde geprüft und freigegeben.\n\
+                Sie ist ab sofort entsprechend deiner Einstellungen in der App, im Kalender und im Newsletter sichtbar.\n\n\
+                Deine Veranstaltungen findest du hier:\n{dashboard_url}\n\n\
+                Viele Grüße\nDas Neuland Team\n\n\
+                Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolstadt e.V. entwickelt und betrieben."
+            );
+        }
+
+        let reason_block = match reason.map(str::trim).filter(|value| !value.is_empty()) {
+            Some(reason) => format!("Begründung:\n{reason}\n\n"),
+            None => String::new(),
+        };
+
+        format!(
+            "Hallo {organizer_name},\n\n\
+            deine Veranstaltung „{event_title}“ wurde geprüft und leider nicht freigegeben.\n\n\
+            {reason_block}\
+            Du kannst die Veranstaltung überarbeiten und erneut zur Veröffentlichung einreichen:\n{dashboard_url}\n\n\
+            Bei Fragen wende dich gerne an uns.\n\n\
+            Viele Grüße\nDas Neuland Team\n\n\
+            Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolstadt e.V. entwickelt und betrieben."
+        )
+    }
+
+    fn render_event_reminder_template(
+        &self,
+        organizer_name: &str,
+        event_title: &str,
+        start_date_time: DateTime<Utc>,
+        missing_fields: &[&str],
+    ) -> String {
+        let dashboard_url = self.events_url();
+        let start = start_date_time
+            .with_timezone(&Berlin)
+            .format("%d.%m.%Y um %H:%M Uhr");
+        let missing = missing_fields
+            .iter()
+            .map(|field| format!("- {field}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "Hallo {organizer_name},\n\n\
+            deine Veranstaltung „{event_title}“ beginnt am {start}.\n\
+            Folgende Angaben fehlen noch:\n{missing}\n\n\
+            Bitte ergänze sie, damit Besucherinnen und Besucher alle wichtigen Informationen finden:\n{dashboard_url}\n\n\
+            Diese Erinnerungen kannst du in deinen Benachrichtigungseinstellungen deaktivieren.\n\n\
+            Viele Grüße\nDas Neuland Team\n\n\
+            Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolstadt e.V. entwickelt und betrieben."
+        )
+    }
+
+    fn render_event_change_template(
+        &self,
+        event_id: i64,
+        event_title: &str,
+        changes: &[String],
+        unsubscribe_token: &str,
+    ) -> String {
+        let event_url = self.public_event_url(event_id);
+        let unsubscribe_url = self.subscription_unsubscribe_url(unsubscribe_token);
+        let change_list = changes
+            .iter()
+            .map(|change| format!("- {change}"))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        format!(
+            "Hallo,\n\n\
+            an der Veranstaltung „{event_title}“, die du abonniert hast, hat sich etwas geändert:\n\n\
+            {change_list}\n\n\
+            Alle Details findest du hier:\n{event_url}\n\n\
+            Du möchtest keine Benachrichtigungen mehr zu dieser Veranstaltung erhalten?\n{unsubscribe_url}\n\n\
+            Viele Grüße\nDas Neuland Team\n\n\
+            Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolstadt e.V. entwickelt und betrieben."
+        )
+    }
+
+    fn render_subscription_confirmation_template(
+        &self,
+        event_id: i64,
+        event_title: &str,
+        subscription_token: &str,
+    ) -> String {
+        let event_url = self.public_event_url(event_id);
+        let confirm_url = self.subscription_confirm_url(subscription_token);
+
+        format!(
+            "Hallo,\n\n\
+            für diese E-Mail-Adresse wurden Benachrichtigungen zu Änderungen an der Veranstaltung „{event_title}“ angefordert:\n{event_url}\n\n\
+            Bitte bestätige das Abonnement über folgenden Link:\n{confirm_url}\n\n\
+            Falls du das nicht warst, kannst du diese E-Mail ignorieren. Ohne Bestätigung erhältst du keine weiteren Nachrichten.\n\n\
+            Viele Grüße\nDas Neuland Team\n\n\
+            Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolstadt e.V. entwickelt und betrieben."
+        )
+    }
+
     fn render_password_reset_template(
         &self,
         display_name: &str,
@@ -367,4 +1069,49 @@ Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolsta
             Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolstadt e.V. entwickelt und betrieben."
         )
     }
+
+    fn render_email_verification_template(
+        &self,
+        display_name: &str,
+        code: &str,
+        valid_minutes: i64,
+    ) -> String {
+        format!(
+            "Hallo {display_name},\n\n\
+            um dein Konto bei Campus Life Events zu aktivieren, bestätige bitte deine E-Mail-Adresse mit folgendem Code:\n\n\
+            {code}\n\n\
+            Der Code ist {valid_minutes} Minuten gültig.\n\n\
+            Falls du kein Konto einrichten wolltest, kannst du diese E-Mail ignorieren.\n\n\
+            Viele Grüße\nDas Neuland Team\n\n\
+            Campus Life Events ist ein Projekt der THI StudVer und wird von Neuland Ingolstadt e.V. entwickelt und betrieben."
+        )
+    }
+}
+
+pub fn recipient_hash(email: &str) -> String {
+    Sha256::digest(email.trim().to_lowercase().as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+fn with_newsletter_unsubscribe_footer(html_body: &str, unsubscribe_url: &str) -> String {
+    let footer = format!(
+        "<p style=\"margin:16px 0;text-align:center;font-size:12px;color:#6b7280;\">Du möchtest den Newsletter nicht mehr erhalten? <a href=\"{}\" style=\"color:#215b9c;\">Hier abmelden</a></p>",
+        unsubscribe_url.replace('&', "&amp;").replace('"', "&quot;")
+    );
+    match html_body.to_ascii_lowercase().rfind("</body>") {
+        Some(index) => format!("{}{footer}\n{}", &html_body[..index], &html_body[index..]),
+        None => format!("{html_body}{footer}"),
+    }
+}
+
+fn parse_env_number<T: FromStr>(key: &str, default: T) -> Result<T, EmailClientError> {
+    match env::var(key) {
+        Ok(value) if !value.trim().is_empty() => value
+            .trim()
+            .parse::<T>()
+            .map_err(|_| EmailClientError::InvalidConfig(format!("{key} must be a number"))),
+        _ => Ok(default),
+    }
 }
@@ -5,7 +5,7 @@ use axum::{
 };
 use thiserror::Error;
 
-use crate::{email::EmailClientError, responses::ErrorResponse};
+use crate::{email::EmailClientError, i18n::ErrorDetails, responses::ErrorResponse};
 
 #[derive(Debug, Error)]
 pub enum AppError {
@@ -15,12 +15,16 @@ pub enum AppError {
     Validation(String),
     #[error("unauthorized: {0}")]
     Unauthorized(String),
+    #[error("conflict: {0}")]
+    Conflict(String),
     #[error("email error: {0}")]
     Email(String),
     #[error("internal server error: {0}")]
     Internal(String),
     #[error("service unavailable: {0}")]
     ServiceUnavailable(String),
+    #[error("too many requests: {0}")]
+    TooManyRequests(String),
     #[error(transparent)]
     Sqlx(#[from] sqlx::Error),
     #[error(transparent)]
@@ -42,6 +46,10 @@ impl AppError {
         Self::Unauthorized(msg.into())
     }
 
+    pub fn conflict(msg: impl Into<String>) -> Self {
+        Self::Conflict(msg.into())
+    }
+
     pub fn internal(msg: impl Into<String>) -> Self {
         Self::Internal(msg.into())
     }
@@ -50,6 +58,10 @@ impl AppError {
         Self::ServiceUnavailable(msg.into())
     }
 
+    pub fn too_many_requests(msg: impl Into<String>) -> Self {
+        Self::TooManyRequests(msg.into())
+    }
+
     pub(crate) fn http_status(&self) -> StatusCode {
         self.status_code()
     }
@@ -59,6 +71,8 @@ impl AppError {
             AppError::NotFound { message } => message.clone(),
             AppError::Validation(message) => message.clone(),
             AppError::Unauthorized(message) => message.clone(),
+            AppError::Conflict(message) => message.clone(),
+            AppError::TooManyRequests(message) => message.clone(),
             AppError::ServiceUnavailable(_) => "service unavailable".to_string(),
             AppError::Internal(_) | AppError::Sqlx(_) | AppError::Serde(_) | AppError::Email(_) => {
                 "request failed".to_string()
@@ -71,22 +85,41 @@ impl AppError {
             AppError::NotFound { .. } => StatusCode::NOT_FOUND,
             AppError::Validation(_) => StatusCode::BAD_REQUEST,
             AppError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            AppError::Conflict(_) => StatusCode::CONFLICT,
             AppError::Email(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
             AppError::ServiceUnavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+            AppError::TooManyRequests(_) => StatusCode::TOO_MANY_REQUESTS,
             AppError::Sqlx(error) => map_sqlx_error_to_status(error),
             AppError::Serde(_) => StatusCode::INTERNAL_SERVER_ERROR,
         }
     }
 
+    fn code(&self) -> &'static str {
+        match self {
+            AppError::NotFound { .. } => "NOT_FOUND",
+            AppError::Validation(_) => "VALIDATION_ERROR",
+            AppError::Unauthorized(_) => "UNAUTHORIZED",
+            AppError::Conflict(_) => "CONFLICT",
+            AppError::Email(_) => "EMAIL_ERROR",
+            AppError::Internal(_) | AppError::Serde(_) => "INTERNAL_ERROR",
+            AppError::ServiceUnavailable(_) => "SERVICE_UNAVAILABLE",
+            AppError::TooManyRequests(_) => "TOO_MANY_REQUESTS",
+            AppError::Sqlx(sqlx::Error::RowNotFound) => "NOT_FOUND",
+            AppError::Sqlx(_) => "DATABASE_ERROR",
+        }
+    }
+
     fn message(&self) -> String {
         match self {
             AppError::NotFound { message } => message.clone(),
             AppError::Validation(message) => message.clone(),
             AppError::Unauthorized(message) => message.clone(),
+            AppError::Conflict(message) => message.clone(),
             AppError::Email(message) => message.clone(),
             AppError::Internal(message) => message.clone(),
             AppError::ServiceUnavailable(message) => message.clone(),
+            AppError::TooManyRequests(message) => message.clone(),
             AppError::Sqlx(error) => match error {
                 sqlx::Error::RowNotFound => "resource not found".to_string(),
                 _ => error
@@ -102,11 +135,19 @@ impl AppError {
 impl IntoResponse for AppError {
     fn into_response(self) -> Response {
         let status = self.status_code();
+        let code = self.code();
         let message = self.message();
 
-        let body = Json(ErrorResponse { message });
+        let body = Json(ErrorResponse {
+            code: code.to_string(),
+            message: message.clone(),
+        });
 
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        response
+            .extensions_mut()
+            .insert(ErrorDetails { code, message });
+        response
     }
 }
 
@@ -118,6 +159,7 @@ impl From<EmailClientError> for AppError {
             | EmailClientError::InvalidConfig(message) => AppError::Email(message),
             EmailClientError::Build(err) => AppError::Email(err.to_string()),
             EmailClientError::Transport(err) => AppError::Email(err.to_string()),
+            err @ EmailClientError::Unreachable => AppError::Email(err.to_string()),
         }
     }
 }
@@ -131,6 +173,7 @@ fn map_sqlx_error_to_status(error: &sqlx::Error) -> StatusCode {
                     Some("23505") => StatusCode::CONFLICT,
                     Some("23503") => StatusCode::CONFLICT,
                     Some("P0001") => StatusCode::BAD_REQUEST,
+                    Some("57014") => StatusCode::SERVICE_UNAVAILABLE,
                     _ => StatusCode::INTERNAL_SERVER_ERROR,
                 }
             } else {
@@ -0,0 +1,36 @@
+use std::env;
+
+const DEFAULT_MAX_EVENTS_PER_DAY: i32 = 25;
+const DEFAULT_MAX_EVENTS_PER_WEEK: i32 = 100;
+const DEFAULT_MAX_FUTURE_EVENTS: i32 = 300;
+
+#[derive(Debug, Clone, Copy)]
+pub struct EventQuotaLimits {
+    pub max_events_per_day: i32,
+    pub max_events_per_week: i32,
+    pub max_future_events: i32,
+}
+
+fn limit_from_env(name: &str, default: i32) -> i32 {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.trim().parse::<i32>().ok())
+        .filter(|value| *value >= 0)
+        .unwrap_or(default)
+}
+
+impl EventQuotaLimits {
+    pub fn from_env() -> Self {
+        Self {
+            max_events_per_day: limit_from_env(
+                "EVENT_QUOTA_MAX_PER_DAY",
+                DEFAULT_MAX_EVENTS_PER_DAY,
+            ),
+            max_events_per_week: limit_from_env(
+                "EVENT_QUOTA_MAX_PER_WEEK",
+                DEFAULT_MAX_EVENTS_PER_WEEK,
+            ),
+            max_future_events: limit_from_env("EVENT_QUOTA_MAX_FUTURE", DEFAULT_MAX_FUTURE_EVENTS),
+        }
+    }
+}
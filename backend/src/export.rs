@@ -0,0 +1,122 @@
+use std::{future::Future, io};
+
+use axum::{
+    body::{Body, Bytes},
+    http::StatusCode,
+    response::Response,
+};
+use futures_util::stream;
+use tokio::sync::mpsc;
+use tracing::error;
+
+use crate::error::AppError;
+
+const EXPORT_CHANNEL_CAPACITY: usize = 16;
+const EXPORT_CHUNK_SIZE: usize = 64 * 1024;
+
+type ExportChunk = Result<Bytes, io::Error>;
+
+pub struct ExportWriter {
+    tx: mpsc::Sender<ExportChunk>,
+    buffer: String,
+}
+
+impl ExportWriter {
+    pub async fn write(&mut self, data: &str) -> Result<(), AppError> {
+        self.buffer.push_str(data);
+        if self.buffer.len() >= EXPORT_CHUNK_SIZE {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    pub async fn write_csv_row<S: AsRef<str>>(&mut self, fields: &[S]) -> Result<(), AppError> {
+        let line = csv_line(fields);
+        self.write(&line).await
+    }
+
+    async fn flush(&mut self) -> Result<(), AppError> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+        let chunk = Bytes::from(std::mem::take(&mut self.buffer));
+        self.tx
+            .send(Ok(chunk))
+            .await
+            .map_err(|_| AppError::internal("export stream closed"))
+    }
+}
+
+pub fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+pub fn csv_line<S: AsRef<str>>(fields: &[S]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|field| csv_field(field.as_ref()))
+        .collect::<Vec<_>>()
+        .join(",");
+    line.push_str("\r\n");
+    line
+}
+
+pub fn stream_export<F, Fut>(
+    content_type: &'static str,
+    filename: &str,
+    producer: F,
+) -> Result<Response, AppError>
+where
+    F: FnOnce(ExportWriter) -> Fut,
+    Fut: Future<Output = Result<ExportWriter, AppError>> + Send + 'static,
+{
+    let (tx, mut rx) = mpsc::channel::<ExportChunk>(EXPORT_CHANNEL_CAPACITY);
+    let error_tx = tx.clone();
+    let export = producer(ExportWriter {
+        tx,
+        buffer: String::new(),
+    });
+
+    tokio::spawn(async move {
+        let result = match export.await {
+            Ok(mut writer) => writer.flush().await,
+            Err(err) => Err(err),
+        };
+        if let Err(err) = result
+            && !error_tx.is_closed()
+        {
+            error!(target: "export", action = "stream", %err, "Export aborted");
+            let _ = error_tx.send(Err(io::Error::other(err.to_string()))).await;
+        }
+    });
+
+    let body = Body::from_stream(stream::poll_fn(move |cx| rx.poll_recv(cx)));
+
+    Response::builder()
+        .status(StatusCode::OK)
+        .header("Content-Type", content_type)
+        .header(
+            "Content-Disposition",
+            format!("attachment; filename=\"{filename}\""),
+        )
+        .header("Cache-Control", "no-store")
+        .body(body)
+        .map_err(|_| AppError::internal("Failed to build response"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quotes_fields_with_separators() {
+        assert_eq!(
+            csv_line(&["plain", "a,b", "say \"hi\"", "multi\nline"]),
+            "plain,\"a,b\",\"say \"\"hi\"\"\",\"multi\nline\"\r\n"
+        );
+    }
+}
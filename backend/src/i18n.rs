@@ -0,0 +1,281 @@
+use axum::{
+    body::Body,
+    extract::{Query, Request},
+    http::{HeaderMap, HeaderValue, header},
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{dto::LanguageQuery, responses::ErrorResponse};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+}
+
+impl Locale {
+    pub fn from_headers(headers: &HeaderMap) -> Self {
+        headers
+            .get(header::ACCEPT_LANGUAGE)
+            .and_then(|value| value.to_str().ok())
+            .map(Self::from_accept_language)
+            .unwrap_or_default()
+    }
+
+    pub fn from_tag(tag: &str) -> Option<Self> {
+        match tag.trim().to_ascii_lowercase().split('-').next() {
+            Some("de") => Some(Locale::De),
+            Some("en") => Some(Locale::En),
+            _ => None,
+        }
+    }
+
+    pub fn from_accept_language(value: &str) -> Self {
+        let mut best: Option<(Locale, f32)> = None;
+        for entry in value.split(',') {
+            let mut parts = entry.trim().split(';');
+            let tag = parts.next().unwrap_or_default();
+            let quality = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.parse::<f32>().ok())
+                .unwrap_or(1.0);
+            let Some(locale) = Self::from_tag(tag) else {
+                continue;
+            };
+            if best.is_none_or(|(_, best_quality)| quality > best_quality) {
+                best = Some((locale, quality));
+            }
+        }
+        best.map(|(locale, _)| locale).unwrap_or_default()
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Locale::En => "en",
+            Locale::De => "de",
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct ErrorDetails {
+    pub code: &'static str,
+    pub message: String,
+}
+
+pub fn translate(message: &str, locale: Locale) -> Option<&'static str> {
+    if locale != Locale::De {
+        return None;
+    }
+    let translated = match message {
+        "resource not found" => "Ressource nicht gefunden",
+        "database error" => "Datenbankfehler",
+        "unexpected error" => "Unerwarteter Fehler",
+        "insufficient permissions" => "Unzureichende Berechtigungen",
+        "organizer account required" => "Ein Veranstalterkonto ist erforderlich",
+        "newsletter permission required" => "Newsletter-Berechtigung erforderlich",
+        "missing organizer context" => "Kein Veranstalter zugeordnet",
+        "missing session" => "Nicht angemeldet",
+        "invalid session format" => "Ungültiges Sitzungsformat",
+        "invalid or expired session" => "Sitzung ungültig oder abgelaufen",
+        "invalid e-mail or password" => "E-Mail-Adresse oder Passwort ist falsch",
+        "invalid current password" => "Das aktuelle Passwort ist falsch",
+        "invalid API token" => "Ungültiger API-Token",
+        "missing API token" => "API-Token fehlt",
+        "invalid token" => "Ungültiger Token",
+        "account not found" => "Konto nicht gefunden",
+        "account not initialized" => "Konto wurde noch nicht eingerichtet",
+        "account already initialized" => "Konto wurde bereits eingerichtet",
+        "account is missing an email address" => {
+            "Für das Konto ist keine E-Mail-Adresse hinterlegt"
+        }
+        "invalid setup token" => "Ungültiger Einrichtungslink",
+        "setup token expired" => "Der Einrichtungslink ist abgelaufen",
+        "Invalid or expired reset token" => {
+            "Der Link zum Zurücksetzen ist ungültig oder abgelaufen"
+        }
+        "email must not be empty" => "Die E-Mail-Adresse darf nicht leer sein",
+        "invalid email address" => "Ungültige E-Mail-Adresse",
+        "name is required" => "Name ist erforderlich",
+        "title is required" => "Titel ist erforderlich",
+        "subject is required" => "Betreff ist erforderlich",
+        "html content is required" => "HTML-Inhalt ist erforderlich",
+        "No fields supplied for update" => "Keine Felder zum Aktualisieren angegeben",
+        "end date time must not be before start date time" => {
+            "Das Ende darf nicht vor dem Beginn liegen"
+        }
+        "max participants must be at least 1" => {
+            "Die maximale Teilnehmerzahl muss mindestens 1 sein"
+        }
+        "status must be DRAFT or PUBLISHED" => "Der Status muss DRAFT oder PUBLISHED sein",
+        "start_before must not be before start_after" => {
+            "start_before darf nicht vor start_after liegen"
+        }
+        "organizer_id is required" => "organizer_id ist erforderlich",
+        "week_start cannot be empty" => "week_start darf nicht leer sein",
+        "invalid week_start, expected YYYY-MM-DD" => {
+            "Ungültiges week_start, erwartet wird YYYY-MM-DD"
+        }
+        "Organizer not found" => "Veranstalter nicht gefunden",
+        "Organizer account not found" => "Veranstalterkonto nicht gefunden",
+        "Event not found" | "event not found" => "Veranstaltung nicht gefunden",
+        "Event not found or not published" => {
+            "Veranstaltung nicht gefunden oder nicht veröffentlicht"
+        }
+        "event has been cancelled" => "Die Veranstaltung wurde abgesagt",
+        "event is already cancelled" => "Die Veranstaltung ist bereits abgesagt",
+        "event is fully booked" => "Die Veranstaltung ist ausgebucht",
+        "event is not pending review" => "Die Veranstaltung wartet nicht auf Freigabe",
+        "registration is closed for this event" => {
+            "Die Anmeldung für diese Veranstaltung ist geschlossen"
+        }
+        "this email is already registered for the event" => {
+            "Diese E-Mail-Adresse ist bereits für die Veranstaltung angemeldet"
+        }
+        "this email is already subscribed to the event" => {
+            "Diese E-Mail-Adresse hat die Veranstaltung bereits abonniert"
+        }
+        "cannot update another organizer's event" => {
+            "Veranstaltungen anderer Veranstalter können nicht bearbeitet werden"
+        }
+        "cannot delete another organizer's event" => {
+            "Veranstaltungen anderer Veranstalter können nicht gelöscht werden"
+        }
+        "cannot cancel another organizer's event" => {
+            "Veranstaltungen anderer Veranstalter können nicht abgesagt werden"
+        }
+        "Short link not found" => "Kurzlink nicht gefunden",
+        "alias is reserved" => "Dieser Alias ist reserviert",
+        "alias is already taken" => "Dieser Alias ist bereits vergeben",
+        "Subscription not found" => "Abonnement nicht gefunden",
+        "speaker not found" => "Referent:in nicht gefunden",
+        "Series not found" | "series not found" => "Veranstaltungsreihe nicht gefunden",
+        "template not found" => "Vorlage nicht gefunden",
+        "audit log entry not found" => "Protokolleintrag nicht gefunden",
+        "only deletion entries can be restored" => {
+            "Nur Löscheinträge können wiederhergestellt werden"
+        }
+        "audit log entry has no valid event snapshot" => {
+            "Der Protokolleintrag enthält keinen gültigen Veranstaltungsstand"
+        }
+        "event already exists" => "Die Veranstaltung existiert bereits",
+        "invalid check-in code" => "Ungültiger Check-in-Code",
+        "check-in code belongs to another event" => {
+            "Der Check-in-Code gehört zu einer anderen Veranstaltung"
+        }
+        "check-in code not found" => "Check-in-Code nicht gefunden",
+        "registration already checked in" => "Die Anmeldung wurde bereits eingecheckt",
+        "currency must be a three-letter ISO 4217 code" => {
+            "Die Währung muss ein dreistelliger ISO-4217-Code sein"
+        }
+        "price must not be negative" => "Der Preis darf nicht negativ sein",
+        "audience must not be empty" => "Die Zielgruppe darf nicht leer sein",
+        "events with free entry cannot have a price" => {
+            "Veranstaltungen mit freiem Eintritt können keinen Preis haben"
+        }
+        "limits must not be negative" => "Limits dürfen nicht negativ sein",
+        "custom field not found" => "Zusatzfeld nicht gefunden",
+        "custom_fields must be an object" => "custom_fields muss ein Objekt sein",
+        "cannot change the type of a custom field that is in use" => {
+            "Der Typ eines verwendeten Zusatzfelds kann nicht geändert werden"
+        }
+        "developer account not found" => "Entwicklerkonto nicht gefunden",
+        "developer account is not approved" => "Das Entwicklerkonto wurde noch nicht freigegeben",
+        "daily API quota exceeded" => "Das tägliche API-Kontingent ist aufgebraucht",
+        "a developer application for this email already exists" => {
+            "Für diese E-Mail-Adresse existiert bereits eine Entwickler-Bewerbung"
+        }
+        "agenda item not found" => "Programmpunkt nicht gefunden",
+        "price tier not found" => "Preisstufe nicht gefunden",
+        "label is required" => "Bezeichnung ist erforderlich",
+        "price_cents must not be negative" => "Der Preis darf nicht negativ sein",
+        "no events to import" => "Keine Veranstaltungen zum Importieren",
+        "nothing to translate" => "Es gibt nichts zu übersetzen",
+        "translation service not configured" => "Der Übersetzungsdienst ist nicht eingerichtet",
+        "translation service failed" => "Der Übersetzungsdienst ist fehlgeschlagen",
+        "service unavailable" => "Dienst nicht verfügbar",
+        _ => return None,
+    };
+    Some(translated)
+}
+
+fn requested_locale(request: &Request) -> Locale {
+    Query::<LanguageQuery>::try_from_uri(request.uri())
+        .ok()
+        .and_then(|Query(query)| query.lang)
+        .as_deref()
+        .and_then(Locale::from_tag)
+        .unwrap_or_else(|| Locale::from_headers(request.headers()))
+}
+
+pub(crate) async fn negotiate_content_language(mut request: Request, next: Next) -> Response {
+    let locale = requested_locale(&request);
+    request.extensions_mut().insert(locale);
+    let mut response = next.run(request).await;
+    response.headers_mut().insert(
+        header::CONTENT_LANGUAGE,
+        HeaderValue::from_static(locale.as_str()),
+    );
+    response
+        .headers_mut()
+        .append(header::VARY, HeaderValue::from_static("accept-language"));
+    response
+}
+
+pub(crate) async fn localize_errors(request: Request, next: Next) -> Response {
+    let locale = Locale::from_headers(request.headers());
+    let response = next.run(request).await;
+
+    let Some(details) = response.extensions().get::<ErrorDetails>().cloned() else {
+        return response;
+    };
+    let Some(message) = translate(&details.message, locale) else {
+        return response;
+    };
+
+    let body = ErrorResponse {
+        code: details.code.to_string(),
+        message: message.to_string(),
+    };
+    let Ok(bytes) = serde_json::to_vec(&body) else {
+        return response;
+    };
+
+    let (mut parts, _) = response.into_parts();
+    parts.headers.insert(
+        header::CONTENT_LANGUAGE,
+        HeaderValue::from_static(locale.as_str()),
+    );
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_highest_weighted_supported_language() {
+        assert_eq!(
+            Locale::from_accept_language("de-DE,de;q=0.9,en;q=0.8"),
+            Locale::De
+        );
+        assert_eq!(
+            Locale::from_accept_language("fr-FR, en;q=0.5, de;q=0.3"),
+            Locale::En
+        );
+        assert_eq!(
+            Locale::from_accept_language("en-US;q=0.4, de;q=0.7"),
+            Locale::De
+        );
+    }
+
+    #[test]
+    fn falls_back_to_english() {
+        assert_eq!(Locale::from_accept_language(""), Locale::En);
+        assert_eq!(Locale::from_accept_language("fr, it;q=0.9"), Locale::En);
+        assert_eq!(translate("insufficient permissions", Locale::En), None);
+        assert_eq!(translate("some dynamic message", Locale::De), None);
+    }
+}
@@ -0,0 +1,175 @@
+use std::{env, time::Duration};
+
+use ldap3::{LdapConnAsync, LdapConnSettings};
+use thiserror::Error;
+
+const DEFAULT_LDAP_TIMEOUT_SECONDS: u64 = 5;
+const INVALID_CREDENTIALS: u32 = 49;
+
+#[derive(Debug, Error)]
+pub enum LdapError {
+    #[error("Invalid LDAP configuration: {0}")]
+    InvalidConfig(String),
+    #[error("LDAP request failed: {0}")]
+    Request(#[from] ldap3::LdapError),
+}
+
+#[derive(Debug, Clone)]
+pub struct LdapAuthenticator {
+    url: String,
+    bind_dn_template: String,
+    email_domain: Option<String>,
+    starttls: bool,
+    timeout: Duration,
+}
+
+impl LdapAuthenticator {
+    pub fn from_env() -> Result<Option<Self>, LdapError> {
+        let Some(url) = env::var("LDAP_URL")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+        else {
+            return Ok(None);
+        };
+        if !url.starts_with("ldap://") && !url.starts_with("ldaps://") {
+            return Err(LdapError::InvalidConfig(
+                "LDAP_URL must start with ldap:// or ldaps://".to_string(),
+            ));
+        }
+
+        let bind_dn_template = env::var("LDAP_BIND_DN_TEMPLATE")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| value.contains("{username}") || value.contains("{email}"))
+            .ok_or_else(|| {
+                LdapError::InvalidConfig(
+                    "LDAP_BIND_DN_TEMPLATE must contain {username} or {email}".to_string(),
+                )
+            })?;
+        let email_domain = env::var("LDAP_EMAIL_DOMAIN")
+            .ok()
+            .map(|value| value.trim().trim_start_matches('@').to_ascii_lowercase())
+            .filter(|value| !value.is_empty());
+        if bind_dn_template.contains("{username}") && email_domain.is_none() {
+            return Err(LdapError::InvalidConfig(
+                "LDAP_EMAIL_DOMAIN is required when LDAP_BIND_DN_TEMPLATE uses {username}"
+                    .to_string(),
+            ));
+        }
+        let starttls = env::var("LDAP_STARTTLS")
+            .map(|value| matches!(value.trim(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        let timeout = env::var("LDAP_TIMEOUT_SECONDS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(DEFAULT_LDAP_TIMEOUT_SECONDS);
+
+        Ok(Some(Self {
+            url,
+            bind_dn_template,
+            email_domain,
+            starttls,
+            timeout: Duration::from_secs(timeout),
+        }))
+    }
+
+    pub fn server(&self) -> &str {
+        &self.url
+    }
+
+    fn handles(&self, email: &str) -> bool {
+        let Some(domain) = &self.email_domain else {
+            return true;
+        };
+        email.rsplit_once('@').is_some_and(|(local, email_domain)| {
+            !local.is_empty() && email_domain.eq_ignore_ascii_case(domain)
+        })
+    }
+
+    fn bind_dn(&self, email: &str) -> String {
+        let username = email.split_once('@').map_or(email, |(local, _)| local);
+        self.bind_dn_template
+            .replace("{username}", &escape_dn_value(username))
+            .replace("{email}", &escape_dn_value(email))
+    }
+
+    pub async fn authenticate(&self, email: &str, password: &str) -> Result<bool, LdapError> {
+        if email.trim().is_empty() || password.is_empty() || !self.handles(email.trim()) {
+            return Ok(false);
+        }
+
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(self.timeout)
+            .set_starttls(self.starttls);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &self.url).await?;
+        ldap3::drive!(conn);
+
+        let result = ldap
+            .with_timeout(self.timeout)
+            .simple_bind(&self.bind_dn(email.trim()), password)
+            .await?;
+        let _ = ldap.unbind().await;
+
+        match result.rc {
+            0 => Ok(true),
+            INVALID_CREDENTIALS => Ok(false),
+            _ => {
+                result.success()?;
+                Ok(false)
+            }
+        }
+    }
+}
+
+fn escape_dn_value(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    let last = value.chars().count().saturating_sub(1);
+    for (index, c) in value.chars().enumerate() {
+        match c {
+            ',' | '+' | '"' | '\\' | '<' | '>' | ';' | '=' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '#' | ' ' if index == 0 => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            ' ' if index == last => escaped.push_str("\\ "),
+            '\0' => escaped.push_str("\\00"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_escaped_bind_dn_from_email() {
+        let ldap = LdapAuthenticator {
+            url: "ldaps://ldap.example.org".to_string(),
+            bind_dn_template: "uid={username},ou=people,dc=thi,dc=de".to_string(),
+            email_domain: Some("thi.de".to_string()),
+            starttls: false,
+            timeout: Duration::from_secs(1),
+        };
+        assert_eq!(
+            ldap.bind_dn("abc1234@thi.de"),
+            "uid=abc1234,ou=people,dc=thi,dc=de"
+        );
+        assert_eq!(
+            ldap.bind_dn("a,b=c@thi.de"),
+            "uid=a\\,b\\=c,ou=people,dc=thi,dc=de"
+        );
+        assert_eq!(escape_dn_value(" #x "), "\\ #x\\ ");
+
+        assert!(ldap.handles("abc1234@THI.de"));
+        assert!(!ldap.handles("abc1234@gmail.com"));
+        assert!(!ldap.handles("abc1234@evil-thi.de"));
+        assert!(!ldap.handles("abc1234"));
+    }
+}
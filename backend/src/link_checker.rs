@@ -0,0 +1,160 @@
+use std::{env, time::Duration};
+
+use reqwest::{Client, Method, StatusCode};
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::{app_state::AppState, error::AppError};
+
+const DEFAULT_INTERVAL_SECONDS: u64 = 6 * 3600;
+const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+const MAX_ERROR_LEN: usize = 500;
+
+#[derive(Debug, Clone)]
+pub struct LinkCheckSettings {
+    pub interval: Option<Duration>,
+    pub timeout: Duration,
+}
+
+impl LinkCheckSettings {
+    pub fn from_env() -> Self {
+        let seconds = |name: &str| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let interval = match seconds("LINK_CHECK_INTERVAL_SECONDS") {
+            Some(0) => None,
+            Some(seconds) => Some(Duration::from_secs(seconds)),
+            None => Some(Duration::from_secs(DEFAULT_INTERVAL_SECONDS)),
+        };
+        let timeout = seconds("LINK_CHECK_TIMEOUT_SECONDS")
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(DEFAULT_TIMEOUT_SECONDS);
+
+        Self {
+            interval,
+            timeout: Duration::from_secs(timeout),
+        }
+    }
+}
+
+struct LinkStatus {
+    ok: bool,
+    status_code: Option<i32>,
+    error: Option<String>,
+}
+
+pub fn spawn_link_checker(state: AppState, settings: LinkCheckSettings) {
+    let Some(every) = settings.interval else {
+        return;
+    };
+    let http = match Client::builder()
+        .timeout(settings.timeout)
+        .user_agent(concat!("campus-life-events/", env!("CARGO_PKG_VERSION")))
+        .build()
+    {
+        Ok(http) => http,
+        Err(err) => {
+            warn!(target: "link_checker", action = "init", %err, "Failed to build link checker HTTP client");
+            return;
+        }
+    };
+
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = check_links(&state, &http).await {
+                warn!(target: "link_checker", action = "run", %err, "Link check run failed");
+            }
+        }
+    });
+}
+
+async fn request_status(http: &Client, method: Method, url: &str) -> Result<StatusCode, String> {
+    http.request(method, url)
+        .send()
+        .await
+        .map(|response| response.status())
+        .map_err(|err| err.to_string())
+}
+
+async fn check_url(http: &Client, url: &str) -> LinkStatus {
+    let mut result = request_status(http, Method::HEAD, url).await;
+    if matches!(
+        result,
+        Ok(StatusCode::METHOD_NOT_ALLOWED | StatusCode::NOT_IMPLEMENTED | StatusCode::FORBIDDEN)
+    ) {
+        result = request_status(http, Method::GET, url).await;
+    }
+
+    match result {
+        Ok(status) => LinkStatus {
+            ok: status.is_success(),
+            status_code: Some(i32::from(status.as_u16())),
+            error: None,
+        },
+        Err(error) => LinkStatus {
+            ok: false,
+            status_code: None,
+            error: Some(error.chars().take(MAX_ERROR_LEN).collect()),
+        },
+    }
+}
+
+async fn check_links(state: &AppState, http: &Client) -> Result<(), AppError> {
+    let urls = sqlx::query_scalar!(
+        r#"
+        SELECT DISTINCT url AS "url!"
+        FROM (
+            SELECT TRIM(event_url) AS url
+            FROM events
+            WHERE end_date_time >= NOW() AND cancelled_at IS NULL
+            UNION
+            SELECT TRIM(website_url) AS url
+            FROM organizers
+        ) AS links
+        WHERE url LIKE 'http://%' OR url LIKE 'https://%'
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut broken = 0usize;
+    for url in &urls {
+        let status = check_url(http, url).await;
+        if !status.ok {
+            broken += 1;
+        }
+        sqlx::query!(
+            r#"
+            INSERT INTO link_checks (url, ok, status_code, error, checked_at)
+            VALUES ($1, $2, $3, $4, NOW())
+            ON CONFLICT (url) DO UPDATE
+            SET ok = EXCLUDED.ok, status_code = EXCLUDED.status_code, error = EXCLUDED.error, checked_at = EXCLUDED.checked_at
+            "#,
+            url,
+            status.ok,
+            status.status_code,
+            status.error
+        )
+        .execute(&state.db)
+        .await?;
+    }
+
+    sqlx::query!("DELETE FROM link_checks WHERE url <> ALL($1)", &urls)
+        .execute(&state.db)
+        .await?;
+
+    info!(
+        target: "link_checker",
+        action = "run",
+        checked = urls.len(),
+        broken,
+        "Link check run finished"
+    );
+
+    Ok(())
+}
@@ -1,22 +1,46 @@
+mod abuse;
 mod api_token;
+mod app_links;
 mod app_state;
 mod authed_user;
 mod cache;
+mod captcha;
 mod cors_config;
+mod database;
 mod dto;
 mod email;
 mod error;
+mod event_quota;
+mod export;
+mod i18n;
+mod ldap;
+mod link_checker;
+mod markdown;
 mod models;
+mod newsletter_delivery;
+mod newsletter_render;
+mod newsletter_scheduler;
+mod og_image;
 mod openapi;
+mod pagination;
+mod password;
+mod pwned_passwords;
+mod reminders;
+mod request_context;
 mod responses;
+mod role;
 mod routes;
+mod term;
+mod timings;
+mod translation;
+mod webhooks;
 
 use std::net::SocketAddr;
 use std::path::Path;
+use std::time::Duration;
 
-use axum::Router;
 use axum::http::{HeaderValue, header};
-use sqlx::postgres::PgPoolOptions;
+use axum::{Router, middleware};
 use tokio::net::TcpListener;
 use tower_http::set_header::SetResponseHeaderLayer;
 use tracing::{error, info, warn};
@@ -25,11 +49,24 @@ use utoipa::OpenApi;
 use utoipa_swagger_ui::{Config, SwaggerUi, SyntaxHighlight};
 
 use crate::{
+    abuse::AbuseSettings,
     app_state::AppState,
     cache::CacheService,
+    captcha::CaptchaVerifier,
+    database::DatabaseSettings,
     email::{EmailClient, EmailClientError},
+    event_quota::EventQuotaLimits,
+    ldap::LdapAuthenticator,
+    link_checker::LinkCheckSettings,
+    newsletter_scheduler::NewsletterScheduleSettings,
     openapi::ApiDoc,
+    password::PasswordHashing,
+    pwned_passwords::PwnedPasswordChecker,
+    reminders::ReminderSettings,
+    role::ProcessRole,
     routes::api_router,
+    translation::TranslationClient,
+    webhooks::WebhookSettings,
 };
 
 fn load_dotenv_from_backend_dir() {
@@ -72,33 +109,35 @@ async fn main() {
     load_dotenv_from_backend_dir();
     init_tracing();
 
+    let role = ProcessRole::from_args_and_env().unwrap_or_else(|err| panic!("{err}"));
+    info!(target: "startup", component = "process", action = "init", %role, "Starting process");
+
     let database_url = std::env::var("DATABASE_URL").expect("DATABASE_URL must be set");
 
-    let pool = PgPoolOptions::new()
-        .max_connections(10)
+    // Run database migrations at startup, without the per-statement timeout of the pool
+    database::run_migrations(&database_url)
+        .await
+        .expect("Failed to run migrations");
+    info!(target: "startup", component = "database", action = "migrate", "Database migrations applied");
+
+    let pool = DatabaseSettings::from_env()
         .connect(&database_url)
         .await
         .expect("Failed to connect to database");
 
     info!(target: "startup", component = "database", action = "connect", "Connected to database");
 
-    // Run database migrations at startup
-    sqlx::migrate!("./migrations")
-        .run(&pool)
-        .await
-        .expect("Failed to run migrations");
-    info!(target: "startup", component = "database", action = "migrate", "Database migrations applied");
-
-    let email_client = match EmailClient::from_env() {
+    let email_client = match EmailClient::from_env().await {
         Ok(Some(client)) => {
             info!(
                 target: "startup",
                 component = "email",
                 action = "init",
                 mode = "enabled",
+                health = client.health().as_str(),
                 "Email notifications enabled"
             );
-            Some(client)
+            Some(client.with_database(pool.clone()))
         }
         Ok(None) => {
             warn!(
@@ -166,9 +205,29 @@ async fn main() {
         db: pool.clone(),
         email: email_client,
         cache,
+        translator: build_translator(),
+        ldap: build_ldap(),
+        captcha: build_captcha(),
+        passwords: PasswordHashing::from_env(),
+        pwned_passwords: build_pwned_passwords(),
         api_token_hmac_key,
+        event_quota: EventQuotaLimits::from_env(),
+        abuse: AbuseSettings::from_env(),
+        public_changes: routes::event_stream::public_change_channel(),
     };
 
+    if role.runs_jobs() {
+        spawn_background_jobs(&state);
+    }
+
+    if !role.serves_http() {
+        info!(target: "startup", component = "http", action = "init", mode = "disabled", "Worker process running background jobs only");
+        tokio::signal::ctrl_c()
+            .await
+            .expect("failed to listen for shutdown signal");
+        return;
+    }
+
     let cors = cors_config::build_cors_layer();
 
     // Note: Rate limiting and CSRF protection would require additional middleware
@@ -191,11 +250,24 @@ async fn main() {
     let api = Router::new()
         .nest("/api/v1", api_router())
         .nest("/api/ical", routes::ical::router())
-        .merge(swagger_router);
+        .merge(swagger_router)
+        .layer(middleware::from_fn(i18n::localize_errors))
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            routes::debug_timings::debug_timings,
+        ));
 
     let app = Router::new()
         .merge(api)
         .merge(routes::mcp::router())
+        .merge(routes::short_links::redirect_router())
+        .merge(routes::sitemap::router())
+        .layer(middleware::from_fn_with_state(
+            state.clone(),
+            routes::abuse::abuse_guard,
+        ))
+        .layer(middleware::from_fn(database::with_route_context))
+        .layer(middleware::from_fn(request_context::with_request_id))
         .layer(cors)
         .layer(SetResponseHeaderLayer::overriding(
             header::X_FRAME_OPTIONS,
@@ -217,9 +289,58 @@ async fn main() {
     let listener = TcpListener::bind(addr)
         .await
         .expect("failed to bind address");
-    axum::serve(listener, app.into_make_service())
-        .await
-        .expect("server error");
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<SocketAddr>(),
+    )
+    .await
+    .expect("server error");
+}
+
+fn spawn_background_jobs(state: &AppState) {
+    if state.cache.is_some() {
+        let flush_seconds = std::env::var("EVENT_VIEW_FLUSH_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|value| value.parse::<u64>().ok())
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(60);
+        routes::event_views::spawn_event_view_flush(
+            state.clone(),
+            Duration::from_secs(flush_seconds),
+        );
+    }
+
+    let reminder_settings = ReminderSettings::from_env();
+    if state.email.is_some() && !reminder_settings.lead_days.is_empty() {
+        info!(target: "startup", component = "reminders", action = "init", mode = "enabled", lead_days = ?reminder_settings.lead_days, "Event reminder emails enabled");
+        reminders::spawn_event_reminders(state.clone(), reminder_settings);
+    } else {
+        info!(target: "startup", component = "reminders", action = "init", mode = "disabled", "Event reminder emails disabled");
+    }
+
+    let link_check_settings = LinkCheckSettings::from_env();
+    if link_check_settings.interval.is_some() {
+        info!(target: "startup", component = "link_checker", action = "init", mode = "enabled", interval = ?link_check_settings.interval, "Dead link checker enabled");
+        link_checker::spawn_link_checker(state.clone(), link_check_settings);
+    } else {
+        info!(target: "startup", component = "link_checker", action = "init", mode = "disabled", "Dead link checker disabled");
+    }
+
+    let webhook_settings = WebhookSettings::from_env();
+    if webhook_settings.interval.is_some() {
+        info!(target: "startup", component = "webhooks", action = "init", mode = "enabled", interval = ?webhook_settings.interval, max_attempts = webhook_settings.max_attempts, "Webhook dispatcher enabled");
+        webhooks::spawn_webhook_dispatcher(state.clone(), webhook_settings);
+    } else {
+        info!(target: "startup", component = "webhooks", action = "init", mode = "disabled", "Webhook dispatcher disabled");
+    }
+
+    let newsletter_schedule = NewsletterScheduleSettings::from_env();
+    if state.email.is_some() && newsletter_schedule.send_time.is_some() {
+        info!(target: "startup", component = "newsletter_scheduler", action = "init", mode = "enabled", send_time = ?newsletter_schedule.send_time, "Weekly newsletter scheduling enabled");
+        newsletter_scheduler::spawn_newsletter_scheduler(state.clone(), newsletter_schedule);
+    } else {
+        info!(target: "startup", component = "newsletter_scheduler", action = "init", mode = "disabled", "Weekly newsletter scheduling disabled");
+    }
 }
 
 fn init_tracing() {
@@ -236,6 +357,74 @@ fn init_tracing() {
         .init();
 }
 
+fn build_translator() -> Option<TranslationClient> {
+    match TranslationClient::from_env() {
+        Ok(Some(client)) => {
+            info!(target: "startup", component = "translation", action = "init", mode = "enabled", provider = client.provider_name(), "Translation suggestions enabled");
+            Some(client)
+        }
+        Ok(None) => {
+            info!(target: "startup", component = "translation", action = "init", mode = "disabled", "Translation suggestions disabled; TRANSLATION_API_KEY not set");
+            None
+        }
+        Err(err) => {
+            warn!(target: "startup", component = "translation", action = "init", mode = "disabled", %err, "Translation suggestions disabled due to invalid configuration");
+            None
+        }
+    }
+}
+
+fn build_pwned_passwords() -> Option<PwnedPasswordChecker> {
+    match PwnedPasswordChecker::from_env() {
+        Ok(Some(checker)) => {
+            info!(target: "startup", component = "pwned_passwords", action = "init", mode = "enabled", "Breached password check enabled");
+            Some(checker)
+        }
+        Ok(None) => {
+            info!(target: "startup", component = "pwned_passwords", action = "init", mode = "disabled", "Breached password check disabled; PWNED_PASSWORDS_CHECK not set");
+            None
+        }
+        Err(err) => {
+            warn!(target: "startup", component = "pwned_passwords", action = "init", mode = "disabled", %err, "Breached password check disabled due to invalid configuration");
+            None
+        }
+    }
+}
+
+fn build_captcha() -> Option<CaptchaVerifier> {
+    match CaptchaVerifier::from_env() {
+        Ok(Some(captcha)) => {
+            info!(target: "startup", component = "captcha", action = "init", mode = "enabled", provider = captcha.provider_name(), "CAPTCHA verification enabled for login and password reset");
+            Some(captcha)
+        }
+        Ok(None) => {
+            info!(target: "startup", component = "captcha", action = "init", mode = "disabled", "CAPTCHA verification disabled; CAPTCHA_SECRET_KEY not set");
+            None
+        }
+        Err(err) => {
+            warn!(target: "startup", component = "captcha", action = "init", mode = "disabled", %err, "CAPTCHA verification disabled due to invalid configuration");
+            None
+        }
+    }
+}
+
+fn build_ldap() -> Option<LdapAuthenticator> {
+    match LdapAuthenticator::from_env() {
+        Ok(Some(ldap)) => {
+            info!(target: "startup", component = "ldap", action = "init", mode = "enabled", server = ldap.server(), "LDAP login enabled for admin accounts");
+            Some(ldap)
+        }
+        Ok(None) => {
+            info!(target: "startup", component = "ldap", action = "init", mode = "disabled", "LDAP login disabled; LDAP_URL not set");
+            None
+        }
+        Err(err) => {
+            warn!(target: "startup", component = "ldap", action = "init", mode = "disabled", %err, "LDAP login disabled due to invalid configuration");
+            None
+        }
+    }
+}
+
 async fn build_cache() -> Option<CacheService> {
     let redis_url = match std::env::var("REDIS_URL") {
         Ok(url) => url,
@@ -249,11 +438,22 @@ async fn build_cache() -> Option<CacheService> {
         .ok()
         .and_then(|value| value.parse::<u64>().ok())
         .unwrap_or(60);
+    let failure_threshold = std::env::var("CACHE_BREAKER_FAILURE_THRESHOLD")
+        .ok()
+        .and_then(|value| value.parse::<u32>().ok())
+        .unwrap_or(3);
+    let cooldown_seconds = std::env::var("CACHE_BREAKER_COOLDOWN_SECONDS")
+        .ok()
+        .and_then(|value| value.parse::<u64>().ok())
+        .unwrap_or(30);
 
     match CacheService::connect(&redis_url, ttl, "cle").await {
         Ok(cache) => {
-            info!(target: "startup", component = "cache", action = "init", mode = "enabled", ttl_seconds = ttl, "Connected to Redis cache");
-            Some(cache)
+            info!(target: "startup", component = "cache", action = "init", mode = "enabled", ttl_seconds = ttl, failure_threshold, cooldown_seconds, "Connected to Redis cache");
+            Some(
+                cache
+                    .with_circuit_breaker(failure_threshold, Duration::from_secs(cooldown_seconds)),
+            )
         }
         Err(err) => {
             warn!(target: "startup", component = "cache", action = "init", mode = "disabled", %err, "Cache disabled due to initialization failure");
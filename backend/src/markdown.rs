@@ -0,0 +1,42 @@
+use pulldown_cmark::{Options, Parser, html};
+
+pub fn render_markdown(source: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+
+    let mut rendered = String::new();
+    html::push_html(&mut rendered, Parser::new_ext(source, options));
+
+    ammonia::Builder::default()
+        .link_rel(Some("noopener noreferrer nofollow"))
+        .url_schemes(["http", "https", "mailto"].into_iter().collect())
+        .clean(&rendered)
+        .to_string()
+}
+
+pub fn render_optional_markdown(source: Option<&str>) -> Option<String> {
+    source
+        .map(str::trim)
+        .filter(|source| !source.is_empty())
+        .map(render_markdown)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_formatting_and_strips_unsafe_html() {
+        let html = render_markdown(
+            "**Bold** and [link](https://example.com)\n\n- one\n- two\n\n<script>alert(1)</script>\n\n[x](javascript:alert(1))",
+        );
+        assert!(html.contains("<strong>Bold</strong>"));
+        assert!(html.contains(
+            "<a href=\"https://example.com\" rel=\"noopener noreferrer nofollow\">link</a>"
+        ));
+        assert!(html.contains("<li>one</li>"));
+        assert!(!html.contains("<script"));
+        assert!(!html.contains("href=\"javascript"));
+    }
+}
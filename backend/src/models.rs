@@ -1,4 +1,4 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::FromRow;
@@ -36,11 +36,176 @@ pub struct Organizer {
     pub registration_number: Option<String>,
     pub non_profit: bool,
     pub newsletter: bool,
+    pub event_footer_de: Option<String>,
+    pub event_footer_en: Option<String>,
+    pub calendar_name: Option<String>,
+    pub calendar_color: Option<String>,
+    pub calendar_refresh_minutes: Option<i32>,
     pub organizer_kind: OrganizerKind,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema, Default,
+)]
+#[sqlx(type_name = "event_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventStatus {
+    Draft,
+    #[default]
+    Published,
+    PendingReview,
+    Rejected,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema, Default,
+)]
+#[sqlx(type_name = "event_visibility", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventVisibility {
+    #[default]
+    Public,
+    Unlisted,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, sqlx::Type, ToSchema,
+)]
+#[sqlx(type_name = "event_audience", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventAudience {
+    Students,
+    Staff,
+    Alumni,
+    Public,
+}
+
+impl EventAudience {
+    pub const ALL: [EventAudience; 4] = [
+        EventAudience::Students,
+        EventAudience::Staff,
+        EventAudience::Alumni,
+        EventAudience::Public,
+    ];
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema, Default,
+)]
+#[sqlx(type_name = "developer_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeveloperStatus {
+    #[default]
+    Pending,
+    Approved,
+    Rejected,
+    Suspended,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "custom_field_type", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CustomFieldType {
+    Text,
+    Number,
+    Boolean,
+    Date,
+    DateTime,
+    Url,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct CustomFieldDefinition {
+    pub id: i64,
+    pub key: String,
+    pub label_de: String,
+    pub label_en: String,
+    pub field_type: CustomFieldType,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct NewsletterSponsor {
+    pub id: i64,
+    pub name: String,
+    pub logo_url: Option<String>,
+    pub website_url: Option<String>,
+    pub position: i32,
+    pub active_from: Option<NaiveDate>,
+    pub active_until: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct NewsletterTemplate {
+    pub version: i32,
+    pub language: NewsletterLanguage,
+    pub header_title: String,
+    pub greeting: String,
+    pub intro: String,
+    pub events_heading: String,
+    pub outlook_heading: String,
+    pub no_events_text: String,
+    pub no_outlook_text: String,
+    pub sponsors_heading: String,
+    pub footer_title: String,
+    pub footer_tagline: String,
+    pub footer_note: String,
+    pub created_by: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Venue {
+    pub id: i64,
+    pub name: String,
+    pub building: Option<String>,
+    pub room: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+    pub capacity: Option<i32>,
+    pub close_registration_at_capacity: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(
+    type_name = "academic_period_kind",
+    rename_all = "SCREAMING_SNAKE_CASE"
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AcademicPeriodKind {
+    LectureBreak,
+    ExamPeriod,
+    Holiday,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct AcademicPeriod {
+    pub id: i64,
+    pub kind: AcademicPeriodKind,
+    pub name_de: String,
+    pub name_en: String,
+    pub starts_on: NaiveDate,
+    pub ends_on: NaiveDate,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct AbuseAllowlistEntry {
+    pub ip: String,
+    pub note: Option<String>,
+    pub created_by: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
 #[sqlx(type_name = "audit_type", rename_all = "SCREAMING_SNAKE_CASE")]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
@@ -66,6 +231,21 @@ pub struct Event {
     pub publish_newsletter: bool,
     pub publish_in_ical: bool,
     pub publish_web: bool,
+    pub max_participants: Option<i32>,
+    pub status: EventStatus,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub visibility: EventVisibility,
+    pub series_id: Option<i64>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub custom_fields: Value,
+    pub price_cents: Option<i32>,
+    pub currency: String,
+    pub free_entry: bool,
+    pub audience: Vec<EventAudience>,
+    pub venue_id: Option<i64>,
+    pub featured_until: Option<DateTime<Utc>>,
+    pub term: String,
+    pub all_day: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -86,12 +266,300 @@ pub struct EventWithOrganizer {
     pub publish_newsletter: bool,
     pub publish_in_ical: bool,
     pub publish_web: bool,
+    pub max_participants: Option<i32>,
+    pub status: EventStatus,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub visibility: EventVisibility,
+    pub series_id: Option<i64>,
+    pub publish_at: Option<DateTime<Utc>>,
+    pub custom_fields: Value,
+    pub price_cents: Option<i32>,
+    pub currency: String,
+    pub free_entry: bool,
+    pub audience: Vec<EventAudience>,
+    pub venue_id: Option<i64>,
+    pub featured_until: Option<DateTime<Utc>>,
+    pub term: String,
+    pub all_day: bool,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub organizer_name: String,
     pub organizer_website: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "webhook_event_type")]
+pub enum WebhookEventType {
+    #[sqlx(rename = "EVENT_CREATED")]
+    #[serde(rename = "event.created")]
+    Created,
+    #[sqlx(rename = "EVENT_UPDATED")]
+    #[serde(rename = "event.updated")]
+    Updated,
+    #[sqlx(rename = "EVENT_DELETED")]
+    #[serde(rename = "event.deleted")]
+    Deleted,
+    #[sqlx(rename = "EVENT_CANCELLED")]
+    #[serde(rename = "event.cancelled")]
+    Cancelled,
+}
+
+impl WebhookEventType {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Created => "event.created",
+            Self::Updated => "event.updated",
+            Self::Deleted => "event.deleted",
+            Self::Cancelled => "event.cancelled",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "api_token_scope", rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ApiTokenScope {
+    #[serde(rename = "full")]
+    Full,
+    #[serde(rename = "ical:read")]
+    IcalRead,
+    #[serde(rename = "events:read")]
+    EventsRead,
+    #[serde(rename = "events:write")]
+    EventsWrite,
+}
+
+impl ApiTokenScope {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Full => "full",
+            Self::IcalRead => "ical:read",
+            Self::EventsRead => "events:read",
+            Self::EventsWrite => "events:write",
+        }
+    }
+
+    pub fn grants(self, required: ApiTokenScope) -> bool {
+        self == required
+            || self == Self::Full
+            || (self == Self::EventsWrite && required == Self::EventsRead)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(
+    type_name = "webhook_delivery_status",
+    rename_all = "SCREAMING_SNAKE_CASE"
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WebhookDeliveryStatus {
+    Pending,
+    Delivered,
+    Failed,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Webhook {
+    pub id: i64,
+    pub organizer_id: Option<i64>,
+    pub url: String,
+    pub event_types: Vec<WebhookEventType>,
+    pub active: bool,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct WebhookDelivery {
+    pub id: i64,
+    pub webhook_id: i64,
+    pub event_type: WebhookEventType,
+    pub event_id: i64,
+    pub payload: Value,
+    pub status: WebhookDeliveryStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_status_code: Option<i32>,
+    pub last_error: Option<String>,
+    pub delivered_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct ShortLink {
+    pub id: i64,
+    pub alias: String,
+    pub target_url: String,
+    pub event_id: Option<i64>,
+    pub organizer_id: Option<i64>,
+    pub click_count: i64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema, Default,
+)]
+#[sqlx(type_name = "newsletter_language", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NewsletterLanguage {
+    #[default]
+    De,
+    En,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema, Default)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NewsletterLayout {
+    #[default]
+    Separate,
+    Combined,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema, Default,
+)]
+#[sqlx(type_name = "newsletter_grouping", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NewsletterGrouping {
+    #[default]
+    Day,
+    Organizer,
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema, Default,
+)]
+#[sqlx(type_name = "newsletter_ordering", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NewsletterOrdering {
+    #[default]
+    StartTime,
+    Title,
+    Organizer,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "email_template", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EmailTemplate {
+    OrganizerInvite,
+    AdminInvite,
+    Welcome,
+    PasswordReset,
+    NewsletterPreview,
+    EventReviewDecision,
+    EventChangeNotification,
+    EventReminder,
+    Newsletter,
+    NewsletterScheduleReport,
+    EmailVerification,
+    EventSubscriptionConfirmation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "email_send_status", rename_all = "SCREAMING_SNAKE_CASE")]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EmailSendStatus {
+    Sent,
+    Failed,
+    Skipped,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(
+    type_name = "onboarding_milestone",
+    rename_all = "SCREAMING_SNAKE_CASE"
+)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OnboardingMilestone {
+    AccountInitialized,
+    ProfileCompleted,
+    FirstEventCreated,
+    FirstNewsletterInclusion,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Registration {
+    pub id: i64,
+    pub event_id: i64,
+    pub name: String,
+    pub email: String,
+    pub check_in_code: String,
+    pub checked_in_at: Option<DateTime<Utc>>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct EventSeries {
+    pub id: i64,
+    pub organizer_id: i64,
+    pub title_de: String,
+    pub title_en: String,
+    pub description_de: Option<String>,
+    pub description_en: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct EventTemplate {
+    pub id: i64,
+    pub organizer_id: i64,
+    pub name: String,
+    pub title_de: String,
+    pub title_en: String,
+    pub description_de: Option<String>,
+    pub description_en: Option<String>,
+    pub event_url: Option<String>,
+    pub location: Option<String>,
+    pub duration_minutes: Option<i32>,
+    pub publish_app: bool,
+    pub publish_newsletter: bool,
+    pub publish_in_ical: bool,
+    pub publish_web: bool,
+    pub max_participants: Option<i32>,
+    pub visibility: EventVisibility,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct EventPriceTier {
+    pub id: i64,
+    pub event_id: i64,
+    pub label: String,
+    pub price_cents: i32,
+    pub description: Option<String>,
+    pub position: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct EventAgendaItem {
+    pub id: i64,
+    pub event_id: i64,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub title: String,
+    pub speaker: Option<String>,
+    pub room: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+pub struct Speaker {
+    pub id: i64,
+    pub organizer_id: i64,
+    pub name: String,
+    pub bio: Option<String>,
+    pub photo_url: Option<String>,
+    pub links: Vec<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
 pub struct AuditLogEntry {
     pub id: i64,
@@ -119,6 +587,7 @@ pub struct OrganizerWithInvite {
     pub email: Option<String>,
     pub account_id: Option<i64>,
     pub newsletter: bool,
+    pub needs_review: bool,
     pub organizer_kind: OrganizerKind,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -133,6 +602,7 @@ pub struct OrganizerInviteRow {
     pub account_id: Option<i64>,
     pub account_email: Option<String>,
     pub newsletter: bool,
+    pub needs_review: bool,
     pub organizer_kind: OrganizerKind,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
@@ -188,6 +658,7 @@ impl OrganizerWithInvite {
             email: row.account_email,
             account_id: row.account_id,
             newsletter: row.newsletter,
+            needs_review: row.needs_review,
             organizer_kind: row.organizer_kind,
             created_at: row.created_at,
             updated_at: row.updated_at,
@@ -0,0 +1,179 @@
+use std::{collections::HashSet, env, time::Duration};
+
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use futures_util::future::join_all;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+use sqlx::PgPool;
+use tracing::warn;
+
+use crate::{
+    email::{EmailClient, recipient_hash},
+    models::NewsletterLayout,
+    newsletter_render::RenderedNewsletter,
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const UNSUBSCRIBE_TOKEN_CONTEXT: &[u8] = b"newsletter-unsubscribe-v1:";
+
+const DEFAULT_BATCH_SIZE: usize = 25;
+const DEFAULT_BATCH_DELAY_MS: u64 = 1000;
+
+#[derive(Debug, Clone)]
+pub struct NewsletterDeliverySettings {
+    pub recipients: Vec<String>,
+    pub layout: NewsletterLayout,
+    pub batch_size: usize,
+    pub batch_delay: Duration,
+}
+
+impl NewsletterDeliverySettings {
+    pub fn from_env() -> Self {
+        let mut recipients: Vec<String> = env::var("NEWSLETTER_RECIPIENTS")
+            .unwrap_or_default()
+            .split(',')
+            .map(|recipient| recipient.trim().to_string())
+            .filter(|recipient| !recipient.is_empty())
+            .collect();
+        recipients.sort_unstable_by_key(|recipient| recipient.to_lowercase());
+        recipients.dedup_by_key(|recipient| recipient.to_lowercase());
+
+        let layout = match env::var("NEWSLETTER_LAYOUT") {
+            Ok(value) if value.trim().eq_ignore_ascii_case("combined") => {
+                NewsletterLayout::Combined
+            }
+            _ => NewsletterLayout::Separate,
+        };
+
+        Self {
+            recipients,
+            layout,
+            batch_size: env::var("NEWSLETTER_BATCH_SIZE")
+                .ok()
+                .and_then(|value| value.trim().parse::<usize>().ok())
+                .filter(|size| *size > 0)
+                .unwrap_or(DEFAULT_BATCH_SIZE),
+            batch_delay: Duration::from_millis(
+                env::var("NEWSLETTER_BATCH_DELAY_MS")
+                    .ok()
+                    .and_then(|value| value.trim().parse::<u64>().ok())
+                    .unwrap_or(DEFAULT_BATCH_DELAY_MS),
+            ),
+        }
+    }
+}
+
+fn unsubscribe_mac(key: &[u8; 32], email: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key.as_slice()).expect("HMAC accepts 32-byte key");
+    mac.update(UNSUBSCRIBE_TOKEN_CONTEXT);
+    mac.update(email.as_bytes());
+    mac
+}
+
+pub fn unsubscribe_token(key: &[u8; 32], email: &str) -> String {
+    let email = email.trim().to_lowercase();
+    let signature = unsubscribe_mac(key, &email).finalize().into_bytes();
+    format!(
+        "{}.{}",
+        URL_SAFE_NO_PAD.encode(email.as_bytes()),
+        URL_SAFE_NO_PAD.encode(signature)
+    )
+}
+
+pub fn verify_unsubscribe_token(key: &[u8; 32], token: &str) -> Option<String> {
+    let (email, signature) = token.split_once('.')?;
+    let email = String::from_utf8(URL_SAFE_NO_PAD.decode(email).ok()?).ok()?;
+    let signature = URL_SAFE_NO_PAD.decode(signature).ok()?;
+    unsubscribe_mac(key, &email)
+        .verify_slice(&signature)
+        .ok()
+        .map(|_| email)
+}
+
+pub async fn active_newsletter_recipients(
+    db: &PgPool,
+    settings: &NewsletterDeliverySettings,
+) -> Result<Vec<String>, sqlx::Error> {
+    let unsubscribed: HashSet<String> =
+        sqlx::query_scalar!("SELECT recipient_hash FROM newsletter_unsubscribes")
+            .fetch_all(db)
+            .await?
+            .into_iter()
+            .collect();
+    Ok(settings
+        .recipients
+        .iter()
+        .filter(|recipient| !unsubscribed.contains(&recipient_hash(recipient)))
+        .cloned()
+        .collect())
+}
+
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NewsletterDeliveryOutcome {
+    pub sent: usize,
+    pub failed: usize,
+}
+
+pub async fn deliver_newsletter(
+    email: &EmailClient,
+    settings: &NewsletterDeliverySettings,
+    recipients: &[String],
+    subject: &str,
+    rendered: &RenderedNewsletter,
+    unsubscribe_key: Option<&[u8; 32]>,
+) -> NewsletterDeliveryOutcome {
+    let mut outcome = NewsletterDeliveryOutcome::default();
+    for (index, batch) in recipients.chunks(settings.batch_size).enumerate() {
+        if index > 0 && !settings.batch_delay.is_zero() {
+            tokio::time::sleep(settings.batch_delay).await;
+        }
+        let results = join_all(batch.iter().map(|recipient| {
+            let token = unsubscribe_key.map(|key| unsubscribe_token(key, recipient));
+            async move {
+                email
+                    .send_newsletter_email(
+                        recipient,
+                        subject,
+                        &rendered.html,
+                        &rendered.text,
+                        token.as_deref(),
+                    )
+                    .await
+            }
+        }))
+        .await;
+        for result in results {
+            match result {
+                Ok(()) => outcome.sent += 1,
+                Err(err) => {
+                    outcome.failed += 1;
+                    warn!(target: "newsletter", action = "send", batch = index, %err, "Failed to deliver newsletter email");
+                }
+            }
+        }
+    }
+    outcome
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unsubscribe_tokens_roundtrip_and_reject_tampering() {
+        let key = [7u8; 32];
+        let token = unsubscribe_token(&key, " Student@THI.de ");
+        assert_eq!(
+            verify_unsubscribe_token(&key, &token).as_deref(),
+            Some("student@thi.de")
+        );
+        assert_eq!(verify_unsubscribe_token(&[8u8; 32], &token), None);
+        let forged = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode("other@thi.de"),
+            token.split_once('.').unwrap().1
+        );
+        assert_eq!(verify_unsubscribe_token(&key, &forged), None);
+    }
+}
@@ -0,0 +1,397 @@
+use chrono::{DateTime, Datelike, Duration, Utc, Weekday};
+use chrono_tz::Europe::Berlin;
+use minijinja::Environment;
+use serde::Serialize;
+
+use crate::{
+    models::{NewsletterLanguage, NewsletterTemplate},
+    responses::{NewsletterDataResponse, NewsletterEventResponse},
+};
+
+const HTML_TEMPLATE: &str = "newsletter.html";
+const TEXT_TEMPLATE: &str = "newsletter.txt";
+const HEADER_IMAGE_URL: &str =
+    "https://nbg1.your-objectstorage.com/neuland/uploads/cl-tool/cl-header.webp";
+const CONTACT_EMAIL: &str = "campus-life@thi.de";
+
+#[derive(Clone)]
+pub struct RenderedNewsletter {
+    pub html: String,
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NewsletterVariant {
+    German,
+    English,
+    Combined,
+}
+
+impl NewsletterVariant {
+    fn primary_language(self) -> NewsletterLanguage {
+        match self {
+            Self::English => NewsletterLanguage::En,
+            Self::German | Self::Combined => NewsletterLanguage::De,
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct NewsletterView<'a> {
+    subject: &'a str,
+    lang: &'static str,
+    copy: CopyView,
+    labels: LabelsView,
+    intro: Option<&'a str>,
+    intro_secondary: Option<&'a str>,
+    outro: Option<&'a str>,
+    outro_secondary: Option<&'a str>,
+    header_image_url: &'static str,
+    contact_email: &'static str,
+    week_number: u32,
+    week_after_number: u32,
+    week_range: String,
+    show_section_labels: bool,
+    sections: Vec<SectionView<'a>>,
+    following: Vec<EventView<'a>>,
+    organizer_names: Vec<&'a str>,
+    sponsors: Vec<SponsorView<'a>>,
+}
+
+#[derive(Serialize)]
+struct CopyView {
+    header_title: String,
+    greeting: String,
+    intro: String,
+    events_heading: String,
+    outlook_heading: String,
+    no_events_text: String,
+    no_outlook_text: String,
+    sponsors_heading: String,
+    footer_title: String,
+    footer_tagline: String,
+    footer_note: String,
+}
+
+#[derive(Serialize)]
+struct LabelsView {
+    calendar_week: &'static str,
+    learn_more: &'static str,
+    organizers: &'static str,
+    contact: &'static str,
+}
+
+#[derive(Serialize)]
+struct SectionView<'a> {
+    label: String,
+    events: Vec<EventView<'a>>,
+}
+
+#[derive(Serialize)]
+struct EventView<'a> {
+    title: &'a str,
+    title_secondary: Option<&'a str>,
+    organizer_name: &'a str,
+    organizer_website: Option<&'a str>,
+    date_label: String,
+    time_label: Option<String>,
+    location: Option<&'a str>,
+    price_label: Option<String>,
+    description_html: Option<&'a str>,
+    description_text: Option<&'a str>,
+    description_secondary_html: Option<&'a str>,
+    description_secondary_text: Option<&'a str>,
+    event_url: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct SponsorView<'a> {
+    name: &'a str,
+    logo_url: Option<&'a str>,
+    website_url: Option<&'a str>,
+}
+
+pub(crate) fn german_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Montag",
+        Weekday::Tue => "Dienstag",
+        Weekday::Wed => "Mittwoch",
+        Weekday::Thu => "Donnerstag",
+        Weekday::Fri => "Freitag",
+        Weekday::Sat => "Samstag",
+        Weekday::Sun => "Sonntag",
+    }
+}
+
+fn english_weekday(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "Monday",
+        Weekday::Tue => "Tuesday",
+        Weekday::Wed => "Wednesday",
+        Weekday::Thu => "Thursday",
+        Weekday::Fri => "Friday",
+        Weekday::Sat => "Saturday",
+        Weekday::Sun => "Sunday",
+    }
+}
+
+fn labels(language: NewsletterLanguage) -> LabelsView {
+    match language {
+        NewsletterLanguage::De => LabelsView {
+            calendar_week: "Kalenderwoche",
+            learn_more: "Mehr erfahren",
+            organizers: "Die teilnehmenden Organisationen und Hochschulgruppen:",
+            contact: "Bei Rückfragen wenden Sie sich bitte an",
+        },
+        NewsletterLanguage::En => LabelsView {
+            calendar_week: "Calendar week",
+            learn_more: "Learn more",
+            organizers: "Participating organizations and student groups:",
+            contact: "If you have any questions, please contact",
+        },
+    }
+}
+
+fn date_label(value: DateTime<Utc>, language: NewsletterLanguage) -> String {
+    let local = value.with_timezone(&Berlin);
+    let weekday = match language {
+        NewsletterLanguage::De => german_weekday(local.weekday()),
+        NewsletterLanguage::En => english_weekday(local.weekday()),
+    };
+    format!("{weekday}, {}", local.format("%d.%m.%Y"))
+}
+
+fn price_label(cents: i32, currency: &str) -> String {
+    let symbol = match currency {
+        "EUR" => "€",
+        other => other,
+    };
+    format!("{},{:02} {symbol}", cents / 100, cents % 100)
+}
+
+fn non_blank(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn combine(german: &str, english: &str, separator: &str) -> String {
+    if german == english || english.is_empty() {
+        german.to_string()
+    } else if german.is_empty() {
+        english.to_string()
+    } else {
+        format!("{german}{separator}{english}")
+    }
+}
+
+fn copy_view(
+    variant: NewsletterVariant,
+    german: &NewsletterTemplate,
+    english: &NewsletterTemplate,
+) -> CopyView {
+    let (primary, secondary) = match variant {
+        NewsletterVariant::German => (german, None),
+        NewsletterVariant::English => (english, None),
+        NewsletterVariant::Combined => (german, Some(english)),
+    };
+    let heading = |field: fn(&NewsletterTemplate) -> &str| match secondary {
+        Some(secondary) => combine(field(primary), field(secondary), " / "),
+        None => field(primary).to_string(),
+    };
+    let paragraph = |field: fn(&NewsletterTemplate) -> &str| match secondary {
+        Some(secondary) => combine(field(primary), field(secondary), "\n\n"),
+        None => field(primary).to_string(),
+    };
+    CopyView {
+        header_title: heading(|copy| &copy.header_title),
+        greeting: heading(|copy| &copy.greeting),
+        intro: paragraph(|copy| &copy.intro),
+        events_heading: heading(|copy| &copy.events_heading),
+        outlook_heading: heading(|copy| &copy.outlook_heading),
+        no_events_text: heading(|copy| &copy.no_events_text),
+        no_outlook_text: heading(|copy| &copy.no_outlook_text),
+        sponsors_heading: heading(|copy| &copy.sponsors_heading),
+        footer_title: heading(|copy| &copy.footer_title),
+        footer_tagline: heading(|copy| &copy.footer_tagline),
+        footer_note: paragraph(|copy| &copy.footer_note),
+    }
+}
+
+fn event_view(event: &NewsletterEventResponse, variant: NewsletterVariant) -> EventView<'_> {
+    let details = &event.event;
+    let language = variant.primary_language();
+    let time_label = (!details.all_day).then(|| {
+        let start = details
+            .start_date_time
+            .with_timezone(&Berlin)
+            .format("%H:%M");
+        let end = details.end_date_time.with_timezone(&Berlin).format("%H:%M");
+        match language {
+            NewsletterLanguage::De => format!("{start} – {end} Uhr"),
+            NewsletterLanguage::En => format!("{start} – {end}"),
+        }
+    });
+    let price_label = if details.free_entry {
+        Some(
+            match language {
+                NewsletterLanguage::De => "Eintritt frei",
+                NewsletterLanguage::En => "Free entry",
+            }
+            .to_string(),
+        )
+    } else {
+        details
+            .price_cents
+            .map(|cents| price_label(cents, &details.currency))
+    };
+
+    let title_de = details.title_de.as_str();
+    let title_en = non_blank(Some(&details.title_en));
+    let german = (
+        event.description_de_html.as_deref(),
+        non_blank(details.description_de.as_deref()),
+    );
+    let english = (
+        event.description_en_html.as_deref(),
+        non_blank(details.description_en.as_deref()),
+    );
+
+    let (title, title_secondary, description, description_secondary) = match variant {
+        NewsletterVariant::German => (title_de, None, german, (None, None)),
+        NewsletterVariant::English => (
+            title_en.unwrap_or(title_de),
+            None,
+            if english.1.is_some() { english } else { german },
+            (None, None),
+        ),
+        NewsletterVariant::Combined => (
+            title_de,
+            title_en.filter(|title| *title != title_de),
+            german,
+            english,
+        ),
+    };
+
+    EventView {
+        title,
+        title_secondary,
+        organizer_name: &details.organizer_name,
+        organizer_website: details.organizer_website.as_deref(),
+        date_label: date_label(details.start_date_time, language),
+        time_label,
+        location: details.location.as_deref(),
+        price_label,
+        description_html: description.0,
+        description_text: description.1,
+        description_secondary_html: description_secondary.0,
+        description_secondary_text: description_secondary.1,
+        event_url: details.event_url.as_deref(),
+    }
+}
+
+fn newsletter_view<'a>(
+    data: &'a NewsletterDataResponse,
+    variant: NewsletterVariant,
+    german: &NewsletterTemplate,
+    english: &NewsletterTemplate,
+) -> NewsletterView<'a> {
+    let sections = data
+        .next_week_groups
+        .iter()
+        .map(|group| SectionView {
+            label: match variant {
+                NewsletterVariant::German => group.label_de.clone(),
+                NewsletterVariant::English => group.label_en.clone(),
+                NewsletterVariant::Combined => combine(&group.label_de, &group.label_en, " / "),
+            },
+            events: group
+                .event_ids
+                .iter()
+                .filter_map(|id| data.next_week_events.iter().find(|e| e.event.id == *id))
+                .map(|event| event_view(event, variant))
+                .collect(),
+        })
+        .collect();
+    let intro_de = non_blank(data.intro_de.as_deref());
+    let intro_en = non_blank(data.intro_en.as_deref());
+    let outro_de = non_blank(data.outro_de.as_deref());
+    let outro_en = non_blank(data.outro_en.as_deref());
+    let ((intro, intro_secondary), (outro, outro_secondary)) = match variant {
+        NewsletterVariant::German => ((intro_de, None), (outro_de, None)),
+        NewsletterVariant::English => {
+            ((intro_en.or(intro_de), None), (outro_en.or(outro_de), None))
+        }
+        NewsletterVariant::Combined => ((intro_de, intro_en), (outro_de, outro_en)),
+    };
+    let local_start = data.next_week_start.with_timezone(&Berlin);
+    let local_after = data.week_after_start.with_timezone(&Berlin);
+    let language = variant.primary_language();
+    NewsletterView {
+        subject: match language {
+            NewsletterLanguage::De => &data.subject,
+            NewsletterLanguage::En => &data.subject_en,
+        },
+        lang: match language {
+            NewsletterLanguage::De => "de",
+            NewsletterLanguage::En => "en",
+        },
+        copy: copy_view(variant, german, english),
+        labels: labels(language),
+        intro,
+        intro_secondary,
+        outro,
+        outro_secondary,
+        header_image_url: HEADER_IMAGE_URL,
+        contact_email: CONTACT_EMAIL,
+        week_number: local_start.iso_week().week(),
+        week_after_number: local_after.iso_week().week(),
+        week_range: format!(
+            "{} – {}",
+            local_start.format("%d.%m.%Y"),
+            (local_after - Duration::days(1)).format("%d.%m.%Y")
+        ),
+        show_section_labels: data.next_week_groups.len() > 1,
+        sections,
+        following: data
+            .following_week_events
+            .iter()
+            .map(|event| event_view(event, variant))
+            .collect(),
+        organizer_names: data
+            .all_organizers
+            .iter()
+            .map(|organizer| organizer.name.as_str())
+            .collect(),
+        sponsors: data
+            .sponsors
+            .iter()
+            .map(|sponsor| SponsorView {
+                name: &sponsor.name,
+                logo_url: sponsor.logo_url.as_deref(),
+                website_url: sponsor.website_url.as_deref(),
+            })
+            .collect(),
+    }
+}
+
+fn environment() -> Result<Environment<'static>, minijinja::Error> {
+    let mut env = Environment::new();
+    env.set_trim_blocks(true);
+    env.set_lstrip_blocks(true);
+    env.add_template(HTML_TEMPLATE, include_str!("../templates/newsletter.html"))?;
+    env.add_template(TEXT_TEMPLATE, include_str!("../templates/newsletter.txt"))?;
+    Ok(env)
+}
+
+pub fn render_newsletter(
+    data: &NewsletterDataResponse,
+    variant: NewsletterVariant,
+    german: &NewsletterTemplate,
+    english: &NewsletterTemplate,
+) -> Result<RenderedNewsletter, minijinja::Error> {
+    let env = environment()?;
+    let view = newsletter_view(data, variant, german, english);
+    Ok(RenderedNewsletter {
+        html: env.get_template(HTML_TEMPLATE)?.render(&view)?,
+        text: env.get_template(TEXT_TEMPLATE)?.render(&view)?,
+    })
+}
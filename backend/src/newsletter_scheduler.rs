@@ -0,0 +1,169 @@
+use std::{env, time::Duration};
+
+use chrono::{DateTime, Datelike, NaiveTime, Utc, Weekday};
+use chrono_tz::Europe::Berlin;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::NewsletterDataQuery,
+    error::AppError,
+    routes::{
+        events::{newsletter_data, next_week_monday},
+        newsletter::send_newsletter_issue,
+    },
+};
+
+const DEFAULT_CHECK_INTERVAL_SECONDS: u64 = 300;
+const SEND_WEEKDAY: Weekday = Weekday::Fri;
+
+#[derive(Debug, Clone)]
+pub struct NewsletterScheduleSettings {
+    pub send_time: Option<NaiveTime>,
+    pub check_interval: Duration,
+}
+
+impl NewsletterScheduleSettings {
+    pub fn from_env() -> Self {
+        let send_time = env::var("NEWSLETTER_SCHEDULE_TIME")
+            .ok()
+            .and_then(|value| NaiveTime::parse_from_str(value.trim(), "%H:%M").ok());
+        let check_interval = env::var("NEWSLETTER_SCHEDULE_CHECK_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(DEFAULT_CHECK_INTERVAL_SECONDS);
+
+        Self {
+            send_time,
+            check_interval: Duration::from_secs(check_interval),
+        }
+    }
+}
+
+fn is_due(now: DateTime<Utc>, send_time: NaiveTime) -> bool {
+    let local = now.with_timezone(&Berlin);
+    local.weekday() == SEND_WEEKDAY && local.time() >= send_time
+}
+
+pub fn spawn_newsletter_scheduler(state: AppState, settings: NewsletterScheduleSettings) {
+    let Some(send_time) = settings.send_time else {
+        return;
+    };
+    tokio::spawn(async move {
+        let mut ticker = interval(settings.check_interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if !is_due(Utc::now(), send_time) {
+                continue;
+            }
+            if let Err(err) = run_scheduled_newsletter(&state).await {
+                warn!(target: "newsletter", action = "schedule", %err, "Scheduled newsletter run failed");
+            }
+        }
+    });
+}
+
+async fn run_scheduled_newsletter(state: &AppState) -> Result<(), AppError> {
+    let week_start = next_week_monday(Utc::now());
+    let claimed = sqlx::query_scalar!(
+        r#"
+        INSERT INTO newsletter_issues (week_start, scheduled_run_at)
+        VALUES ($1, NOW())
+        ON CONFLICT (week_start) DO UPDATE
+        SET scheduled_run_at = NOW()
+        WHERE newsletter_issues.scheduled_run_at IS NULL
+          AND newsletter_issues.sent_at IS NULL
+        RETURNING week_start
+        "#,
+        week_start
+    )
+    .fetch_optional(&state.db)
+    .await?;
+    if claimed.is_none() {
+        return Ok(());
+    }
+
+    let data = newsletter_data(
+        state,
+        NewsletterDataQuery {
+            week_start: Some(week_start.to_string()),
+            grouping: None,
+            ordering: None,
+        },
+    )
+    .await?;
+    let week_label = week_start.format("%d.%m.%Y");
+
+    let summary = if data.next_week_events.is_empty() {
+        info!(target: "newsletter", action = "schedule", week_start = %week_start, "Skipping scheduled newsletter without events");
+        format!(
+            "der Newsletter für die Woche ab {week_label} wurde nicht automatisch versendet, da keine Veranstaltungen für den Newsletter freigegeben sind."
+        )
+    } else {
+        match send_newsletter_issue(state, data, None).await {
+            Ok(sent) => format!(
+                "der Newsletter „{}“ für die Woche ab {week_label} wurde automatisch an {} Empfänger versendet ({} fehlgeschlagen).",
+                sent.subject, sent.recipient_count, sent.failed_count
+            ),
+            Err(err) => {
+                warn!(target: "newsletter", action = "schedule", week_start = %week_start, %err, "Scheduled newsletter send failed");
+                format!(
+                    "der automatische Versand des Newsletters für die Woche ab {week_label} ist fehlgeschlagen: {err}\nBitte versende den Newsletter manuell."
+                )
+            }
+        }
+    };
+
+    notify_admins(state, &summary).await
+}
+
+async fn notify_admins(state: &AppState, summary: &str) -> Result<(), AppError> {
+    let Some(email_client) = &state.email else {
+        return Ok(());
+    };
+    let admins = sqlx::query!(
+        r#"
+        SELECT display_name, email AS "email!"
+        FROM accounts
+        WHERE account_type = 'ADMIN' AND email IS NOT NULL
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    for admin in admins {
+        if let Err(err) = email_client
+            .send_newsletter_schedule_report(&admin.email, &admin.display_name, summary)
+            .await
+        {
+            warn!(target: "newsletter", action = "schedule_report", %err, "Failed to notify admin about scheduled newsletter");
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn scheduled_send_is_due_on_friday_after_send_time() {
+        let send_time = NaiveTime::from_hms_opt(10, 0, 0).unwrap();
+        let at = |day, hour| {
+            Berlin
+                .with_ymd_and_hms(2026, 10, day, hour, 30, 0)
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        assert!(!is_due(at(16, 9), send_time));
+        assert!(is_due(at(16, 10), send_time));
+        assert!(is_due(at(16, 23), send_time));
+        assert!(!is_due(at(17, 11), send_time));
+        assert!(!is_due(at(15, 11), send_time));
+    }
+}
@@ -0,0 +1,172 @@
+use std::{
+    env,
+    sync::{Arc, LazyLock},
+};
+
+use resvg::{
+    tiny_skia::{Pixmap, Transform},
+    usvg::{self, fontdb},
+};
+
+const WIDTH: u32 = 1200;
+const HEIGHT: u32 = 630;
+const TITLE_LINE_CHARS: usize = 26;
+const TITLE_FONT_SIZE: usize = 64;
+const TITLE_LINE_HEIGHT: usize = 80;
+const MAX_TITLE_LINES: usize = 3;
+const MAX_META_CHARS: usize = 60;
+const PREFERRED_FAMILIES: &[&str] = &["DejaVu Sans", "Liberation Sans", "Arial"];
+
+static FONTS: LazyLock<Arc<fontdb::Database>> = LazyLock::new(|| {
+    let mut fonts = fontdb::Database::new();
+    match env::var("OG_IMAGE_FONT_DIR") {
+        Ok(dir) if !dir.trim().is_empty() => fonts.load_fonts_dir(dir.trim()),
+        _ => fonts.load_system_fonts(),
+    }
+    let family = PREFERRED_FAMILIES
+        .iter()
+        .find(|family| {
+            fonts
+                .faces()
+                .any(|face| face.families.iter().any(|(name, _)| name == *family))
+        })
+        .map(|family| family.to_string())
+        .or_else(|| {
+            fonts
+                .faces()
+                .find_map(|face| face.families.first().map(|(name, _)| name.clone()))
+        });
+    if let Some(family) = family {
+        fonts.set_sans_serif_family(family);
+    }
+    Arc::new(fonts)
+});
+
+pub struct ShareCard<'a> {
+    pub title: &'a str,
+    pub date: &'a str,
+    pub organizer: &'a str,
+}
+
+pub(crate) fn escape_xml(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            c if c.is_control() => escaped.push(' '),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+fn truncate(value: &str, max_chars: usize) -> String {
+    if value.chars().count() <= max_chars {
+        return value.to_string();
+    }
+    let mut truncated: String = value.chars().take(max_chars - 1).collect();
+    truncated.truncate(truncated.trim_end().len());
+    truncated.push('…');
+    truncated
+}
+
+fn wrap_title(title: &str) -> Vec<String> {
+    let mut lines: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for word in title.split_whitespace() {
+        let word = truncate(word, TITLE_LINE_CHARS);
+        if !current.is_empty()
+            && current.chars().count() + 1 + word.chars().count() > TITLE_LINE_CHARS
+        {
+            lines.push(std::mem::take(&mut current));
+        }
+        if !current.is_empty() {
+            current.push(' ');
+        }
+        current.push_str(&word);
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+
+    if lines.len() > MAX_TITLE_LINES {
+        let rest = lines.split_off(MAX_TITLE_LINES - 1).join(" ");
+        lines.push(truncate(&rest, TITLE_LINE_CHARS));
+    }
+    lines
+}
+
+fn card_svg(card: &ShareCard<'_>) -> String {
+    let lines = wrap_title(card.title);
+    let title_top = 150;
+
+    let title: String = lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            format!(
+                r#"<tspan x="80" y="{}">{}</tspan>"#,
+                title_top + TITLE_FONT_SIZE + index * TITLE_LINE_HEIGHT,
+                escape_xml(line)
+            )
+        })
+        .collect();
+
+    format!(
+        r##"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{HEIGHT}" viewBox="0 0 {WIDTH} {HEIGHT}">
+<defs><linearGradient id="bg" x1="0" y1="0" x2="1" y2="1"><stop offset="0" stop-color="#0f172a"/><stop offset="1" stop-color="#1e3a8a"/></linearGradient></defs>
+<rect width="{WIDTH}" height="{HEIGHT}" fill="url(#bg)"/>
+<rect x="80" y="90" width="96" height="8" rx="4" fill="#38bdf8"/>
+<text font-family="sans-serif" font-size="{TITLE_FONT_SIZE}" font-weight="bold" fill="#ffffff">{title}</text>
+<text x="80" y="500" font-family="sans-serif" font-size="38" fill="#bae6fd">{date}</text>
+<text x="80" y="560" font-family="sans-serif" font-size="32" fill="#cbd5e1">{organizer}</text>
+</svg>"##,
+        date = escape_xml(&truncate(card.date, MAX_META_CHARS)),
+        organizer = escape_xml(&truncate(card.organizer, MAX_META_CHARS)),
+    )
+}
+
+pub fn render_share_card(card: &ShareCard<'_>) -> Result<Vec<u8>, String> {
+    let options = usvg::Options {
+        fontdb: Arc::clone(&FONTS),
+        ..usvg::Options::default()
+    };
+    let tree = usvg::Tree::from_str(&card_svg(card), &options).map_err(|err| err.to_string())?;
+    let mut pixmap =
+        Pixmap::new(WIDTH, HEIGHT).ok_or_else(|| "failed to allocate share card".to_string())?;
+    resvg::render(&tree, Transform::default(), &mut pixmap.as_mut());
+    pixmap.encode_png().map_err(|err| err.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wraps_long_titles_and_escapes_markup() {
+        let lines = wrap_title(
+            "Große Erstsemester-Party mit Live-Musik, DJ und Überraschungsgästen im Audimax bis spät in die Nacht",
+        );
+        assert_eq!(lines.len(), MAX_TITLE_LINES);
+        assert!(
+            lines
+                .iter()
+                .all(|line| line.chars().count() <= TITLE_LINE_CHARS)
+        );
+        assert!(lines[MAX_TITLE_LINES - 1].ends_with('…'));
+
+        assert_eq!(escape_xml("Tom & <Jerry>"), "Tom &amp; &lt;Jerry&gt;");
+
+        let png = render_share_card(&ShareCard {
+            title: "Spieleabend",
+            date: "17.10.2026, 19:00 Uhr",
+            organizer: "Neuland",
+        })
+        .unwrap();
+        assert!(png.starts_with(b"\x89PNG"));
+    }
+}
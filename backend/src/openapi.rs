@@ -2,23 +2,72 @@ use utoipa::OpenApi;
 
 use crate::{
     dto::{
-        ChangePasswordRequest, CreateApiTokenRequest, CreateEventRequest, CreateOrganizerRequest,
-        InitAccountRequest, InviteAdminRequest, ListAuditLogsQuery, ListEventsQuery,
-        ListPublicOrganizersQuery, LoginRequest, RequestPasswordResetRequest, ResetPasswordRequest,
-        SendNewsletterPreviewRequest, SetupTokenLookupRequest, UpdateAccountEmailRequest,
-        UpdateEventRequest, UpdateOrganizerPermissionsRequest, UpdateOrganizerRequest,
+        AbuseAllowlistRequest, AcademicPeriodQuery, AcademicPeriodRequest, AgendaItemRequest,
+        BulkCloneEventsRequest, CacheNamespace, CapacityWarningQuery, ChangePasswordRequest,
+        ChannelQuery, CheckInRequest, CreateApiTokenRequest, CreateDeveloperApiKeyRequest,
+        CreateEventRequest, CreateEventSeriesRequest, CreateEventSubscriptionRequest,
+        CreateOrganizerRequest, CreateRegistrationRequest, CreateShortLinkRequest,
+        CreateWebhookRequest, CustomFieldDefinitionRequest, DeveloperAccountQuery,
+        DeveloperApplicationRequest, EmailLogQuery, EventArchiveQuery, EventChangesQuery,
+        EventConflictQuery, EventSnapshotQuery, EventTemplateRequest, EventViewStatsQuery,
+        ExportAuditLogsQuery, ExportEventsQuery, ExportFormat, FeatureEventRequest, IcalFeedQuery,
+        ImportEventsRequest, InitAccountRequest, InstantiateEventTemplateRequest,
+        InviteAdminRequest, LanguageQuery, ListAuditLogsQuery, ListEventsQuery,
+        ListPublicOrganizersQuery, LocationSuggestQuery, LoginRequest, NewsletterArchiveQuery,
+        NewsletterChangesQuery, NewsletterSponsorRequest, NewsletterTemplateQuery,
+        OnboardingReportQuery, OrganizerFilterQuery, PriceTierRequest, PublicChannel,
+        PublicSearchQuery, PurgeCacheRequest, RejectEventRequest, RequestPasswordResetRequest,
+        ResetPasswordRequest, SendNewsletterPreviewRequest, SendNewsletterRequest,
+        SetEventSpeakersRequest, SetupTokenLookupRequest, SpeakerRequest, SuggestTranslationQuery,
+        TermStatsQuery, TranslationReportQuery, UpdateAccountEmailRequest,
+        UpdateDeveloperAccountRequest, UpdateEventQuotaRequest, UpdateEventRequest,
+        UpdateNewsletterIssueRequest, UpdateNewsletterIssueTextsRequest,
+        UpdateNewsletterTemplateRequest, UpdateNotificationPreferencesRequest,
+        UpdateOrganizerPermissionsRequest, UpdateOrganizerRequest, UpdateWebhookRequest,
+        VenueRequest,
     },
     models::{
-        AdminWithInvite, AuditLogEntry, Event, InviteStatus, Organizer, OrganizerKind,
-        OrganizerWithInvite,
+        AbuseAllowlistEntry, AcademicPeriod, AcademicPeriodKind, AdminWithInvite, ApiTokenScope,
+        AuditLogEntry, CustomFieldDefinition, CustomFieldType, DeveloperStatus, EmailSendStatus,
+        EmailTemplate, Event, EventAgendaItem, EventAudience, EventPriceTier, EventSeries,
+        EventStatus, EventTemplate, EventVisibility, EventWithOrganizer, InviteStatus,
+        NewsletterGrouping, NewsletterLanguage, NewsletterLayout, NewsletterOrdering,
+        NewsletterSponsor, NewsletterTemplate, OnboardingMilestone, Organizer, OrganizerKind,
+        OrganizerWithInvite, Registration, ShortLink, Speaker, Venue, Webhook, WebhookDelivery,
+        WebhookDeliveryStatus, WebhookEventType,
     },
     responses::{
         AccountEmailUpdatedResponse, ApiTokenCreatedResponse, ApiTokenSummaryResponse,
-        AuthUserResponse, ErrorResponse, HealthResponse, IcalEventResponse, NewsletterDataResponse,
-        OrganizerWithStatsResponse, PasswordResetRequestResponse, PublicEventResponse,
-        PublicOrganizerResponse, SetupTokenInfoResponse, SetupTokenResponse,
+        ApiTokenUsageResponse, ApiUsageDayResponse, AuthUserResponse, BrokenLinkResponse,
+        BrokenLinkSource, CachePurgeResponse, CacheStatusResponse, CapacityWarningLevel,
+        CaptchaConfigResponse, CheckInResponse, DeveloperAccountResponse,
+        DeveloperApiKeyCreatedResponse, DeveloperApiKeyResponse, DeveloperApplicationResponse,
+        EmailLogEntry, EmailStatusResponse, EmailVerificationSentResponse, ErrorResponse,
+        EventCapacityWarning, EventConflictReason, EventConflictWarning,
+        EventRegistrationsResponse, EventSnapshotStatus, EventStatisticsResponse,
+        EventSubscriberCountResponse, EventTranslationReportEntry, EventViewDay,
+        EventViewStatsResponse, EventWithConflictsResponse, HealthResponse, IcalEventResponse,
+        IcsImportPreviewResponse, IcsImportSkippedEntry, LocationSuggestion,
+        NewsletterArchiveEntry, NewsletterChangeEntry, NewsletterChangeReportResponse,
+        NewsletterDataResponse, NewsletterEventGroup, NewsletterEventResponse,
+        NewsletterIcalTokenResponse, NewsletterIssueResponse, NewsletterIssueTextsResponse,
+        NewsletterPreviewResponse, NewsletterSendResponse, NewsletterVariantPreview,
+        NotificationPreferencesResponse, OnboardingFunnelResponse, OnboardingStageResponse,
+        OrganizerApiUsageResponse, OrganizerEventQuotaResponse, OrganizerIcalTokenResponse,
+        OrganizerOnboardingResponse, OrganizerSnapshotEntry, OrganizerTranslationReportEntry,
+        OrganizerWithStatsResponse, PasswordResetRequestResponse, PossibleDuplicateEvent,
+        PrivateFeedCreatedResponse, PrivateFeedResponse, PublicEventArchiveMonth,
+        PublicEventArchiveResponse, PublicEventChangeMessage, PublicEventChangesResponse,
+        PublicEventDetailResponse, PublicEventResponse, PublicEventSeriesResponse,
+        PublicEventSnapshotResponse, PublicEventSubscriptionResponse,
+        PublicNewsletterIssueResponse, PublicNewsletterIssueSummary, PublicOrganizerResponse,
+        PublicRegistrationResponse, PublicRelatedEventsResponse, PublicSearchResponse,
+        PublicSearchResult, PublicSearchResultKind, PublicVenueResponse, SetupTokenInfoResponse,
+        SetupTokenResponse, TermStats, TranslationIssue, TranslationReportResponse,
+        TranslationSuggestionResponse, WebhookCreatedResponse,
     },
     routes,
+    translation::TranslationLanguage,
 };
 
 #[derive(OpenApi)]
@@ -48,38 +97,177 @@ use crate::{
         routes::organizers::update_organizer,
         routes::organizers::delete_organizer,
         routes::organizers::generate_setup_token,
+        routes::organizers::get_organizer_api_usage,
+        routes::event_quota::get_event_quota,
+        routes::event_quota::update_event_quota,
         routes::admin::invite_admin,
         routes::admin::list_admins,
         routes::admin::update_account_email,
         routes::admin::update_organizer_permissions,
+        routes::admin::purge_cache,
+        routes::admin::get_cache_status,
+        routes::admin::get_email_status,
+        routes::developers::list_developer_accounts,
+        routes::developers::update_developer_account,
+        routes::translation_report::get_translation_report,
+        routes::moderation::list_pending_events,
+        routes::moderation::approve_event,
+        routes::moderation::set_event_featured,
+        routes::moderation::reject_event,
         routes::events::list_events,
+        routes::events::export_events,
+        routes::custom_fields::list_custom_fields,
+        routes::custom_fields::create_custom_field,
+        routes::custom_fields::update_custom_field,
+        routes::custom_fields::delete_custom_field,
+        routes::custom_fields::list_public_custom_fields,
+        routes::venues::list_venues,
+        routes::venues::create_venue,
+        routes::venues::update_venue,
+        routes::venues::delete_venue,
+        routes::venues::suggest_locations,
+        routes::organizer_snapshots::list_organizer_snapshots,
+        routes::organizer_snapshots::revert_organizer_snapshot,
         routes::events::create_event,
+        routes::conflicts::check_event_conflicts,
+        routes::conflicts::list_capacity_warnings,
+        routes::translations::suggest_translation,
         routes::events::get_event,
         routes::events::update_event,
         routes::events::delete_event,
+        routes::events::cancel_event,
+        routes::event_import::preview_ics_import,
+        routes::event_import::commit_ics_import,
+        routes::event_clone::bulk_clone_events,
         routes::events::get_newsletter_data,
         routes::events::send_newsletter_preview,
+        routes::events::get_newsletter_preview,
         routes::public_events::list_public_events,
         routes::public_events::get_public_event,
+        routes::event_slugs::get_public_event_by_slug,
+        routes::public_events::get_public_event_archive,
+        routes::public_events::list_featured_public_events,
+        routes::public_events::get_public_event_snapshot,
         routes::public_events::list_public_organizers,
         routes::public_events::get_public_organizer,
+        routes::public_events::get_public_series,
+        routes::series::list_series,
+        routes::series::create_series,
+        routes::series::delete_series,
+        routes::event_templates::list_event_templates,
+        routes::event_templates::create_event_template,
+        routes::event_templates::update_event_template,
+        routes::event_templates::delete_event_template,
+        routes::event_templates::instantiate_event_template,
+        routes::registrations::create_registration,
+        routes::registrations::list_event_registrations,
+        routes::registrations::export_event_registrations,
+        routes::registrations::check_in_registration,
+        routes::newsletter::get_newsletter_issue,
+        routes::newsletter::get_newsletter_issue_texts,
+        routes::newsletter::update_newsletter_issue_texts,
+        routes::newsletter::send_newsletter,
+        routes::newsletter::list_newsletter_archive,
+        routes::newsletter_changes::get_newsletter_changes,
+        routes::newsletter::list_public_newsletter_issues,
+        routes::newsletter::get_public_newsletter_issue,
+        routes::newsletter::unsubscribe_newsletter_via_link,
+        routes::newsletter::unsubscribe_newsletter_one_click,
+        routes::onboarding::get_onboarding_report,
+        routes::email_log::list_email_log,
+        routes::newsletter_template::get_newsletter_template,
+        routes::newsletter_template::update_newsletter_template,
+        routes::newsletter_template::list_newsletter_template_versions,
+        routes::link_checks::list_broken_links,
+        routes::academic_periods::list_public_academic_periods,
+        routes::academic_periods::list_academic_periods,
+        routes::academic_periods::create_academic_period,
+        routes::academic_periods::update_academic_period,
+        routes::academic_periods::delete_academic_period,
+        routes::abuse::list_abuse_allowlist,
+        routes::abuse::add_abuse_allowlist_entry,
+        routes::abuse::delete_abuse_allowlist_entry,
+        routes::newsletter::update_newsletter_issue,
+        routes::newsletter::list_newsletter_sponsors,
+        routes::newsletter::create_newsletter_sponsor,
+        routes::newsletter::update_newsletter_sponsor,
+        routes::newsletter::delete_newsletter_sponsor,
+        routes::registrations::get_event_statistics,
+        routes::event_views::get_event_view_stats,
+        routes::event_views::get_term_stats,
+        routes::agenda::list_agenda_items,
+        routes::agenda::create_agenda_item,
+        routes::agenda::update_agenda_item,
+        routes::agenda::delete_agenda_item,
+        routes::price_tiers::list_price_tiers,
+        routes::price_tiers::create_price_tier,
+        routes::price_tiers::update_price_tier,
+        routes::price_tiers::delete_price_tier,
+        routes::subscriptions::create_event_subscription,
+        routes::subscriptions::confirm_event_subscription,
+        routes::subscriptions::delete_event_subscription,
+        routes::subscriptions::unsubscribe_via_link,
+        routes::subscriptions::get_event_subscriber_count,
+        routes::speakers::list_event_speakers,
+        routes::speakers::set_event_speakers,
+        routes::speakers::list_speakers,
+        routes::speakers::create_speaker,
+        routes::speakers::update_speaker,
+        routes::speakers::delete_speaker,
+        routes::event_stream::stream_public_event_changes,
+        routes::public_search::public_search,
+        routes::event_og_image::get_event_og_image,
+        routes::sitemap::get_sitemap,
+        routes::public_events::get_public_event_changes,
+        routes::public_events::list_public_organizer_events,
+        routes::short_links::follow_short_link,
+        routes::short_links::list_short_links,
+        routes::short_links::create_short_link,
+        routes::short_links::delete_short_link,
+        routes::webhooks::list_webhooks,
+        routes::webhooks::create_webhook,
+        routes::webhooks::update_webhook,
+        routes::webhooks::delete_webhook,
+        routes::webhooks::list_webhook_deliveries,
         routes::ical::get_all_events_ical,
         routes::ical::get_cl_events_ical,
         routes::ical::get_thi_events_ical,
         routes::ical::get_organizer_events_ical,
+        routes::ical::get_event_ical,
+        routes::ical::get_private_organizer_ical,
+        routes::private_feeds::get_private_feed,
+        routes::private_feeds::create_private_feed,
+        routes::private_feeds::revoke_private_feed,
+        routes::private_feeds::create_ical_token,
+        routes::private_feeds::revoke_ical_token,
+        routes::private_feeds::create_newsletter_ical_token,
+        routes::private_feeds::revoke_newsletter_ical_token,
+        routes::ical::get_signed_organizer_ical,
+        routes::ical::get_newsletter_ical,
         routes::ical::list_organizer_ical_events,
         routes::audit::list_audit_logs,
+        routes::audit::export_audit_logs,
+        routes::audit::restore_event_from_audit_log,
+        routes::auth::captcha_config,
         routes::auth::login,
         routes::auth::lookup_setup_token,
+        routes::auth::request_email_verification,
         routes::auth::logout,
         routes::auth::me,
+        routes::auth::get_notification_preferences,
+        routes::auth::update_notification_preferences,
         routes::auth::init_account,
         routes::auth::change_password,
         routes::auth::request_password_reset,
         routes::auth::reset_password,
         routes::api_tokens::list_api_tokens,
         routes::api_tokens::create_api_token,
-        routes::api_tokens::revoke_api_token
+        routes::api_tokens::revoke_api_token,
+        routes::developers::apply_for_developer_access,
+        routes::developers::get_developer_account,
+        routes::developers::list_developer_keys,
+        routes::developers::create_developer_key,
+        routes::developers::revoke_developer_key
     ),
     components(schemas(
         AdminWithInvite,
@@ -87,6 +275,7 @@ use crate::{
         OrganizerWithInvite,
         OrganizerWithStatsResponse,
         Event,
+        EventWithOrganizer,
         CreateOrganizerRequest,
         UpdateOrganizerRequest,
         UpdateOrganizerPermissionsRequest,
@@ -95,29 +284,187 @@ use crate::{
         InitAccountRequest,
         SetupTokenLookupRequest,
         ChangePasswordRequest,
+        UpdateNotificationPreferencesRequest,
         RequestPasswordResetRequest,
         PasswordResetRequestResponse,
+        CaptchaConfigResponse,
         ResetPasswordRequest,
         InviteAdminRequest,
         CreateEventRequest,
         UpdateEventRequest,
+        ImportEventsRequest,
+        BulkCloneEventsRequest,
+        IcsImportPreviewResponse,
+        IcsImportSkippedEntry,
         ListEventsQuery,
+        IcalFeedQuery,
         ListPublicOrganizersQuery,
+        LanguageQuery,
+        EventArchiveQuery,
+        EventSnapshotQuery,
         ListAuditLogsQuery,
+        ExportAuditLogsQuery,
+        ExportEventsQuery,
+        ExportFormat,
         SendNewsletterPreviewRequest,
+        PurgeCacheRequest,
+        RejectEventRequest,
+        FeatureEventRequest,
+        CacheNamespace,
+        CachePurgeResponse,
+        EmailStatusResponse,
+        CacheStatusResponse,
+        TranslationReportQuery,
+        TranslationIssue,
+        EventTranslationReportEntry,
+        OrganizerTranslationReportEntry,
+        TranslationReportResponse,
+        SuggestTranslationQuery,
+        TranslationLanguage,
+        TranslationSuggestionResponse,
+        CreateRegistrationRequest,
+        CheckInRequest,
+        EventViewStatsQuery,
+        TermStatsQuery,
+        OnboardingReportQuery,
+        EmailLogQuery,
+        UpdateNewsletterTemplateRequest,
+        NewsletterTemplateQuery,
+        UpdateNewsletterIssueRequest,
+        SendNewsletterRequest,
+        UpdateNewsletterIssueTextsRequest,
+        NewsletterArchiveQuery,
+        NewsletterChangesQuery,
+        NewsletterSponsorRequest,
+        Registration,
+        AgendaItemRequest,
+        EventAgendaItem,
+        PriceTierRequest,
+        EventPriceTier,
+        CreateEventSeriesRequest,
+        EventTemplateRequest,
+        InstantiateEventTemplateRequest,
+        EventTemplate,
+        CustomFieldDefinitionRequest,
+        CustomFieldDefinition,
+        VenueRequest,
+        Venue,
+        CustomFieldType,
+        EventConflictQuery,
+        EventConflictReason,
+        CapacityWarningQuery,
+        CapacityWarningLevel,
+        EventCapacityWarning,
+        PossibleDuplicateEvent,
+        LocationSuggestQuery,
+        LocationSuggestion,
+        OrganizerSnapshotEntry,
+        EventConflictWarning,
+        EventWithConflictsResponse,
+        EventSeries,
+        PublicEventSeriesResponse,
+        SpeakerRequest,
+        SetEventSpeakersRequest,
+        OrganizerFilterQuery,
+        Speaker,
+        CreateShortLinkRequest,
+        ShortLink,
+        PublicEventChangeMessage,
+        EventChangesQuery,
+        PublicEventChangesResponse,
+        PublicSearchQuery,
+        PublicSearchResponse,
+        PublicChannel,
+        ChannelQuery,
+        PublicSearchResult,
+        PublicSearchResultKind,
+        CreateWebhookRequest,
+        UpdateWebhookRequest,
+        Webhook,
+        WebhookCreatedResponse,
+        WebhookDelivery,
+        WebhookDeliveryStatus,
+        WebhookEventType,
+        PublicRegistrationResponse,
+        EventRegistrationsResponse,
+        CheckInResponse,
+        OnboardingFunnelResponse,
+        EmailLogEntry,
+        BrokenLinkSource,
+        BrokenLinkResponse,
+        AbuseAllowlistEntry,
+        AbuseAllowlistRequest,
+        AcademicPeriod,
+        AcademicPeriodKind,
+        AcademicPeriodRequest,
+        AcademicPeriodQuery,
+        OnboardingStageResponse,
+        OrganizerOnboardingResponse,
+        NewsletterEventGroup,
+        NewsletterIssueResponse,
+        NewsletterSendResponse,
+        NewsletterIssueTextsResponse,
+        NewsletterArchiveEntry,
+        NewsletterChangeEntry,
+        NewsletterChangeReportResponse,
+        PrivateFeedResponse,
+        PrivateFeedCreatedResponse,
+        OrganizerIcalTokenResponse,
+        NewsletterIcalTokenResponse,
+        PublicNewsletterIssueSummary,
+        PublicNewsletterIssueResponse,
+        EventStatisticsResponse,
+        EventViewStatsResponse,
+        TermStats,
+        EventViewDay,
+        CreateEventSubscriptionRequest,
+        PublicEventSubscriptionResponse,
+        EventSubscriberCountResponse,
         AuditLogEntry,
         ErrorResponse,
         HealthResponse,
         AuthUserResponse,
+        NotificationPreferencesResponse,
         CreateApiTokenRequest,
         ApiTokenSummaryResponse,
         ApiTokenCreatedResponse,
+        ApiTokenScope,
+        ApiUsageDayResponse,
+        ApiTokenUsageResponse,
+        OrganizerApiUsageResponse,
+        UpdateEventQuotaRequest,
+        OrganizerEventQuotaResponse,
+        DeveloperApplicationRequest,
+        CreateDeveloperApiKeyRequest,
+        UpdateDeveloperAccountRequest,
+        DeveloperAccountQuery,
+        DeveloperStatus,
+        DeveloperAccountResponse,
+        DeveloperApplicationResponse,
+        DeveloperApiKeyResponse,
+        DeveloperApiKeyCreatedResponse,
         SetupTokenResponse,
         AccountEmailUpdatedResponse,
+        EmailVerificationSentResponse,
         SetupTokenInfoResponse,
         NewsletterDataResponse,
-        PublicEventResponse, PublicOrganizerResponse, IcalEventResponse,
+        NewsletterPreviewResponse,
+        NewsletterEventResponse,
+        PublicEventResponse, PublicEventDetailResponse, PublicRelatedEventsResponse, PublicVenueResponse, PublicEventArchiveResponse, PublicEventArchiveMonth, PublicEventSnapshotResponse, EventSnapshotStatus, PublicOrganizerResponse, IcalEventResponse,
         InviteStatus,
+        EventStatus,
+        EventVisibility,
+        EventAudience,
+        NewsletterGrouping,
+        NewsletterOrdering,
+        NewsletterSponsor,
+        NewsletterTemplate,
+        NewsletterLanguage,
+        NewsletterLayout,
+        NewsletterVariantPreview,
+        OnboardingMilestone,
+        EmailTemplate,
+        EmailSendStatus,
         OrganizerKind
     )),
     tags(
@@ -127,7 +474,11 @@ use crate::{
         (name = "Public", description = "Public event and organizer information"),
         (name = "Audit", description = "Inspect change history"),
         (name = "Auth", description = "Organizer login & sessions"),
-        (name = "Admin", description = "Manage admin accounts")
+        (name = "Admin", description = "Manage admin accounts"),
+        (name = "Short Links", description = "Short URLs for posters and printed material"),
+        (name = "Webhooks", description = "Signed change notifications for external systems"),
+        (name = "Speakers", description = "Reusable speaker and performer profiles"),
+        (name = "Developer", description = "Self-service public API keys for app developers")
     )
 )]
 pub struct ApiDoc;
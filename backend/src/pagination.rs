@@ -0,0 +1,74 @@
+use axum::http::{HeaderMap, HeaderName, HeaderValue, Uri, header};
+
+pub const TOTAL_COUNT_HEADER: &str = "x-total-count";
+
+fn page_link(uri: &Uri, limit: i64, offset: i64, rel: &str) -> String {
+    let mut query: Vec<&str> = uri
+        .query()
+        .unwrap_or_default()
+        .split('&')
+        .filter(|pair| {
+            let key = pair.split('=').next().unwrap_or_default();
+            !pair.is_empty() && key != "limit" && key != "offset"
+        })
+        .collect();
+    let paging = format!("limit={limit}&offset={offset}");
+    query.push(&paging);
+    format!("<{}?{}>; rel=\"{rel}\"", uri.path(), query.join("&"))
+}
+
+fn link_header(uri: &Uri, total: i64, limit: i64, offset: i64) -> String {
+    let last = (total.max(1) - 1) / limit * limit;
+    let mut links = vec![page_link(uri, limit, 0, "first")];
+    if offset > 0 {
+        links.push(page_link(uri, limit, (offset - limit).max(0), "prev"));
+    }
+    if offset + limit < total {
+        links.push(page_link(uri, limit, offset + limit, "next"));
+    }
+    links.push(page_link(uri, limit, last, "last"));
+    links.join(", ")
+}
+
+pub fn pagination_headers(
+    uri: &Uri,
+    total: i64,
+    limit: Option<i64>,
+    offset: Option<i64>,
+) -> HeaderMap {
+    let mut headers = HeaderMap::new();
+    headers.insert(
+        HeaderName::from_static(TOTAL_COUNT_HEADER),
+        HeaderValue::from(total),
+    );
+    if let Some(limit) = limit {
+        let link = link_header(uri, total, limit.max(1), offset.unwrap_or(0).max(0));
+        if let Ok(value) = HeaderValue::from_str(&link) {
+            headers.insert(header::LINK, value);
+        }
+    }
+    headers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn links_keep_filters_and_step_through_pages() {
+        let uri: Uri = "/api/v1/events?q=party&limit=10&offset=10".parse().unwrap();
+        let headers = pagination_headers(&uri, 25, Some(10), Some(10));
+        assert_eq!(headers[TOTAL_COUNT_HEADER], "25");
+        assert_eq!(
+            headers[header::LINK],
+            "</api/v1/events?q=party&limit=10&offset=0>; rel=\"first\", \
+             </api/v1/events?q=party&limit=10&offset=0>; rel=\"prev\", \
+             </api/v1/events?q=party&limit=10&offset=20>; rel=\"next\", \
+             </api/v1/events?q=party&limit=10&offset=20>; rel=\"last\""
+        );
+
+        let unpaged = pagination_headers(&"/api/v1/events".parse().unwrap(), 3, None, None);
+        assert_eq!(unpaged[TOTAL_COUNT_HEADER], "3");
+        assert!(!unpaged.contains_key(header::LINK));
+    }
+}
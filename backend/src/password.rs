@@ -0,0 +1,95 @@
+use std::env;
+
+use argon2::{Algorithm, Argon2, Params, Version};
+use password_hash::{
+    PasswordHash, PasswordHasher as _, PasswordVerifier as _, SaltString, rand_core::OsRng,
+};
+use tracing::warn;
+
+#[derive(Clone)]
+pub struct PasswordHashing {
+    argon2: Argon2<'static>,
+    params: Params,
+}
+
+impl PasswordHashing {
+    pub fn from_env() -> Self {
+        let number = |name: &str, default: u32| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u32>().ok())
+                .unwrap_or(default)
+        };
+        let memory = number("ARGON2_MEMORY_KIB", Params::DEFAULT_M_COST);
+        let iterations = number("ARGON2_ITERATIONS", Params::DEFAULT_T_COST);
+        let parallelism = number("ARGON2_PARALLELISM", Params::DEFAULT_P_COST);
+
+        let params = Params::new(memory, iterations, parallelism, None).unwrap_or_else(|err| {
+            warn!(target: "startup", component = "passwords", action = "init", %err, memory, iterations, parallelism, "Invalid Argon2 parameters; using defaults");
+            Params::default()
+        });
+        Self::with_params(params)
+    }
+
+    pub fn with_params(params: Params) -> Self {
+        Self {
+            argon2: Argon2::new(Algorithm::Argon2id, Version::V0x13, params.clone()),
+            params,
+        }
+    }
+
+    pub fn hash(&self, password: &str) -> Result<String, password_hash::Error> {
+        let salt = SaltString::generate(&mut OsRng);
+        Ok(self
+            .argon2
+            .hash_password(password.as_bytes(), &salt)?
+            .to_string())
+    }
+
+    pub fn verify(&self, password: &str, stored_hash: &str) -> Result<(), password_hash::Error> {
+        let parsed = PasswordHash::new(stored_hash)?;
+        self.argon2.verify_password(password.as_bytes(), &parsed)
+    }
+
+    pub fn needs_rehash(&self, stored_hash: &str) -> bool {
+        let Ok(parsed) = PasswordHash::new(stored_hash) else {
+            return false;
+        };
+        if parsed.algorithm != Algorithm::Argon2id.ident()
+            || parsed.version != Some(Version::V0x13.into())
+        {
+            return true;
+        }
+        let Ok(stored) = Params::try_from(&parsed) else {
+            return true;
+        };
+        stored.m_cost() < self.params.m_cost()
+            || stored.t_cost() < self.params.t_cost()
+            || stored.p_cost() < self.params.p_cost()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rehashes_only_when_configured_cost_increases() {
+        let cheap = PasswordHashing::with_params(Params::new(1024, 1, 1, None).unwrap());
+        let stronger = PasswordHashing::with_params(Params::new(2048, 2, 1, None).unwrap());
+
+        let hash = cheap.hash("correct horse battery staple").unwrap();
+        assert!(hash.contains("m=1024,t=1,p=1"));
+        assert!(cheap.verify("correct horse battery staple", &hash).is_ok());
+        assert!(
+            stronger
+                .verify("correct horse battery staple", &hash)
+                .is_ok()
+        );
+        assert!(cheap.verify("wrong", &hash).is_err());
+
+        assert!(!cheap.needs_rehash(&hash));
+        assert!(stronger.needs_rehash(&hash));
+        assert!(!cheap.needs_rehash(&stronger.hash("x").unwrap()));
+    }
+}
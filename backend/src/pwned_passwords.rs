@@ -0,0 +1,85 @@
+use std::{env, time::Duration};
+
+use sha1::{Digest, Sha1};
+
+const DEFAULT_PWNED_PASSWORDS_URL: &str = "https://api.pwnedpasswords.com/range/";
+const DEFAULT_PWNED_PASSWORDS_TIMEOUT_SECONDS: u64 = 3;
+
+#[derive(Clone)]
+pub struct PwnedPasswordChecker {
+    http: reqwest::Client,
+    api_url: String,
+}
+
+impl PwnedPasswordChecker {
+    pub fn from_env() -> Result<Option<Self>, reqwest::Error> {
+        let enabled = env::var("PWNED_PASSWORDS_CHECK")
+            .map(|value| matches!(value.trim(), "1" | "true" | "yes"))
+            .unwrap_or(false);
+        if !enabled {
+            return Ok(None);
+        }
+
+        let api_url = env::var("PWNED_PASSWORDS_API_URL")
+            .ok()
+            .map(|value| value.trim().to_string())
+            .filter(|value| !value.is_empty())
+            .unwrap_or_else(|| DEFAULT_PWNED_PASSWORDS_URL.to_string());
+        let timeout = env::var("PWNED_PASSWORDS_TIMEOUT_SECONDS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(DEFAULT_PWNED_PASSWORDS_TIMEOUT_SECONDS);
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout))
+            .user_agent(concat!("campus-life-events/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Ok(Some(Self { http, api_url }))
+    }
+
+    pub async fn is_pwned(&self, password: &str) -> Result<bool, reqwest::Error> {
+        let digest: String = Sha1::digest(password.as_bytes())
+            .iter()
+            .map(|byte| format!("{byte:02X}"))
+            .collect();
+        let (prefix, suffix) = digest.split_at(5);
+
+        let body = self
+            .http
+            .get(format!("{}{prefix}", self.api_url))
+            .header("Add-Padding", "true")
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        Ok(range_contains(&body, suffix))
+    }
+}
+
+fn range_contains(body: &str, suffix: &str) -> bool {
+    body.lines().any(|line| {
+        line.trim()
+            .split_once(':')
+            .is_some_and(|(candidate, count)| {
+                candidate.eq_ignore_ascii_case(suffix)
+                    && count.trim().parse::<u64>().is_ok_and(|count| count > 0)
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_suffix_and_ignores_padding_entries() {
+        let body = "0018A45C4D1DEF81644B54AB7F969B88D65:1\r\n\
+                    1E4C9B93F3F0682250B6CF8331B7EE68FD8:0\r\n\
+                    00D4F6E8FA6EECAD2A3AA415EEC418D38EC:2";
+        assert!(range_contains(body, "0018a45c4d1def81644b54ab7f969b88d65"));
+        assert!(!range_contains(body, "1E4C9B93F3F0682250B6CF8331B7EE68FD8"));
+        assert!(!range_contains(body, "FFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFFF"));
+    }
+}
@@ -0,0 +1,176 @@
+use std::{env, time::Duration};
+
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{info, warn};
+
+use crate::{app_state::AppState, error::AppError};
+
+const DEFAULT_LEAD_DAYS: [i32; 2] = [7, 2];
+const DEFAULT_INTERVAL_SECONDS: u64 = 3600;
+
+#[derive(Debug, Clone)]
+pub struct ReminderSettings {
+    pub lead_days: Vec<i32>,
+    pub interval: Duration,
+}
+
+impl ReminderSettings {
+    pub fn from_env() -> Self {
+        let mut lead_days = match env::var("EVENT_REMINDER_LEAD_DAYS") {
+            Ok(value) => value
+                .split(',')
+                .filter_map(|part| part.trim().parse::<i32>().ok())
+                .filter(|days| *days > 0)
+                .collect(),
+            Err(_) => DEFAULT_LEAD_DAYS.to_vec(),
+        };
+        lead_days.sort_unstable_by(|a, b| b.cmp(a));
+        lead_days.dedup();
+
+        let interval_seconds = env::var("EVENT_REMINDER_INTERVAL_SECONDS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(DEFAULT_INTERVAL_SECONDS);
+
+        Self {
+            lead_days,
+            interval: Duration::from_secs(interval_seconds),
+        }
+    }
+
+    fn windows(&self) -> impl Iterator<Item = (i32, i32)> + '_ {
+        self.lead_days.iter().enumerate().map(|(index, lead)| {
+            let next_lead = self.lead_days.get(index + 1).copied().unwrap_or(0);
+            (*lead, next_lead)
+        })
+    }
+}
+
+pub fn spawn_event_reminders(state: AppState, settings: ReminderSettings) {
+    tokio::spawn(async move {
+        let mut ticker = interval(settings.interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = send_due_reminders(&state, &settings).await {
+                warn!(target: "reminders", action = "run", %err, "Event reminder run failed");
+            }
+        }
+    });
+}
+
+fn missing_fields(
+    description_en: Option<&str>,
+    location: Option<&str>,
+    event_url: Option<&str>,
+) -> Vec<&'static str> {
+    let blank = |value: Option<&str>| value.is_none_or(|value| value.trim().is_empty());
+    [
+        (blank(description_en), "Beschreibung (Englisch)"),
+        (blank(location), "Ort"),
+        (blank(event_url), "Link zur Veranstaltung"),
+    ]
+    .into_iter()
+    .filter_map(|(missing, label)| missing.then_some(label))
+    .collect()
+}
+
+async fn send_due_reminders(state: &AppState, settings: &ReminderSettings) -> Result<(), AppError> {
+    let Some(email_client) = &state.email else {
+        return Ok(());
+    };
+
+    for (lead_days, next_lead_days) in settings.windows() {
+        let events = sqlx::query!(
+            r#"
+            SELECT e.id, e.organizer_id, e.title_de, e.title_en, e.start_date_time, e.description_en, e.location, e.event_url, o.name AS organizer_name
+            FROM events e
+            INNER JOIN organizers o ON o.id = e.organizer_id
+            WHERE e.cancelled_at IS NULL
+              AND e.status <> 'REJECTED'
+              AND e.start_date_time > NOW() + make_interval(days => $2)
+              AND e.start_date_time <= NOW() + make_interval(days => $1)
+              AND (
+                NULLIF(TRIM(e.description_en), '') IS NULL
+                OR NULLIF(TRIM(e.location), '') IS NULL
+                OR NULLIF(TRIM(e.event_url), '') IS NULL
+              )
+              AND NOT EXISTS (
+                SELECT 1 FROM event_reminders r WHERE r.event_id = e.id AND r.lead_days = $1
+              )
+            ORDER BY e.start_date_time ASC
+            "#,
+            lead_days,
+            next_lead_days
+        )
+        .fetch_all(&state.db)
+        .await?;
+
+        for event in events {
+            let claimed = sqlx::query!(
+                "INSERT INTO event_reminders (event_id, lead_days) VALUES ($1, $2) ON CONFLICT DO NOTHING",
+                event.id,
+                lead_days
+            )
+            .execute(&state.db)
+            .await?
+            .rows_affected()
+                > 0;
+            if !claimed {
+                continue;
+            }
+
+            let recipients = sqlx::query_scalar!(
+                r#"
+                SELECT email AS "email!"
+                FROM accounts
+                WHERE organizer_id = $1 AND account_type = 'ORGANIZER' AND email IS NOT NULL
+                "#,
+                event.organizer_id
+            )
+            .fetch_all(&state.db)
+            .await?;
+
+            let missing = missing_fields(
+                event.description_en.as_deref(),
+                event.location.as_deref(),
+                event.event_url.as_deref(),
+            );
+            let event_title = if event.title_de.is_empty() {
+                &event.title_en
+            } else {
+                &event.title_de
+            };
+
+            let mut failed = 0usize;
+            for recipient in &recipients {
+                if let Err(err) = email_client
+                    .send_event_reminder(
+                        recipient,
+                        &event.organizer_name,
+                        event_title,
+                        event.start_date_time,
+                        &missing,
+                    )
+                    .await
+                {
+                    failed += 1;
+                    warn!(target: "reminders", action = "send", event_id = event.id, %err, "Failed to send event reminder");
+                }
+            }
+
+            info!(
+                target: "reminders",
+                action = "send",
+                event_id = event.id,
+                lead_days,
+                recipients = recipients.len(),
+                failed,
+                "Event reminder processed"
+            );
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,78 @@
+use std::{
+    future::Future,
+    sync::{Arc, OnceLock},
+};
+
+use axum::{extract::Request, http::HeaderValue, middleware::Next, response::Response};
+use tokio::task::JoinHandle;
+use uuid::Uuid;
+
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+tokio::task_local! {
+    static REQUEST_CONTEXT: RequestContext;
+}
+
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    pub request_id: String,
+    account_id: Arc<OnceLock<i64>>,
+}
+
+impl RequestContext {
+    fn new(request_id: String) -> Self {
+        Self {
+            request_id,
+            account_id: Arc::new(OnceLock::new()),
+        }
+    }
+
+    pub fn current() -> Option<Self> {
+        REQUEST_CONTEXT.try_with(Clone::clone).ok()
+    }
+
+    pub fn account_id(&self) -> Option<i64> {
+        self.account_id.get().copied()
+    }
+}
+
+pub fn record_acting_account(account_id: i64) {
+    let _ = REQUEST_CONTEXT.try_with(|context| context.account_id.set(account_id));
+}
+
+pub fn spawn_with_context<F>(future: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    match RequestContext::current() {
+        Some(context) => tokio::spawn(REQUEST_CONTEXT.scope(context, future)),
+        None => tokio::spawn(future),
+    }
+}
+
+fn incoming_request_id(request: &Request) -> Option<String> {
+    let value = request
+        .headers()
+        .get(REQUEST_ID_HEADER)?
+        .to_str()
+        .ok()?
+        .trim();
+    (!value.is_empty()
+        && value.len() <= MAX_REQUEST_ID_LEN
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || matches!(ch, '-' | '_' | '.' | ':')))
+    .then(|| value.to_string())
+}
+
+pub async fn with_request_id(request: Request, next: Next) -> Response {
+    let request_id = incoming_request_id(&request).unwrap_or_else(|| Uuid::new_v4().to_string());
+    let context = RequestContext::new(request_id.clone());
+    let mut response = REQUEST_CONTEXT.scope(context, next.run(request)).await;
+    if let Ok(value) = HeaderValue::from_str(&request_id) {
+        response.headers_mut().insert(REQUEST_ID_HEADER, value);
+    }
+    response
+}
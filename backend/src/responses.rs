@@ -1,11 +1,24 @@
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
 use utoipa::ToSchema;
 
-use crate::models::{AccountType, EventWithOrganizer, Organizer, OrganizerKind};
+use crate::{
+    dto::{CacheNamespace, CreateEventRequest},
+    markdown::render_optional_markdown,
+    models::{
+        AcademicPeriod, AccountType, ApiTokenScope, DeveloperStatus, EmailSendStatus,
+        EmailTemplate, Event, EventAgendaItem, EventAudience, EventPriceTier, EventStatus,
+        EventWithOrganizer, NewsletterGrouping, NewsletterLanguage, NewsletterLayout,
+        NewsletterOrdering, NewsletterSponsor, OnboardingMilestone, Organizer, OrganizerKind,
+        Registration, Speaker, Webhook,
+    },
+    translation::TranslationLanguage,
+};
 
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ErrorResponse {
+    pub code: String,
     pub message: String,
 }
 
@@ -13,6 +26,8 @@ pub struct ErrorResponse {
 pub struct HealthResponse {
     pub status: String,
     pub message: String,
+    pub email: String,
+    pub cache: String,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -30,21 +45,143 @@ pub struct ApiTokenSummaryResponse {
     pub id: i64,
     pub label: String,
     pub token_last_four: String,
+    pub scopes: Vec<ApiTokenScope>,
+    pub organizer_id: Option<i64>,
     pub created_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
     pub last_used_at: Option<DateTime<Utc>>,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiUsageDayResponse {
+    pub date: NaiveDate,
+    pub request_count: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct ApiTokenUsageResponse {
+    pub token_id: i64,
+    pub label: String,
+    pub token_last_four: String,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub total_requests: i64,
+    pub daily: Vec<ApiUsageDayResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizerApiUsageResponse {
+    pub organizer_id: i64,
+    pub since: NaiveDate,
+    pub total_requests: i64,
+    pub tokens: Vec<ApiTokenUsageResponse>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct ApiTokenCreatedResponse {
     pub id: i64,
     pub label: String,
     pub token: String,
     pub token_last_four: String,
+    pub scopes: Vec<ApiTokenScope>,
+    pub organizer_id: Option<i64>,
     pub created_at: DateTime<Utc>,
     pub expires_at: DateTime<Utc>,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PrivateFeedResponse {
+    pub organizer_id: i64,
+    pub token_last_four: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PrivateFeedCreatedResponse {
+    pub organizer_id: i64,
+    pub token: String,
+    pub url: String,
+    pub token_last_four: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizerIcalTokenResponse {
+    pub organizer_id: i64,
+    pub url: String,
+    pub webcal_url: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct WebhookCreatedResponse {
+    #[serde(flatten)]
+    pub webhook: Webhook,
+    pub secret: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterIcalTokenResponse {
+    pub url: String,
+    pub webcal_url: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizerEventQuotaResponse {
+    pub organizer_id: i64,
+    pub max_events_per_day: Option<i32>,
+    pub max_events_per_week: Option<i32>,
+    pub max_future_events: Option<i32>,
+    pub effective_max_events_per_day: i32,
+    pub effective_max_events_per_week: i32,
+    pub effective_max_future_events: i32,
+    pub created_last_day: i64,
+    pub created_last_week: i64,
+    pub future_events: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeveloperAccountResponse {
+    pub id: i64,
+    pub name: String,
+    pub email: String,
+    pub organization: Option<String>,
+    pub use_case: String,
+    pub status: DeveloperStatus,
+    pub daily_quota: i32,
+    pub requests_today: i64,
+    pub active_keys: i64,
+    pub created_at: DateTime<Utc>,
+    pub reviewed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeveloperApplicationResponse {
+    pub developer: DeveloperAccountResponse,
+    pub portal_token: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeveloperApiKeyResponse {
+    pub id: i64,
+    pub label: String,
+    pub key_last_four: String,
+    pub created_at: DateTime<Utc>,
+    pub last_used_at: Option<DateTime<Utc>>,
+    pub revoked_at: Option<DateTime<Utc>>,
+    pub requests_today: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct DeveloperApiKeyCreatedResponse {
+    pub id: i64,
+    pub label: String,
+    pub key: String,
+    pub key_last_four: String,
+    pub created_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct SetupTokenResponse {
     pub setup_token: String,
@@ -55,6 +192,14 @@ pub struct SetupTokenInfoResponse {
     pub account_name: String,
     pub account_type: AccountType,
     pub email: String,
+    pub email_verification_required: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CaptchaConfigResponse {
+    pub enabled: bool,
+    pub provider: Option<String>,
+    pub site_key: Option<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -62,6 +207,12 @@ pub struct PasswordResetRequestResponse {
     pub message: String,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmailVerificationSentResponse {
+    pub email: String,
+    pub expires_at: DateTime<Utc>,
+}
+
 #[derive(Debug, Serialize, ToSchema)]
 pub struct AccountEmailUpdatedResponse {
     pub id: i64,
@@ -72,13 +223,145 @@ pub struct AccountEmailUpdatedResponse {
 #[derive(Debug, Serialize, ToSchema)]
 pub struct NewsletterDataResponse {
     pub subject: String,
-    pub next_week_events: Vec<EventWithOrganizer>,
-    pub following_week_events: Vec<EventWithOrganizer>,
+    pub subject_en: String,
+    pub grouping: NewsletterGrouping,
+    pub ordering: NewsletterOrdering,
+    pub next_week_events: Vec<NewsletterEventResponse>,
+    pub following_week_events: Vec<NewsletterEventResponse>,
+    pub next_week_groups: Vec<NewsletterEventGroup>,
+    pub following_week_groups: Vec<NewsletterEventGroup>,
     pub all_organizers: Vec<Organizer>,
+    pub price_tiers: Vec<EventPriceTier>,
+    pub sponsors: Vec<NewsletterSponsor>,
+    pub intro_de: Option<String>,
+    pub intro_en: Option<String>,
+    pub outro_de: Option<String>,
+    pub outro_en: Option<String>,
     pub next_week_start: DateTime<Utc>,
     pub week_after_start: DateTime<Utc>,
 }
 
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterPreviewResponse {
+    pub layout: NewsletterLayout,
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+    pub variants: Vec<NewsletterVariantPreview>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterVariantPreview {
+    pub language: NewsletterLanguage,
+    pub subject: String,
+    pub html: String,
+    pub text: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterEventResponse {
+    #[serde(flatten)]
+    pub event: EventWithOrganizer,
+    pub description_de_html: Option<String>,
+    pub description_en_html: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterEventGroup {
+    pub key: String,
+    pub label_de: String,
+    pub label_en: String,
+    pub event_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterSendResponse {
+    pub week_start: NaiveDate,
+    pub subject: String,
+    pub recipient_count: i32,
+    pub failed_count: i32,
+    pub sent_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterArchiveEntry {
+    pub week_start: NaiveDate,
+    pub subject: String,
+    pub event_ids: Vec<i64>,
+    pub recipient_count: Option<i32>,
+    pub failed_count: Option<i32>,
+    pub sent_by: Option<i64>,
+    pub sent_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicNewsletterIssueSummary {
+    pub week_start: NaiveDate,
+    pub subject: String,
+    pub event_ids: Vec<i64>,
+    pub sent_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicNewsletterIssueResponse {
+    pub week_start: NaiveDate,
+    pub subject: String,
+    pub html: String,
+    pub html_en: Option<String>,
+    pub event_ids: Vec<i64>,
+    pub sent_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterChangeEntry {
+    pub event_id: i64,
+    pub title_de: String,
+    pub title_en: String,
+    pub start_date_time: DateTime<Utc>,
+    pub end_date_time: DateTime<Utc>,
+    pub previous_start_date_time: Option<DateTime<Utc>>,
+    pub previous_end_date_time: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterChangeReportResponse {
+    pub week_start: NaiveDate,
+    pub previous_week_start: Option<NaiveDate>,
+    pub previous_sent_at: Option<DateTime<Utc>>,
+    pub added: Vec<NewsletterChangeEntry>,
+    pub rescheduled: Vec<NewsletterChangeEntry>,
+    pub cancelled: Vec<NewsletterChangeEntry>,
+    pub removed: Vec<NewsletterChangeEntry>,
+}
+
+#[derive(Debug, Default, Serialize, ToSchema)]
+pub struct NewsletterIssueTextsResponse {
+    pub week_start: NaiveDate,
+    pub intro_de: Option<String>,
+    pub intro_en: Option<String>,
+    pub outro_de: Option<String>,
+    pub outro_en: Option<String>,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NewsletterIssueResponse {
+    pub week_start: NaiveDate,
+    pub grouping: NewsletterGrouping,
+    pub ordering: NewsletterOrdering,
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+impl From<EventWithOrganizer> for NewsletterEventResponse {
+    fn from(event: EventWithOrganizer) -> Self {
+        Self {
+            description_de_html: render_optional_markdown(event.description_de.as_deref()),
+            description_en_html: render_optional_markdown(event.description_en.as_deref()),
+            event,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct PublicEventResponse {
     pub id: i64,
@@ -89,11 +372,233 @@ pub struct PublicEventResponse {
     pub title_en: String,
     pub description_de: Option<String>,
     pub description_en: Option<String>,
+    pub description_de_html: Option<String>,
+    pub description_en_html: Option<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub description_html: Option<String>,
     pub start_date_time: DateTime<Utc>,
     pub end_date_time: DateTime<Utc>,
     pub event_url: Option<String>,
     pub location: Option<String>,
     pub publish_web: bool,
+    pub max_participants: Option<i32>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub series_id: Option<i64>,
+    pub custom_fields: Value,
+    pub price_cents: Option<i32>,
+    pub currency: String,
+    pub free_entry: bool,
+    pub audience: Vec<EventAudience>,
+    pub term: String,
+    pub all_day: bool,
+    pub slug: Option<String>,
+    pub venue: Option<PublicVenueResponse>,
+    pub app_link: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct PublicVenueResponse {
+    pub id: i64,
+    pub name: String,
+    pub building: Option<String>,
+    pub room: Option<String>,
+    pub latitude: Option<f64>,
+    pub longitude: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PublicEventSeriesResponse {
+    pub id: i64,
+    pub organizer_id: i64,
+    pub title_de: String,
+    pub title_en: String,
+    pub description_de: Option<String>,
+    pub description_en: Option<String>,
+    #[serde(default)]
+    pub title: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub events: Vec<PublicEventResponse>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventConflictReason {
+    SameOrganizer,
+    SameLocation,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventConflictWarning {
+    pub event_id: i64,
+    pub organizer_id: i64,
+    pub title_de: String,
+    pub title_en: String,
+    pub start_date_time: DateTime<Utc>,
+    pub end_date_time: DateTime<Utc>,
+    pub location: Option<String>,
+    pub reasons: Vec<EventConflictReason>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CapacityWarningLevel {
+    NearCapacity,
+    AtCapacity,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventCapacityWarning {
+    pub event_id: i64,
+    pub organizer_id: i64,
+    pub title_de: String,
+    pub title_en: String,
+    pub start_date_time: DateTime<Utc>,
+    pub venue_id: i64,
+    pub venue_name: String,
+    pub capacity: i32,
+    pub max_participants: Option<i32>,
+    pub registrations: i64,
+    pub level: CapacityWarningLevel,
+    pub registration_closed: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizerSnapshotEntry {
+    pub id: i64,
+    pub organizer_id: i64,
+    pub account_id: Option<i64>,
+    pub data: Value,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct LocationSuggestion {
+    pub label: String,
+    pub venue_id: Option<i64>,
+    pub uses: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PossibleDuplicateEvent {
+    pub event_id: i64,
+    pub title_de: String,
+    pub title_en: String,
+    pub start_date_time: DateTime<Utc>,
+    pub status: EventStatus,
+    pub similarity: f64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventWithConflictsResponse {
+    #[serde(flatten)]
+    pub event: Event,
+    pub conflicts: Vec<EventConflictWarning>,
+    pub capacity_warning: Option<EventCapacityWarning>,
+    pub possible_duplicates: Vec<PossibleDuplicateEvent>,
+    pub academic_periods: Vec<AcademicPeriod>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PublicEventDetailResponse {
+    #[serde(flatten)]
+    pub event: PublicEventResponse,
+    pub agenda: Vec<EventAgendaItem>,
+    pub price_tiers: Vec<EventPriceTier>,
+    pub speakers: Vec<Speaker>,
+    pub related: PublicRelatedEventsResponse,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EventSnapshotStatus {
+    Unchanged,
+    Full,
+    Delta,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PublicSearchResultKind {
+    Event,
+    Organizer,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicSearchResult {
+    pub kind: PublicSearchResultKind,
+    pub id: i64,
+    pub title: String,
+    pub slug: Option<String>,
+    pub start_date_time: Option<DateTime<Utc>>,
+    pub organizer_name: Option<String>,
+    pub score: f32,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicSearchResponse {
+    pub query: String,
+    pub results: Vec<PublicSearchResult>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+pub struct PublicEventChangeMessage {
+    pub changed_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicEventChangesResponse {
+    pub since: DateTime<Utc>,
+    pub until: DateTime<Utc>,
+    pub created: Vec<PublicEventResponse>,
+    pub updated: Vec<PublicEventResponse>,
+    pub deleted_event_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PublicEventSnapshotResponse {
+    pub hash: String,
+    pub status: EventSnapshotStatus,
+    pub events: Vec<PublicEventResponse>,
+    pub removed_event_ids: Vec<i64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PublicEventArchiveMonth {
+    pub year: i32,
+    pub month: u32,
+    pub event_count: usize,
+    pub events: Vec<PublicEventResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PublicEventArchiveResponse {
+    pub year: i32,
+    pub month: Option<u32>,
+    pub months: Vec<PublicEventArchiveMonth>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct PublicRelatedEventsResponse {
+    pub same_organizer: Vec<PublicEventResponse>,
+    pub nearby: Vec<PublicEventResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IcsImportSkippedEntry {
+    pub uid: Option<String>,
+    pub summary: Option<String>,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct IcsImportPreviewResponse {
+    pub events: Vec<CreateEventRequest>,
+    pub skipped: Vec<IcsImportSkippedEntry>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -110,6 +615,8 @@ pub struct IcalEventResponse {
     pub event_url: Option<String>,
     pub location: Option<String>,
     pub is_internal: bool,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub all_day: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -118,6 +625,8 @@ pub struct PublicOrganizerResponse {
     pub name: String,
     pub description_de: Option<String>,
     pub description_en: Option<String>,
+    #[serde(default)]
+    pub description: Option<String>,
     pub website_url: Option<String>,
     pub instagram_url: Option<String>,
     pub location: Option<String>,
@@ -127,6 +636,7 @@ pub struct PublicOrganizerResponse {
     pub organizer_kind: OrganizerKind,
     pub active_events_count: i64,
     pub activity_score: f64,
+    pub app_link: Option<String>,
 }
 
 #[derive(Debug, Serialize, ToSchema)]
@@ -142,9 +652,224 @@ pub struct OrganizerWithStatsResponse {
     pub registration_number: Option<String>,
     pub non_profit: bool,
     pub newsletter: bool,
+    pub event_footer_de: Option<String>,
+    pub event_footer_en: Option<String>,
+    pub calendar_name: Option<String>,
+    pub calendar_color: Option<String>,
+    pub calendar_refresh_minutes: Option<i32>,
     pub organizer_kind: OrganizerKind,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub active_events_count: i64,
     pub activity_score: f64,
 }
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmailStatusResponse {
+    pub status: String,
+    pub pool_max_size: u32,
+    pub sent_total: u64,
+    pub failed_total: u64,
+    pub average_latency_ms: Option<u64>,
+    pub last_latency_ms: Option<u64>,
+    pub max_latency_ms: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TranslationIssue {
+    MissingTitle,
+    UntranslatedTitle,
+    MissingDescription,
+    UntranslatedDescription,
+    ShortDescription,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventTranslationReportEntry {
+    pub event_id: i64,
+    pub organizer_id: i64,
+    pub organizer_name: String,
+    pub title_de: String,
+    pub start_date_time: DateTime<Utc>,
+    pub issues: Vec<TranslationIssue>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizerTranslationReportEntry {
+    pub organizer_id: i64,
+    pub name: String,
+    pub issues: Vec<TranslationIssue>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OnboardingStageResponse {
+    pub milestone: OnboardingMilestone,
+    pub organizer_count: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OrganizerOnboardingResponse {
+    pub organizer_id: i64,
+    pub organizer_name: String,
+    pub organizer_kind: OrganizerKind,
+    pub invited_at: DateTime<Utc>,
+    pub account_initialized_at: Option<DateTime<Utc>>,
+    pub profile_completed_at: Option<DateTime<Utc>>,
+    pub first_event_created_at: Option<DateTime<Utc>>,
+    pub first_newsletter_inclusion_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventViewDay {
+    pub date: NaiveDate,
+    pub views: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventViewStatsResponse {
+    pub event_id: i64,
+    pub total_views: i64,
+    pub days: Vec<EventViewDay>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TermStats {
+    pub term: String,
+    pub events: i64,
+    pub published_events: i64,
+    pub cancelled_events: i64,
+    pub total_views: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct NotificationPreferencesResponse {
+    pub reminders: bool,
+    pub digests: bool,
+    pub audit_alerts: bool,
+    pub broadcast: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EmailLogEntry {
+    pub id: i64,
+    pub recipient_hash: String,
+    pub template: EmailTemplate,
+    pub status: EmailSendStatus,
+    pub message_id: Option<String>,
+    pub provider_message_id: Option<String>,
+    pub error: Option<String>,
+    pub request_id: Option<String>,
+    pub account_id: Option<i64>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, ToSchema)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum BrokenLinkSource {
+    Event,
+    Organizer,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct BrokenLinkResponse {
+    pub source: BrokenLinkSource,
+    pub source_id: i64,
+    pub organizer_id: i64,
+    pub name: String,
+    pub start_date_time: Option<DateTime<Utc>>,
+    pub url: String,
+    pub status_code: Option<i32>,
+    pub error: Option<String>,
+    pub checked_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct OnboardingFunnelResponse {
+    pub total_organizers: i64,
+    pub stages: Vec<OnboardingStageResponse>,
+    pub organizers: Vec<OrganizerOnboardingResponse>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TranslationReportResponse {
+    pub window_start: DateTime<Utc>,
+    pub window_end: DateTime<Utc>,
+    pub events: Vec<EventTranslationReportEntry>,
+    pub organizers: Vec<OrganizerTranslationReportEntry>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct TranslationSuggestionResponse {
+    pub event_id: i64,
+    pub provider: String,
+    pub source_language: TranslationLanguage,
+    pub target_language: TranslationLanguage,
+    pub title: String,
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CacheStatusResponse {
+    pub status: String,
+    pub failure_threshold: u32,
+    pub cooldown_seconds: u64,
+    pub consecutive_failures: u32,
+    pub trips_total: u64,
+    pub bypassed_total: u64,
+    pub pending_flush: bool,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CachePurgeResponse {
+    pub namespaces: Vec<CacheNamespace>,
+    pub deleted_keys: u64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicEventSubscriptionResponse {
+    pub event_id: i64,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventSubscriberCountResponse {
+    pub event_id: i64,
+    pub subscriber_count: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct PublicRegistrationResponse {
+    pub id: i64,
+    pub event_id: i64,
+    pub name: String,
+    pub check_in_code: String,
+    pub check_in_payload: String,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventRegistrationsResponse {
+    pub event_id: i64,
+    pub max_participants: Option<i32>,
+    pub registration_count: i64,
+    pub registrations: Vec<Registration>,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct CheckInResponse {
+    pub registration_id: i64,
+    pub event_id: i64,
+    pub name: String,
+    pub checked_in_at: DateTime<Utc>,
+    pub checked_in_count: i64,
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+pub struct EventStatisticsResponse {
+    pub event_id: i64,
+    pub max_participants: Option<i32>,
+    pub registration_count: i64,
+    pub checked_in_count: i64,
+    pub subscriber_count: i64,
+}
@@ -0,0 +1,64 @@
+use std::{env, fmt, str::FromStr};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ProcessRole {
+    Web,
+    Worker,
+    #[default]
+    All,
+}
+
+impl ProcessRole {
+    pub fn from_args_and_env() -> Result<Self, String> {
+        let mut args = env::args().skip(1);
+        while let Some(arg) = args.next() {
+            if let Some(value) = arg.strip_prefix("--role=") {
+                return value.parse();
+            }
+            if arg == "--role" {
+                return args
+                    .next()
+                    .ok_or_else(|| "--role requires a value (web, worker or all)".to_string())?
+                    .parse();
+            }
+        }
+
+        match env::var("APP_ROLE") {
+            Ok(value) if !value.trim().is_empty() => value.parse(),
+            _ => Ok(Self::default()),
+        }
+    }
+
+    pub fn serves_http(self) -> bool {
+        matches!(self, Self::Web | Self::All)
+    }
+
+    pub fn runs_jobs(self) -> bool {
+        matches!(self, Self::Worker | Self::All)
+    }
+}
+
+impl FromStr for ProcessRole {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "web" => Ok(Self::Web),
+            "worker" => Ok(Self::Worker),
+            "all" => Ok(Self::All),
+            other => Err(format!(
+                "unknown role '{other}' (expected web, worker or all)"
+            )),
+        }
+    }
+}
+
+impl fmt::Display for ProcessRole {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::Web => "web",
+            Self::Worker => "worker",
+            Self::All => "all",
+        })
+    }
+}
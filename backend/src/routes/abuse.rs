@@ -0,0 +1,278 @@
+use std::{collections::HashSet, net::SocketAddr, time::SystemTime};
+
+use axum::{
+    Json, Router,
+    extract::{ConnectInfo, Path, Request, State},
+    http::{HeaderMap, HeaderValue, StatusCode, header},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    routing::{delete, get},
+};
+use tracing::{info, instrument, warn};
+
+use crate::{
+    abuse::{AbuseVerdict, client_ip, normalize_ip},
+    app_state::AppState,
+    cache::CacheService,
+    dto::AbuseAllowlistRequest,
+    error::AppError,
+    models::AbuseAllowlistEntry,
+    responses::ErrorResponse,
+};
+
+use super::shared::current_user_from_headers;
+
+const PUBLIC_PATH_PREFIXES: [&str; 3] = ["/api/v1/public/", "/api/ical/", "/go/"];
+const ALLOWLIST_CACHE_KEY: &str = "abuse:allowlist";
+const MAX_ALLOWLIST_NOTE_LEN: usize = 500;
+
+async fn allowlisted_ips(state: &AppState, cache: &CacheService) -> HashSet<String> {
+    if let Ok(Some(ips)) = cache.get_json::<Vec<String>>(ALLOWLIST_CACHE_KEY).await {
+        return ips.into_iter().collect();
+    }
+    let ips = match sqlx::query_scalar!("SELECT ip FROM abuse_allowlist")
+        .fetch_all(&state.db)
+        .await
+    {
+        Ok(ips) => ips,
+        Err(err) => {
+            warn!(target: "abuse", action = "load_allowlist", %err, "Failed to load abuse allowlist");
+            return HashSet::new();
+        }
+    };
+    if let Err(err) = cache.set_json(ALLOWLIST_CACHE_KEY, &ips).await {
+        warn!(target: "cache", action = "set", scope = "abuse_allowlist", %err, "Failed to cache abuse allowlist");
+    }
+    ips.into_iter().collect()
+}
+
+fn blocked_response(retry_after_seconds: u64) -> Response {
+    let mut response =
+        AppError::too_many_requests("too many requests from this address").into_response();
+    if let Ok(value) = HeaderValue::from_str(&retry_after_seconds.to_string()) {
+        response.headers_mut().insert(header::RETRY_AFTER, value);
+    }
+    response
+}
+
+pub(crate) async fn abuse_guard(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path();
+    let Some(cache) = state.cache.as_ref() else {
+        return next.run(request).await;
+    };
+    if !state.abuse.enabled()
+        || !PUBLIC_PATH_PREFIXES
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+    {
+        return next.run(request).await;
+    }
+    let Some(ip) = client_ip(
+        request.headers(),
+        request.extensions().get::<ConnectInfo<SocketAddr>>(),
+    ) else {
+        return next.run(request).await;
+    };
+    let ip = ip.to_string();
+
+    if allowlisted_ips(&state, cache).await.contains(&ip) {
+        return next.run(request).await;
+    }
+
+    let block_key = format!("abuse:block:{ip}");
+    if let Ok(Some(_)) = cache.get_string(&block_key).await {
+        return blocked_response(state.abuse.block_duration.as_secs());
+    }
+
+    let window = state.abuse.window.as_secs().max(1);
+    let bucket = SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / window)
+        .unwrap_or_default();
+    let requests = match cache
+        .increment_with_ttl(&format!("abuse:hits:{ip}:{bucket}"), state.abuse.window)
+        .await
+    {
+        Ok(requests) => requests,
+        Err(err) => {
+            warn!(target: "abuse", action = "count", %err, "Failed to count public API request");
+            return next.run(request).await;
+        }
+    };
+
+    match state.abuse.verdict(requests) {
+        AbuseVerdict::Allow => next.run(request).await,
+        AbuseVerdict::Tarpit(delay) => {
+            if requests == state.abuse.tarpit_threshold.unwrap_or_default() + 1 {
+                warn!(target: "abuse", action = "tarpit", %ip, requests, "Slowing down public API client");
+            }
+            tokio::time::sleep(delay).await;
+            next.run(request).await
+        }
+        AbuseVerdict::Block(duration) => {
+            if let Err(err) = cache.set_string_with_ttl(&block_key, "1", duration).await {
+                warn!(target: "abuse", action = "block", %ip, %err, "Failed to store abuse block");
+            }
+            warn!(target: "abuse", action = "block", %ip, requests, block_seconds = duration.as_secs(), "Temporarily blocking public API client");
+            blocked_response(duration.as_secs())
+        }
+    }
+}
+
+async fn refresh_allowlist_cache(state: &AppState, unblocked_ip: Option<&str>) {
+    let Some(cache) = &state.cache else {
+        return;
+    };
+    if let Err(err) = cache.delete(ALLOWLIST_CACHE_KEY).await {
+        warn!(target: "cache", action = "purge", scope = "abuse_allowlist", %err, "Failed to purge abuse allowlist cache");
+    }
+    if let Some(ip) = unblocked_ip
+        && let Err(err) = cache.delete(&format!("abuse:block:{ip}")).await
+    {
+        warn!(target: "cache", action = "purge", scope = "abuse_block", %err, "Failed to lift abuse block");
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/abuse/allowlist",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Client addresses exempt from public API abuse detection", body = [AbuseAllowlistEntry]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_abuse_allowlist(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<AbuseAllowlistEntry>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let entries = sqlx::query_as!(
+        AbuseAllowlistEntry,
+        "SELECT ip, note, created_by, created_at FROM abuse_allowlist ORDER BY created_at DESC"
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(entries))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/abuse/allowlist",
+    tag = "Admin",
+    request_body = AbuseAllowlistRequest,
+    responses(
+        (status = 201, description = "Address allowlisted and any active block lifted", body = AbuseAllowlistEntry),
+        (status = 400, description = "Invalid IP address", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn add_abuse_allowlist_entry(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<AbuseAllowlistRequest>,
+) -> Result<(StatusCode, Json<AbuseAllowlistEntry>), AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let ip = normalize_ip(&payload.ip)
+        .ok_or_else(|| AppError::validation("ip must be a valid IPv4 or IPv6 address"))?
+        .to_string();
+    let note = payload
+        .note
+        .map(|note| note.trim().to_string())
+        .filter(|note| !note.is_empty());
+    if note
+        .as_ref()
+        .is_some_and(|note| note.chars().count() > MAX_ALLOWLIST_NOTE_LEN)
+    {
+        return Err(AppError::validation(format!(
+            "note must be at most {MAX_ALLOWLIST_NOTE_LEN} characters"
+        )));
+    }
+
+    let entry = sqlx::query_as!(
+        AbuseAllowlistEntry,
+        r#"
+        INSERT INTO abuse_allowlist (ip, note, created_by)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (ip) DO UPDATE SET note = EXCLUDED.note
+        RETURNING ip, note, created_by, created_at
+        "#,
+        ip,
+        note,
+        user.account_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    refresh_allowlist_cache(&state, Some(&entry.ip)).await;
+
+    info!(target: "abuse", action = "allowlist_add", account_id = user.account_id, ip = %entry.ip, "Address added to abuse allowlist");
+
+    Ok((StatusCode::CREATED, Json(entry)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/abuse/allowlist/{ip}",
+    tag = "Admin",
+    params(("ip" = String, Path, description = "Allowlisted IP address")),
+    responses(
+        (status = 204, description = "Address removed from the allowlist"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Address not allowlisted", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_abuse_allowlist_entry(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(ip): Path<String>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let ip = normalize_ip(&ip)
+        .map(|ip| ip.to_string())
+        .ok_or_else(|| AppError::not_found("address not allowlisted"))?;
+    let result = sqlx::query!("DELETE FROM abuse_allowlist WHERE ip = $1", ip)
+        .execute(&state.db)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("address not allowlisted"));
+    }
+
+    refresh_allowlist_cache(&state, None).await;
+
+    info!(target: "abuse", action = "allowlist_remove", account_id = user.account_id, %ip, "Address removed from abuse allowlist");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/abuse/allowlist",
+            get(list_abuse_allowlist).post(add_abuse_allowlist_entry),
+        )
+        .route(
+            "/abuse/allowlist/{ip}",
+            delete(delete_abuse_allowlist_entry),
+        )
+}
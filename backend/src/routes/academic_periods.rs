@@ -0,0 +1,319 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    routing::{get, put},
+};
+use chrono::{DateTime, Utc};
+use chrono_tz::Europe::Berlin;
+use tracing::{info, instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::{AcademicPeriodQuery, AcademicPeriodRequest},
+    error::AppError,
+    models::{AcademicPeriod, AcademicPeriodKind},
+    responses::ErrorResponse,
+    timings::{Timed, TimedJson, TimingPhase},
+};
+
+use super::shared::current_user_from_headers;
+
+const MAX_PERIOD_NAME_LEN: usize = 200;
+const PUBLIC_CACHE_PREFIX: &str = "public:academic-periods";
+
+struct NormalizedPeriod {
+    name_de: String,
+    name_en: String,
+}
+
+fn normalize_period(payload: &AcademicPeriodRequest) -> Result<NormalizedPeriod, AppError> {
+    let name = |raw: &str, field: &str| {
+        let value = raw.trim();
+        if value.is_empty() || value.chars().count() > MAX_PERIOD_NAME_LEN {
+            return Err(AppError::validation(format!(
+                "{field} must be between 1 and {MAX_PERIOD_NAME_LEN} characters"
+            )));
+        }
+        Ok(value.to_string())
+    };
+    if payload.ends_on < payload.starts_on {
+        return Err(AppError::validation("ends_on must not be before starts_on"));
+    }
+    Ok(NormalizedPeriod {
+        name_de: name(&payload.name_de, "name_de")?,
+        name_en: name(&payload.name_en, "name_en")?,
+    })
+}
+
+async fn invalidate_public_period_cache(state: &AppState) {
+    if let Some(cache) = &state.cache
+        && let Err(err) = cache.purge_prefix(PUBLIC_CACHE_PREFIX).await
+    {
+        warn!(target: "cache", action = "purge", scope = "academic_periods", %err, "Failed to purge academic periods cache");
+    }
+}
+
+pub(crate) async fn academic_periods_overlapping(
+    state: &AppState,
+    start_date_time: DateTime<Utc>,
+    end_date_time: DateTime<Utc>,
+) -> Result<Vec<AcademicPeriod>, AppError> {
+    let periods = sqlx::query_as!(
+        AcademicPeriod,
+        r#"
+        SELECT id, kind as "kind: AcademicPeriodKind", name_de, name_en, starts_on, ends_on, created_at, updated_at
+        FROM academic_periods
+        WHERE starts_on <= $2 AND ends_on >= $1
+        ORDER BY starts_on ASC, id ASC
+        "#,
+        start_date_time.with_timezone(&Berlin).date_naive(),
+        end_date_time.with_timezone(&Berlin).date_naive()
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(periods)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/academic-periods",
+    tag = "Public",
+    params(AcademicPeriodQuery),
+    responses(
+        (status = 200, description = "Lecture breaks, exam periods and holidays overlapping the requested range (default: from today on)", body = [AcademicPeriod]),
+        (status = 400, description = "Invalid range", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn list_public_academic_periods(
+    State(state): State<AppState>,
+    Query(query): Query<AcademicPeriodQuery>,
+) -> Result<TimedJson<Vec<AcademicPeriod>>, AppError> {
+    let from = query
+        .from
+        .unwrap_or_else(|| Utc::now().with_timezone(&Berlin).date_naive());
+    if query.to.is_some_and(|to| to < from) {
+        return Err(AppError::validation("to must not be before from"));
+    }
+
+    let cache_key = format!(
+        "{PUBLIC_CACHE_PREFIX}:{from}:{}",
+        query.to.map(|to| to.to_string()).unwrap_or_default()
+    );
+    if let Some(cache) = &state.cache {
+        match cache.get_json::<Vec<AcademicPeriod>>(&cache_key).await {
+            Ok(Some(cached)) => return Ok(TimedJson(cached)),
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "cache", action = "get", scope = "academic_periods", %err, "Failed to read academic periods from cache")
+            }
+        }
+    }
+
+    let periods = sqlx::query_as!(
+        AcademicPeriod,
+        r#"
+        SELECT id, kind as "kind: AcademicPeriodKind", name_de, name_en, starts_on, ends_on, created_at, updated_at
+        FROM academic_periods
+        WHERE ends_on >= $1 AND ($2::date IS NULL OR starts_on <= $2)
+        ORDER BY starts_on ASC, id ASC
+        "#,
+        from,
+        query.to
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    if let Some(cache) = &state.cache
+        && let Err(err) = cache.set_json(&cache_key, &periods).await
+    {
+        warn!(target: "cache", action = "set", scope = "academic_periods", %err, "Failed to store academic periods in cache");
+    }
+
+    Ok(TimedJson(periods))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/academic-periods",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "All configured academic periods", body = [AcademicPeriod]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_academic_periods(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<AcademicPeriod>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let periods = sqlx::query_as!(
+        AcademicPeriod,
+        r#"
+        SELECT id, kind as "kind: AcademicPeriodKind", name_de, name_en, starts_on, ends_on, created_at, updated_at
+        FROM academic_periods
+        ORDER BY starts_on DESC, id DESC
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(periods))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/academic-periods",
+    tag = "Admin",
+    request_body = AcademicPeriodRequest,
+    responses(
+        (status = 201, description = "Academic period created", body = AcademicPeriod),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_academic_period(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<AcademicPeriodRequest>,
+) -> Result<(StatusCode, Json<AcademicPeriod>), AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let names = normalize_period(&payload)?;
+    let period = sqlx::query_as!(
+        AcademicPeriod,
+        r#"
+        INSERT INTO academic_periods (kind, name_de, name_en, starts_on, ends_on)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, kind as "kind: AcademicPeriodKind", name_de, name_en, starts_on, ends_on, created_at, updated_at
+        "#,
+        payload.kind as AcademicPeriodKind,
+        names.name_de,
+        names.name_en,
+        payload.starts_on,
+        payload.ends_on
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    invalidate_public_period_cache(&state).await;
+
+    info!(target: "academic_periods", action = "create", period_id = period.id, "Academic period created");
+
+    Ok((StatusCode::CREATED, Json(period)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/academic-periods/{id}",
+    tag = "Admin",
+    params(("id" = i64, Path, description = "Academic period identifier")),
+    request_body = AcademicPeriodRequest,
+    responses(
+        (status = 200, description = "Academic period updated", body = AcademicPeriod),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Academic period not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_academic_period(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<AcademicPeriodRequest>,
+) -> Result<Json<AcademicPeriod>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let names = normalize_period(&payload)?;
+    let period = sqlx::query_as!(
+        AcademicPeriod,
+        r#"
+        UPDATE academic_periods
+        SET kind = $2, name_de = $3, name_en = $4, starts_on = $5, ends_on = $6, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, kind as "kind: AcademicPeriodKind", name_de, name_en, starts_on, ends_on, created_at, updated_at
+        "#,
+        id,
+        payload.kind as AcademicPeriodKind,
+        names.name_de,
+        names.name_en,
+        payload.starts_on,
+        payload.ends_on
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("academic period not found"))?;
+
+    invalidate_public_period_cache(&state).await;
+
+    info!(target: "academic_periods", action = "update", period_id = id, "Academic period updated");
+
+    Ok(Json(period))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/admin/academic-periods/{id}",
+    tag = "Admin",
+    params(("id" = i64, Path, description = "Academic period identifier")),
+    responses(
+        (status = 204, description = "Academic period deleted"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Academic period not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_academic_period(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let result = sqlx::query!("DELETE FROM academic_periods WHERE id = $1", id)
+        .execute(&state.db)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("academic period not found"));
+    }
+
+    invalidate_public_period_cache(&state).await;
+
+    info!(target: "academic_periods", action = "delete", period_id = id, "Academic period deleted");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn admin_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/academic-periods",
+            get(list_academic_periods).post(create_academic_period),
+        )
+        .route(
+            "/academic-periods/{id}",
+            put(update_academic_period).delete(delete_academic_period),
+        )
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new().route("/academic-periods", get(list_public_academic_periods))
+}
@@ -1,40 +1,34 @@
 use axum::{
     Json, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
     routing::{get, post, put},
 };
-use lettre::message::Mailbox;
-use std::str::FromStr;
 use tracing::{error, info, instrument, warn};
 
 use crate::{
     app_state::AppState,
-    dto::{InviteAdminRequest, UpdateAccountEmailRequest, UpdateOrganizerPermissionsRequest},
+    dto::{
+        CacheNamespace, EmailStatusQuery, InviteAdminRequest, PurgeCacheRequest,
+        UpdateAccountEmailRequest, UpdateOrganizerPermissionsRequest,
+    },
     error::AppError,
     models::{
         AccountType, AdminInviteRow, AdminWithInvite, OrganizerInviteRow, OrganizerKind,
         OrganizerWithInvite,
     },
-    responses::{AccountEmailUpdatedResponse, SetupTokenResponse},
+    responses::{
+        AccountEmailUpdatedResponse, CachePurgeResponse, CacheStatusResponse, EmailStatusResponse,
+        ErrorResponse, SetupTokenResponse,
+    },
 };
 
 use super::{
     organizers::invalidate_public_organizer_caches,
-    shared::{current_user_from_headers, generate_setup_token_value},
+    shared::{current_user_from_headers, generate_setup_token_value, normalize_email},
 };
 
-fn normalize_account_email(raw: &str) -> Result<String, AppError> {
-    let trimmed = raw.trim();
-    if trimmed.is_empty() {
-        return Err(AppError::validation("email must not be empty"));
-    }
-    let wrapped = format!("n <{trimmed}>");
-    Mailbox::from_str(&wrapped).map_err(|_| AppError::validation("invalid email address"))?;
-    Ok(trimmed.to_string())
-}
-
 #[utoipa::path(
     get,
     path = "/api/v1/admin/list",
@@ -115,11 +109,13 @@ pub(crate) async fn update_organizer_permissions(
         UPDATE organizers
         SET newsletter = $1,
             organizer_kind = $2,
+            needs_review = COALESCE($3, needs_review),
             updated_at = NOW()
-        WHERE id = $3
+        WHERE id = $4
         "#,
         newsletter,
         payload.organizer_kind as OrganizerKind,
+        payload.needs_review,
         id
     )
     .execute(&state.db)
@@ -138,6 +134,7 @@ pub(crate) async fn update_organizer_permissions(
             a.id AS account_id,
             a.email AS account_email,
             o.newsletter AS newsletter,
+            o.needs_review,
             o.organizer_kind as "organizer_kind: crate::models::OrganizerKind",
             o.created_at,
             o.updated_at,
@@ -185,7 +182,7 @@ pub(crate) async fn update_account_email(
         return Err(AppError::unauthorized("insufficient permissions"));
     }
 
-    let email = normalize_account_email(&payload.email)?;
+    let email = normalize_email(&payload.email)?;
 
     let conflict = sqlx::query!(
         r#"
@@ -210,6 +207,7 @@ pub(crate) async fn update_account_email(
         r#"
         UPDATE accounts
         SET email = $1,
+            email_verified_at = CASE WHEN email IS DISTINCT FROM $1 THEN NULL ELSE email_verified_at END,
             updated_at = NOW()
         WHERE id = $2
         RETURNING id, email
@@ -297,13 +295,176 @@ pub(crate) async fn invite_admin(
     ))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/cache/purge",
+    tag = "Admin",
+    request_body = PurgeCacheRequest,
+    responses(
+        (status = 200, description = "Cache namespaces purged", body = CachePurgeResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 503, description = "Cache not configured", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn purge_cache(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<PurgeCacheRequest>,
+) -> Result<Json<CachePurgeResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    if payload.namespaces.is_empty() {
+        return Err(AppError::validation("at least one namespace is required"));
+    }
+
+    let Some(cache) = &state.cache else {
+        return Err(AppError::service_unavailable("cache is not configured"));
+    };
+
+    let mut namespaces: Vec<CacheNamespace> = Vec::new();
+    for namespace in payload.namespaces {
+        if !namespaces.contains(&namespace) {
+            namespaces.push(namespace);
+        }
+    }
+    if namespaces.contains(&CacheNamespace::All) {
+        namespaces = vec![CacheNamespace::All];
+    }
+
+    let mut deleted_keys = 0;
+    for namespace in &namespaces {
+        deleted_keys += cache
+            .purge_prefix(namespace.key_prefix())
+            .await
+            .map_err(|err| {
+                error!(target: "cache", action = "purge", namespace = ?namespace, %err, "Failed to purge cache namespace");
+                AppError::service_unavailable("failed to purge cache")
+            })?;
+    }
+
+    info!(
+        target: "cache",
+        action = "purge",
+        account_id = user.account_id,
+        namespaces = ?namespaces,
+        deleted_keys,
+        "Cache namespaces purged by admin"
+    );
+
+    Ok(Json(CachePurgeResponse {
+        namespaces,
+        deleted_keys,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/cache/status",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Redis circuit breaker state and counters", body = CacheStatusResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 503, description = "Cache not configured", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_cache_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<CacheStatusResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let Some(cache) = &state.cache else {
+        return Err(AppError::service_unavailable("cache is not configured"));
+    };
+
+    let snapshot = cache.breaker_snapshot();
+
+    Ok(Json(CacheStatusResponse {
+        status: snapshot.health.as_str().to_string(),
+        failure_threshold: snapshot.failure_threshold,
+        cooldown_seconds: snapshot.cooldown_seconds,
+        consecutive_failures: snapshot.consecutive_failures,
+        trips_total: snapshot.trips_total,
+        bypassed_total: snapshot.bypassed_total,
+        pending_flush: snapshot.pending_flush,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/email/status",
+    tag = "Admin",
+    params(EmailStatusQuery),
+    responses(
+        (status = 200, description = "SMTP health and send latency metrics", body = EmailStatusResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 503, description = "Email delivery not configured", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_email_status(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<EmailStatusQuery>,
+) -> Result<Json<EmailStatusResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let Some(email_client) = &state.email else {
+        return Err(AppError::service_unavailable(
+            "email delivery not configured",
+        ));
+    };
+
+    if query.verify.unwrap_or(false)
+        && let Err(err) = email_client.verify_connection().await
+    {
+        warn!(target: "email", action = "verify", %err, "SMTP connection check failed");
+    }
+
+    let snapshot = email_client.metrics_snapshot();
+
+    Ok(Json(EmailStatusResponse {
+        status: snapshot.health.as_str().to_string(),
+        pool_max_size: snapshot.pool_max_size,
+        sent_total: snapshot.sent_total,
+        failed_total: snapshot.failed_total,
+        average_latency_ms: snapshot.average_latency_ms,
+        last_latency_ms: snapshot.last_latency_ms,
+        max_latency_ms: snapshot.max_latency_ms,
+    }))
+}
+
 pub(crate) fn router() -> Router<AppState> {
     Router::new()
         .route("/invite", post(invite_admin))
         .route("/list", get(list_admins))
+        .route("/cache/purge", post(purge_cache))
+        .route("/cache/status", get(get_cache_status))
+        .route("/email/status", get(get_email_status))
         .route("/accounts/{account_id}/email", put(update_account_email))
         .route(
             "/organizers/{id}/permissions",
             put(update_organizer_permissions),
         )
+        .merge(super::moderation::router())
+        .merge(super::abuse::router())
+        .merge(super::academic_periods::admin_router())
+        .merge(super::translation_report::router())
+        .merge(super::developers::admin_router())
+        .merge(super::onboarding::router())
+        .merge(super::email_log::router())
+        .merge(super::newsletter_template::router())
+        .merge(super::link_checks::router())
 }
@@ -0,0 +1,299 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{get, put},
+};
+use chrono::{DateTime, Utc};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState, dto::AgendaItemRequest, error::AppError, models::EventAgendaItem,
+    responses::ErrorResponse,
+};
+
+use super::{
+    events::invalidate_public_event_caches,
+    shared::{AuthedUser, current_user_from_headers},
+};
+
+const MAX_AGENDA_TITLE_LEN: usize = 200;
+const MAX_AGENDA_FIELD_LEN: usize = 200;
+
+struct ValidatedAgendaItem {
+    start_time: DateTime<Utc>,
+    end_time: Option<DateTime<Utc>>,
+    title: String,
+    speaker: Option<String>,
+    room: Option<String>,
+}
+
+fn optional_text(value: Option<String>, field: &str) -> Result<Option<String>, AppError> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    if value.chars().count() > MAX_AGENDA_FIELD_LEN {
+        return Err(AppError::validation(format!(
+            "{field} must be at most {MAX_AGENDA_FIELD_LEN} characters"
+        )));
+    }
+    Ok(Some(value.to_string()))
+}
+
+fn validate_agenda_item(payload: AgendaItemRequest) -> Result<ValidatedAgendaItem, AppError> {
+    let title = payload.title.trim();
+    if title.is_empty() {
+        return Err(AppError::validation("title is required"));
+    }
+    if title.chars().count() > MAX_AGENDA_TITLE_LEN {
+        return Err(AppError::validation(format!(
+            "title must be at most {MAX_AGENDA_TITLE_LEN} characters"
+        )));
+    }
+    if let Some(end_time) = payload.end_time
+        && end_time < payload.start_time
+    {
+        return Err(AppError::validation(
+            "end_time must not be before start_time",
+        ));
+    }
+
+    Ok(ValidatedAgendaItem {
+        start_time: payload.start_time,
+        end_time: payload.end_time,
+        title: title.to_string(),
+        speaker: optional_text(payload.speaker, "speaker")?,
+        room: optional_text(payload.room, "room")?,
+    })
+}
+
+async fn ensure_can_manage_event(
+    state: &AppState,
+    user: &AuthedUser,
+    event_id: i64,
+) -> Result<(), AppError> {
+    let event = sqlx::query!("SELECT organizer_id FROM events WHERE id = $1", event_id)
+        .fetch_optional(&state.db)
+        .await?;
+
+    let Some(event) = event else {
+        return Err(AppError::not_found("event not found"));
+    };
+
+    if !user.is_admin() && user.organizer_id() != Some(event.organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot manage another organizer's agenda",
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn fetch_agenda_items(
+    state: &AppState,
+    event_id: i64,
+) -> Result<Vec<EventAgendaItem>, AppError> {
+    let items = sqlx::query_as!(
+        EventAgendaItem,
+        r#"
+        SELECT id, event_id, start_time, end_time, title, speaker, room, created_at, updated_at
+        FROM event_agenda_items
+        WHERE event_id = $1
+        ORDER BY start_time ASC, id ASC
+        "#,
+        event_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(items)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/agenda",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Agenda items for the event", body = [EventAgendaItem]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_agenda_items(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<EventAgendaItem>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_can_manage_event(&state, &user, id).await?;
+
+    Ok(Json(fetch_agenda_items(&state, id).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/agenda",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    request_body = AgendaItemRequest,
+    responses(
+        (status = 201, description = "Agenda item created", body = EventAgendaItem),
+        (status = 400, description = "Invalid agenda item", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_agenda_item(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<AgendaItemRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_can_manage_event(&state, &user, id).await?;
+    let item = validate_agenda_item(payload)?;
+
+    let item = sqlx::query_as!(
+        EventAgendaItem,
+        r#"
+        INSERT INTO event_agenda_items (event_id, start_time, end_time, title, speaker, room)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, event_id, start_time, end_time, title, speaker, room, created_at, updated_at
+        "#,
+        id,
+        item.start_time,
+        item.end_time,
+        item.title,
+        item.speaker,
+        item.room
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(
+        target: "agenda",
+        action = "create",
+        event_id = id,
+        agenda_item_id = item.id,
+        "Agenda item created"
+    );
+
+    Ok((StatusCode::CREATED, Json(item)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/agenda/{item_id}",
+    tag = "Events",
+    params(
+        ("id" = i64, Path, description = "Event identifier"),
+        ("item_id" = i64, Path, description = "Agenda item identifier")
+    ),
+    request_body = AgendaItemRequest,
+    responses(
+        (status = 200, description = "Agenda item updated", body = EventAgendaItem),
+        (status = 400, description = "Invalid agenda item", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event or agenda item not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_agenda_item(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((id, item_id)): Path<(i64, i64)>,
+    Json(payload): Json<AgendaItemRequest>,
+) -> Result<Json<EventAgendaItem>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_can_manage_event(&state, &user, id).await?;
+    let item = validate_agenda_item(payload)?;
+
+    let item = sqlx::query_as!(
+        EventAgendaItem,
+        r#"
+        UPDATE event_agenda_items
+        SET start_time = $3, end_time = $4, title = $5, speaker = $6, room = $7, updated_at = NOW()
+        WHERE id = $1 AND event_id = $2
+        RETURNING id, event_id, start_time, end_time, title, speaker, room, created_at, updated_at
+        "#,
+        item_id,
+        id,
+        item.start_time,
+        item.end_time,
+        item.title,
+        item.speaker,
+        item.room
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let Some(item) = item else {
+        return Err(AppError::not_found("agenda item not found"));
+    };
+
+    invalidate_public_event_caches(&state).await;
+
+    Ok(Json(item))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/agenda/{item_id}",
+    tag = "Events",
+    params(
+        ("id" = i64, Path, description = "Event identifier"),
+        ("item_id" = i64, Path, description = "Agenda item identifier")
+    ),
+    responses(
+        (status = 204, description = "Agenda item removed"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event or agenda item not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_agenda_item(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((id, item_id)): Path<(i64, i64)>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_can_manage_event(&state, &user, id).await?;
+
+    let result = sqlx::query!(
+        "DELETE FROM event_agenda_items WHERE id = $1 AND event_id = $2",
+        item_id,
+        id
+    )
+    .execute(&state.db)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("agenda item not found"));
+    }
+
+    invalidate_public_event_caches(&state).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/agenda",
+            get(list_agenda_items).post(create_agenda_item),
+        )
+        .route(
+            "/{id}/agenda/{item_id}",
+            put(update_agenda_item).delete(delete_agenda_item),
+        )
+}
@@ -12,13 +12,15 @@ use crate::{
     app_state::AppState,
     dto::CreateApiTokenRequest,
     error::AppError,
+    models::ApiTokenScope,
     responses::{ApiTokenCreatedResponse, ApiTokenSummaryResponse},
 };
 
-use super::shared::current_user_from_headers;
+use super::shared::{AuthedUser, session_user_from_headers};
 
 const MAX_API_TOKEN_LABEL_LEN: usize = 200;
 const API_TOKEN_LIFETIME_DAYS: i64 = 30;
+const MAX_API_TOKEN_LIFETIME_DAYS: i64 = 365;
 
 fn normalize_label(raw: &str) -> Result<String, AppError> {
     let t = raw.trim();
@@ -30,13 +32,71 @@ fn normalize_label(raw: &str) -> Result<String, AppError> {
     Ok(t.to_string())
 }
 
+fn normalize_scopes(
+    scopes: Option<Vec<ApiTokenScope>>,
+    organizer_bound: bool,
+) -> Result<Vec<ApiTokenScope>, AppError> {
+    let Some(scopes) = scopes else {
+        return Ok(if organizer_bound {
+            vec![ApiTokenScope::EventsRead, ApiTokenScope::EventsWrite]
+        } else {
+            vec![ApiTokenScope::Full]
+        });
+    };
+    let mut normalized = Vec::with_capacity(scopes.len());
+    for scope in scopes {
+        if !normalized.contains(&scope) {
+            normalized.push(scope);
+        }
+    }
+    if normalized.is_empty() {
+        return Err(AppError::validation(
+            "scopes must contain at least one scope",
+        ));
+    }
+    if organizer_bound && normalized.contains(&ApiTokenScope::Full) {
+        return Err(AppError::validation(
+            "tokens bound to an organizer cannot have the full scope",
+        ));
+    }
+    Ok(normalized)
+}
+
+async fn token_organizer(
+    state: &AppState,
+    user: &AuthedUser,
+    requested: Option<i64>,
+) -> Result<Option<i64>, AppError> {
+    let Some(organizer_id) = requested else {
+        return Ok(None);
+    };
+    if !user.is_admin() {
+        if user.organizer_id() != Some(organizer_id) {
+            return Err(AppError::unauthorized(
+                "cannot create tokens for another organizer",
+            ));
+        }
+        return Ok(None);
+    }
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM organizers WHERE id = $1) AS "exists!""#,
+        organizer_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+    if !exists {
+        return Err(AppError::not_found("Organizer not found"));
+    }
+    Ok(Some(organizer_id))
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/auth/api-tokens",
     tag = "Auth",
     responses(
         (status = 200, description = "API tokens for the current account", body = [ApiTokenSummaryResponse]),
-        (status = 401, description = "Not authenticated"),
+        (status = 401, description = "Not authenticated with a session"),
     )
 )]
 #[instrument(skip(state, headers))]
@@ -44,10 +104,11 @@ pub(crate) async fn list_api_tokens(
     State(state): State<AppState>,
     headers: HeaderMap,
 ) -> Result<Json<Vec<ApiTokenSummaryResponse>>, AppError> {
-    let user = current_user_from_headers(&headers, &state).await?;
+    let user = session_user_from_headers(&headers, &state).await?;
     let rows = sqlx::query!(
         r#"
-        SELECT id, label, token_last_four, created_at, expires_at, last_used_at as "last_used_at?: DateTime<Utc>"
+        SELECT id, label, token_last_four, scopes as "scopes: Vec<ApiTokenScope>", organizer_id,
+               created_at, expires_at, last_used_at as "last_used_at?: DateTime<Utc>"
         FROM api_tokens
         WHERE account_id = $1
         ORDER BY created_at DESC
@@ -63,6 +124,8 @@ pub(crate) async fn list_api_tokens(
             id: r.id,
             label: r.label,
             token_last_four: r.token_last_four,
+            scopes: r.scopes,
+            organizer_id: r.organizer_id,
             created_at: r.created_at,
             expires_at: r.expires_at,
             last_used_at: r.last_used_at,
@@ -79,7 +142,9 @@ pub(crate) async fn list_api_tokens(
     request_body = CreateApiTokenRequest,
     responses(
         (status = 200, description = "New token; copy `token` now; it is not shown again", body = ApiTokenCreatedResponse),
-        (status = 401, description = "Not authenticated"),
+        (status = 400, description = "Invalid scopes or lifetime"),
+        (status = 401, description = "Not authenticated with a session"),
+        (status = 404, description = "Organizer not found"),
         (status = 503, description = "Server not configured for API tokens"),
     )
 )]
@@ -95,24 +160,34 @@ pub(crate) async fn create_api_token(
         ));
     };
 
-    let user = current_user_from_headers(&headers, &state).await?;
+    let user = session_user_from_headers(&headers, &state).await?;
     let label = normalize_label(&payload.label)?;
+    let organizer_id = token_organizer(&state, &user, payload.organizer_id).await?;
+    let scopes = normalize_scopes(payload.scopes, organizer_id.is_some())?;
+    let lifetime_days = payload.expires_in_days.unwrap_or(API_TOKEN_LIFETIME_DAYS);
+    if !(1..=MAX_API_TOKEN_LIFETIME_DAYS).contains(&lifetime_days) {
+        return Err(AppError::validation(format!(
+            "expires_in_days must be between 1 and {MAX_API_TOKEN_LIFETIME_DAYS}"
+        )));
+    }
     let raw = api_token::generate_raw_token();
     let h = api_token::hash_raw_token(key, &raw);
     let token_last_four = api_token::token_last_four(&raw);
-    let expires_at = Utc::now() + Duration::days(API_TOKEN_LIFETIME_DAYS);
+    let expires_at = Utc::now() + Duration::days(lifetime_days);
 
     let row = sqlx::query!(
         r#"
-        INSERT INTO api_tokens (account_id, token_hmac, label, token_last_four, expires_at)
-        VALUES ($1, $2, $3, $4, $5)
+        INSERT INTO api_tokens (account_id, token_hmac, label, token_last_four, expires_at, scopes, organizer_id)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
         RETURNING id, created_at, expires_at
         "#,
         user.account_id,
         &h[..],
         &label,
         &token_last_four,
-        expires_at
+        expires_at,
+        &scopes as &[ApiTokenScope],
+        organizer_id
     )
     .fetch_one(&state.db)
     .await?;
@@ -122,6 +197,8 @@ pub(crate) async fn create_api_token(
         label,
         token: raw,
         token_last_four,
+        scopes,
+        organizer_id,
         created_at: row.created_at,
         expires_at: row.expires_at,
     }))
@@ -136,7 +213,7 @@ pub(crate) async fn create_api_token(
     ),
     responses(
         (status = 204, description = "Revoked"),
-        (status = 401, description = "Not authenticated"),
+        (status = 401, description = "Not authenticated with a session"),
         (status = 404, description = "Not found"),
     )
 )]
@@ -146,7 +223,7 @@ pub(crate) async fn revoke_api_token(
     headers: HeaderMap,
     Path(id): Path<i64>,
 ) -> Result<StatusCode, AppError> {
-    let user = current_user_from_headers(&headers, &state).await?;
+    let user = session_user_from_headers(&headers, &state).await?;
     let res = sqlx::query!(
         "DELETE FROM api_tokens WHERE id = $1 AND account_id = $2",
         id,
@@ -167,3 +244,62 @@ pub(crate) fn router() -> Router<AppState> {
         .route("/api-tokens", get(list_api_tokens).post(create_api_token))
         .route("/api-tokens/{id}", delete(revoke_api_token))
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::http::{HeaderValue, header::AUTHORIZATION};
+    use sqlx::postgres::PgPoolOptions;
+
+    use super::*;
+    use crate::{
+        abuse::AbuseSettings, event_quota::EventQuotaLimits, password::PasswordHashing,
+        routes::event_stream::public_change_channel,
+    };
+
+    #[tokio::test]
+    async fn bearer_tokens_cannot_mint_tokens() {
+        let state = AppState {
+            db: PgPoolOptions::new()
+                .connect_lazy("postgres://cle@localhost/cle_db")
+                .unwrap(),
+            email: None,
+            cache: None,
+            translator: None,
+            ldap: None,
+            captcha: None,
+            passwords: PasswordHashing::from_env(),
+            pwned_passwords: None,
+            api_token_hmac_key: Some(api_token::derive_key("test")),
+            event_quota: EventQuotaLimits::from_env(),
+            abuse: AbuseSettings::from_env(),
+            public_changes: public_change_channel(),
+        };
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            AUTHORIZATION,
+            HeaderValue::from_static("Bearer cle_bound_organizer_token"),
+        );
+        let payload = CreateApiTokenRequest {
+            label: "escalate".to_string(),
+            scopes: Some(vec![ApiTokenScope::Full]),
+            expires_in_days: Some(365),
+            organizer_id: None,
+        };
+
+        let result = create_api_token(State(state), headers, Json(payload)).await;
+        assert!(matches!(result, Err(AppError::Unauthorized(_))));
+    }
+
+    #[test]
+    fn organizer_bound_tokens_never_get_full_scope() {
+        assert_eq!(
+            normalize_scopes(None, true).unwrap(),
+            vec![ApiTokenScope::EventsRead, ApiTokenScope::EventsWrite]
+        );
+        assert_eq!(
+            normalize_scopes(None, false).unwrap(),
+            vec![ApiTokenScope::Full]
+        );
+        assert!(normalize_scopes(Some(vec![ApiTokenScope::Full]), true).is_err());
+    }
+}
@@ -1,47 +1,119 @@
 use axum::{
     Json, Router,
-    extract::{Query, State},
-    http::HeaderMap,
-    routing::get,
+    extract::{OriginalUri, Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::Response,
+    routing::{get, post},
 };
+use chrono::{DateTime, Utc};
+use futures_util::TryStreamExt;
+use serde::Deserialize;
+use serde_json::{Map, Value};
 use sqlx::{Postgres, QueryBuilder};
-use tracing::instrument;
+use tracing::{info, instrument};
 
-use crate::{app_state::AppState, dto::ListAuditLogsQuery, error::AppError, models::AuditLogEntry};
+use crate::{
+    app_state::AppState,
+    dto::{ExportAuditLogsQuery, ExportFormat, ListAuditLogsQuery},
+    error::AppError,
+    export::stream_export,
+    models::{AuditLogEntry, AuditType, Event, EventAudience, EventStatus, EventVisibility},
+    pagination::pagination_headers,
+    responses::ErrorResponse,
+    term::term_for,
+};
 
-use super::shared::current_user_from_headers;
+use super::{
+    event_slugs::assign_event_slug,
+    events::{DEFAULT_CURRENCY, invalidate_public_event_caches, record_audit},
+    shared::{AuthedUser, current_user_from_headers},
+};
 
-#[utoipa::path(
-    get,
-    path = "/api/v1/audit-logs",
-    tag = "Audit",
-    params(ListAuditLogsQuery),
-    responses((status = 200, description = "List audit log entries", body = [AuditLogEntry]))
-)]
-#[instrument(skip(state, query_params))]
-pub(crate) async fn list_audit_logs(
-    State(state): State<AppState>,
-    headers: HeaderMap,
-    Query(mut query_params): Query<ListAuditLogsQuery>,
-) -> Result<Json<Vec<AuditLogEntry>>, AppError> {
-    let user = current_user_from_headers(&headers, &state).await?;
-    if !user.is_admin() {
-        let organizer_id = user
-            .organizer_id()
-            .ok_or_else(|| AppError::unauthorized("missing organizer context"))?;
-        if let Some(requested) = query_params.organizer_id {
-            if requested != organizer_id {
-                return Err(AppError::unauthorized(
-                    "cannot view other organizers' audit logs",
-                ));
-            }
-        } else {
-            query_params.organizer_id = Some(organizer_id);
-        }
+#[derive(Debug, Deserialize)]
+struct EventSnapshot {
+    id: i64,
+    organizer_id: i64,
+    title_de: String,
+    title_en: String,
+    description_de: Option<String>,
+    description_en: Option<String>,
+    start_date_time: DateTime<Utc>,
+    end_date_time: DateTime<Utc>,
+    event_url: Option<String>,
+    location: Option<String>,
+    #[serde(default = "default_true")]
+    publish_app: bool,
+    #[serde(default = "default_true")]
+    publish_newsletter: bool,
+    #[serde(default = "default_true")]
+    publish_in_ical: bool,
+    #[serde(default = "default_true")]
+    publish_web: bool,
+    max_participants: Option<i32>,
+    #[serde(default)]
+    status: EventStatus,
+    cancelled_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    visibility: EventVisibility,
+    series_id: Option<i64>,
+    publish_at: Option<DateTime<Utc>>,
+    custom_fields: Option<Value>,
+    price_cents: Option<i32>,
+    currency: Option<String>,
+    #[serde(default)]
+    free_entry: bool,
+    audience: Option<Vec<EventAudience>>,
+    venue_id: Option<i64>,
+    term: Option<String>,
+    #[serde(default)]
+    all_day: bool,
+    created_at: DateTime<Utc>,
+}
+
+const fn default_true() -> bool {
+    true
+}
+
+const AUDIT_CSV_HEADER: [&str; 8] = [
+    "id",
+    "event_id",
+    "organizer_id",
+    "user_id",
+    "type",
+    "at",
+    "old_data",
+    "new_data",
+];
+
+fn scoped_organizer_id(user: &AuthedUser, requested: Option<i64>) -> Result<Option<i64>, AppError> {
+    if user.is_admin() {
+        return Ok(requested);
     }
-    let mut builder = QueryBuilder::<Postgres>::new(
-        "SELECT id, event_id, organizer_id, user_id, type, at, old_data, new_data FROM audit_log",
-    );
+    let organizer_id = user
+        .organizer_id()
+        .ok_or_else(|| AppError::unauthorized("missing organizer context"))?;
+    if requested.is_some_and(|requested| requested != organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot view other organizers' audit logs",
+        ));
+    }
+    Ok(Some(organizer_id))
+}
+
+fn audit_type_label(audit_type: &AuditType) -> &'static str {
+    match audit_type {
+        AuditType::Create => "CREATE",
+        AuditType::Update => "UPDATE",
+        AuditType::Delete => "DELETE",
+    }
+}
+
+fn audit_logs_query(
+    select: &str,
+    query_params: &ListAuditLogsQuery,
+) -> QueryBuilder<'static, Postgres> {
+    let mut builder = QueryBuilder::<Postgres>::new(select);
+    builder.push(" FROM audit_log");
 
     let mut any = false;
     if let Some(event_id) = query_params.event_id {
@@ -57,6 +129,32 @@ pub(crate) async fn list_audit_logs(
         builder.push("organizer_id = ").push_bind(organizer_id);
     }
 
+    builder
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/audit-logs",
+    tag = "Audit",
+    params(ListAuditLogsQuery),
+    responses((status = 200, description = "List audit log entries", body = [AuditLogEntry], headers(
+        ("X-Total-Count" = i64, description = "Number of entries matching the filters, ignoring limit and offset"),
+        ("Link" = String, description = "RFC 8288 first, prev, next and last page links when limit is set"),
+    )))
+)]
+#[instrument(skip(state, query_params))]
+pub(crate) async fn list_audit_logs(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    headers: HeaderMap,
+    Query(mut query_params): Query<ListAuditLogsQuery>,
+) -> Result<(HeaderMap, Json<Vec<AuditLogEntry>>), AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    query_params.organizer_id = scoped_organizer_id(&user, query_params.organizer_id)?;
+    let mut builder = audit_logs_query(
+        "SELECT id, event_id, organizer_id, user_id, type, at, old_data, new_data",
+        &query_params,
+    );
     builder.push(" ORDER BY at DESC");
 
     if let Some(limit) = query_params.limit {
@@ -70,10 +168,264 @@ pub(crate) async fn list_audit_logs(
         .build_query_as::<AuditLogEntry>()
         .fetch_all(&state.db)
         .await?;
+    let total = audit_logs_query("SELECT COUNT(*)", &query_params)
+        .build_query_scalar::<i64>()
+        .fetch_one(&state.db)
+        .await?;
+
+    Ok((
+        pagination_headers(&uri, total, query_params.limit, query_params.offset),
+        Json(entries),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/audit-logs/export",
+    tag = "Audit",
+    params(ExportAuditLogsQuery),
+    responses(
+        (status = 200, description = "All matching audit log entries streamed as NDJSON or CSV", content_type = "application/x-ndjson"),
+        (status = 401, description = "Unauthorized"),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn export_audit_logs(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query_params): Query<ExportAuditLogsQuery>,
+) -> Result<Response, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = scoped_organizer_id(&user, query_params.organizer_id)?;
+    let event_id = query_params.event_id;
+    let format = query_params.format;
+    let db = state.db.clone();
+
+    let (content_type, filename) = match format {
+        ExportFormat::Ndjson => ("application/x-ndjson", "audit-log.ndjson"),
+        ExportFormat::Csv => ("text/csv; charset=utf-8", "audit-log.csv"),
+    };
+
+    stream_export(content_type, filename, move |mut writer| async move {
+        if format == ExportFormat::Csv {
+            writer.write_csv_row(&AUDIT_CSV_HEADER).await?;
+        }
+
+        let mut rows = sqlx::query_as::<_, AuditLogEntry>(
+            "SELECT id, event_id, organizer_id, user_id, type, at, old_data, new_data FROM audit_log WHERE ($1::bigint IS NULL OR event_id = $1) AND ($2::bigint IS NULL OR organizer_id = $2) ORDER BY at DESC",
+        )
+        .bind(event_id)
+        .bind(organizer_id)
+        .fetch(&db);
+
+        while let Some(entry) = rows.try_next().await? {
+            match format {
+                ExportFormat::Ndjson => {
+                    let mut line = serde_json::to_string(&entry)?;
+                    line.push('\n');
+                    writer.write(&line).await?;
+                }
+                ExportFormat::Csv => {
+                    let json_or_empty = |value: &Option<serde_json::Value>| {
+                        value.as_ref().map(ToString::to_string).unwrap_or_default()
+                    };
+                    writer
+                        .write_csv_row(&[
+                            entry.id.to_string(),
+                            entry.event_id.to_string(),
+                            entry.organizer_id.to_string(),
+                            entry.user_id.map(|id| id.to_string()).unwrap_or_default(),
+                            audit_type_label(&entry.r#type).to_string(),
+                            entry.at.to_rfc3339(),
+                            json_or_empty(&entry.old_data),
+                            json_or_empty(&entry.new_data),
+                        ])
+                        .await?;
+                }
+            }
+        }
+
+        Ok(writer)
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/audit-logs/{id}/restore",
+    tag = "Audit",
+    params(("id" = i64, Path, description = "Audit log entry of the deletion")),
+    responses(
+        (status = 201, description = "Event recreated from the snapshot; registrations, agenda and price tiers are not restored", body = Event),
+        (status = 400, description = "Entry has no event snapshot", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Audit log entry not found", body = ErrorResponse),
+        (status = 409, description = "Event still exists", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn restore_event_from_audit_log(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<(StatusCode, Json<Event>), AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let mut transaction = state.db.begin().await?;
+
+    let entry = sqlx::query!(
+        r#"
+        SELECT organizer_id, type AS "audit_type: AuditType", old_data
+        FROM audit_log
+        WHERE id = $1
+        "#,
+        id
+    )
+    .fetch_optional(&mut *transaction)
+    .await?
+    .ok_or_else(|| AppError::not_found("audit log entry not found"))?;
+
+    if !user.is_admin() && user.organizer_id() != Some(entry.organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot view other organizers' audit logs",
+        ));
+    }
+
+    let old_data = match (entry.audit_type, entry.old_data) {
+        (AuditType::Delete, Some(old_data)) => old_data,
+        _ => {
+            return Err(AppError::validation(
+                "only deletion entries can be restored",
+            ));
+        }
+    };
+    let snapshot: EventSnapshot = serde_json::from_value(old_data)
+        .map_err(|_| AppError::validation("audit log entry has no valid event snapshot"))?;
+
+    let event_exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM events WHERE id = $1) AS "exists!""#,
+        snapshot.id
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+    if event_exists {
+        return Err(AppError::conflict("event already exists"));
+    }
+
+    let organizer_exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM organizers WHERE id = $1) AS "exists!""#,
+        snapshot.organizer_id
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+    if !organizer_exists {
+        return Err(AppError::not_found("Organizer not found"));
+    }
+
+    let series_id = match snapshot.series_id {
+        Some(series_id) => {
+            sqlx::query_scalar!(
+                "SELECT id FROM event_series WHERE id = $1 AND organizer_id = $2",
+                series_id,
+                snapshot.organizer_id
+            )
+            .fetch_optional(&mut *transaction)
+            .await?
+        }
+        None => None,
+    };
+    let venue_id = match snapshot.venue_id {
+        Some(venue_id) => {
+            sqlx::query_scalar!("SELECT id FROM venues WHERE id = $1", venue_id)
+                .fetch_optional(&mut *transaction)
+                .await?
+        }
+        None => None,
+    };
+    let custom_fields = match snapshot.custom_fields {
+        Some(Value::Object(custom_fields)) => Value::Object(custom_fields),
+        _ => Value::Object(Map::new()),
+    };
+
+    let audience = snapshot
+        .audience
+        .filter(|audience| !audience.is_empty())
+        .unwrap_or_else(|| EventAudience::ALL.to_vec());
+
+    let event = sqlx::query_as!(
+        Event,
+        r#"
+        INSERT INTO events (id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status, cancelled_at, visibility, series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience, venue_id, term, all_day, created_at)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29)
+        RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+        "#,
+        snapshot.id,
+        snapshot.organizer_id,
+        snapshot.title_de,
+        snapshot.title_en,
+        snapshot.description_de,
+        snapshot.description_en,
+        snapshot.start_date_time,
+        snapshot.end_date_time,
+        snapshot.event_url,
+        snapshot.location,
+        snapshot.publish_app,
+        snapshot.publish_newsletter,
+        snapshot.publish_in_ical,
+        snapshot.publish_web,
+        snapshot.max_participants,
+        snapshot.status as EventStatus,
+        snapshot.cancelled_at,
+        snapshot.visibility as EventVisibility,
+        series_id,
+        snapshot.publish_at,
+        custom_fields,
+        snapshot.price_cents,
+        snapshot
+            .currency
+            .unwrap_or_else(|| DEFAULT_CURRENCY.to_string()),
+        snapshot.free_entry,
+        &audience as &[EventAudience],
+        venue_id,
+        snapshot
+            .term
+            .unwrap_or_else(|| term_for(snapshot.start_date_time)),
+        snapshot.all_day,
+        snapshot.created_at
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+    assign_event_slug(&mut transaction, &event).await?;
+
+    record_audit(
+        &mut transaction,
+        event.id,
+        event.organizer_id,
+        user.account_id,
+        AuditType::Create,
+        None,
+        Some(&event),
+    )
+    .await?;
+
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(
+        target: "audit",
+        action = "restore",
+        audit_log_id = id,
+        event_id = event.id,
+        restored_by = user.account_id,
+        "Event restored from audit log"
+    );
 
-    Ok(Json(entries))
+    Ok((StatusCode::CREATED, Json(event)))
 }
 
 pub(crate) fn router() -> Router<AppState> {
-    Router::new().route("/", get(list_audit_logs))
+    Router::new()
+        .route("/", get(list_audit_logs))
+        .route("/export", get(export_audit_logs))
+        .route("/{id}/restore", post(restore_event_from_audit_log))
 }
@@ -1,33 +1,45 @@
-use argon2::{
-    Argon2,
-    password_hash::rand_core::{OsRng, RngCore},
-};
+use argon2::password_hash::rand_core::{OsRng, RngCore};
+use std::net::SocketAddr;
+
 use axum::{
     Json, Router,
-    extract::State,
+    extract::{ConnectInfo, State},
     http::{HeaderMap, HeaderValue, StatusCode},
     response::{IntoResponse, Response},
     routing::{get, post},
 };
 use base64::{Engine as _, engine::general_purpose};
 use chrono::{DateTime, Duration, Utc};
-use password_hash::{PasswordHash, PasswordHasher, PasswordVerifier, SaltString};
 use password_policy::{COMMON_PASSWORDS, HighSecurityPolicy, PasswordPolicy};
+use sha2::{Digest, Sha256};
 use tracing::{error, info, instrument, warn};
 use uuid::Uuid;
 
 use crate::{
+    abuse::client_ip,
     app_state::AppState,
+    captcha::CAPTCHA_TOKEN_HEADER,
     dto::{
         ChangePasswordRequest, InitAccountRequest, LoginRequest, RequestPasswordResetRequest,
-        ResetPasswordRequest, SetupTokenLookupRequest,
+        ResetPasswordRequest, SetupTokenLookupRequest, UpdateNotificationPreferencesRequest,
     },
+    email::recipient_hash,
     error::AppError,
-    models::{AccountType, OrganizerKind},
-    responses::{AuthUserResponse, PasswordResetRequestResponse, SetupTokenInfoResponse},
+    models::{AccountType, OnboardingMilestone, OrganizerKind},
+    responses::{
+        AuthUserResponse, CaptchaConfigResponse, EmailVerificationSentResponse, ErrorResponse,
+        NotificationPreferencesResponse, PasswordResetRequestResponse, SetupTokenInfoResponse,
+    },
 };
 
-use super::shared::{current_user_from_headers, get_cookie, session_cookie_attributes};
+const EMAIL_VERIFICATION_VALID_MINUTES: i64 = 15;
+const EMAIL_VERIFICATION_RESEND_SECONDS: i64 = 60;
+const EMAIL_VERIFICATION_MAX_ATTEMPTS: i32 = 5;
+
+use super::{
+    onboarding::record_onboarding_milestone,
+    shared::{current_user_from_headers, get_cookie, session_cookie_attributes},
+};
 
 async fn organizer_kind_for_organizer(
     state: &AppState,
@@ -49,21 +61,120 @@ async fn organizer_kind_for_organizer(
     Ok(row.map(|r| r.organizer_kind))
 }
 
+async fn require_captcha(
+    state: &AppState,
+    headers: &HeaderMap,
+    ip: Option<&str>,
+) -> Result<(), AppError> {
+    let Some(captcha) = &state.captcha else {
+        return Ok(());
+    };
+    let Some(token) = headers
+        .get(CAPTCHA_TOKEN_HEADER)
+        .and_then(|value| value.to_str().ok())
+    else {
+        return Err(AppError::validation("captcha verification required"));
+    };
+    match captcha.verify(token, ip).await {
+        Ok(true) => Ok(()),
+        Ok(false) => Err(AppError::validation("captcha verification failed")),
+        Err(err) => {
+            warn!(target: "auth", action = "verify_captcha", %err, "CAPTCHA verification request failed");
+            Err(AppError::service_unavailable(
+                "captcha verification is currently unavailable",
+            ))
+        }
+    }
+}
+
+fn email_login_failure_key(email: &str) -> String {
+    format!("auth:login-failures:email:{}", recipient_hash(email))
+}
+
+fn login_failure_keys(email: &str, ip: Option<&str>) -> Vec<String> {
+    let mut keys = vec![email_login_failure_key(email)];
+    if let Some(ip) = ip {
+        keys.push(format!("auth:login-failures:ip:{ip}"));
+    }
+    keys
+}
+
+async fn login_requires_captcha(state: &AppState, email: &str, ip: Option<&str>) -> bool {
+    let (Some(captcha), Some(cache)) = (&state.captcha, &state.cache) else {
+        return false;
+    };
+    let mut failures = 0;
+    for key in login_failure_keys(email, ip) {
+        if let Ok(Some(count)) = cache.get_string(&key).await {
+            failures = failures.max(count.parse::<u64>().unwrap_or_default());
+        }
+    }
+    captcha.login_requires_captcha(failures)
+}
+
+async fn record_login_failure(state: &AppState, email: &str, ip: Option<&str>) {
+    let (Some(captcha), Some(cache)) = (&state.captcha, &state.cache) else {
+        return;
+    };
+    for key in login_failure_keys(email, ip) {
+        if let Err(err) = cache
+            .increment_with_ttl(&key, captcha.login_failure_window())
+            .await
+        {
+            warn!(target: "cache", action = "increment", scope = "login_failures", %err, "Failed to record login failure");
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/auth/captcha",
+    tag = "Auth",
+    responses(
+        (status = 200, description = "CAPTCHA widget configuration for login and password reset", body = CaptchaConfigResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn captcha_config(State(state): State<AppState>) -> Json<CaptchaConfigResponse> {
+    Json(match &state.captcha {
+        Some(captcha) => CaptchaConfigResponse {
+            enabled: true,
+            provider: Some(captcha.provider_name().to_string()),
+            site_key: Some(captcha.site_key().to_string()),
+        },
+        None => CaptchaConfigResponse {
+            enabled: false,
+            provider: None,
+            site_key: None,
+        },
+    })
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/auth/login",
     tag = "Auth",
     request_body = LoginRequest,
+    params(("X-Captcha-Token" = Option<String>, Header, description = "CAPTCHA response token; required after repeated failed logins when CAPTCHA is configured")),
     responses(
         (status = 200, description = "Logged in; cookie set", body = AuthUserResponse),
+        (status = 400, description = "CAPTCHA required or invalid", body = ErrorResponse),
         (status = 401, description = "Invalid credentials"),
     )
 )]
-#[instrument(skip(state, payload), fields(email = %payload.email))]
+#[instrument(skip(state, headers, payload), fields(email = %payload.email))]
 pub(crate) async fn login(
     State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Json(payload): Json<LoginRequest>,
 ) -> Result<Response, AppError> {
+    let ip = client_ip(&headers, Some(&ConnectInfo(peer))).map(|ip| ip.to_string());
+    let ip = ip.as_deref();
+    if login_requires_captcha(&state, &payload.email, ip).await {
+        require_captcha(&state, &headers, ip).await?;
+    }
+
     let rec = sqlx::query!(
         r#"
         SELECT id, display_name, password_hash, account_type as "account_type: AccountType", organizer_id
@@ -77,6 +188,7 @@ pub(crate) async fn login(
 
     let Some(row) = rec else {
         tracing::warn!("Failed login attempt for email: {}", payload.email);
+        record_login_failure(&state, &payload.email, ip).await;
         return Err(AppError::unauthorized("invalid e-mail or password"));
     };
 
@@ -84,25 +196,55 @@ pub(crate) async fn login(
     let display_name = row.display_name;
     let account_type = row.account_type;
     let organizer_id = row.organizer_id;
-    let Some(stored_hash) = row.password_hash else {
-        tracing::warn!(
-            "Failed login attempt for email: {} (no password hash)",
-            payload.email
-        );
-        return Err(AppError::unauthorized("invalid e-mail or password"));
+
+    let ldap_authenticated = match (&state.ldap, account_type) {
+        (Some(ldap), AccountType::Admin) => ldap
+            .authenticate(&payload.email, &payload.password)
+            .await
+            .unwrap_or_else(|err| {
+                tracing::warn!(
+                    "LDAP bind failed for email: {} ({}); falling back to local password",
+                    payload.email,
+                    err
+                );
+                false
+            }),
+        _ => false,
     };
 
-    let parsed_hash = PasswordHash::new(&stored_hash)
-        .map_err(|_| AppError::unauthorized("invalid e-mail or password"))?;
-    Argon2::default()
-        .verify_password(payload.password.as_bytes(), &parsed_hash)
-        .map_err(|_| {
+    if !ldap_authenticated {
+        let Some(stored_hash) = row.password_hash else {
+            tracing::warn!(
+                "Failed login attempt for email: {} (no password hash)",
+                payload.email
+            );
+            record_login_failure(&state, &payload.email, ip).await;
+            return Err(AppError::unauthorized("invalid e-mail or password"));
+        };
+
+        if state
+            .passwords
+            .verify(&payload.password, &stored_hash)
+            .is_err()
+        {
             tracing::warn!(
                 "Failed login attempt for email: {} (invalid password)",
                 payload.email
             );
-            AppError::unauthorized("invalid e-mail or password")
-        })?;
+            record_login_failure(&state, &payload.email, ip).await;
+            return Err(AppError::unauthorized("invalid e-mail or password"));
+        }
+
+        if state.passwords.needs_rehash(&stored_hash) {
+            rehash_password(&state, id, &payload.password).await;
+        }
+    }
+
+    if let Some(cache) = &state.cache
+        && state.captcha.is_some()
+    {
+        let _ = cache.delete(&email_login_failure_key(&payload.email)).await;
+    }
 
     let session_id = Uuid::new_v4();
     // 24 hours expiry
@@ -170,6 +312,7 @@ pub(crate) async fn lookup_setup_token(
         display_name,
         account_type,
         invited_email,
+        email_verified,
         ..
     } = ensure_pending_setup_token(&state, &payload.token).await?;
 
@@ -177,9 +320,184 @@ pub(crate) async fn lookup_setup_token(
         account_name: display_name,
         account_type,
         email: invited_email,
+        email_verification_required: !email_verified && state.email.is_some(),
     }))
 }
 
+async fn rehash_password(state: &AppState, account_id: i64, password: &str) {
+    let hash = match state.passwords.hash(password) {
+        Ok(hash) => hash,
+        Err(err) => {
+            warn!(account_id, %err, "failed to rehash password with current Argon2 parameters");
+            return;
+        }
+    };
+    match sqlx::query!(
+        "UPDATE accounts SET password_hash = $1 WHERE id = $2",
+        hash,
+        account_id
+    )
+    .execute(&state.db)
+    .await
+    {
+        Ok(_) => info!(
+            target: "auth",
+            action = "rehash_password",
+            account_id,
+            "Password rehashed with current Argon2 parameters"
+        ),
+        Err(err) => {
+            warn!(account_id, %err, "failed to store rehashed password");
+        }
+    }
+}
+
+fn email_verification_code_hash(account_id: i64, code: &str) -> String {
+    Sha256::digest(format!("{account_id}:{}", code.trim()).as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/auth/init/verify-email",
+    tag = "Auth",
+    request_body = SetupTokenLookupRequest,
+    responses(
+        (status = 202, description = "Verification code sent to the invited e-mail address", body = EmailVerificationSentResponse),
+        (status = 400, description = "Invalid or expired token, or e-mail already verified", body = ErrorResponse),
+        (status = 429, description = "A code was requested too recently", body = ErrorResponse),
+        (status = 503, description = "E-mail delivery is not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, payload))]
+pub(crate) async fn request_email_verification(
+    State(state): State<AppState>,
+    Json(payload): Json<SetupTokenLookupRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let PendingSetupToken {
+        account_id,
+        display_name,
+        invited_email,
+        email_verified,
+        ..
+    } = ensure_pending_setup_token(&state, &payload.token).await?;
+
+    if email_verified {
+        return Err(AppError::validation("email already verified"));
+    }
+    let Some(email_client) = &state.email else {
+        return Err(AppError::service_unavailable(
+            "email delivery is not configured",
+        ));
+    };
+
+    let code = format!("{:06}", OsRng.next_u32() % 1_000_000);
+    let expires_at = Utc::now() + Duration::minutes(EMAIL_VERIFICATION_VALID_MINUTES);
+    let issued = sqlx::query!(
+        r#"
+        UPDATE accounts
+        SET email_verification_code_hash = $2,
+            email_verification_expires_at = $3,
+            email_verification_attempts = 0
+        WHERE id = $1
+          AND (email_verification_expires_at IS NULL
+               OR email_verification_expires_at <= $3::timestamptz - make_interval(secs => $4))
+        "#,
+        account_id,
+        email_verification_code_hash(account_id, &code),
+        expires_at,
+        EMAIL_VERIFICATION_RESEND_SECONDS as f64
+    )
+    .execute(&state.db)
+    .await?;
+
+    if issued.rows_affected() == 0 {
+        return Err(AppError::too_many_requests(
+            "a verification code was sent recently; please wait before requesting another one",
+        ));
+    }
+
+    if let Err(err) = email_client
+        .send_email_verification_code(
+            &invited_email,
+            &display_name,
+            &code,
+            EMAIL_VERIFICATION_VALID_MINUTES,
+        )
+        .await
+    {
+        error!(error = %err, "failed to send verification code to {}", invited_email);
+        sqlx::query!(
+            "UPDATE accounts SET email_verification_code_hash = NULL, email_verification_expires_at = NULL WHERE id = $1",
+            account_id
+        )
+        .execute(&state.db)
+        .await?;
+        return Err(AppError::service_unavailable(
+            "failed to send verification code",
+        ));
+    }
+
+    info!(
+        target: "auth",
+        action = "request_email_verification",
+        account_id,
+        "Email verification code sent"
+    );
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(EmailVerificationSentResponse {
+            email: invited_email,
+            expires_at,
+        }),
+    ))
+}
+
+async fn verify_email_code(
+    state: &AppState,
+    account_id: i64,
+    code: Option<&str>,
+) -> Result<(), AppError> {
+    let Some(code) = code.map(str::trim).filter(|code| !code.is_empty()) else {
+        return Err(AppError::validation(
+            "email verification required; request a code via /api/v1/auth/init/verify-email",
+        ));
+    };
+
+    let row = sqlx::query!(
+        r#"
+        UPDATE accounts
+        SET email_verification_attempts = email_verification_attempts + 1
+        WHERE id = $1
+        RETURNING email_verification_code_hash, email_verification_expires_at, email_verification_attempts
+        "#,
+        account_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    let (Some(expected), Some(expires_at)) = (
+        row.email_verification_code_hash,
+        row.email_verification_expires_at,
+    ) else {
+        return Err(AppError::validation("invalid or expired verification code"));
+    };
+
+    if expires_at <= Utc::now() || row.email_verification_attempts > EMAIL_VERIFICATION_MAX_ATTEMPTS
+    {
+        return Err(AppError::validation("invalid or expired verification code"));
+    }
+    if expected != email_verification_code_hash(account_id, code) {
+        warn!(account_id, "invalid email verification code");
+        return Err(AppError::validation("invalid or expired verification code"));
+    }
+
+    Ok(())
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/auth/init",
@@ -187,7 +505,7 @@ pub(crate) async fn lookup_setup_token(
     request_body = InitAccountRequest,
     responses(
         (status = 200, description = "Initialized; cookie set", body = AuthUserResponse),
-        (status = 400, description = "Already initialized or invalid token"),
+        (status = 400, description = "Already initialized, invalid token or missing e-mail verification"),
     )
 )]
 #[instrument(skip(state, payload))]
@@ -202,15 +520,22 @@ pub(crate) async fn init_account(
         account_type,
         organizer_id,
         invited_email,
+        email_verified,
     } = pending;
 
-    ensure_password_requirements(&payload.password)?;
+    ensure_password_requirements(&state, &payload.password).await?;
+
+    let verify_email = !email_verified && state.email.is_some();
+    if verify_email {
+        verify_email_code(&state, account_id, payload.verification_code.as_deref()).await?;
+    } else if !email_verified {
+        warn!("email client not configured; initializing account without email verification");
+    }
 
-    let salt = SaltString::generate(&mut OsRng);
-    let hash = Argon2::default()
-        .hash_password(payload.password.as_bytes(), &salt)
-        .map_err(|_| AppError::validation("failed to hash password"))?
-        .to_string();
+    let hash = state
+        .passwords
+        .hash(&payload.password)
+        .map_err(|_| AppError::validation("failed to hash password"))?;
 
     sqlx::query!(
         r#"
@@ -218,11 +543,16 @@ pub(crate) async fn init_account(
         SET password_hash = $1,
             setup_token = NULL,
             setup_token_expires_at = NULL,
+            email_verified_at = CASE WHEN $3 THEN NOW() ELSE email_verified_at END,
+            email_verification_code_hash = NULL,
+            email_verification_expires_at = NULL,
+            email_verification_attempts = 0,
             updated_at = NOW()
         WHERE id = $2
         "#,
         hash,
-        account_id
+        account_id,
+        verify_email
     )
     .execute(&state.db)
     .await?;
@@ -247,6 +577,15 @@ pub(crate) async fn init_account(
         24 * 60 * 60
     );
 
+    if let Some(organizer_id) = organizer_id {
+        record_onboarding_milestone(
+            &state,
+            &[organizer_id],
+            OnboardingMilestone::AccountInitialized,
+        )
+        .await;
+    }
+
     let organizer_kind = organizer_kind_for_organizer(&state, organizer_id).await?;
 
     if let Some(email_client) = &state.email {
@@ -350,6 +689,85 @@ pub(crate) async fn me(
     }))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/auth/notification-preferences",
+    tag = "Auth",
+    responses(
+        (status = 200, description = "Email categories the current account receives", body = NotificationPreferencesResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_notification_preferences(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<NotificationPreferencesResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let preferences = sqlx::query_as!(
+        NotificationPreferencesResponse,
+        r#"
+        SELECT notify_reminders AS reminders, notify_digests AS digests, notify_audit_alerts AS audit_alerts, notify_broadcast AS broadcast
+        FROM accounts
+        WHERE id = $1
+        "#,
+        user.account_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(Json(preferences))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/auth/notification-preferences",
+    tag = "Auth",
+    request_body = UpdateNotificationPreferencesRequest,
+    responses(
+        (status = 200, description = "Notification preferences updated", body = NotificationPreferencesResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Not authenticated", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_notification_preferences(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateNotificationPreferencesRequest>,
+) -> Result<Json<NotificationPreferencesResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let preferences = sqlx::query_as!(
+        NotificationPreferencesResponse,
+        r#"
+        UPDATE accounts
+        SET notify_reminders = COALESCE($2, notify_reminders),
+            notify_digests = COALESCE($3, notify_digests),
+            notify_audit_alerts = COALESCE($4, notify_audit_alerts),
+            notify_broadcast = COALESCE($5, notify_broadcast),
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING notify_reminders AS reminders, notify_digests AS digests, notify_audit_alerts AS audit_alerts, notify_broadcast AS broadcast
+        "#,
+        user.account_id,
+        payload.reminders,
+        payload.digests,
+        payload.audit_alerts,
+        payload.broadcast
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(
+        target: "auth",
+        action = "update_notification_preferences",
+        account_id = user.account_id,
+        "Notification preferences updated"
+    );
+
+    Ok(Json(preferences))
+}
+
 async fn determine_newsletter_access(
     state: &AppState,
     account_type: &AccountType,
@@ -406,19 +824,17 @@ pub(crate) async fn change_password(
         return Err(AppError::validation("account not initialized"));
     };
 
-    let parsed_hash = PasswordHash::new(&stored)
-        .map_err(|_| AppError::unauthorized("invalid current password"))?;
-    Argon2::default()
-        .verify_password(payload.current_password.as_bytes(), &parsed_hash)
+    state
+        .passwords
+        .verify(&payload.current_password, &stored)
         .map_err(|_| AppError::unauthorized("invalid current password"))?;
 
-    ensure_password_requirements(&payload.new_password)?;
+    ensure_password_requirements(&state, &payload.new_password).await?;
 
-    let salt = SaltString::generate(&mut OsRng);
-    let new_hash = Argon2::default()
-        .hash_password(payload.new_password.as_bytes(), &salt)
-        .map_err(|_| AppError::validation("failed to hash password"))?
-        .to_string();
+    let new_hash = state
+        .passwords
+        .hash(&payload.new_password)
+        .map_err(|_| AppError::validation("failed to hash password"))?;
 
     let mut tx = state.db.begin().await?;
     sqlx::query!(
@@ -446,6 +862,7 @@ struct PendingSetupToken {
     account_type: AccountType,
     organizer_id: Option<i64>,
     invited_email: String,
+    email_verified: bool,
 }
 
 async fn ensure_pending_setup_token(
@@ -470,7 +887,7 @@ async fn ensure_pending_setup_token(
 
     let row = sqlx::query!(
         r#"
-        SELECT id, display_name, email, password_hash, account_type as "account_type: AccountType", organizer_id, setup_token_expires_at as "setup_token_expires_at?: DateTime<Utc>"
+        SELECT id, display_name, email, password_hash, account_type as "account_type: AccountType", organizer_id, setup_token_expires_at as "setup_token_expires_at?: DateTime<Utc>", email_verified_at
         FROM accounts
         WHERE setup_token = $1
         "#,
@@ -508,10 +925,11 @@ async fn ensure_pending_setup_token(
         account_type: row.account_type,
         organizer_id: row.organizer_id,
         invited_email,
+        email_verified: row.email_verified_at.is_some(),
     })
 }
 
-fn ensure_password_requirements(password: &str) -> Result<(), AppError> {
+async fn ensure_password_requirements(state: &AppState, password: &str) -> Result<(), AppError> {
     let policy = HighSecurityPolicy {
         min_length: 20,
         ..Default::default()
@@ -565,6 +983,20 @@ fn ensure_password_requirements(password: &str) -> Result<(), AppError> {
         )));
     }
 
+    if let Some(checker) = &state.pwned_passwords {
+        match checker.is_pwned(password).await {
+            Ok(true) => {
+                return Err(AppError::validation(
+                    "password appears in a known data breach; please choose a different one",
+                ));
+            }
+            Ok(false) => {}
+            Err(err) => {
+                warn!(target: "auth", action = "pwned_password_check", %err, "Breached password check failed; accepting password");
+            }
+        }
+    }
+
     Ok(())
 }
 
@@ -573,19 +1005,26 @@ fn ensure_password_requirements(password: &str) -> Result<(), AppError> {
     path = "/api/v1/auth/request-password-reset",
     tag = "Auth",
     request_body = RequestPasswordResetRequest,
+    params(("X-Captcha-Token" = Option<String>, Header, description = "CAPTCHA response token; required when CAPTCHA is configured")),
     responses(
         (
             status = 200,
             description = "Password reset email sent if account exists",
             body = PasswordResetRequestResponse
         ),
+        (status = 400, description = "CAPTCHA required or invalid", body = ErrorResponse),
     )
 )]
-#[instrument(skip(state, payload))]
+#[instrument(skip(state, headers, payload))]
 pub(crate) async fn request_password_reset(
     State(state): State<AppState>,
+    ConnectInfo(peer): ConnectInfo<SocketAddr>,
+    headers: HeaderMap,
     Json(payload): Json<RequestPasswordResetRequest>,
 ) -> Result<Json<PasswordResetRequestResponse>, AppError> {
+    let ip = client_ip(&headers, Some(&ConnectInfo(peer))).map(|ip| ip.to_string());
+    require_captcha(&state, &headers, ip.as_deref()).await?;
+
     let rec = sqlx::query!(
         r#"
         SELECT id, display_name, email
@@ -689,14 +1128,13 @@ pub(crate) async fn reset_password(
     let display_name = row.display_name;
 
     // Validate new password
-    ensure_password_requirements(&payload.new_password)?;
+    ensure_password_requirements(&state, &payload.new_password).await?;
 
     // Hash the new password
-    let salt = SaltString::generate(&mut OsRng);
-    let new_hash = Argon2::default()
-        .hash_password(payload.new_password.as_bytes(), &salt)
-        .map_err(|_| AppError::validation("Failed to hash password"))?
-        .to_string();
+    let new_hash = state
+        .passwords
+        .hash(&payload.new_password)
+        .map_err(|_| AppError::validation("Failed to hash password"))?;
 
     // Start transaction
     let mut tx = state.db.begin().await?;
@@ -734,13 +1172,19 @@ pub(crate) async fn reset_password(
 
 pub(crate) fn router() -> Router<AppState> {
     Router::new()
+        .route("/captcha", get(captcha_config))
         .route("/login", post(login))
         .route("/register-info", post(lookup_setup_token))
         .route("/init", post(init_account))
+        .route("/init/verify-email", post(request_email_verification))
         .route("/logout", post(logout))
         .route("/change-password", post(change_password))
         .route("/request-password-reset", post(request_password_reset))
         .route("/reset-password", post(reset_password))
         .route("/me", get(me))
+        .route(
+            "/notification-preferences",
+            get(get_notification_preferences).put(update_notification_preferences),
+        )
         .merge(super::api_tokens::router())
 }
@@ -0,0 +1,369 @@
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::HeaderMap,
+    routing::get,
+};
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Europe::Berlin;
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    dto::{CapacityWarningQuery, EventConflictQuery},
+    error::AppError,
+    models::{Event, EventStatus},
+    responses::{
+        CapacityWarningLevel, ErrorResponse, EventCapacityWarning, EventConflictReason,
+        EventConflictWarning, PossibleDuplicateEvent,
+    },
+};
+
+use super::shared::current_user_from_headers;
+
+const MAX_CONFLICTS: i64 = 20;
+const NEAR_CAPACITY_PERCENT: i64 = 90;
+const DUPLICATE_TITLE_SIMILARITY: f64 = 0.75;
+const MAX_POSSIBLE_DUPLICATES: usize = 5;
+
+fn normalized_location(location: Option<&str>) -> Option<String> {
+    location
+        .map(|location| location.trim().to_lowercase())
+        .filter(|location| !location.is_empty())
+}
+
+pub(crate) async fn find_event_conflicts(
+    state: &AppState,
+    organizer_id: i64,
+    start_date_time: DateTime<Utc>,
+    end_date_time: DateTime<Utc>,
+    location: Option<&str>,
+    exclude_event_id: Option<i64>,
+) -> Result<Vec<EventConflictWarning>, AppError> {
+    let location = normalized_location(location);
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT id, organizer_id, title_de, title_en, start_date_time, end_date_time, location
+        FROM events
+        WHERE cancelled_at IS NULL
+          AND status <> 'REJECTED'
+          AND ($1::bigint IS NULL OR id <> $1)
+          AND start_date_time < $3
+          AND end_date_time > $2
+          AND (
+            organizer_id = $4
+            OR (
+              $5::text IS NOT NULL
+              AND status = 'PUBLISHED'
+              AND (publish_at IS NULL OR publish_at <= NOW())
+              AND LOWER(TRIM(location)) = $5
+            )
+          )
+        ORDER BY start_date_time ASC, id ASC
+        LIMIT $6
+        "#,
+        exclude_event_id,
+        start_date_time,
+        end_date_time,
+        organizer_id,
+        location,
+        MAX_CONFLICTS
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| {
+            let mut reasons = Vec::new();
+            if row.organizer_id == organizer_id {
+                reasons.push(EventConflictReason::SameOrganizer);
+            }
+            if location.is_some() && normalized_location(row.location.as_deref()) == location {
+                reasons.push(EventConflictReason::SameLocation);
+            }
+            EventConflictWarning {
+                event_id: row.id,
+                organizer_id: row.organizer_id,
+                title_de: row.title_de,
+                title_en: row.title_en,
+                start_date_time: row.start_date_time,
+                end_date_time: row.end_date_time,
+                location: row.location,
+                reasons,
+            }
+        })
+        .collect())
+}
+
+pub(crate) async fn conflicts_for_event(
+    state: &AppState,
+    event: &Event,
+) -> Result<Vec<EventConflictWarning>, AppError> {
+    if event.cancelled_at.is_some() {
+        return Ok(Vec::new());
+    }
+    find_event_conflicts(
+        state,
+        event.organizer_id,
+        event.start_date_time,
+        event.end_date_time,
+        event.location.as_deref(),
+        Some(event.id),
+    )
+    .await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/conflicts",
+    tag = "Events",
+    params(EventConflictQuery),
+    responses(
+        (status = 200, description = "Overlapping events of the organizer or at the same location", body = [EventConflictWarning]),
+        (status = 400, description = "Invalid time range", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn check_event_conflicts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<EventConflictQuery>,
+) -> Result<Json<Vec<EventConflictWarning>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let organizer_id = match user.organizer_id() {
+        Some(organizer_id) => organizer_id,
+        None if user.is_admin() => query
+            .organizer_id
+            .ok_or_else(|| AppError::validation("organizer_id is required"))?,
+        None => return Err(AppError::unauthorized("organizer account required")),
+    };
+
+    if query.end_date_time < query.start_date_time {
+        return Err(AppError::validation(
+            "end date time must not be before start date time",
+        ));
+    }
+
+    let conflicts = find_event_conflicts(
+        &state,
+        organizer_id,
+        query.start_date_time,
+        query.end_date_time,
+        query.location.as_deref(),
+        query.exclude_event_id,
+    )
+    .await?;
+
+    Ok(Json(conflicts))
+}
+
+fn title_bigrams(title: &str) -> Vec<(char, char)> {
+    let normalized: Vec<char> = title
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ")
+        .chars()
+        .collect();
+    let mut bigrams: Vec<(char, char)> = normalized.windows(2).map(|w| (w[0], w[1])).collect();
+    bigrams.sort_unstable();
+    bigrams
+}
+
+fn title_similarity(left: &str, right: &str) -> f64 {
+    let left = title_bigrams(left);
+    let right = title_bigrams(right);
+    if left.is_empty() || right.is_empty() {
+        return 0.0;
+    }
+    let (mut i, mut j, mut shared) = (0, 0, 0);
+    while i < left.len() && j < right.len() {
+        match left[i].cmp(&right[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => {
+                shared += 1;
+                i += 1;
+                j += 1;
+            }
+        }
+    }
+    (2 * shared) as f64 / (left.len() + right.len()) as f64
+}
+
+pub(crate) async fn possible_duplicates_for_event(
+    state: &AppState,
+    event: &Event,
+) -> Result<Vec<PossibleDuplicateEvent>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT id, title_de, title_en, start_date_time, status as "status: EventStatus"
+        FROM events
+        WHERE organizer_id = $1
+          AND id <> $2
+          AND cancelled_at IS NULL
+          AND start_date_time BETWEEN $3 AND $4
+        ORDER BY start_date_time ASC, id ASC
+        "#,
+        event.organizer_id,
+        event.id,
+        event.start_date_time - Duration::days(1),
+        event.start_date_time + Duration::days(1)
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let day = event.start_date_time.with_timezone(&Berlin).date_naive();
+    let mut duplicates: Vec<PossibleDuplicateEvent> = rows
+        .into_iter()
+        .filter(|row| row.start_date_time.with_timezone(&Berlin).date_naive() == day)
+        .filter_map(|row| {
+            let similarity = title_similarity(&event.title_de, &row.title_de)
+                .max(title_similarity(&event.title_en, &row.title_en));
+            (similarity >= DUPLICATE_TITLE_SIMILARITY).then_some(PossibleDuplicateEvent {
+                event_id: row.id,
+                title_de: row.title_de,
+                title_en: row.title_en,
+                start_date_time: row.start_date_time,
+                status: row.status,
+                similarity,
+            })
+        })
+        .collect();
+    duplicates.sort_by(|a, b| b.similarity.total_cmp(&a.similarity));
+    duplicates.truncate(MAX_POSSIBLE_DUPLICATES);
+    Ok(duplicates)
+}
+
+fn capacity_warning_level(registrations: i64, capacity: i32) -> Option<CapacityWarningLevel> {
+    let capacity = i64::from(capacity);
+    if registrations >= capacity {
+        Some(CapacityWarningLevel::AtCapacity)
+    } else if registrations * 100 >= capacity * NEAR_CAPACITY_PERCENT {
+        Some(CapacityWarningLevel::NearCapacity)
+    } else {
+        None
+    }
+}
+
+async fn find_capacity_warnings(
+    state: &AppState,
+    organizer_id: Option<i64>,
+    event_id: Option<i64>,
+) -> Result<Vec<EventCapacityWarning>, AppError> {
+    let rows = sqlx::query!(
+        r#"
+        SELECT e.id, e.organizer_id, e.title_de, e.title_en, e.start_date_time, e.max_participants,
+               v.id AS venue_id, v.name AS venue_name, v.capacity AS "capacity!",
+               v.close_registration_at_capacity,
+               (SELECT COUNT(*) FROM registrations r WHERE r.event_id = e.id) AS "registrations!"
+        FROM events e
+        INNER JOIN venues v ON v.id = e.venue_id
+        WHERE v.capacity IS NOT NULL
+          AND e.cancelled_at IS NULL
+          AND e.end_date_time >= NOW()
+          AND ($1::bigint IS NULL OR e.organizer_id = $1)
+          AND ($2::bigint IS NULL OR e.id = $2)
+        ORDER BY e.start_date_time ASC, e.id ASC
+        "#,
+        organizer_id,
+        event_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|row| {
+            let level = capacity_warning_level(row.registrations, row.capacity)?;
+            Some(EventCapacityWarning {
+                event_id: row.id,
+                organizer_id: row.organizer_id,
+                title_de: row.title_de,
+                title_en: row.title_en,
+                start_date_time: row.start_date_time,
+                venue_id: row.venue_id,
+                venue_name: row.venue_name,
+                capacity: row.capacity,
+                max_participants: row.max_participants,
+                registrations: row.registrations,
+                level,
+                registration_closed: row.close_registration_at_capacity
+                    && level == CapacityWarningLevel::AtCapacity,
+            })
+        })
+        .collect())
+}
+
+pub(crate) async fn capacity_warning_for_event(
+    state: &AppState,
+    event_id: i64,
+) -> Result<Option<EventCapacityWarning>, AppError> {
+    Ok(find_capacity_warnings(state, None, Some(event_id))
+        .await?
+        .into_iter()
+        .next())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/capacity-warnings",
+    tag = "Events",
+    params(CapacityWarningQuery),
+    responses(
+        (status = 200, description = "Upcoming events whose registrations are close to or at their venue's capacity", body = [EventCapacityWarning]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_capacity_warnings(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<CapacityWarningQuery>,
+) -> Result<Json<Vec<EventCapacityWarning>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = if user.is_admin() {
+        query.organizer_id
+    } else {
+        let organizer_id = user
+            .organizer_id()
+            .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+        if query
+            .organizer_id
+            .is_some_and(|requested| requested != organizer_id)
+        {
+            return Err(AppError::unauthorized("insufficient permissions"));
+        }
+        Some(organizer_id)
+    };
+
+    let warnings = find_capacity_warnings(&state, organizer_id, None).await?;
+    Ok(Json(warnings))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/conflicts", get(check_event_conflicts))
+        .route("/capacity-warnings", get(list_capacity_warnings))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::title_similarity;
+
+    #[test]
+    fn title_similarity_ignores_case_and_punctuation() {
+        assert_eq!(
+            title_similarity("Erstsemester-Party!", "erstsemester party"),
+            1.0
+        );
+        assert!(title_similarity("Spieleabend", "Spieleabend 2") >= 0.75);
+        assert!(title_similarity("Spieleabend", "Hackathon") < 0.75);
+        assert_eq!(title_similarity("", "Hackathon"), 0.0);
+    }
+}
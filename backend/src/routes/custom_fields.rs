@@ -0,0 +1,364 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    routing::{get, put},
+};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde_json::{Map, Value};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::CustomFieldDefinitionRequest,
+    error::AppError,
+    models::{CustomFieldDefinition, CustomFieldType},
+    responses::ErrorResponse,
+};
+
+use super::{
+    events::invalidate_public_event_caches,
+    shared::{current_user_from_headers, validate_http_url},
+};
+
+const MAX_CUSTOM_FIELD_KEY_LEN: usize = 50;
+const MAX_CUSTOM_FIELD_LABEL_LEN: usize = 100;
+const MAX_CUSTOM_FIELD_TEXT_LEN: usize = 500;
+
+fn normalize_key(raw: &str) -> Result<String, AppError> {
+    let key = raw.trim();
+    let mut chars = key.chars();
+    let valid = chars.next().is_some_and(|c| c.is_ascii_lowercase())
+        && chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+        && key.len() <= MAX_CUSTOM_FIELD_KEY_LEN;
+    if !valid {
+        return Err(AppError::validation(format!(
+            "key must start with a lowercase letter and contain only lowercase letters, digits and underscores (max {MAX_CUSTOM_FIELD_KEY_LEN} characters)"
+        )));
+    }
+    Ok(key.to_string())
+}
+
+fn normalize_label(raw: &str, field: &str) -> Result<String, AppError> {
+    let label = raw.trim();
+    if label.is_empty() || label.chars().count() > MAX_CUSTOM_FIELD_LABEL_LEN {
+        return Err(AppError::validation(format!(
+            "{field} must be between 1 and {MAX_CUSTOM_FIELD_LABEL_LEN} characters"
+        )));
+    }
+    Ok(label.to_string())
+}
+
+fn normalize_value(
+    definition: &CustomFieldDefinition,
+    value: Value,
+) -> Result<Option<Value>, AppError> {
+    let key = &definition.key;
+    let invalid = || AppError::validation(format!("invalid value for custom field {key}"));
+    let normalized = match (definition.field_type, value) {
+        (_, Value::Null) => None,
+        (CustomFieldType::Number, value @ Value::Number(_)) => Some(value),
+        (CustomFieldType::Boolean, value @ Value::Bool(_)) => Some(value),
+        (CustomFieldType::Text, Value::String(text)) => {
+            let text = text.trim();
+            if text.chars().count() > MAX_CUSTOM_FIELD_TEXT_LEN {
+                return Err(AppError::validation(format!(
+                    "custom field {key} must be at most {MAX_CUSTOM_FIELD_TEXT_LEN} characters"
+                )));
+            }
+            (!text.is_empty()).then(|| Value::String(text.to_string()))
+        }
+        (CustomFieldType::Date, Value::String(text)) => {
+            let date = NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").map_err(|_| invalid())?;
+            Some(Value::String(date.format("%Y-%m-%d").to_string()))
+        }
+        (CustomFieldType::DateTime, Value::String(text)) => {
+            let date_time = DateTime::parse_from_rfc3339(text.trim()).map_err(|_| invalid())?;
+            Some(Value::String(date_time.with_timezone(&Utc).to_rfc3339()))
+        }
+        (CustomFieldType::Url, Value::String(text)) => {
+            Some(Value::String(validate_http_url(&text, key)?))
+        }
+        _ => return Err(invalid()),
+    };
+    Ok(normalized)
+}
+
+pub(crate) async fn validate_custom_fields(
+    state: &AppState,
+    custom_fields: Value,
+) -> Result<Value, AppError> {
+    let Value::Object(fields) = custom_fields else {
+        return Err(AppError::validation("custom_fields must be an object"));
+    };
+    if fields.is_empty() {
+        return Ok(Value::Object(Map::new()));
+    }
+
+    let definitions = fetch_custom_field_definitions(state).await?;
+    let mut normalized = Map::new();
+    for (key, value) in fields {
+        let Some(definition) = definitions.iter().find(|definition| definition.key == key) else {
+            return Err(AppError::validation(format!("unknown custom field {key}")));
+        };
+        if let Some(value) = normalize_value(definition, value)? {
+            normalized.insert(key, value);
+        }
+    }
+    Ok(Value::Object(normalized))
+}
+
+async fn fetch_custom_field_definitions(
+    state: &AppState,
+) -> Result<Vec<CustomFieldDefinition>, AppError> {
+    let definitions = sqlx::query_as!(
+        CustomFieldDefinition,
+        r#"
+        SELECT id, key, label_de, label_en, field_type AS "field_type: CustomFieldType", position, created_at, updated_at
+        FROM custom_field_definitions
+        ORDER BY position ASC, id ASC
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(definitions)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/custom-fields",
+    tag = "Events",
+    responses(
+        (status = 200, description = "Custom event fields defined by admins", body = [CustomFieldDefinition]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_custom_fields(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<CustomFieldDefinition>>, AppError> {
+    current_user_from_headers(&headers, &state).await?;
+    Ok(Json(fetch_custom_field_definitions(&state).await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/custom-fields",
+    tag = "Public",
+    responses((status = 200, description = "Labels and types of custom event fields", body = [CustomFieldDefinition]))
+)]
+#[instrument(skip(state))]
+pub(crate) async fn list_public_custom_fields(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<CustomFieldDefinition>>, AppError> {
+    Ok(Json(fetch_custom_field_definitions(&state).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/custom-fields",
+    tag = "Events",
+    request_body = CustomFieldDefinitionRequest,
+    responses(
+        (status = 201, description = "Custom field created", body = CustomFieldDefinition),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Key already in use", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_custom_field(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CustomFieldDefinitionRequest>,
+) -> Result<(StatusCode, Json<CustomFieldDefinition>), AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let key = normalize_key(&payload.key)?;
+    let label_de = normalize_label(&payload.label_de, "label_de")?;
+    let label_en = normalize_label(&payload.label_en, "label_en")?;
+
+    let definition = sqlx::query_as!(
+        CustomFieldDefinition,
+        r#"
+        INSERT INTO custom_field_definitions (key, label_de, label_en, field_type, position)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, key, label_de, label_en, field_type AS "field_type: CustomFieldType", position, created_at, updated_at
+        "#,
+        key,
+        label_de,
+        label_en,
+        payload.field_type as CustomFieldType,
+        payload.position
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "custom_fields", action = "create", key = %definition.key, "Custom field created");
+
+    Ok((StatusCode::CREATED, Json(definition)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/custom-fields/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Custom field identifier")),
+    request_body = CustomFieldDefinitionRequest,
+    responses(
+        (status = 200, description = "Custom field updated", body = CustomFieldDefinition),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Custom field not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_custom_field(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<CustomFieldDefinitionRequest>,
+) -> Result<Json<CustomFieldDefinition>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let key = normalize_key(&payload.key)?;
+    let label_de = normalize_label(&payload.label_de, "label_de")?;
+    let label_en = normalize_label(&payload.label_en, "label_en")?;
+
+    let mut transaction = state.db.begin().await?;
+
+    let existing = sqlx::query!(
+        r#"
+        SELECT key, field_type AS "field_type: CustomFieldType"
+        FROM custom_field_definitions
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_optional(&mut *transaction)
+    .await?
+    .ok_or_else(|| AppError::not_found("custom field not found"))?;
+
+    if existing.field_type != payload.field_type {
+        let in_use = sqlx::query_scalar!(
+            r#"SELECT EXISTS (SELECT 1 FROM events WHERE custom_fields ? $1) AS "in_use!""#,
+            &existing.key
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+        if in_use {
+            return Err(AppError::conflict(
+                "cannot change the type of a custom field that is in use",
+            ));
+        }
+    }
+
+    let definition = sqlx::query_as!(
+        CustomFieldDefinition,
+        r#"
+        UPDATE custom_field_definitions
+        SET key = $2, label_de = $3, label_en = $4, field_type = $5, position = $6, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, key, label_de, label_en, field_type AS "field_type: CustomFieldType", position, created_at, updated_at
+        "#,
+        id,
+        key,
+        label_de,
+        label_en,
+        payload.field_type as CustomFieldType,
+        payload.position
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+
+    if existing.key != definition.key {
+        sqlx::query!(
+            r#"
+            UPDATE events
+            SET custom_fields = (custom_fields - $1::text) || jsonb_build_object($2::text, custom_fields -> $1::text)
+            WHERE custom_fields ? $1
+            "#,
+            &existing.key,
+            &definition.key
+        )
+        .execute(&mut *transaction)
+        .await?;
+    }
+
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(target: "custom_fields", action = "update", custom_field_id = id, key = %definition.key, "Custom field updated");
+
+    Ok(Json(definition))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/custom-fields/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Custom field identifier")),
+    responses(
+        (status = 204, description = "Custom field deleted and removed from all events"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Custom field not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_custom_field(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let mut transaction = state.db.begin().await?;
+
+    let key = sqlx::query_scalar!(
+        "DELETE FROM custom_field_definitions WHERE id = $1 RETURNING key",
+        id
+    )
+    .fetch_optional(&mut *transaction)
+    .await?
+    .ok_or_else(|| AppError::not_found("custom field not found"))?;
+
+    sqlx::query!(
+        "UPDATE events SET custom_fields = custom_fields - $1::text WHERE custom_fields ? $1",
+        &key
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(target: "custom_fields", action = "delete", custom_field_id = id, key = %key, "Custom field deleted");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_custom_fields).post(create_custom_field))
+        .route(
+            "/{id}",
+            put(update_custom_field).delete(delete_custom_field),
+        )
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new().route("/custom-fields", get(list_public_custom_fields))
+}
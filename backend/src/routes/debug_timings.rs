@@ -0,0 +1,39 @@
+use std::time::Instant;
+
+use axum::{
+    extract::{Request, State},
+    http::HeaderValue,
+    middleware::Next,
+    response::Response,
+};
+
+use crate::{app_state::AppState, timings::TimingRecorder};
+
+use super::shared::{bearer_token, current_user_from_headers};
+
+pub(crate) const DEBUG_TIMINGS_HEADER: &str = "x-debug-timings";
+
+pub(crate) async fn debug_timings(
+    State(state): State<AppState>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let headers = request.headers();
+    if !headers.contains_key(DEBUG_TIMINGS_HEADER) || bearer_token(headers).is_some() {
+        return next.run(request).await;
+    }
+    let is_admin = current_user_from_headers(headers, &state)
+        .await
+        .is_ok_and(|user| user.is_admin());
+    if !is_admin {
+        return next.run(request).await;
+    }
+
+    let recorder = TimingRecorder::default();
+    let started = Instant::now();
+    let mut response = recorder.scope(next.run(request)).await;
+    if let Ok(value) = HeaderValue::from_str(&recorder.header_value(started.elapsed())) {
+        response.headers_mut().insert(DEBUG_TIMINGS_HEADER, value);
+    }
+    response
+}
@@ -0,0 +1,510 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{delete, get, post, put},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    api_token::{self, DEVELOPER_KEY_PREFIX, DEVELOPER_PORTAL_TOKEN_PREFIX},
+    app_state::AppState,
+    dto::{
+        CreateDeveloperApiKeyRequest, DeveloperAccountQuery, DeveloperApplicationRequest,
+        UpdateDeveloperAccountRequest,
+    },
+    error::AppError,
+    models::DeveloperStatus,
+    responses::{
+        DeveloperAccountResponse, DeveloperApiKeyCreatedResponse, DeveloperApiKeyResponse,
+        DeveloperApplicationResponse, ErrorResponse,
+    },
+};
+
+use super::shared::{current_user_from_headers, normalize_email};
+
+const MAX_DEVELOPER_NAME_LEN: usize = 200;
+const MAX_DEVELOPER_USE_CASE_LEN: usize = 2000;
+const MAX_DEVELOPER_KEY_LABEL_LEN: usize = 200;
+const MAX_ACTIVE_DEVELOPER_KEYS: i64 = 5;
+const MAX_DEVELOPER_DAILY_QUOTA: i32 = 1_000_000;
+
+struct DeveloperContext {
+    id: i64,
+    status: DeveloperStatus,
+}
+
+fn hmac_key(state: &AppState) -> Result<&[u8; 32], AppError> {
+    state.api_token_hmac_key.as_ref().ok_or_else(|| {
+        AppError::service_unavailable(
+            "API token management is not configured (set API_TOKEN_SECRET)",
+        )
+    })
+}
+
+fn required_text(raw: &str, field: &str, max_len: usize) -> Result<String, AppError> {
+    let value = raw.trim();
+    if value.is_empty() {
+        return Err(AppError::validation(format!("{field} must not be empty")));
+    }
+    if value.chars().count() > max_len {
+        return Err(AppError::validation(format!(
+            "{field} must be at most {max_len} characters"
+        )));
+    }
+    Ok(value.to_string())
+}
+
+fn portal_token(headers: &HeaderMap) -> Result<&str, AppError> {
+    let token = headers
+        .get(axum::http::header::AUTHORIZATION)
+        .ok_or_else(|| AppError::unauthorized("missing API token"))?
+        .to_str()
+        .map_err(|_| AppError::unauthorized("invalid API token"))?
+        .strip_prefix("Bearer ")
+        .ok_or_else(|| AppError::unauthorized("invalid API token"))?
+        .trim();
+    if !token.starts_with(DEVELOPER_PORTAL_TOKEN_PREFIX) {
+        return Err(AppError::unauthorized("invalid API token"));
+    }
+    Ok(token)
+}
+
+async fn developer_from_headers(
+    headers: &HeaderMap,
+    state: &AppState,
+) -> Result<DeveloperContext, AppError> {
+    let key = hmac_key(state)?;
+    let digest = api_token::hash_raw_token(key, portal_token(headers)?);
+    let row = sqlx::query!(
+        r#"
+        SELECT id, status AS "status: DeveloperStatus"
+        FROM developer_accounts
+        WHERE portal_token_hmac = $1
+        "#,
+        &digest[..]
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::unauthorized("invalid API token"))?;
+
+    Ok(DeveloperContext {
+        id: row.id,
+        status: row.status,
+    })
+}
+
+async fn query_developers(
+    state: &AppState,
+    id: Option<i64>,
+    status: Option<DeveloperStatus>,
+) -> Result<Vec<DeveloperAccountResponse>, AppError> {
+    let developers = sqlx::query_as!(
+        DeveloperAccountResponse,
+        r#"
+        SELECT
+            d.id,
+            d.name,
+            d.email,
+            d.organization,
+            d.use_case,
+            d.status AS "status: DeveloperStatus",
+            d.daily_quota,
+            COALESCE((
+                SELECT SUM(u.request_count)
+                FROM developer_api_key_usage u
+                JOIN developer_api_keys k ON k.id = u.key_id
+                WHERE k.developer_id = d.id AND u.usage_date = CURRENT_DATE
+            ), 0)::BIGINT AS "requests_today!",
+            (
+                SELECT COUNT(*)
+                FROM developer_api_keys k
+                WHERE k.developer_id = d.id AND k.revoked_at IS NULL
+            ) AS "active_keys!",
+            d.created_at,
+            d.reviewed_at
+        FROM developer_accounts d
+        WHERE ($1::bigint IS NULL OR d.id = $1)
+          AND ($2::developer_status IS NULL OR d.status = $2)
+        ORDER BY d.created_at DESC
+        "#,
+        id,
+        status as Option<DeveloperStatus>
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(developers)
+}
+
+async fn fetch_developer(state: &AppState, id: i64) -> Result<DeveloperAccountResponse, AppError> {
+    query_developers(state, Some(id), None)
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| AppError::not_found("developer account not found"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/developer/apply",
+    tag = "Developer",
+    request_body = DeveloperApplicationRequest,
+    responses(
+        (status = 201, description = "Application received; copy `portal_token` now, it is not shown again", body = DeveloperApplicationResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 409, description = "An application for this email already exists", body = ErrorResponse),
+        (status = 503, description = "Server not configured for API tokens", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, payload))]
+pub(crate) async fn apply_for_developer_access(
+    State(state): State<AppState>,
+    Json(payload): Json<DeveloperApplicationRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let key = hmac_key(&state)?;
+    let name = required_text(&payload.name, "name", MAX_DEVELOPER_NAME_LEN)?;
+    let email = normalize_email(&payload.email)?;
+    let use_case = required_text(&payload.use_case, "use_case", MAX_DEVELOPER_USE_CASE_LEN)?;
+    let organization = payload
+        .organization
+        .as_deref()
+        .map(str::trim)
+        .filter(|organization| !organization.is_empty())
+        .map(|organization| required_text(organization, "organization", MAX_DEVELOPER_NAME_LEN))
+        .transpose()?;
+
+    let existing = sqlx::query_scalar!(
+        "SELECT id FROM developer_accounts WHERE LOWER(email) = LOWER($1)",
+        &email
+    )
+    .fetch_optional(&state.db)
+    .await?;
+    if existing.is_some() {
+        return Err(AppError::conflict(
+            "a developer application for this email already exists",
+        ));
+    }
+
+    let portal_token = api_token::generate_prefixed_token(DEVELOPER_PORTAL_TOKEN_PREFIX);
+    let digest = api_token::hash_raw_token(key, &portal_token);
+    let id = sqlx::query_scalar!(
+        r#"
+        INSERT INTO developer_accounts (name, email, organization, use_case, portal_token_hmac)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id
+        "#,
+        &name,
+        &email,
+        organization,
+        &use_case,
+        &digest[..]
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "developers", action = "apply", developer_id = id, "Developer application received");
+
+    let developer = fetch_developer(&state, id).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(DeveloperApplicationResponse {
+            developer,
+            portal_token,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/developer/me",
+    tag = "Developer",
+    params(("Authorization" = String, Header, description = "Bearer developer portal token")),
+    responses(
+        (status = 200, description = "Developer account with quota and today's usage", body = DeveloperAccountResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_developer_account(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<DeveloperAccountResponse>, AppError> {
+    let developer = developer_from_headers(&headers, &state).await?;
+    Ok(Json(fetch_developer(&state, developer.id).await?))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/developer/keys",
+    tag = "Developer",
+    params(("Authorization" = String, Header, description = "Bearer developer portal token")),
+    responses(
+        (status = 200, description = "Public API keys of the developer", body = [DeveloperApiKeyResponse]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_developer_keys(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<DeveloperApiKeyResponse>>, AppError> {
+    let developer = developer_from_headers(&headers, &state).await?;
+    let keys = sqlx::query_as!(
+        DeveloperApiKeyResponse,
+        r#"
+        SELECT
+            k.id,
+            k.label,
+            k.key_last_four,
+            k.created_at,
+            k.last_used_at,
+            k.revoked_at,
+            COALESCE(u.request_count, 0) AS "requests_today!"
+        FROM developer_api_keys k
+        LEFT JOIN developer_api_key_usage u ON u.key_id = k.id AND u.usage_date = CURRENT_DATE
+        WHERE k.developer_id = $1
+        ORDER BY k.created_at DESC
+        "#,
+        developer.id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(keys))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/developer/keys",
+    tag = "Developer",
+    params(("Authorization" = String, Header, description = "Bearer developer portal token")),
+    request_body = CreateDeveloperApiKeyRequest,
+    responses(
+        (status = 201, description = "New read-only public API key; copy `key` now, it is not shown again", body = DeveloperApiKeyCreatedResponse),
+        (status = 400, description = "Invalid request or key limit reached", body = ErrorResponse),
+        (status = 401, description = "Unauthorized or account not approved", body = ErrorResponse),
+        (status = 503, description = "Server not configured for API tokens", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_developer_key(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateDeveloperApiKeyRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let developer = developer_from_headers(&headers, &state).await?;
+    if developer.status != DeveloperStatus::Approved {
+        return Err(AppError::unauthorized("developer account is not approved"));
+    }
+
+    let label = payload.label.trim().to_string();
+    if label.chars().count() > MAX_DEVELOPER_KEY_LABEL_LEN {
+        return Err(AppError::validation(format!(
+            "label must be at most {MAX_DEVELOPER_KEY_LABEL_LEN} characters"
+        )));
+    }
+
+    let active_keys = sqlx::query_scalar!(
+        r#"
+        SELECT COUNT(*) AS "count!"
+        FROM developer_api_keys
+        WHERE developer_id = $1 AND revoked_at IS NULL
+        "#,
+        developer.id
+    )
+    .fetch_one(&state.db)
+    .await?;
+    if active_keys >= MAX_ACTIVE_DEVELOPER_KEYS {
+        return Err(AppError::validation(format!(
+            "at most {MAX_ACTIVE_DEVELOPER_KEYS} active keys are allowed"
+        )));
+    }
+
+    let key = hmac_key(&state)?;
+    let raw = api_token::generate_prefixed_token(DEVELOPER_KEY_PREFIX);
+    let digest = api_token::hash_raw_token(key, &raw);
+    let key_last_four = api_token::token_last_four(&raw);
+
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO developer_api_keys (developer_id, key_hmac, label, key_last_four)
+        VALUES ($1, $2, $3, $4)
+        RETURNING id, created_at
+        "#,
+        developer.id,
+        &digest[..],
+        &label,
+        &key_last_four
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "developers", action = "create_key", developer_id = developer.id, key_id = row.id, "Developer API key created");
+
+    Ok((
+        StatusCode::CREATED,
+        Json(DeveloperApiKeyCreatedResponse {
+            id: row.id,
+            label,
+            key: raw,
+            key_last_four,
+            created_at: row.created_at,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/developer/keys/{id}",
+    tag = "Developer",
+    params(
+        ("id" = i64, Path, description = "Developer API key id"),
+        ("Authorization" = String, Header, description = "Bearer developer portal token"),
+    ),
+    responses(
+        (status = 204, description = "Revoked"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn revoke_developer_key(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let developer = developer_from_headers(&headers, &state).await?;
+    let res = sqlx::query!(
+        r#"
+        UPDATE developer_api_keys
+        SET revoked_at = NOW()
+        WHERE id = $1 AND developer_id = $2 AND revoked_at IS NULL
+        "#,
+        id,
+        developer.id
+    )
+    .execute(&state.db)
+    .await?;
+
+    if res.rows_affected() == 0 {
+        return Err(AppError::not_found("token not found"));
+    }
+
+    info!(target: "developers", action = "revoke_key", developer_id = developer.id, key_id = id, "Developer API key revoked");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/developers",
+    tag = "Admin",
+    params(DeveloperAccountQuery),
+    responses(
+        (status = 200, description = "Developer accounts with quota and today's usage", body = [DeveloperAccountResponse]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_developer_accounts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<DeveloperAccountQuery>,
+) -> Result<Json<Vec<DeveloperAccountResponse>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let developers = query_developers(&state, None, query.status).await?;
+
+    Ok(Json(developers))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/developers/{id}",
+    tag = "Admin",
+    params(("id" = i64, Path, description = "Developer account id")),
+    request_body = UpdateDeveloperAccountRequest,
+    responses(
+        (status = 200, description = "Developer account reviewed or quota changed", body = DeveloperAccountResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Developer account not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_developer_account(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<UpdateDeveloperAccountRequest>,
+) -> Result<Json<DeveloperAccountResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    if payload.status.is_none() && payload.daily_quota.is_none() {
+        return Err(AppError::validation("no changes provided"));
+    }
+    if let Some(daily_quota) = payload.daily_quota
+        && !(1..=MAX_DEVELOPER_DAILY_QUOTA).contains(&daily_quota)
+    {
+        return Err(AppError::validation(format!(
+            "daily_quota must be between 1 and {MAX_DEVELOPER_DAILY_QUOTA}"
+        )));
+    }
+
+    let updated = sqlx::query_scalar!(
+        r#"
+        UPDATE developer_accounts
+        SET status = COALESCE($2, status),
+            daily_quota = COALESCE($3, daily_quota),
+            reviewed_by = CASE WHEN $2::developer_status IS NULL THEN reviewed_by ELSE $4 END,
+            reviewed_at = CASE WHEN $2::developer_status IS NULL THEN reviewed_at ELSE NOW() END,
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id
+        "#,
+        id,
+        payload.status as Option<DeveloperStatus>,
+        payload.daily_quota,
+        user.account_id
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    if updated.is_none() {
+        return Err(AppError::not_found("developer account not found"));
+    }
+
+    info!(
+        target: "developers",
+        action = "review",
+        developer_id = id,
+        status = ?payload.status,
+        daily_quota = ?payload.daily_quota,
+        reviewed_by = user.account_id,
+        "Developer account updated"
+    );
+
+    Ok(Json(fetch_developer(&state, id).await?))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/apply", post(apply_for_developer_access))
+        .route("/me", get(get_developer_account))
+        .route("/keys", get(list_developer_keys).post(create_developer_key))
+        .route("/keys/{id}", delete(revoke_developer_key))
+}
+
+pub(crate) fn admin_router() -> Router<AppState> {
+    Router::new()
+        .route("/developers", get(list_developer_accounts))
+        .route("/developers/{id}", put(update_developer_account))
+}
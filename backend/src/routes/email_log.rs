@@ -0,0 +1,98 @@
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::HeaderMap,
+    routing::get,
+};
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    dto::EmailLogQuery,
+    email::recipient_hash,
+    error::AppError,
+    models::{EmailSendStatus, EmailTemplate},
+    responses::{EmailLogEntry, ErrorResponse},
+};
+
+use super::shared::current_user_from_headers;
+
+const DEFAULT_EMAIL_LOG_LIMIT: i64 = 100;
+const MAX_EMAIL_LOG_LIMIT: i64 = 500;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/email-log",
+    tag = "Admin",
+    params(EmailLogQuery),
+    responses(
+        (status = 200, description = "Outgoing email sends, newest first", body = [EmailLogEntry]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, query))]
+pub(crate) async fn list_email_log(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<EmailLogQuery>,
+) -> Result<Json<Vec<EmailLogEntry>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let recipient = query
+        .email
+        .as_deref()
+        .map(str::trim)
+        .filter(|email| !email.is_empty())
+        .map(recipient_hash);
+    let request_id = query
+        .request_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|request_id| !request_id.is_empty());
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_EMAIL_LOG_LIMIT)
+        .clamp(1, MAX_EMAIL_LOG_LIMIT);
+    let offset = query.offset.unwrap_or(0).max(0);
+
+    let entries = sqlx::query_as!(
+        EmailLogEntry,
+        r#"
+        SELECT
+            id,
+            recipient_hash,
+            template AS "template: EmailTemplate",
+            status AS "status: EmailSendStatus",
+            message_id,
+            provider_message_id,
+            error,
+            request_id,
+            account_id,
+            created_at
+        FROM email_log
+        WHERE ($1::TEXT IS NULL OR recipient_hash = $1)
+          AND ($2::email_template IS NULL OR template = $2)
+          AND ($3::email_send_status IS NULL OR status = $3)
+          AND ($4::TEXT IS NULL OR request_id = $4)
+        ORDER BY created_at DESC, id DESC
+        LIMIT $5 OFFSET $6
+        "#,
+        recipient,
+        query.template as Option<EmailTemplate>,
+        query.status as Option<EmailSendStatus>,
+        request_id,
+        limit,
+        offset
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(entries))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/email-log", get(list_email_log))
+}
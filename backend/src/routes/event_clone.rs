@@ -0,0 +1,198 @@
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    routing::post,
+};
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::BulkCloneEventsRequest,
+    error::AppError,
+    models::{AuditType, Event, EventAudience, EventStatus, EventVisibility},
+    responses::ErrorResponse,
+    term::term_for,
+};
+
+use super::{
+    event_quota::ensure_event_quota_for,
+    event_slugs::assign_event_slug,
+    events::{invalidate_public_event_caches, record_audit},
+    shared::current_user_from_headers,
+};
+
+const MAX_CLONE_EVENTS: usize = 200;
+const MAX_SHIFT_WEEKS: i32 = 104;
+
+fn shift_local(value: DateTime<Utc>, weeks: i32) -> Result<DateTime<Utc>, AppError> {
+    let local = value.with_timezone(&Berlin).naive_local() + Duration::weeks(i64::from(weeks));
+    Berlin
+        .from_local_datetime(&local)
+        .earliest()
+        .or_else(|| {
+            Berlin
+                .from_local_datetime(&(local + Duration::hours(1)))
+                .earliest()
+        })
+        .map(|shifted| shifted.with_timezone(&Utc))
+        .ok_or_else(|| AppError::validation("shifted date does not exist in local time"))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/bulk-clone",
+    tag = "Events",
+    request_body = BulkCloneEventsRequest,
+    responses(
+        (status = 201, description = "Copies of the selected events created as drafts with shifted dates", body = [Event]),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "One or more events not found", body = ErrorResponse),
+        (status = 429, description = "Event quota exceeded", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn bulk_clone_events(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<BulkCloneEventsRequest>,
+) -> Result<(StatusCode, Json<Vec<Event>>), AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = user
+        .organizer_id()
+        .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+
+    let mut event_ids = payload.event_ids;
+    event_ids.sort_unstable();
+    event_ids.dedup();
+    if event_ids.is_empty() {
+        return Err(AppError::validation("no events selected"));
+    }
+    if event_ids.len() > MAX_CLONE_EVENTS {
+        return Err(AppError::validation(format!(
+            "at most {MAX_CLONE_EVENTS} events can be cloned at once"
+        )));
+    }
+    if !(1..=MAX_SHIFT_WEEKS).contains(&payload.shift_weeks) {
+        return Err(AppError::validation(format!(
+            "shift_weeks must be between 1 and {MAX_SHIFT_WEEKS}"
+        )));
+    }
+
+    let sources = sqlx::query_as!(
+        Event,
+        r#"
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+        FROM events
+        WHERE id = ANY($1) AND organizer_id = $2
+        ORDER BY start_date_time ASC, id ASC
+        "#,
+        &event_ids,
+        organizer_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+    if sources.len() != event_ids.len() {
+        return Err(AppError::not_found("one or more events not found"));
+    }
+
+    let now = Utc::now();
+    let mut shifted = Vec::with_capacity(sources.len());
+    for source in &sources {
+        let start_date_time = shift_local(source.start_date_time, payload.shift_weeks)?;
+        let end_date_time = shift_local(source.end_date_time, payload.shift_weeks)?;
+        if start_date_time <= now {
+            return Err(AppError::validation(format!(
+                "event {} would still start in the past after shifting",
+                source.id
+            )));
+        }
+        shifted.push((start_date_time, end_date_time));
+    }
+
+    ensure_event_quota_for(
+        &state,
+        organizer_id,
+        sources.len() as i64,
+        sources.len() as i64,
+    )
+    .await?;
+
+    let mut transaction = state.db.begin().await?;
+    let mut created = Vec::with_capacity(sources.len());
+
+    for (source, (start_date_time, end_date_time)) in sources.iter().zip(shifted) {
+        let event = sqlx::query_as!(
+            Event,
+            r#"
+            INSERT INTO events (organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status, visibility, series_id, custom_fields, price_cents, currency, free_entry, audience, venue_id, term, all_day)
+            VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25)
+            RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+            "#,
+            organizer_id,
+            source.title_de,
+            source.title_en,
+            source.description_de,
+            source.description_en,
+            start_date_time,
+            end_date_time,
+            source.event_url,
+            source.location,
+            source.publish_app,
+            source.publish_newsletter,
+            source.publish_in_ical,
+            source.publish_web,
+            source.max_participants,
+            EventStatus::Draft as EventStatus,
+            source.visibility as EventVisibility,
+            source.series_id,
+            source.custom_fields,
+            source.price_cents,
+            source.currency,
+            source.free_entry,
+            &source.audience as &[EventAudience],
+            source.venue_id,
+            term_for(start_date_time),
+            source.all_day
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+        assign_event_slug(&mut transaction, &event).await?;
+
+        record_audit(
+            &mut transaction,
+            event.id,
+            event.organizer_id,
+            user.account_id,
+            AuditType::Create,
+            None,
+            Some(&event),
+        )
+        .await?;
+
+        created.push(event);
+    }
+
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(
+        target: "events",
+        action = "bulk_clone",
+        account_id = user.account_id,
+        organizer_id,
+        shift_weeks = payload.shift_weeks,
+        count = created.len(),
+        "Events cloned into a new semester"
+    );
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/bulk-clone", post(bulk_clone_events))
+}
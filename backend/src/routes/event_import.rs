@@ -0,0 +1,256 @@
+use axum::{
+    Json, Router,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::post,
+};
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::{Europe::Berlin, Tz};
+use icalendar::{Calendar, CalendarDateTime, Component, DatePerhapsTime, EventLike};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::{CreateEventRequest, ImportEventsRequest},
+    error::AppError,
+    models::{Event, EventStatus, EventVisibility, OnboardingMilestone},
+    responses::{ErrorResponse, IcsImportPreviewResponse, IcsImportSkippedEntry},
+};
+
+use super::{
+    event_quota::ensure_event_quota_for,
+    events::{
+        ensure_valid_max_participants, insert_event_with_user, invalidate_public_event_caches,
+        starts_in_future,
+    },
+    onboarding::record_onboarding_milestone,
+    shared::current_user_from_headers,
+};
+
+const MAX_IMPORT_EVENTS: usize = 500;
+
+fn local_to_utc(tz: Tz, value: NaiveDateTime) -> Option<DateTime<Utc>> {
+    tz.from_local_datetime(&value)
+        .earliest()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+fn date_to_utc(date: NaiveDate) -> Option<DateTime<Utc>> {
+    local_to_utc(Berlin, date.and_hms_opt(0, 0, 0)?)
+}
+
+fn to_utc(value: DatePerhapsTime) -> Option<DateTime<Utc>> {
+    match value {
+        DatePerhapsTime::DateTime(CalendarDateTime::Utc(dt)) => Some(dt),
+        DatePerhapsTime::DateTime(CalendarDateTime::Floating(naive)) => local_to_utc(Berlin, naive),
+        DatePerhapsTime::DateTime(CalendarDateTime::WithTimezone { date_time, tzid }) => {
+            local_to_utc(tzid.parse::<Tz>().unwrap_or(Berlin), date_time)
+        }
+        DatePerhapsTime::Date(date) => date_to_utc(date),
+    }
+}
+
+fn non_empty(value: Option<&str>) -> Option<String> {
+    value
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(str::to_string)
+}
+
+fn parse_ics_event(event: &icalendar::Event) -> Result<CreateEventRequest, &'static str> {
+    let title = non_empty(event.get_summary()).ok_or("missing SUMMARY")?;
+    if event.property_value("RRULE").is_some() {
+        return Err("recurring events are not supported");
+    }
+
+    let start = event.get_start().ok_or("missing DTSTART")?;
+    let is_all_day = matches!(start, DatePerhapsTime::Date(_));
+    let start_date_time = to_utc(start).ok_or("invalid DTSTART")?;
+    let end_date_time = match event.get_end() {
+        Some(end) => to_utc(end).ok_or("invalid DTEND")?,
+        None if is_all_day => start_date_time + Duration::days(1),
+        None => start_date_time,
+    };
+    if end_date_time < start_date_time {
+        return Err("DTEND is before DTSTART");
+    }
+
+    let description = non_empty(event.get_description());
+
+    Ok(CreateEventRequest {
+        title_de: title.clone(),
+        title_en: title,
+        description_de: description.clone(),
+        description_en: description,
+        start_date_time,
+        end_date_time,
+        event_url: non_empty(event.get_url()),
+        location: non_empty(event.get_location()),
+        publish_app: true,
+        publish_newsletter: true,
+        publish_in_ical: true,
+        publish_web: true,
+        max_participants: None,
+        status: EventStatus::Draft,
+        visibility: EventVisibility::Public,
+        series_id: None,
+        publish_at: None,
+        custom_fields: None,
+        price_cents: None,
+        currency: None,
+        free_entry: false,
+        audience: None,
+        venue_id: None,
+        term: None,
+        all_day: is_all_day,
+    })
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/import/preview",
+    tag = "Events",
+    request_body(content = String, content_type = "text/calendar", description = "iCalendar file contents"),
+    responses(
+        (status = 200, description = "Events parsed from the iCalendar file", body = IcsImportPreviewResponse),
+        (status = 400, description = "Invalid iCalendar file", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, body))]
+pub(crate) async fn preview_ics_import(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    body: String,
+) -> Result<Json<IcsImportPreviewResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if user.organizer_id().is_none() {
+        return Err(AppError::unauthorized("organizer account required"));
+    }
+
+    let calendar = body
+        .parse::<Calendar>()
+        .map_err(|err| AppError::validation(format!("invalid iCalendar file: {err}")))?;
+
+    let mut events = Vec::new();
+    let mut skipped = Vec::new();
+    for event in calendar.components.iter().filter_map(|c| c.as_event()) {
+        match parse_ics_event(event) {
+            Ok(parsed) => events.push(parsed),
+            Err(reason) => skipped.push(IcsImportSkippedEntry {
+                uid: non_empty(event.get_uid()),
+                summary: non_empty(event.get_summary()),
+                reason: reason.to_string(),
+            }),
+        }
+    }
+
+    if events.len() > MAX_IMPORT_EVENTS {
+        return Err(AppError::validation(format!(
+            "an import may contain at most {MAX_IMPORT_EVENTS} events"
+        )));
+    }
+
+    events.sort_by_key(|event| event.start_date_time);
+
+    Ok(Json(IcsImportPreviewResponse { events, skipped }))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/import",
+    tag = "Events",
+    request_body = ImportEventsRequest,
+    responses(
+        (status = 201, description = "Events imported", body = [Event]),
+        (status = 400, description = "Invalid events; nothing was imported", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 429, description = "Event quota exceeded; nothing was imported", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn commit_ics_import(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<ImportEventsRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let Some(organizer_id) = user.organizer_id() else {
+        return Err(AppError::unauthorized("organizer account required"));
+    };
+
+    if payload.events.is_empty() {
+        return Err(AppError::validation("no events to import"));
+    }
+    if payload.events.len() > MAX_IMPORT_EVENTS {
+        return Err(AppError::validation(format!(
+            "an import may contain at most {MAX_IMPORT_EVENTS} events"
+        )));
+    }
+    for (index, event) in payload.events.iter().enumerate() {
+        if event.title_de.trim().is_empty() || event.title_en.trim().is_empty() {
+            return Err(AppError::validation(format!(
+                "event {index} is missing a title"
+            )));
+        }
+        if event.end_date_time < event.start_date_time {
+            return Err(AppError::validation(format!(
+                "event {index}: end date time must not be before start date time"
+            )));
+        }
+        ensure_valid_max_participants(event.max_participants)?;
+    }
+
+    let future_events = payload
+        .events
+        .iter()
+        .filter(|event| starts_in_future(event))
+        .count();
+    ensure_event_quota_for(
+        &state,
+        organizer_id,
+        payload.events.len() as i64,
+        future_events as i64,
+    )
+    .await?;
+
+    let mut transaction = state.db.begin().await?;
+    let mut created = Vec::with_capacity(payload.events.len());
+    for (index, event) in payload.events.into_iter().enumerate() {
+        let event = insert_event_with_user(&state, &mut transaction, &user, event)
+            .await
+            .map_err(|err| match err {
+                AppError::Validation(message) => {
+                    AppError::validation(format!("event {index}: {message}"))
+                }
+                err => err,
+            })?;
+        created.push(event);
+    }
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(&state).await;
+    record_onboarding_milestone(
+        &state,
+        &[organizer_id],
+        OnboardingMilestone::FirstEventCreated,
+    )
+    .await;
+
+    info!(
+        target: "events",
+        action = "import",
+        account_id = user.account_id,
+        count = created.len(),
+        "Events imported from iCalendar file"
+    );
+
+    Ok((StatusCode::CREATED, Json(created)))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/import", post(commit_ics_import))
+        .route("/import/preview", post(preview_ics_import))
+}
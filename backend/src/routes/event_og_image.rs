@@ -0,0 +1,129 @@
+use axum::{
+    Extension, Router,
+    extract::{Path, State},
+    http::header,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use chrono_tz::Europe::Berlin;
+use tracing::{instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::LanguageQuery,
+    error::AppError,
+    i18n::Locale,
+    og_image::{ShareCard, render_share_card},
+    responses::ErrorResponse,
+    timings::{Timed, TimingPhase},
+};
+
+use super::public_events::resolve_title;
+
+const OG_IMAGE_CACHE_CONTROL: &str = "public, max-age=3600";
+
+fn card_date(
+    locale: Locale,
+    start: DateTime<Utc>,
+    all_day: bool,
+    cancelled_at: Option<DateTime<Utc>>,
+) -> String {
+    let start = start.with_timezone(&Berlin);
+    let date = match (locale, all_day) {
+        (Locale::De, true) => start.format("%d.%m.%Y").to_string(),
+        (Locale::De, false) => start.format("%d.%m.%Y, %H:%M Uhr").to_string(),
+        (Locale::En, true) => start.format("%d %b %Y").to_string(),
+        (Locale::En, false) => start.format("%d %b %Y, %H:%M").to_string(),
+    };
+    match (cancelled_at, locale) {
+        (Some(_), Locale::De) => format!("Abgesagt · {date}"),
+        (Some(_), Locale::En) => format!("Cancelled · {date}"),
+        (None, _) => date,
+    }
+}
+
+fn png_response(png: Vec<u8>) -> Response {
+    (
+        [
+            (header::CONTENT_TYPE, "image/png"),
+            (header::CACHE_CONTROL, OG_IMAGE_CACHE_CONTROL),
+        ],
+        png,
+    )
+        .into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/{id}/og-image",
+    tag = "Public",
+    params(("id" = i64, Path, description = "Event identifier"), LanguageQuery),
+    responses(
+        (status = 200, description = "1200×630 PNG share card with title, date and organizer", content_type = "image/png", body = Vec<u8>),
+        (status = 404, description = "Event not found or not published", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_event_og_image(
+    State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
+    Path(id): Path<i64>,
+) -> Result<Response, AppError> {
+    let cache_key = format!("public:events:og:{id}:{}", locale.as_str());
+    if let Some(cache) = &state.cache {
+        match cache.get_bytes(&cache_key).await {
+            Ok(Some(png)) => return Ok(png_response(png)),
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "cache", action = "get", scope = "og_image", %err, "Failed to read share card from cache")
+            }
+        }
+    }
+
+    let event = sqlx::query!(
+        r#"
+        SELECT e.title_de, e.title_en, e.start_date_time, e.all_day, e.cancelled_at,
+               o.name AS organizer_name
+        FROM events e
+        INNER JOIN organizers o ON o.id = e.organizer_id
+        WHERE e.id = $1 AND (e.publish_app OR e.publish_web) AND e.status = 'PUBLISHED'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        "#,
+        id
+    )
+    .fetch_optional(&state.db)
+    .timed(TimingPhase::Database)
+    .await?
+    .ok_or_else(|| AppError::not_found("Event not found or not published"))?;
+
+    let title = resolve_title(locale, &event.title_de, &event.title_en);
+    let date = card_date(
+        locale,
+        event.start_date_time,
+        event.all_day,
+        event.cancelled_at,
+    );
+    let png = tokio::task::spawn_blocking(move || {
+        render_share_card(&ShareCard {
+            title: &title,
+            date: &date,
+            organizer: &event.organizer_name,
+        })
+    })
+    .await
+    .map_err(|err| AppError::internal(format!("share card rendering panicked: {err}")))?
+    .map_err(|err| AppError::internal(format!("failed to render share card: {err}")))?;
+
+    if let Some(cache) = &state.cache
+        && let Err(err) = cache.set_bytes(&cache_key, &png).await
+    {
+        warn!(target: "cache", action = "set", scope = "og_image", %err, "Failed to store share card in cache");
+    }
+
+    Ok(png_response(png))
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new().route("/events/{id}/og-image", get(get_event_og_image))
+}
@@ -0,0 +1,245 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::HeaderMap,
+    routing::get,
+};
+use tracing::{info, instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::UpdateEventQuotaRequest,
+    error::AppError,
+    responses::{ErrorResponse, OrganizerEventQuotaResponse},
+};
+
+use super::shared::current_user_from_headers;
+
+fn effective_limit(override_limit: Option<i32>, default_limit: i32) -> i32 {
+    override_limit.unwrap_or(default_limit)
+}
+
+fn exceeds(limit: i32, current: i64, additional: i64) -> bool {
+    limit > 0 && current + additional > i64::from(limit)
+}
+
+async fn fetch_event_quota(
+    state: &AppState,
+    organizer_id: i64,
+) -> Result<OrganizerEventQuotaResponse, AppError> {
+    let row = sqlx::query!(
+        r#"
+        SELECT
+            o.max_events_per_day,
+            o.max_events_per_week,
+            o.max_future_events,
+            (
+                SELECT COUNT(*) FROM events e
+                WHERE e.organizer_id = o.id AND e.created_at >= NOW() - INTERVAL '1 day'
+            ) AS "created_last_day!",
+            (
+                SELECT COUNT(*) FROM events e
+                WHERE e.organizer_id = o.id AND e.created_at >= NOW() - INTERVAL '7 days'
+            ) AS "created_last_week!",
+            (
+                SELECT COUNT(*) FROM events e
+                WHERE e.organizer_id = o.id AND e.start_date_time > NOW() AND e.cancelled_at IS NULL
+            ) AS "future_events!"
+        FROM organizers o
+        WHERE o.id = $1
+        "#,
+        organizer_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("Organizer not found"))?;
+
+    let defaults = state.event_quota;
+    Ok(OrganizerEventQuotaResponse {
+        organizer_id,
+        max_events_per_day: row.max_events_per_day,
+        max_events_per_week: row.max_events_per_week,
+        max_future_events: row.max_future_events,
+        effective_max_events_per_day: effective_limit(
+            row.max_events_per_day,
+            defaults.max_events_per_day,
+        ),
+        effective_max_events_per_week: effective_limit(
+            row.max_events_per_week,
+            defaults.max_events_per_week,
+        ),
+        effective_max_future_events: effective_limit(
+            row.max_future_events,
+            defaults.max_future_events,
+        ),
+        created_last_day: row.created_last_day,
+        created_last_week: row.created_last_week,
+        future_events: row.future_events,
+    })
+}
+
+pub(crate) async fn ensure_event_quota(
+    state: &AppState,
+    organizer_id: i64,
+    start_in_future: bool,
+) -> Result<(), AppError> {
+    ensure_event_quota_for(state, organizer_id, 1, i64::from(start_in_future)).await
+}
+
+pub(crate) async fn ensure_event_quota_for(
+    state: &AppState,
+    organizer_id: i64,
+    new_events: i64,
+    new_future_events: i64,
+) -> Result<(), AppError> {
+    let quota = fetch_event_quota(state, organizer_id).await?;
+
+    let exceeded = if exceeds(
+        quota.effective_max_events_per_day,
+        quota.created_last_day,
+        new_events,
+    ) {
+        Some(format!(
+            "event quota exceeded: at most {} new events per day",
+            quota.effective_max_events_per_day
+        ))
+    } else if exceeds(
+        quota.effective_max_events_per_week,
+        quota.created_last_week,
+        new_events,
+    ) {
+        Some(format!(
+            "event quota exceeded: at most {} new events per week",
+            quota.effective_max_events_per_week
+        ))
+    } else if new_future_events > 0
+        && exceeds(
+            quota.effective_max_future_events,
+            quota.future_events,
+            new_future_events,
+        )
+    {
+        Some(format!(
+            "event quota exceeded: at most {} upcoming events",
+            quota.effective_max_future_events
+        ))
+    } else {
+        None
+    };
+
+    if let Some(message) = exceeded {
+        warn!(
+            target: "event_quota",
+            action = "reject",
+            organizer_id,
+            created_last_day = quota.created_last_day,
+            created_last_week = quota.created_last_week,
+            future_events = quota.future_events,
+            "Event creation rejected by quota"
+        );
+        return Err(AppError::too_many_requests(message));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/organizers/{id}/event-quota",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier")),
+    responses(
+        (status = 200, description = "Event creation limits and current usage of the organizer", body = OrganizerEventQuotaResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organizer not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_event_quota(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<OrganizerEventQuotaResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() && user.organizer_id() != Some(id) {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    Ok(Json(fetch_event_quota(&state, id).await?))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/organizers/{id}/event-quota",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier")),
+    request_body = UpdateEventQuotaRequest,
+    responses(
+        (status = 200, description = "Per-organizer overrides updated", body = OrganizerEventQuotaResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organizer not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_event_quota(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<UpdateEventQuotaRequest>,
+) -> Result<Json<OrganizerEventQuotaResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let limits = [
+        payload.max_events_per_day,
+        payload.max_events_per_week,
+        payload.max_future_events,
+    ];
+    if limits.into_iter().flatten().any(|limit| limit < 0) {
+        return Err(AppError::validation("limits must not be negative"));
+    }
+
+    let result = sqlx::query!(
+        r#"
+        UPDATE organizers
+        SET max_events_per_day = $2,
+            max_events_per_week = $3,
+            max_future_events = $4,
+            updated_at = NOW()
+        WHERE id = $1
+        "#,
+        id,
+        payload.max_events_per_day,
+        payload.max_events_per_week,
+        payload.max_future_events
+    )
+    .execute(&state.db)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("Organizer not found"));
+    }
+
+    info!(
+        target: "event_quota",
+        action = "override",
+        organizer_id = id,
+        max_events_per_day = ?payload.max_events_per_day,
+        max_events_per_week = ?payload.max_events_per_week,
+        max_future_events = ?payload.max_future_events,
+        updated_by = user.account_id,
+        "Organizer event quota updated"
+    );
+
+    Ok(Json(fetch_event_quota(&state, id).await?))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route(
+        "/{id}/event-quota",
+        get(get_event_quota).put(update_event_quota),
+    )
+}
@@ -0,0 +1,193 @@
+use axum::{
+    Extension, Router,
+    extract::{Path, Query, State},
+    routing::get,
+};
+use chrono::Datelike;
+use chrono_tz::Europe::Berlin;
+use sqlx::{Postgres, Transaction};
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    dto::{ChannelQuery, LanguageQuery},
+    error::AppError,
+    i18n::Locale,
+    models::Event,
+    responses::{ErrorResponse, PublicEventDetailResponse},
+    timings::{Timed, TimedJson, TimingPhase},
+};
+
+use super::public_events::get_public_event;
+
+const MAX_SLUG_TITLE_LEN: usize = 60;
+const FALLBACK_SLUG_TITLE: &str = "event";
+
+fn slugify(title: &str) -> String {
+    let mut slug = String::with_capacity(title.len());
+    for ch in title.chars().flat_map(char::to_lowercase) {
+        let replacement = match ch {
+            'ä' => "ae",
+            'ö' => "oe",
+            'ü' => "ue",
+            'ß' => "ss",
+            _ => "",
+        };
+        if !replacement.is_empty() {
+            slug.push_str(replacement);
+        } else if ch.is_ascii_alphanumeric() {
+            slug.push(ch);
+        } else if !slug.is_empty() && !slug.ends_with('-') {
+            slug.push('-');
+        }
+        if slug.len() >= MAX_SLUG_TITLE_LEN {
+            break;
+        }
+    }
+    slug.truncate(MAX_SLUG_TITLE_LEN);
+    let slug = slug.trim_matches('-');
+    if slug.is_empty() {
+        FALLBACK_SLUG_TITLE.to_string()
+    } else {
+        slug.to_string()
+    }
+}
+
+fn base_slug(event: &Event) -> String {
+    format!(
+        "{}-{}",
+        slugify(&event.title_de),
+        event.start_date_time.with_timezone(&Berlin).year()
+    )
+}
+
+fn slug_matches_base(slug: &str, base: &str) -> bool {
+    slug == base
+        || slug
+            .strip_prefix(base)
+            .and_then(|rest| rest.strip_prefix('-'))
+            .is_some_and(|suffix| {
+                !suffix.is_empty() && suffix.chars().all(|ch| ch.is_ascii_digit())
+            })
+}
+
+async fn slug_taken(
+    transaction: &mut Transaction<'_, Postgres>,
+    slug: &str,
+    event_id: i64,
+) -> Result<bool, AppError> {
+    let taken = sqlx::query_scalar!(
+        r#"
+        SELECT (
+            EXISTS (SELECT 1 FROM events WHERE slug = $1 AND id <> $2)
+            OR EXISTS (SELECT 1 FROM event_slug_history WHERE slug = $1 AND event_id <> $2)
+        ) AS "taken!"
+        "#,
+        slug,
+        event_id
+    )
+    .fetch_one(&mut **transaction)
+    .await?;
+    Ok(taken)
+}
+
+pub(crate) async fn assign_event_slug(
+    transaction: &mut Transaction<'_, Postgres>,
+    event: &Event,
+) -> Result<String, AppError> {
+    let current = sqlx::query_scalar!("SELECT slug FROM events WHERE id = $1", event.id)
+        .fetch_one(&mut **transaction)
+        .await?;
+    let base = base_slug(event);
+    if let Some(current) = current.as_deref()
+        && slug_matches_base(current, &base)
+    {
+        return Ok(current.to_string());
+    }
+
+    let mut slug = base.clone();
+    let mut suffix = 2;
+    while slug_taken(transaction, &slug, event.id).await? {
+        slug = format!("{base}-{suffix}");
+        suffix += 1;
+    }
+
+    sqlx::query!(
+        "DELETE FROM event_slug_history WHERE slug = $1 AND event_id = $2",
+        slug,
+        event.id
+    )
+    .execute(&mut **transaction)
+    .await?;
+    if let Some(previous) = current {
+        sqlx::query!(
+            "INSERT INTO event_slug_history (slug, event_id) VALUES ($1, $2) ON CONFLICT (slug) DO NOTHING",
+            previous,
+            event.id
+        )
+        .execute(&mut **transaction)
+        .await?;
+    }
+    sqlx::query!("UPDATE events SET slug = $2 WHERE id = $1", event.id, slug)
+        .execute(&mut **transaction)
+        .await?;
+
+    Ok(slug)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/by-slug/{slug}",
+    tag = "Public",
+    params(("slug" = String, Path, description = "Current or previous event slug"), ChannelQuery, LanguageQuery),
+    responses(
+        (status = 200, description = "Public event details; the event's current slug may differ from the requested one", body = PublicEventDetailResponse),
+        (status = 404, description = "Event not found or not published", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_public_event_by_slug(
+    State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
+    Path(slug): Path<String>,
+    channel: Query<ChannelQuery>,
+) -> Result<TimedJson<PublicEventDetailResponse>, AppError> {
+    let slug = slug.trim().to_lowercase();
+    let event_id = sqlx::query_scalar!(
+        r#"
+        SELECT id AS "id!" FROM events WHERE slug = $1
+        UNION ALL
+        SELECT event_id AS "id!" FROM event_slug_history WHERE slug = $1
+        LIMIT 1
+        "#,
+        slug
+    )
+    .fetch_optional(&state.db)
+    .timed(TimingPhase::Database)
+    .await?
+    .ok_or_else(|| AppError::not_found("Event not found or not published"))?;
+
+    get_public_event(State(state), Extension(locale), Path(event_id), channel).await
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new().route("/events/by-slug/{slug}", get(get_public_event_by_slug))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slugifies_titles_and_recognizes_suffixed_slugs() {
+        assert_eq!(
+            slugify("Sommerfest: Grüße & Bier!"),
+            "sommerfest-gruesse-bier"
+        );
+        assert_eq!(slugify("  ??  "), FALLBACK_SLUG_TITLE);
+        assert!(slug_matches_base("summer-fest-2025", "summer-fest-2025"));
+        assert!(slug_matches_base("summer-fest-2025-3", "summer-fest-2025"));
+        assert!(!slug_matches_base("summer-fest-2025-x", "summer-fest-2025"));
+        assert!(!slug_matches_base("summer-fest-2026", "summer-fest-2025"));
+    }
+}
@@ -0,0 +1,68 @@
+use std::convert::Infallible;
+
+use axum::{
+    Router,
+    extract::State,
+    response::sse::{Event as SseEvent, KeepAlive, Sse},
+    routing::get,
+};
+use chrono::Utc;
+use futures_util::{Stream, stream};
+use tokio::sync::broadcast::{self, error::RecvError};
+use tracing::{instrument, warn};
+
+use crate::{app_state::AppState, responses::PublicEventChangeMessage};
+
+const CHANGE_EVENT_NAME: &str = "events.changed";
+const CHANGE_BUFFER: usize = 64;
+
+pub(crate) fn public_change_channel() -> broadcast::Sender<PublicEventChangeMessage> {
+    broadcast::channel(CHANGE_BUFFER).0
+}
+
+fn change_event(change: &PublicEventChangeMessage) -> SseEvent {
+    SseEvent::default()
+        .event(CHANGE_EVENT_NAME)
+        .json_data(change)
+        .unwrap_or_else(|err| {
+            warn!(target: "event_stream", action = "serialize", %err, "Failed to serialize change message");
+            SseEvent::default().event(CHANGE_EVENT_NAME)
+        })
+}
+
+pub(crate) fn notify_public_event_change(state: &AppState) {
+    let _ = state.public_changes.send(PublicEventChangeMessage {
+        changed_at: Utc::now(),
+    });
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/stream",
+    tag = "Public",
+    responses(
+        (status = 200, description = "Server-Sent Events stream; emits an `events.changed` message whenever public event data changes", content_type = "text/event-stream", body = PublicEventChangeMessage),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn stream_public_event_changes(
+    State(state): State<AppState>,
+) -> Sse<impl Stream<Item = Result<SseEvent, Infallible>>> {
+    let receiver = state.public_changes.subscribe();
+    let changes = stream::unfold(receiver, |mut receiver| async move {
+        let change = match receiver.recv().await {
+            Ok(change) => change,
+            Err(RecvError::Lagged(_)) => PublicEventChangeMessage {
+                changed_at: Utc::now(),
+            },
+            Err(RecvError::Closed) => return None,
+        };
+        Some((Ok(change_event(&change)), receiver))
+    });
+
+    Sse::new(changes).keep_alive(KeepAlive::default())
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new().route("/events/stream", get(stream_public_event_changes))
+}
@@ -0,0 +1,365 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{get, post, put},
+};
+use chrono::{DateTime, Duration, Utc};
+use chrono_tz::Europe::Berlin;
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::{
+        CreateEventRequest, EventTemplateRequest, InstantiateEventTemplateRequest,
+        OrganizerFilterQuery,
+    },
+    error::AppError,
+    models::{Event, EventTemplate, EventVisibility},
+    responses::ErrorResponse,
+};
+
+use super::{
+    events::{create_event_with_user, ensure_valid_max_participants},
+    shared::{AuthedUser, current_user_from_headers},
+};
+
+const MAX_TEMPLATE_NAME_LEN: usize = 100;
+const MAX_TEMPLATE_TITLE_LEN: usize = 200;
+const DATE_PLACEHOLDER: &str = "{date}";
+
+fn required_text(value: &str, field: &str, max_len: usize) -> Result<String, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(AppError::validation(format!("{field} is required")));
+    }
+    if value.chars().count() > max_len {
+        return Err(AppError::validation(format!(
+            "{field} must be at most {max_len} characters"
+        )));
+    }
+    Ok(value.to_string())
+}
+
+fn validate_template(mut payload: EventTemplateRequest) -> Result<EventTemplateRequest, AppError> {
+    payload.name = required_text(&payload.name, "name", MAX_TEMPLATE_NAME_LEN)?;
+    payload.title_de = required_text(&payload.title_de, "title_de", MAX_TEMPLATE_TITLE_LEN)?;
+    payload.title_en = required_text(&payload.title_en, "title_en", MAX_TEMPLATE_TITLE_LEN)?;
+    if payload.duration_minutes.is_some_and(|minutes| minutes < 1) {
+        return Err(AppError::validation("duration_minutes must be at least 1"));
+    }
+    ensure_valid_max_participants(payload.max_participants)?;
+    Ok(payload)
+}
+
+fn render_title(pattern: &str, start: DateTime<Utc>) -> String {
+    let date = start.with_timezone(&Berlin).format("%d.%m.%Y").to_string();
+    pattern.replace(DATE_PLACEHOLDER, &date)
+}
+
+async fn fetch_template(state: &AppState, id: i64) -> Result<EventTemplate, AppError> {
+    sqlx::query_as!(
+        EventTemplate,
+        r#"
+        SELECT id, organizer_id, name, title_de, title_en, description_de, description_en, event_url, location, duration_minutes, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, visibility as "visibility: EventVisibility", created_at, updated_at
+        FROM event_templates
+        WHERE id = $1
+        "#,
+        id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("template not found"))
+}
+
+fn ensure_can_manage_template(user: &AuthedUser, template: &EventTemplate) -> Result<(), AppError> {
+    if !user.is_admin() && user.organizer_id() != Some(template.organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot manage another organizer's templates",
+        ));
+    }
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/event-templates",
+    tag = "Events",
+    params(OrganizerFilterQuery),
+    responses(
+        (status = 200, description = "Event templates of the organizer", body = [EventTemplate]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_event_templates(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<OrganizerFilterQuery>,
+) -> Result<Json<Vec<EventTemplate>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let organizer_id = if user.is_admin() {
+        query.organizer_id
+    } else {
+        Some(
+            user.organizer_id()
+                .ok_or_else(|| AppError::unauthorized("organizer account required"))?,
+        )
+    };
+
+    let templates = sqlx::query_as!(
+        EventTemplate,
+        r#"
+        SELECT id, organizer_id, name, title_de, title_en, description_de, description_en, event_url, location, duration_minutes, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, visibility as "visibility: EventVisibility", created_at, updated_at
+        FROM event_templates
+        WHERE ($1::bigint IS NULL OR organizer_id = $1)
+        ORDER BY name ASC, id ASC
+        "#,
+        organizer_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(templates))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/event-templates",
+    tag = "Events",
+    request_body = EventTemplateRequest,
+    responses(
+        (status = 201, description = "Event template created", body = EventTemplate),
+        (status = 400, description = "Invalid template", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_event_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<EventTemplateRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = user
+        .organizer_id()
+        .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+    let payload = validate_template(payload)?;
+
+    let template = sqlx::query_as!(
+        EventTemplate,
+        r#"
+        INSERT INTO event_templates (organizer_id, name, title_de, title_en, description_de, description_en, event_url, location, duration_minutes, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, visibility)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15)
+        RETURNING id, organizer_id, name, title_de, title_en, description_de, description_en, event_url, location, duration_minutes, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, visibility as "visibility: EventVisibility", created_at, updated_at
+        "#,
+        organizer_id,
+        payload.name,
+        payload.title_de,
+        payload.title_en,
+        payload.description_de,
+        payload.description_en,
+        payload.event_url,
+        payload.location,
+        payload.duration_minutes,
+        payload.publish_app,
+        payload.publish_newsletter,
+        payload.publish_in_ical,
+        payload.publish_web,
+        payload.max_participants,
+        payload.visibility as EventVisibility
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(
+        target: "event_templates",
+        action = "create",
+        organizer_id,
+        template_id = template.id,
+        "Event template created"
+    );
+
+    Ok((StatusCode::CREATED, Json(template)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/event-templates/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Template identifier")),
+    request_body = EventTemplateRequest,
+    responses(
+        (status = 200, description = "Event template updated", body = EventTemplate),
+        (status = 400, description = "Invalid template", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Template not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_event_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<EventTemplateRequest>,
+) -> Result<Json<EventTemplate>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let existing = fetch_template(&state, id).await?;
+    ensure_can_manage_template(&user, &existing)?;
+    let payload = validate_template(payload)?;
+
+    let template = sqlx::query_as!(
+        EventTemplate,
+        r#"
+        UPDATE event_templates
+        SET name = $2, title_de = $3, title_en = $4, description_de = $5, description_en = $6, event_url = $7, location = $8, duration_minutes = $9, publish_app = $10, publish_newsletter = $11, publish_in_ical = $12, publish_web = $13, max_participants = $14, visibility = $15, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, organizer_id, name, title_de, title_en, description_de, description_en, event_url, location, duration_minutes, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, visibility as "visibility: EventVisibility", created_at, updated_at
+        "#,
+        id,
+        payload.name,
+        payload.title_de,
+        payload.title_en,
+        payload.description_de,
+        payload.description_en,
+        payload.event_url,
+        payload.location,
+        payload.duration_minutes,
+        payload.publish_app,
+        payload.publish_newsletter,
+        payload.publish_in_ical,
+        payload.publish_web,
+        payload.max_participants,
+        payload.visibility as EventVisibility
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(Json(template))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/event-templates/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Template identifier")),
+    responses(
+        (status = 204, description = "Template removed"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Template not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_event_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let existing = fetch_template(&state, id).await?;
+    ensure_can_manage_template(&user, &existing)?;
+
+    sqlx::query!("DELETE FROM event_templates WHERE id = $1", id)
+        .execute(&state.db)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/event-templates/{id}/events",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Template identifier")),
+    request_body = InstantiateEventTemplateRequest,
+    responses(
+        (status = 201, description = "Event created from template", body = Event),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Template not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn instantiate_event_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<InstantiateEventTemplateRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let template = fetch_template(&state, id).await?;
+    if user.organizer_id() != Some(template.organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot manage another organizer's templates",
+        ));
+    }
+
+    let start_date_time = payload.start_date_time;
+    let end_date_time = match (payload.end_date_time, template.duration_minutes) {
+        (Some(end), _) => end,
+        (None, Some(minutes)) => start_date_time + Duration::minutes(i64::from(minutes)),
+        (None, None) => {
+            return Err(AppError::validation(
+                "end_date_time is required for templates without a duration",
+            ));
+        }
+    };
+
+    let title_de = payload.title_de.unwrap_or(template.title_de);
+    let title_en = payload.title_en.unwrap_or(template.title_en);
+
+    let event = create_event_with_user(
+        &state,
+        &user,
+        CreateEventRequest {
+            title_de: render_title(&title_de, start_date_time),
+            title_en: render_title(&title_en, start_date_time),
+            description_de: template.description_de,
+            description_en: template.description_en,
+            start_date_time,
+            end_date_time,
+            event_url: template.event_url,
+            location: template.location,
+            publish_app: template.publish_app,
+            publish_newsletter: template.publish_newsletter,
+            publish_in_ical: template.publish_in_ical,
+            publish_web: template.publish_web,
+            max_participants: template.max_participants,
+            status: payload.status,
+            visibility: template.visibility,
+            series_id: payload.series_id,
+            publish_at: payload.publish_at,
+            custom_fields: None,
+            price_cents: None,
+            currency: None,
+            free_entry: false,
+            audience: None,
+            venue_id: None,
+            term: None,
+            all_day: false,
+        },
+    )
+    .await?;
+
+    info!(
+        target: "event_templates",
+        action = "instantiate",
+        template_id = id,
+        event_id = event.id,
+        "Event created from template"
+    );
+
+    Ok((StatusCode::CREATED, Json(event)))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_event_templates).post(create_event_template))
+        .route(
+            "/{id}",
+            put(update_event_template).delete(delete_event_template),
+        )
+        .route("/{id}/events", post(instantiate_event_template))
+}
@@ -0,0 +1,246 @@
+use std::time::Duration;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    routing::get,
+};
+use chrono::{NaiveDate, Utc};
+use chrono_tz::Europe::Berlin;
+use tokio::time::{MissedTickBehavior, interval};
+use tracing::{info, instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::{EventViewStatsQuery, TermStatsQuery},
+    error::AppError,
+    responses::{ErrorResponse, EventViewDay, EventViewStatsResponse, TermStats},
+};
+
+use super::shared::current_user_from_headers;
+
+const EVENT_VIEWS_COUNTER: &str = "event_views";
+const DEFAULT_STATS_DAYS: i32 = 30;
+const MAX_STATS_DAYS: i32 = 365;
+
+fn counter_field(event_id: i64, date: NaiveDate) -> String {
+    format!("{event_id}:{date}")
+}
+
+fn parse_counter_field(field: &str) -> Option<(i64, NaiveDate)> {
+    let (event_id, date) = field.split_once(':')?;
+    Some((event_id.parse().ok()?, date.parse().ok()?))
+}
+
+async fn store_view_counts(
+    state: &AppState,
+    counts: &[(i64, NaiveDate, i64)],
+) -> Result<(), AppError> {
+    if counts.is_empty() {
+        return Ok(());
+    }
+    let event_ids: Vec<i64> = counts.iter().map(|(event_id, _, _)| *event_id).collect();
+    let dates: Vec<NaiveDate> = counts.iter().map(|(_, date, _)| *date).collect();
+    let views: Vec<i64> = counts.iter().map(|(_, _, views)| *views).collect();
+
+    sqlx::query!(
+        r#"
+        INSERT INTO event_view_counts (event_id, view_date, views)
+        SELECT c.event_id, c.view_date, SUM(c.views)
+        FROM UNNEST($1::BIGINT[], $2::DATE[], $3::BIGINT[]) AS c(event_id, view_date, views)
+        WHERE EXISTS (SELECT 1 FROM events e WHERE e.id = c.event_id)
+        GROUP BY c.event_id, c.view_date
+        ON CONFLICT (event_id, view_date) DO UPDATE
+        SET views = event_view_counts.views + EXCLUDED.views
+        "#,
+        &event_ids,
+        &dates,
+        &views
+    )
+    .execute(&state.db)
+    .await?;
+    Ok(())
+}
+
+pub(crate) async fn record_event_view(state: &AppState, event_id: i64) {
+    let today = Utc::now().with_timezone(&Berlin).date_naive();
+
+    if let Some(cache) = &state.cache {
+        if let Err(err) = cache
+            .increment_counter(EVENT_VIEWS_COUNTER, &counter_field(event_id, today))
+            .await
+        {
+            warn!(target: "event_views", action = "count", event_id, %err, "Failed to count event view");
+        }
+        return;
+    }
+
+    if let Err(err) = store_view_counts(state, &[(event_id, today, 1)]).await {
+        warn!(target: "event_views", action = "count", event_id, %err, "Failed to count event view");
+    }
+}
+
+async fn flush_event_views(state: &AppState) -> Result<usize, AppError> {
+    let Some(cache) = &state.cache else {
+        return Ok(0);
+    };
+    let counters = cache
+        .drain_counters(EVENT_VIEWS_COUNTER)
+        .await
+        .map_err(|err| AppError::internal(err.to_string()))?;
+    let counts: Vec<(i64, NaiveDate, i64)> = counters
+        .iter()
+        .filter_map(|(field, views)| {
+            parse_counter_field(field).map(|(event_id, date)| (event_id, date, *views))
+        })
+        .filter(|(_, _, views)| *views > 0)
+        .collect();
+    store_view_counts(state, &counts).await?;
+    Ok(counts.len())
+}
+
+pub(crate) fn spawn_event_view_flush(state: AppState, every: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            match flush_event_views(&state).await {
+                Ok(0) => {}
+                Ok(flushed) => {
+                    info!(target: "event_views", action = "flush", flushed, "Flushed event view counters")
+                }
+                Err(err) => {
+                    warn!(target: "event_views", action = "flush", %err, "Failed to flush event view counters")
+                }
+            }
+        }
+    });
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/stats",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier"), EventViewStatsQuery),
+    responses(
+        (status = 200, description = "Daily public detail views of the event", body = EventViewStatsResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_event_view_stats(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<EventViewStatsQuery>,
+) -> Result<Json<EventViewStatsResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = sqlx::query_scalar!("SELECT organizer_id FROM events WHERE id = $1", id)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::not_found("event not found"))?;
+    if !user.is_admin() && user.organizer_id() != Some(organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot view another organizer's event statistics",
+        ));
+    }
+
+    let days_back = query
+        .days
+        .unwrap_or(DEFAULT_STATS_DAYS)
+        .clamp(1, MAX_STATS_DAYS);
+    let today = Utc::now().with_timezone(&Berlin).date_naive();
+
+    let days = sqlx::query_as!(
+        EventViewDay,
+        r#"
+        SELECT day::DATE AS "date!", COALESCE(c.views, 0) AS "views!"
+        FROM generate_series($2::DATE - ($3::INT - 1), $2::DATE, INTERVAL '1 day') AS day
+        LEFT JOIN event_view_counts c ON c.event_id = $1 AND c.view_date = day::DATE
+        ORDER BY day ASC
+        "#,
+        id,
+        today,
+        days_back
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let total_views = sqlx::query_scalar!(
+        r#"SELECT COALESCE(SUM(views), 0)::BIGINT AS "total!" FROM event_view_counts WHERE event_id = $1"#,
+        id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(Json(EventViewStatsResponse {
+        event_id: id,
+        total_views,
+        days,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/stats/terms",
+    tag = "Events",
+    params(TermStatsQuery),
+    responses(
+        (status = 200, description = "Event and view counts per semester, newest term first", body = [TermStats]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_term_stats(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<TermStatsQuery>,
+) -> Result<Json<Vec<TermStats>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = if user.is_admin() {
+        query.organizer_id
+    } else {
+        let organizer_id = user
+            .organizer_id()
+            .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+        if query
+            .organizer_id
+            .is_some_and(|requested| requested != organizer_id)
+        {
+            return Err(AppError::unauthorized("insufficient permissions"));
+        }
+        Some(organizer_id)
+    };
+
+    let stats = sqlx::query_as!(
+        TermStats,
+        r#"
+        SELECT e.term,
+               COUNT(*) AS "events!",
+               COUNT(*) FILTER (WHERE e.status = 'PUBLISHED' AND e.cancelled_at IS NULL) AS "published_events!",
+               COUNT(*) FILTER (WHERE e.cancelled_at IS NOT NULL) AS "cancelled_events!",
+               COALESCE(SUM(v.views), 0)::BIGINT AS "total_views!"
+        FROM events e
+        LEFT JOIN (
+            SELECT event_id, SUM(views) AS views FROM event_view_counts GROUP BY event_id
+        ) v ON v.event_id = e.id
+        WHERE ($1::bigint IS NULL OR e.organizer_id = $1)
+        GROUP BY e.term
+        ORDER BY MIN(e.start_date_time) DESC
+        "#,
+        organizer_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(stats))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/stats/terms", get(get_term_stats))
+        .route("/{id}/stats", get(get_event_view_stats))
+}
@@ -1,36 +1,129 @@
 use axum::{
     Json, Router,
-    extract::{Path, Query, State},
+    extract::{OriginalUri, Path, Query, State},
     http::{HeaderMap, StatusCode},
-    response::IntoResponse,
+    response::{IntoResponse, Response},
     routing::{get, post},
 };
-use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, NaiveTime, TimeZone, Utc};
 use chrono_tz::Europe::Berlin;
-use serde_json::Value;
+use futures_util::TryStreamExt;
+use serde_json::{Map, Value};
 use sqlx::{Postgres, QueryBuilder, Transaction};
 use tracing::{instrument, warn};
 
 use crate::{
     app_state::AppState,
     dto::{
-        CreateEventRequest, ListEventsQuery, NewsletterDataQuery, SendNewsletterPreviewRequest,
-        UpdateEventRequest,
+        CreateEventRequest, ExportEventsQuery, ListEventsQuery, NewsletterDataQuery,
+        SendNewsletterPreviewRequest, UpdateEventRequest,
     },
     error::AppError,
-    models::{AccountType, AuditType, Event, EventWithOrganizer, Organizer, OrganizerKind},
-    responses::{ErrorResponse, NewsletterDataResponse},
+    export::stream_export,
+    models::{
+        AccountType, AuditType, Event, EventAudience, EventStatus, EventVisibility,
+        EventWithOrganizer, NewsletterLanguage, OnboardingMilestone, Organizer, OrganizerKind,
+    },
+    newsletter_delivery::NewsletterDeliverySettings,
+    pagination::pagination_headers,
+    responses::{
+        ErrorResponse, EventWithConflictsResponse, NewsletterDataResponse, NewsletterEventResponse,
+        NewsletterPreviewResponse, NewsletterVariantPreview,
+    },
+    term::{normalize_term, term_for},
+    webhooks::{enqueue_event_webhooks, webhook_event_type},
 };
 
-use super::shared::{
-    AuthedUser, SessionOrganizerKindScope, current_user_from_headers,
-    refresh_organizer_activity_stats, session_organizer_kind_scope,
+use super::{
+    academic_periods::academic_periods_overlapping,
+    conflicts::{capacity_warning_for_event, conflicts_for_event, possible_duplicates_for_event},
+    custom_fields::validate_custom_fields,
+    event_quota::ensure_event_quota,
+    event_slugs::assign_event_slug,
+    event_stream::notify_public_event_change,
+    newsletter::{
+        active_newsletter_sponsors, arrange_newsletter_events, newsletter_issue_layout,
+        newsletter_issue_texts, parse_newsletter_week_start, render_newsletter_variants,
+    },
+    onboarding::record_onboarding_milestone,
+    price_tiers::fetch_price_tiers,
+    shared::{
+        AuthedUser, EventsRead, EventsWrite, ScopedUser, SessionOrganizerKindScope,
+        append_event_footer, current_user_from_headers, refresh_organizer_activity_stats,
+        session_organizer_kind_scope,
+    },
+    subscriptions::notify_event_subscribers,
+    venues::venue_location_label,
 };
 
+const MAX_SEARCH_QUERY_LEN: usize = 200;
+pub(crate) const DEFAULT_CURRENCY: &str = "EUR";
+const EVENT_CSV_HEADER: [&str; 24] = [
+    "id",
+    "organizer_id",
+    "organizer_name",
+    "title_de",
+    "title_en",
+    "start_date_time",
+    "end_date_time",
+    "term",
+    "location",
+    "event_url",
+    "status",
+    "visibility",
+    "publish_app",
+    "publish_newsletter",
+    "publish_in_ical",
+    "publish_web",
+    "max_participants",
+    "price_cents",
+    "currency",
+    "free_entry",
+    "cancelled_at",
+    "publish_at",
+    "created_at",
+    "updated_at",
+];
+
 pub(crate) async fn create_event_with_user(
     state: &AppState,
     user: &AuthedUser,
     payload: CreateEventRequest,
+) -> Result<Event, AppError> {
+    let organizer_id = user
+        .organizer_id()
+        .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+    ensure_event_quota(state, organizer_id, starts_in_future(&payload)).await?;
+
+    let mut transaction = state.db.begin().await?;
+    let event = insert_event_with_user(state, &mut transaction, user, payload).await?;
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(state).await;
+    record_onboarding_milestone(
+        state,
+        &[organizer_id],
+        OnboardingMilestone::FirstEventCreated,
+    )
+    .await;
+
+    Ok(event)
+}
+
+pub(crate) fn starts_in_future(payload: &CreateEventRequest) -> bool {
+    let start_date_time = if payload.all_day {
+        all_day_range(payload.start_date_time, payload.end_date_time).0
+    } else {
+        payload.start_date_time
+    };
+    start_date_time > Utc::now()
+}
+
+pub(crate) async fn insert_event_with_user(
+    state: &AppState,
+    transaction: &mut Transaction<'_, Postgres>,
+    user: &AuthedUser,
+    payload: CreateEventRequest,
 ) -> Result<Event, AppError> {
     let organizer_id = user
         .organizer_id()
@@ -48,6 +141,19 @@ pub(crate) async fn create_event_with_user(
         publish_newsletter,
         publish_in_ical,
         publish_web,
+        max_participants,
+        status,
+        visibility,
+        series_id,
+        publish_at,
+        custom_fields,
+        price_cents,
+        currency,
+        free_entry,
+        audience,
+        venue_id,
+        term,
+        all_day,
     } = payload;
 
     if end_date_time < start_date_time {
@@ -55,15 +161,34 @@ pub(crate) async fn create_event_with_user(
             "end date time must not be before start date time",
         ));
     }
+    let (start_date_time, end_date_time) = if all_day {
+        all_day_range(start_date_time, end_date_time)
+    } else {
+        (start_date_time, end_date_time)
+    };
 
-    let mut transaction = state.db.begin().await?;
+    ensure_valid_max_participants(max_participants)?;
+    let currency = normalize_currency(currency.as_deref().unwrap_or(DEFAULT_CURRENCY))?;
+    ensure_valid_admission(price_cents, free_entry)?;
+    let audience = normalize_audience(audience.unwrap_or_else(|| EventAudience::ALL.to_vec()))?;
+    ensure_series_belongs_to_organizer(state, series_id, organizer_id).await?;
+    let location = location.or(venue_location_label(state, venue_id).await?);
+    let term = match term {
+        Some(term) => normalize_term(&term)?,
+        None => term_for(start_date_time),
+    };
+    let status = resolve_event_status(state, user, organizer_id, status).await?;
+    let custom_fields = match custom_fields {
+        Some(custom_fields) => validate_custom_fields(state, custom_fields).await?,
+        None => Value::Object(Map::new()),
+    };
 
     let event = sqlx::query_as!(
         Event,
         r#"
-        INSERT INTO events (organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web)
-        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
-        RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, created_at, updated_at
+        INSERT INTO events (organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status, visibility, series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience, venue_id, term, all_day)
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26)
+        RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
         "#,
         organizer_id,
         title_de,
@@ -77,13 +202,27 @@ pub(crate) async fn create_event_with_user(
         publish_app,
         publish_newsletter,
         publish_in_ical,
-        publish_web
+        publish_web,
+        max_participants,
+        status as EventStatus,
+        visibility as EventVisibility,
+        series_id,
+        publish_at,
+        custom_fields,
+        price_cents,
+        currency,
+        free_entry,
+        &audience as &[EventAudience],
+        venue_id,
+        term,
+        all_day
     )
-    .fetch_one(&mut *transaction)
+    .fetch_one(&mut **transaction)
     .await?;
+    assign_event_slug(transaction, &event).await?;
 
     record_audit(
-        &mut transaction,
+        transaction,
         event.id,
         event.organizer_id,
         user.account_id,
@@ -93,10 +232,6 @@ pub(crate) async fn create_event_with_user(
     )
     .await?;
 
-    transaction.commit().await?;
-
-    invalidate_public_event_caches(state).await;
-
     Ok(event)
 }
 
@@ -108,7 +243,7 @@ pub(crate) async fn get_event_with_user(
     let event = sqlx::query_as!(
         Event,
         r#"
-        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, created_at, updated_at
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
         FROM events
         WHERE id = $1
         "#,
@@ -125,7 +260,10 @@ pub(crate) async fn get_event_with_user(
         return Ok(event);
     }
 
-    if matches!(user.account_type, AccountType::Organizer) && event.publish_app {
+    if matches!(user.account_type, AccountType::Organizer)
+        && event.publish_app
+        && event.status == EventStatus::Published
+    {
         let Some(uid) = user.organizer_id() else {
             return Err(AppError::not_found("event not found"));
         };
@@ -183,6 +321,19 @@ pub(crate) async fn update_event_with_user(
         publish_newsletter,
         publish_in_ical,
         publish_web,
+        max_participants,
+        status,
+        visibility,
+        series_id,
+        publish_at,
+        custom_fields,
+        price_cents,
+        currency,
+        free_entry,
+        audience,
+        venue_id,
+        term,
+        all_day,
     } = payload;
 
     if !has_updates {
@@ -194,9 +345,10 @@ pub(crate) async fn update_event_with_user(
     let existing_event = sqlx::query_as!(
         Event,
         r#"
-        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, created_at, updated_at
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
         FROM events
         WHERE id = $1
+        FOR UPDATE
         "#,
         id
     )
@@ -219,8 +371,61 @@ pub(crate) async fn update_event_with_user(
             "end date time must not be before start date time",
         ));
     }
+    let (start_date_time, end_date_time, effective_start) = if all_day
+        .unwrap_or(existing_event.all_day)
+        && (all_day == Some(true) || start_date_time.is_some() || end_date_time.is_some())
+    {
+        let (start, end) = all_day_range(effective_start, effective_end);
+        (Some(start), Some(end), start)
+    } else {
+        (start_date_time, end_date_time, effective_start)
+    };
 
-    let mut builder = QueryBuilder::<Postgres>::new("UPDATE events SET updated_at = NOW()");
+    ensure_valid_max_participants(max_participants.flatten())?;
+    if let Some(Some(max_participants)) = max_participants {
+        let registration_count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM registrations WHERE event_id = $1"#,
+            id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+        ensure_max_participants_covers_registrations(max_participants, registration_count)?;
+    }
+    let currency = currency.as_deref().map(normalize_currency).transpose()?;
+    let audience = audience.map(normalize_audience).transpose()?;
+    ensure_valid_admission(
+        price_cents.or(existing_event.price_cents),
+        free_entry.unwrap_or(existing_event.free_entry),
+    )?;
+    ensure_series_belongs_to_organizer(state, series_id, existing_event.organizer_id).await?;
+    let venue_location = venue_location_label(state, venue_id).await?;
+    let location = location.or(venue_location);
+    let term = match term {
+        Some(term) => Some(normalize_term(&term)?),
+        None if start_date_time.is_some()
+            && existing_event.term == term_for(existing_event.start_date_time) =>
+        {
+            Some(term_for(effective_start))
+        }
+        None => None,
+    };
+    let custom_fields = match custom_fields {
+        Some(custom_fields) => Some(validate_custom_fields(state, custom_fields).await?),
+        None => None,
+    };
+    let status = match status {
+        Some(EventStatus::Published) if existing_event.status == EventStatus::Published => {
+            Some(EventStatus::Published)
+        }
+        Some(requested) => {
+            Some(resolve_event_status(state, user, existing_event.organizer_id, requested).await?)
+        }
+        None => None,
+    };
+
+    let mut builder = QueryBuilder::<Postgres>::new(
+        "UPDATE events SET updated_at = NOW(), revision = revision + 1",
+    );
     if let Some(title_de) = title_de {
         builder.push(", title_de = ").push_bind(title_de);
     }
@@ -267,14 +472,60 @@ pub(crate) async fn update_event_with_user(
     if let Some(publish_web) = publish_web {
         builder.push(", publish_web = ").push_bind(publish_web);
     }
+    if let Some(max_participants) = max_participants {
+        builder
+            .push(", max_participants = ")
+            .push_bind(max_participants);
+    }
+    if let Some(status) = status {
+        builder.push(", status = ").push_bind(status);
+    }
+    if let Some(visibility) = visibility {
+        builder.push(", visibility = ").push_bind(visibility);
+    }
+    if let Some(series_id) = series_id {
+        builder.push(", series_id = ").push_bind(series_id);
+    }
+    if let Some(publish_at) = publish_at {
+        builder.push(", publish_at = ").push_bind(publish_at);
+    }
+    if let Some(custom_fields) = custom_fields {
+        builder.push(", custom_fields = ").push_bind(custom_fields);
+    }
+    if let Some(price_cents) = price_cents {
+        builder.push(", price_cents = ").push_bind(price_cents);
+    }
+    if let Some(currency) = currency {
+        builder.push(", currency = ").push_bind(currency);
+    }
+    if let Some(free_entry) = free_entry {
+        builder.push(", free_entry = ").push_bind(free_entry);
+    }
+    if let Some(audience) = audience {
+        builder.push(", audience = ").push_bind(audience);
+    }
+    if let Some(venue_id) = venue_id {
+        builder.push(", venue_id = ").push_bind(venue_id);
+    }
+    if let Some(term) = term {
+        builder.push(", term = ").push_bind(term);
+    }
+    if let Some(all_day) = all_day {
+        builder.push(", all_day = ").push_bind(all_day);
+    }
 
     builder.push(" WHERE id = ").push_bind(id);
-    builder.push(" RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, created_at, updated_at");
+    builder.push(" RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status, cancelled_at, visibility, series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience, venue_id, featured_until, term, all_day, created_at, updated_at");
 
     let updated_event = builder
         .build_query_as::<Event>()
         .fetch_one(&mut *transaction)
         .await?;
+    if updated_event.title_de != existing_event.title_de
+        || updated_event.start_date_time != existing_event.start_date_time
+    {
+        assign_event_slug(&mut transaction, &updated_event).await?;
+    }
 
     record_audit(
         &mut transaction,
@@ -290,6 +541,7 @@ pub(crate) async fn update_event_with_user(
     transaction.commit().await?;
 
     invalidate_public_event_caches(state).await;
+    notify_event_subscribers(state, &existing_event, &updated_event).await;
 
     Ok(updated_event)
 }
@@ -312,7 +564,7 @@ pub(crate) async fn delete_event_with_user(
     let existing_event = sqlx::query_as!(
         Event,
         r#"
-        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, created_at, updated_at
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
         FROM events
         WHERE id = $1
         "#,
@@ -355,6 +607,72 @@ pub(crate) async fn delete_event_with_user(
     Ok(())
 }
 
+pub(crate) async fn cancel_event_with_user(
+    state: &AppState,
+    user: &AuthedUser,
+    id: i64,
+) -> Result<Event, AppError> {
+    let mut transaction = state.db.begin().await?;
+
+    let existing_event = sqlx::query_as!(
+        Event,
+        r#"
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+        FROM events
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
+
+    let Some(existing_event) = existing_event else {
+        return Err(AppError::not_found("Event not found"));
+    };
+
+    if !user.is_admin() && user.organizer_id() != Some(existing_event.organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot cancel another organizer's event",
+        ));
+    }
+
+    if existing_event.cancelled_at.is_some() {
+        return Err(AppError::conflict("event is already cancelled"));
+    }
+
+    let cancelled_event = sqlx::query_as!(
+        Event,
+        r#"
+        UPDATE events
+        SET cancelled_at = NOW(), updated_at = NOW(), revision = revision + 1
+        WHERE id = $1
+        RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+        "#,
+        id
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+
+    record_audit(
+        &mut transaction,
+        cancelled_event.id,
+        cancelled_event.organizer_id,
+        user.account_id,
+        AuditType::Update,
+        Some(&existing_event),
+        Some(&cancelled_event),
+    )
+    .await?;
+
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(state).await;
+    notify_event_subscribers(state, &existing_event, &cancelled_event).await;
+
+    Ok(cancelled_event)
+}
+
 pub(crate) async fn list_events_for_organizer(
     state: &AppState,
     organizer_id: i64,
@@ -363,7 +681,7 @@ pub(crate) async fn list_events_for_organizer(
     offset: Option<i64>,
 ) -> Result<Vec<Event>, AppError> {
     let mut builder = QueryBuilder::<Postgres>::new(
-        "SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, created_at, updated_at FROM events",
+        "SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status, cancelled_at, visibility, series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience, venue_id, featured_until, term, all_day, created_at, updated_at FROM events",
     );
 
     builder
@@ -397,23 +715,25 @@ pub(crate) async fn newsletter_data_with_user(
     query_params: NewsletterDataQuery,
 ) -> Result<NewsletterDataResponse, AppError> {
     ensure_newsletter_access(user, state).await?;
+    newsletter_data(state, query_params).await
+}
 
-    let now = Utc::now();
-    let default_next_week = next_week_monday(now);
-    let (next_week_start, week_after_start, week_after_end) =
-        match query_params.week_start.as_deref().map(str::trim) {
-            Some("") => return Err(AppError::validation("week_start cannot be empty")),
-            Some(value) => {
-                let parsed_date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
-                    .map_err(|_| AppError::validation("invalid week_start, expected YYYY-MM-DD"))?;
-                let monday = parsed_date
-                    - Duration::days(parsed_date.weekday().num_days_from_monday() as i64);
-                compute_week_boundaries(monday)
-            }
-            None => compute_week_boundaries(default_next_week),
-        };
+pub(crate) async fn newsletter_data(
+    state: &AppState,
+    query_params: NewsletterDataQuery,
+) -> Result<NewsletterDataResponse, AppError> {
+    let monday = match query_params.week_start.as_deref() {
+        Some(value) => parse_newsletter_week_start(value)?,
+        None => next_week_monday(Utc::now()),
+    };
+    let (next_week_start, week_after_start, week_after_end) = compute_week_boundaries(monday);
+
+    let (stored_grouping, stored_ordering) = newsletter_issue_layout(state, monday).await?;
+    let texts = newsletter_issue_texts(state, monday).await?;
+    let grouping = query_params.grouping.unwrap_or(stored_grouping);
+    let ordering = query_params.ordering.unwrap_or(stored_ordering);
 
-    let subject = build_newsletter_subject(next_week_start);
+    let (subject, subject_en) = build_newsletter_subjects(next_week_start);
 
     let club_kind = OrganizerKind::StudentAssociation;
 
@@ -422,11 +742,14 @@ pub(crate) async fn newsletter_data_with_user(
         r#"
         SELECT e.id, e.organizer_id, e.title_de, e.title_en, e.description_de, e.description_en,
                e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_app,
-               e.publish_newsletter, e.publish_in_ical, e.publish_web, e.created_at, e.updated_at,
+               e.publish_newsletter, e.publish_in_ical, e.publish_web, e.max_participants, e.status as "status: EventStatus", e.cancelled_at, e.visibility as "visibility: EventVisibility", e.series_id, e.publish_at, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.venue_id, e.featured_until, e.term, e.all_day, e.created_at, e.updated_at,
                o.name as organizer_name, o.website_url as organizer_website
         FROM events e
         JOIN organizers o ON e.organizer_id = o.id
         WHERE e.publish_newsletter = true
+        AND e.status = 'PUBLISHED'
+        AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
         AND e.start_date_time >= $1
         AND e.start_date_time < $2
         AND o.organizer_kind = $3
@@ -439,23 +762,68 @@ pub(crate) async fn newsletter_data_with_user(
     .fetch_all(&state.db)
     .await?;
 
-    let (next_week_events, following_week_events): (Vec<_>, Vec<_>) = events
-        .into_iter()
-        .partition(|event| event.start_date_time < week_after_start);
-
     let all_organizers = sqlx::query_as!(
         Organizer,
-        r#"SELECT id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, organizer_kind as "organizer_kind: OrganizerKind", created_at, updated_at FROM organizers WHERE organizer_kind = $1 ORDER BY name"#,
+        r#"SELECT id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, event_footer_de, event_footer_en, calendar_name, calendar_color, calendar_refresh_minutes, organizer_kind as "organizer_kind: OrganizerKind", created_at, updated_at FROM organizers WHERE organizer_kind = $1 ORDER BY name"#,
         club_kind as OrganizerKind
     )
     .fetch_all(&state.db)
     .await?;
 
+    let (mut next_week_events, mut following_week_events): (Vec<_>, Vec<_>) = events
+        .into_iter()
+        .map(|mut event| {
+            if let Some(organizer) = all_organizers
+                .iter()
+                .find(|organizer| organizer.id == event.organizer_id)
+            {
+                event.description_de =
+                    append_event_footer(event.description_de, organizer.event_footer_de.as_deref());
+                event.description_en =
+                    append_event_footer(event.description_en, organizer.event_footer_en.as_deref());
+            }
+            NewsletterEventResponse::from(event)
+        })
+        .partition(|event| event.event.start_date_time < week_after_start);
+    let next_week_groups = arrange_newsletter_events(&mut next_week_events, grouping, ordering);
+    let following_week_groups =
+        arrange_newsletter_events(&mut following_week_events, grouping, ordering);
+
+    let event_ids: Vec<i64> = next_week_events
+        .iter()
+        .chain(following_week_events.iter())
+        .map(|event| event.event.id)
+        .collect();
+    let price_tiers = fetch_price_tiers(state, &event_ids).await?;
+    let sponsors = active_newsletter_sponsors(state, monday).await?;
+
+    let included_organizer_ids: Vec<i64> = next_week_events
+        .iter()
+        .map(|event| event.event.organizer_id)
+        .collect();
+    record_onboarding_milestone(
+        state,
+        &included_organizer_ids,
+        OnboardingMilestone::FirstNewsletterInclusion,
+    )
+    .await;
+
     Ok(NewsletterDataResponse {
         subject,
+        subject_en,
+        grouping,
+        ordering,
         next_week_events,
         following_week_events,
+        next_week_groups,
+        following_week_groups,
         all_organizers,
+        price_tiers,
+        sponsors,
+        intro_de: texts.intro_de,
+        intro_en: texts.intro_en,
+        outro_de: texts.outro_de,
+        outro_en: texts.outro_en,
         next_week_start,
         week_after_start,
     })
@@ -501,9 +869,15 @@ pub(crate) async fn send_newsletter_preview_with_user(
         return Err(AppError::validation("account is missing an email address"));
     };
 
+    let text = payload
+        .text
+        .as_deref()
+        .map(str::trim)
+        .filter(|text| !text.is_empty());
+
     let preview_subject = format!("[Vorschau] {subject}");
     email_client
-        .send_newsletter_preview_email(&email, &preview_subject, html)
+        .send_newsletter_preview_email(&email, &preview_subject, html, text)
         .await?;
 
     Ok(())
@@ -511,29 +885,118 @@ pub(crate) async fn send_newsletter_preview_with_user(
 
 #[utoipa::path(
     get,
-    path = "/api/v1/events",
+    path = "/api/v1/events/export",
     tag = "Events",
-    params(ListEventsQuery),
-    responses((status = 200, description = "List events", body = [Event]), (status = 401, description = "Unauthorized", body = ErrorResponse))
+    params(ExportEventsQuery),
+    responses(
+        (status = 200, description = "All events of the organizer (or all organizers for admins) streamed as CSV", content_type = "text/csv"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
 )]
-#[instrument(skip(state, query_params, headers))]
-pub(crate) async fn list_events(
+#[instrument(skip(state, headers))]
+pub(crate) async fn export_events(
     State(state): State<AppState>,
-    Query(query_params): Query<ListEventsQuery>,
     headers: HeaderMap,
-) -> Result<Json<Vec<Event>>, AppError> {
+    Query(query_params): Query<ExportEventsQuery>,
+) -> Result<Response, AppError> {
     let user = current_user_from_headers(&headers, &state).await?;
-    let scope = session_organizer_kind_scope(&state, &user).await?;
-
-    let enforced_organizer_kind = match scope {
-        SessionOrganizerKindScope::All => None,
-        SessionOrganizerKindScope::OnlyKind(k) => Some(k),
-        SessionOrganizerKindScope::None => return Ok(Json(vec![])),
+    let organizer_id = if user.is_admin() {
+        query_params.organizer_id
+    } else {
+        let organizer_id = user
+            .organizer_id()
+            .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+        if query_params
+            .organizer_id
+            .is_some_and(|requested| requested != organizer_id)
+        {
+            return Err(AppError::unauthorized("insufficient permissions"));
+        }
+        Some(organizer_id)
     };
+    let term = query_params
+        .term
+        .as_deref()
+        .map(normalize_term)
+        .transpose()?;
+    let db = state.db.clone();
+
+    stream_export(
+        "text/csv; charset=utf-8",
+        "events.csv",
+        move |mut writer| async move {
+            writer.write_csv_row(&EVENT_CSV_HEADER).await?;
+
+            let mut rows = sqlx::query!(
+                r#"
+            SELECT e.id, e.organizer_id, o.name AS organizer_name, e.title_de, e.title_en,
+                   e.start_date_time, e.end_date_time, e.term, e.location, e.event_url,
+                   e.status::text AS "status!", e.visibility::text AS "visibility!",
+                   e.publish_app, e.publish_newsletter, e.publish_in_ical, e.publish_web,
+                   e.max_participants, e.price_cents, e.currency, e.free_entry,
+                   e.cancelled_at, e.publish_at, e.created_at, e.updated_at
+            FROM events e
+            INNER JOIN organizers o ON e.organizer_id = o.id
+            WHERE ($1::bigint IS NULL OR e.organizer_id = $1)
+              AND ($2::text IS NULL OR e.term = $2)
+            ORDER BY e.start_date_time ASC, e.id ASC
+            "#,
+                organizer_id,
+                term
+            )
+            .fetch(&db);
+
+            let optional_time = |value: Option<DateTime<Utc>>| {
+                value.map(|value| value.to_rfc3339()).unwrap_or_default()
+            };
+            while let Some(row) = rows.try_next().await? {
+                writer
+                    .write_csv_row(&[
+                        row.id.to_string(),
+                        row.organizer_id.to_string(),
+                        row.organizer_name,
+                        row.title_de,
+                        row.title_en,
+                        row.start_date_time.to_rfc3339(),
+                        row.end_date_time.to_rfc3339(),
+                        row.term,
+                        row.location.unwrap_or_default(),
+                        row.event_url.unwrap_or_default(),
+                        row.status,
+                        row.visibility,
+                        row.publish_app.to_string(),
+                        row.publish_newsletter.to_string(),
+                        row.publish_in_ical.to_string(),
+                        row.publish_web.to_string(),
+                        row.max_participants
+                            .map(|max| max.to_string())
+                            .unwrap_or_default(),
+                        row.price_cents
+                            .map(|price| price.to_string())
+                            .unwrap_or_default(),
+                        row.currency,
+                        row.free_entry.to_string(),
+                        optional_time(row.cancelled_at),
+                        optional_time(row.publish_at),
+                        row.created_at.to_rfc3339(),
+                        row.updated_at.to_rfc3339(),
+                    ])
+                    .await?;
+            }
 
-    let mut builder = QueryBuilder::<Postgres>::new(
-        "SELECT e.id, e.organizer_id, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_app, e.publish_newsletter, e.publish_in_ical, e.publish_web, e.created_at, e.updated_at FROM events e INNER JOIN organizers o ON e.organizer_id = o.id",
-    );
+            Ok(writer)
+        },
+    )
+}
+
+fn list_events_query(
+    select: &str,
+    user: &AuthedUser,
+    enforced_organizer_kind: Option<OrganizerKind>,
+    query_params: &ListEventsQuery,
+) -> Result<QueryBuilder<'static, Postgres>, AppError> {
+    let mut builder = QueryBuilder::<Postgres>::new(select);
+    builder.push(" FROM events e INNER JOIN organizers o ON e.organizer_id = o.id");
 
     let mut has_where = false;
 
@@ -562,6 +1025,61 @@ pub(crate) async fn list_events(
         has_where = true;
     }
 
+    if let Some(series_id) = query_params.series_id {
+        if has_where {
+            builder.push(" AND e.series_id = ").push_bind(series_id);
+        } else {
+            builder.push(" WHERE e.series_id = ").push_bind(series_id);
+            has_where = true;
+        }
+    }
+
+    if let Some(free_entry) = query_params.free_entry {
+        builder
+            .push(if has_where { " AND " } else { " WHERE " })
+            .push("e.free_entry = ")
+            .push_bind(free_entry);
+        has_where = true;
+    }
+
+    if let Some(audience) = query_params.audience {
+        builder
+            .push(if has_where { " AND " } else { " WHERE " })
+            .push_bind(audience)
+            .push(" = ANY(e.audience)");
+        has_where = true;
+    }
+
+    if let Some(term) = query_params.term.as_deref() {
+        builder
+            .push(if has_where { " AND " } else { " WHERE " })
+            .push("e.term = ")
+            .push_bind(normalize_term(term)?);
+        has_where = true;
+    }
+
+    if let Some(search) = search_term(query_params.q.as_deref())? {
+        builder.push(if has_where { " AND " } else { " WHERE " });
+        push_search_filter(&mut builder, search);
+        has_where = true;
+    }
+
+    ensure_valid_start_range(query_params)?;
+    if let Some(start_after) = query_params.start_after {
+        builder
+            .push(if has_where { " AND " } else { " WHERE " })
+            .push("e.start_date_time >= ")
+            .push_bind(start_after);
+        has_where = true;
+    }
+    if let Some(start_before) = query_params.start_before {
+        builder
+            .push(if has_where { " AND " } else { " WHERE " })
+            .push("e.start_date_time < ")
+            .push_bind(start_before);
+        has_where = true;
+    }
+
     if query_params.upcoming_only.unwrap_or(false) {
         if has_where {
             builder
@@ -574,6 +1092,45 @@ pub(crate) async fn list_events(
         }
     }
 
+    Ok(builder)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events",
+    tag = "Events",
+    params(ListEventsQuery),
+    responses((status = 200, description = "List events", body = [Event], headers(
+        ("X-Total-Count" = i64, description = "Number of events matching the filters, ignoring limit and offset"),
+        ("Link" = String, description = "RFC 8288 first, prev, next and last page links when limit is set"),
+    )), (status = 401, description = "Unauthorized", body = ErrorResponse))
+)]
+#[instrument(skip(state, query_params, user))]
+pub(crate) async fn list_events(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Query(query_params): Query<ListEventsQuery>,
+    ScopedUser(user, _): ScopedUser<EventsRead>,
+) -> Result<(HeaderMap, Json<Vec<Event>>), AppError> {
+    let scope = session_organizer_kind_scope(&state, &user).await?;
+
+    let enforced_organizer_kind = match scope {
+        SessionOrganizerKindScope::All => None,
+        SessionOrganizerKindScope::OnlyKind(k) => Some(k),
+        SessionOrganizerKindScope::None => {
+            return Ok((
+                pagination_headers(&uri, 0, query_params.limit, query_params.offset),
+                Json(vec![]),
+            ));
+        }
+    };
+
+    let mut builder = list_events_query(
+        "SELECT e.id, e.organizer_id, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_app, e.publish_newsletter, e.publish_in_ical, e.publish_web, e.max_participants, e.status, e.cancelled_at, e.visibility, e.series_id, e.publish_at, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience, e.venue_id, e.featured_until, e.term, e.all_day, e.created_at, e.updated_at",
+        &user,
+        enforced_organizer_kind,
+        &query_params,
+    )?;
     builder.push(" ORDER BY e.start_date_time ASC");
 
     if let Some(limit) = query_params.limit {
@@ -587,8 +1144,20 @@ pub(crate) async fn list_events(
         .build_query_as::<Event>()
         .fetch_all(&state.db)
         .await?;
+    let total = list_events_query(
+        "SELECT COUNT(*)",
+        &user,
+        enforced_organizer_kind,
+        &query_params,
+    )?
+    .build_query_scalar::<i64>()
+    .fetch_one(&state.db)
+    .await?;
 
-    Ok(Json(events))
+    Ok((
+        pagination_headers(&uri, total, query_params.limit, query_params.offset),
+        Json(events),
+    ))
 }
 
 #[utoipa::path(
@@ -596,17 +1165,30 @@ pub(crate) async fn list_events(
     path = "/api/v1/events",
     tag = "Events",
     request_body = CreateEventRequest,
-    responses((status = 201, description = "Event created", body = Event))
+    responses((status = 201, description = "Event created, with overlapping events, possible duplicates and overlapping exam periods or breaks as warnings", body = EventWithConflictsResponse))
 )]
-#[instrument(skip(state, payload))]
+#[instrument(skip(state, user, payload))]
 pub(crate) async fn create_event(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    ScopedUser(user, _): ScopedUser<EventsWrite>,
     Json(payload): Json<CreateEventRequest>,
 ) -> Result<impl IntoResponse, AppError> {
-    let user = current_user_from_headers(&headers, &state).await?;
     let event = create_event_with_user(&state, &user, payload).await?;
-    Ok((StatusCode::CREATED, Json(event)))
+    let conflicts = conflicts_for_event(&state, &event).await?;
+    let capacity_warning = capacity_warning_for_event(&state, event.id).await?;
+    let possible_duplicates = possible_duplicates_for_event(&state, &event).await?;
+    let academic_periods =
+        academic_periods_overlapping(&state, event.start_date_time, event.end_date_time).await?;
+    Ok((
+        StatusCode::CREATED,
+        Json(EventWithConflictsResponse {
+            event,
+            conflicts,
+            capacity_warning,
+            possible_duplicates,
+            academic_periods,
+        }),
+    ))
 }
 
 #[utoipa::path(
@@ -616,13 +1198,12 @@ pub(crate) async fn create_event(
     params(("id" = i64, Path, description = "Event identifier")),
     responses((status = 200, description = "Event details", body = Event), (status = 401, description = "Unauthorized", body = ErrorResponse))
 )]
-#[instrument(skip(state, headers))]
+#[instrument(skip(state, user))]
 pub(crate) async fn get_event(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    ScopedUser(user, _): ScopedUser<EventsRead>,
     Path(id): Path<i64>,
 ) -> Result<Json<Event>, AppError> {
-    let user = current_user_from_headers(&headers, &state).await?;
     let event = get_event_with_user(&state, &user, id).await?;
     Ok(Json(event))
 }
@@ -633,18 +1214,25 @@ pub(crate) async fn get_event(
     tag = "Events",
     params(("id" = i64, Path, description = "Event identifier")),
     request_body = UpdateEventRequest,
-    responses((status = 200, description = "Event updated", body = Event))
+    responses((status = 200, description = "Event updated, with overlapping events as warnings", body = EventWithConflictsResponse))
 )]
-#[instrument(skip(state, headers, payload))]
+#[instrument(skip(state, user, payload))]
 pub(crate) async fn update_event(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    ScopedUser(user, _): ScopedUser<EventsWrite>,
     Path(id): Path<i64>,
     Json(payload): Json<UpdateEventRequest>,
-) -> Result<Json<Event>, AppError> {
-    let user = current_user_from_headers(&headers, &state).await?;
-    let updated = update_event_with_user(&state, &user, id, payload).await?;
-    Ok(Json(updated))
+) -> Result<Json<EventWithConflictsResponse>, AppError> {
+    let event = update_event_with_user(&state, &user, id, payload).await?;
+    let conflicts = conflicts_for_event(&state, &event).await?;
+    let capacity_warning = capacity_warning_for_event(&state, event.id).await?;
+    Ok(Json(EventWithConflictsResponse {
+        event,
+        conflicts,
+        capacity_warning,
+        possible_duplicates: Vec::new(),
+        academic_periods: Vec::new(),
+    }))
 }
 
 #[utoipa::path(
@@ -654,17 +1242,38 @@ pub(crate) async fn update_event(
     params(("id" = i64, Path, description = "Event identifier")),
     responses((status = 204, description = "Event removed"))
 )]
-#[instrument(skip(state))]
+#[instrument(skip(state, user))]
 pub(crate) async fn delete_event(
     State(state): State<AppState>,
-    headers: HeaderMap,
+    ScopedUser(user, _): ScopedUser<EventsWrite>,
     Path(id): Path<i64>,
 ) -> Result<StatusCode, AppError> {
-    let user = current_user_from_headers(&headers, &state).await?;
     delete_event_with_user(&state, &user, id).await?;
     Ok(StatusCode::NO_CONTENT)
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/cancel",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Event cancelled", body = Event),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "Event is already cancelled", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state, user))]
+pub(crate) async fn cancel_event(
+    State(state): State<AppState>,
+    ScopedUser(user, _): ScopedUser<EventsWrite>,
+    Path(id): Path<i64>,
+) -> Result<Json<Event>, AppError> {
+    let cancelled = cancel_event_with_user(&state, &user, id).await?;
+    Ok(Json(cancelled))
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/events/newsletter-data",
@@ -687,6 +1296,50 @@ pub(crate) async fn get_newsletter_data(
     Ok(Json(data))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/newsletter-preview",
+    tag = "Events",
+    params(NewsletterDataQuery),
+    responses(
+        (status = 200, description = "Newsletter rendered as HTML and plain text", body = NewsletterPreviewResponse),
+        (status = 400, description = "Invalid week_start", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 500, description = "Internal server error", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state, query_params, headers))]
+pub(crate) async fn get_newsletter_preview(
+    State(state): State<AppState>,
+    Query(query_params): Query<NewsletterDataQuery>,
+    headers: HeaderMap,
+) -> Result<Json<NewsletterPreviewResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let data = newsletter_data_with_user(&state, &user, query_params).await?;
+    let layout = NewsletterDeliverySettings::from_env().layout;
+    let renderings = render_newsletter_variants(&state, &data, layout).await?;
+    Ok(Json(NewsletterPreviewResponse {
+        layout,
+        subject: data.subject.clone(),
+        html: renderings.primary.html,
+        text: renderings.primary.text,
+        variants: vec![
+            NewsletterVariantPreview {
+                language: NewsletterLanguage::De,
+                subject: data.subject,
+                html: renderings.german.html,
+                text: renderings.german.text,
+            },
+            NewsletterVariantPreview {
+                language: NewsletterLanguage::En,
+                subject: data.subject_en,
+                html: renderings.english.html,
+                text: renderings.english.text,
+            },
+        ],
+    }))
+}
+
 #[utoipa::path(
     post,
     path = "/api/v1/events/newsletter-preview",
@@ -710,7 +1363,159 @@ pub(crate) async fn send_newsletter_preview(
     Ok(StatusCode::NO_CONTENT)
 }
 
-async fn ensure_newsletter_access(user: &AuthedUser, state: &AppState) -> Result<(), AppError> {
+pub(crate) fn ensure_valid_max_participants(max_participants: Option<i32>) -> Result<(), AppError> {
+    if let Some(max_participants) = max_participants
+        && max_participants < 1
+    {
+        return Err(AppError::validation("max participants must be at least 1"));
+    }
+    Ok(())
+}
+
+fn ensure_max_participants_covers_registrations(
+    max_participants: i32,
+    registration_count: i64,
+) -> Result<(), AppError> {
+    if i64::from(max_participants) < registration_count {
+        return Err(AppError::validation(format!(
+            "max participants must not be below the current {registration_count} registrations"
+        )));
+    }
+    Ok(())
+}
+
+pub(crate) fn normalize_currency(currency: &str) -> Result<String, AppError> {
+    let currency = currency.trim().to_ascii_uppercase();
+    if currency.len() != 3 || !currency.chars().all(|c| c.is_ascii_uppercase()) {
+        return Err(AppError::validation(
+            "currency must be a three-letter ISO 4217 code",
+        ));
+    }
+    Ok(currency)
+}
+
+pub(crate) fn normalize_audience(
+    mut audience: Vec<EventAudience>,
+) -> Result<Vec<EventAudience>, AppError> {
+    audience.sort();
+    audience.dedup();
+    if audience.is_empty() {
+        return Err(AppError::validation("audience must not be empty"));
+    }
+    Ok(audience)
+}
+
+pub(crate) fn ensure_valid_admission(
+    price_cents: Option<i32>,
+    free_entry: bool,
+) -> Result<(), AppError> {
+    if let Some(price_cents) = price_cents {
+        if price_cents < 0 {
+            return Err(AppError::validation("price must not be negative"));
+        }
+        if free_entry && price_cents > 0 {
+            return Err(AppError::validation(
+                "events with free entry cannot have a price",
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn search_term(q: Option<&str>) -> Result<Option<&str>, AppError> {
+    let Some(q) = q.map(str::trim).filter(|q| !q.is_empty()) else {
+        return Ok(None);
+    };
+    if q.chars().count() > MAX_SEARCH_QUERY_LEN {
+        return Err(AppError::validation(format!(
+            "q must be at most {MAX_SEARCH_QUERY_LEN} characters"
+        )));
+    }
+    Ok(Some(q))
+}
+
+pub(crate) fn ensure_valid_start_range(query: &ListEventsQuery) -> Result<(), AppError> {
+    if let (Some(start_after), Some(start_before)) = (query.start_after, query.start_before)
+        && start_before < start_after
+    {
+        return Err(AppError::validation(
+            "start_before must not be before start_after",
+        ));
+    }
+    Ok(())
+}
+
+pub(crate) fn push_search_filter(builder: &mut QueryBuilder<'_, Postgres>, search: &str) {
+    builder
+        .push("e.search_vector @@ (websearch_to_tsquery('german', ")
+        .push_bind(search.to_string())
+        .push(") || websearch_to_tsquery('english', ")
+        .push_bind(search.to_string())
+        .push("))");
+}
+
+async fn ensure_series_belongs_to_organizer(
+    state: &AppState,
+    series_id: Option<i64>,
+    organizer_id: i64,
+) -> Result<(), AppError> {
+    let Some(series_id) = series_id else {
+        return Ok(());
+    };
+
+    let series_organizer_id = sqlx::query_scalar!(
+        "SELECT organizer_id FROM event_series WHERE id = $1",
+        series_id
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    if series_organizer_id != Some(organizer_id) {
+        return Err(AppError::validation(
+            "series must belong to the event's organizer",
+        ));
+    }
+
+    Ok(())
+}
+
+async fn resolve_event_status(
+    state: &AppState,
+    user: &AuthedUser,
+    organizer_id: i64,
+    requested: EventStatus,
+) -> Result<EventStatus, AppError> {
+    if user.is_admin() {
+        return Ok(requested);
+    }
+
+    match requested {
+        EventStatus::Draft => Ok(EventStatus::Draft),
+        EventStatus::Published => {
+            let needs_review = sqlx::query_scalar!(
+                "SELECT needs_review FROM organizers WHERE id = $1",
+                organizer_id
+            )
+            .fetch_optional(&state.db)
+            .await?
+            .unwrap_or(false);
+
+            if needs_review {
+                Ok(EventStatus::PendingReview)
+            } else {
+                Ok(EventStatus::Published)
+            }
+        }
+        EventStatus::PendingReview | EventStatus::Rejected => {
+            Err(AppError::validation("status must be DRAFT or PUBLISHED"))
+        }
+    }
+}
+
+pub(crate) async fn ensure_newsletter_access(
+    user: &AuthedUser,
+    state: &AppState,
+) -> Result<(), AppError> {
     if user.is_admin() {
         return Ok(());
     }
@@ -743,20 +1548,39 @@ async fn ensure_newsletter_access(user: &AuthedUser, state: &AppState) -> Result
     Ok(())
 }
 
-fn compute_week_boundaries(week_start: NaiveDate) -> (DateTime<Utc>, DateTime<Utc>, DateTime<Utc>) {
+pub(crate) fn compute_week_boundaries(
+    week_start: NaiveDate,
+) -> (DateTime<Utc>, DateTime<Utc>, DateTime<Utc>) {
     let next_week_start = start_of_day_utc(week_start);
     let week_after_start = next_week_start + Duration::days(7);
     let week_after_end = week_after_start + Duration::days(7);
     (next_week_start, week_after_start, week_after_end)
 }
 
-fn next_week_monday(now: DateTime<Utc>) -> NaiveDate {
+pub(crate) fn next_week_monday(now: DateTime<Utc>) -> NaiveDate {
     let berlin_now = now.with_timezone(&Berlin);
     let weekday_offset = berlin_now.weekday().num_days_from_monday() as i64;
     let current_week_monday = berlin_now.date_naive() - Duration::days(weekday_offset);
     current_week_monday + Duration::days(7)
 }
 
+pub(crate) fn all_day_range(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> (DateTime<Utc>, DateTime<Utc>) {
+    let first_day = start.with_timezone(&Berlin).date_naive();
+    let end_local = end.with_timezone(&Berlin);
+    let last_day = if end_local.time() == NaiveTime::MIN && end_local.date_naive() > first_day {
+        end_local.date_naive() - Duration::days(1)
+    } else {
+        end_local.date_naive()
+    };
+    (
+        start_of_day_utc(first_day),
+        start_of_day_utc(last_day.max(first_day) + Duration::days(1)),
+    )
+}
+
 fn start_of_day_utc(date: NaiveDate) -> DateTime<Utc> {
     Berlin
         .from_local_datetime(&date.and_hms_opt(0, 0, 0).expect("valid midnight"))
@@ -765,17 +1589,24 @@ fn start_of_day_utc(date: NaiveDate) -> DateTime<Utc> {
         .with_timezone(&Utc)
 }
 
-fn build_newsletter_subject(next_week_start: DateTime<Utc>) -> String {
+fn build_newsletter_subjects(next_week_start: DateTime<Utc>) -> (String, String) {
     let local_start = next_week_start.with_timezone(&Berlin);
     let iso = local_start.iso_week();
-    format!(
-        "THI Campus Live Events – Newsletter KW {} {}",
-        iso.week(),
-        iso.year()
+    (
+        format!(
+            "THI Campus Live Events – Newsletter KW {} {}",
+            iso.week(),
+            iso.year()
+        ),
+        format!(
+            "THI Campus Life Events – Newsletter CW {} {}",
+            iso.week(),
+            iso.year()
+        ),
     )
 }
 
-async fn record_audit(
+pub(crate) async fn record_audit(
     transaction: &mut Transaction<'_, Postgres>,
     event_id: i64,
     organizer_id: i64,
@@ -784,6 +1615,7 @@ async fn record_audit(
     old_data: Option<&Event>,
     new_data: Option<&Event>,
 ) -> Result<(), AppError> {
+    let webhook_event_type = webhook_event_type(&audit_type, old_data, new_data);
     let old_json: Option<Value> = match old_data {
         Some(data) => Some(serde_json::to_value(data)?),
         None => None,
@@ -808,10 +1640,19 @@ async fn record_audit(
     .execute(&mut **transaction)
     .await?;
 
-    Ok(())
+    enqueue_event_webhooks(
+        transaction,
+        webhook_event_type,
+        event_id,
+        organizer_id,
+        old_json.as_ref(),
+        new_json.as_ref(),
+    )
+    .await
 }
 
-async fn invalidate_public_event_caches(state: &AppState) {
+pub(crate) async fn invalidate_public_event_caches(state: &AppState) {
+    notify_public_event_change(state);
     if let Some(cache) = &state.cache {
         if let Err(err) = cache.purge_prefix("public:events").await {
             warn!(target: "cache", action = "purge", scope = "public_events", %err, "Failed to purge public events cache");
@@ -826,10 +1667,52 @@ async fn invalidate_public_event_caches(state: &AppState) {
 pub(crate) fn router() -> Router<AppState> {
     Router::new()
         .route("/", get(list_events).post(create_event))
+        .route("/export", get(export_events))
+        .merge(super::conflicts::router())
         .route("/newsletter-data", get(get_newsletter_data))
-        .route("/newsletter-preview", post(send_newsletter_preview))
+        .route(
+            "/newsletter-preview",
+            get(get_newsletter_preview).post(send_newsletter_preview),
+        )
         .route(
             "/{id}",
             get(get_event).put(update_event).delete(delete_event),
         )
+        .route("/{id}/cancel", post(cancel_event))
+        .merge(super::registrations::router())
+        .merge(super::event_views::router())
+        .merge(super::agenda::router())
+        .merge(super::price_tiers::router())
+        .merge(super::speakers::event_router())
+        .merge(super::subscriptions::router())
+        .merge(super::event_import::router())
+        .merge(super::event_clone::router())
+        .merge(super::translations::router())
+        .merge(super::venues::event_router())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn max_participants_can_be_cleared_but_not_lowered_below_registrations() {
+        let cleared: UpdateEventRequest =
+            serde_json::from_str(r#"{"max_participants": null}"#).unwrap();
+        assert_eq!(cleared.max_participants, Some(None));
+        assert!(cleared.has_updates());
+
+        let untouched: UpdateEventRequest = serde_json::from_str(r#"{"title_de": "Neu"}"#).unwrap();
+        assert_eq!(untouched.max_participants, None);
+
+        let limited: UpdateEventRequest =
+            serde_json::from_str(r#"{"max_participants": 20}"#).unwrap();
+        assert_eq!(limited.max_participants, Some(Some(20)));
+
+        assert!(ensure_max_participants_covers_registrations(20, 20).is_ok());
+        assert!(matches!(
+            ensure_max_participants_covers_registrations(19, 20),
+            Err(AppError::Validation(_))
+        ));
+    }
 }
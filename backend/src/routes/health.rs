@@ -1,4 +1,4 @@
-use axum::{Json, Router, response::IntoResponse, routing::get};
+use axum::{Json, Router, extract::State, response::IntoResponse, routing::get};
 
 use crate::{app_state::AppState, responses::HealthResponse};
 
@@ -8,10 +8,21 @@ use crate::{app_state::AppState, responses::HealthResponse};
     tag = "Health",
     responses((status = 200, description = "API is ready", body = HealthResponse))
 )]
-pub(crate) async fn health_check() -> impl IntoResponse {
+pub(crate) async fn health_check(State(state): State<AppState>) -> impl IntoResponse {
+    let email = match &state.email {
+        Some(client) => client.health().as_str(),
+        None => "disabled",
+    };
+    let cache = match &state.cache {
+        Some(cache) => cache.health().as_str(),
+        None => "disabled",
+    };
+
     Json(HealthResponse {
         status: "ok".to_string(),
         message: "API Services".to_string(),
+        email: email.to_string(),
+        cache: cache.to_string(),
     })
 }
 
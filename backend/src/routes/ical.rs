@@ -1,26 +1,47 @@
+use std::sync::LazyLock;
+
 use axum::{
     Json, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
     response::{IntoResponse, Response},
     routing::get,
 };
-use chrono::{DateTime, Utc};
+use base64::{Engine as _, engine::general_purpose::URL_SAFE_NO_PAD};
+use chrono::{DateTime, Duration, Utc};
 use chrono_tz::Europe::Berlin;
-use icalendar::{Calendar, Component, Event as ICalEvent, EventLike, Property};
+use hmac::{Hmac, Mac};
+use icalendar::{
+    Calendar, Component, Event as ICalEvent, EventLike, EventStatus as ICalEventStatus, Property,
+};
+use sha2::Sha256;
+use sqlx::{Postgres, Transaction};
 use tracing::{instrument, warn};
 
 use crate::{
     api_token,
     app_state::AppState,
+    authed_user::AuthedUser,
+    dto::IcalFeedQuery,
     error::AppError,
-    models::{Event, Organizer, OrganizerKind},
+    models::{
+        AccountType, ApiTokenScope, Event, EventAudience, EventStatus, Organizer, OrganizerKind,
+    },
     responses::IcalEventResponse,
 };
 
+use super::{
+    events::{compute_week_boundaries, ensure_newsletter_access, next_week_monday},
+    private_feeds::PRIVATE_FEED_TOKEN_PREFIX,
+};
+
 #[derive(Debug, Clone)]
 struct IcalEventWithOrganizer {
     pub id: i64,
+    pub organizer_name: String,
+    pub organizer_website: Option<String>,
+    pub organizer_kind: Option<OrganizerKind>,
+    pub audience: Vec<EventAudience>,
     pub title_de: String,
     pub title_en: String,
     pub description_de: Option<String>,
@@ -30,9 +51,32 @@ struct IcalEventWithOrganizer {
     pub event_url: Option<String>,
     pub location: Option<String>,
     pub organizer_location: Option<String>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub all_day: bool,
+    pub revision: i32,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl IcalEventWithOrganizer {
+    fn categories(&self) -> Vec<String> {
+        let kind = self.organizer_kind.map(|kind| match kind {
+            OrganizerKind::StudentAssociation => "Campus Life",
+            OrganizerKind::ThiDepartment => "THI",
+        });
+        let audiences = self.audience.iter().map(|audience| match audience {
+            EventAudience::Students => "Students",
+            EventAudience::Staff => "Staff",
+            EventAudience::Alumni => "Alumni",
+            EventAudience::Public => "Public",
+        });
+        kind.into_iter()
+            .map(str::to_string)
+            .chain(std::iter::once(category_value(&self.organizer_name)))
+            .chain(audiences.map(str::to_string))
+            .filter(|category| !category.is_empty())
+            .collect()
+    }
+
     fn to_ical_event(&self) -> ICalEvent {
         let mut ical_event = ICalEvent::new();
 
@@ -64,33 +108,320 @@ impl IcalEventWithOrganizer {
             ical_event.description(desc);
         }
 
-        let start_local = self.start_date_time.with_timezone(&Berlin);
-        let mut start_property =
-            Property::new("DTSTART", start_local.format("%Y%m%dT%H%M%S").to_string());
-        start_property.add_parameter("TZID", BERLIN_TZID);
-        ical_event.append_property(start_property);
-
-        let end_local = self.end_date_time.with_timezone(&Berlin);
-        let mut end_property =
-            Property::new("DTEND", end_local.format("%Y%m%dT%H%M%S").to_string());
-        end_property.add_parameter("TZID", BERLIN_TZID);
-        ical_event.append_property(end_property);
+        for (name, value) in [
+            ("DTSTART", self.start_date_time),
+            ("DTEND", self.end_date_time),
+        ] {
+            let local = value.with_timezone(&Berlin);
+            let property = if self.all_day {
+                let mut property = Property::new(name, local.format("%Y%m%d").to_string());
+                property.add_parameter("VALUE", "DATE");
+                property
+            } else {
+                let mut property = Property::new(name, local.format("%Y%m%dT%H%M%S").to_string());
+                property.add_parameter("TZID", BERLIN_TZID);
+                property
+            };
+            ical_event.append_property(property);
+        }
 
         if let Some(url) = &self.event_url {
             ical_event.url(url);
         }
 
-        ical_event.uid(&format!("campus-life-event-{}", self.id));
+        ical_event.uid(&event_uid(self.id, ICAL_UID_DOMAIN.as_deref()));
+        let mut organizer = Property::new(
+            "ORGANIZER",
+            self.organizer_website
+                .clone()
+                .unwrap_or_else(|| format!("mailto:{ORGANIZER_FALLBACK_EMAIL}")),
+        );
+        organizer.add_parameter("CN", &quoted_parameter(&self.organizer_name));
+        ical_event.append_property(organizer);
+
+        let categories = self.categories();
+        if !categories.is_empty() {
+            ical_event.append_property(Property::new("CATEGORIES", categories.join(",")));
+        }
+
+        ical_event.sequence(u32::try_from(self.revision).unwrap_or_default());
+        ical_event.last_modified(self.updated_at);
+        ical_event.timestamp(self.updated_at);
+
+        if self.cancelled_at.is_some() {
+            ical_event.status(ICalEventStatus::Cancelled);
+        }
 
         ical_event.done()
     }
 }
 
+type HmacSha256 = Hmac<Sha256>;
+
+const ORGANIZER_FEED_TOKEN_CONTEXT: &[u8] = b"organizer-ical-feed-v1:";
+const NEWSLETTER_FEED_TOKEN_CONTEXT: &[u8] = b"newsletter-ical-feed-v1:";
 const BERLIN_TZID: &str = "Europe/Berlin";
+const ORGANIZER_FALLBACK_EMAIL: &str = "campus-life@thi.de";
+
+static ICAL_UID_DOMAIN: LazyLock<Option<String>> = LazyLock::new(|| {
+    std::env::var("ICAL_UID_DOMAIN")
+        .ok()
+        .map(|value| value.trim().trim_start_matches('@').to_lowercase())
+        .filter(|value| !value.is_empty())
+});
+
+fn event_uid(event_id: i64, domain: Option<&str>) -> String {
+    match domain {
+        Some(domain) => format!("{event_id}@{domain}"),
+        None => format!("campus-life-event-{event_id}"),
+    }
+}
+const BERLIN_VTIMEZONE: &str = "BEGIN:VTIMEZONE\r
+TZID:Europe/Berlin\r
+X-LIC-LOCATION:Europe/Berlin\r
+BEGIN:DAYLIGHT\r
+TZOFFSETFROM:+0100\r
+TZOFFSETTO:+0200\r
+TZNAME:CEST\r
+DTSTART:19700329T020000\r
+RRULE:FREQ=YEARLY;BYMONTH=3;BYDAY=-1SU\r
+END:DAYLIGHT\r
+BEGIN:STANDARD\r
+TZOFFSETFROM:+0200\r
+TZOFFSETTO:+0100\r
+TZNAME:CET\r
+DTSTART:19701025T030000\r
+RRULE:FREQ=YEARLY;BYMONTH=10;BYDAY=-1SU\r
+END:STANDARD\r
+END:VTIMEZONE\r
+";
+const MAX_WINDOW_DAYS: u32 = 3650;
+const DEFAULT_PAST_DAYS: u32 = 180;
+const DEFAULT_FUTURE_DAYS: u32 = 730;
+const ORGANIZER_FEED_GRACE_PERIOD_DAYS: i64 = 90;
+const ORGANIZER_FEED_TOMBSTONE_LOOKBACK_DAYS: i64 = 30;
+
+pub(crate) async fn record_organizer_feed_tombstone(
+    transaction: &mut Transaction<'_, Postgres>,
+    organizer_id: i64,
+) -> Result<(), AppError> {
+    sqlx::query!(
+        "DELETE FROM organizer_feed_tombstones WHERE expires_at <= NOW() OR organizer_id = $1",
+        organizer_id
+    )
+    .execute(&mut **transaction)
+    .await?;
+
+    let expires_at = Utc::now() + Duration::days(ORGANIZER_FEED_GRACE_PERIOD_DAYS);
+    let inserted = sqlx::query!(
+        r#"
+        INSERT INTO organizer_feed_tombstones (organizer_id, organizer_name, expires_at)
+        SELECT id, name, $2
+        FROM organizers
+        WHERE id = $1
+        "#,
+        organizer_id,
+        expires_at
+    )
+    .execute(&mut **transaction)
+    .await?;
+
+    if inserted.rows_affected() == 0 {
+        return Ok(());
+    }
+
+    sqlx::query!(
+        r#"
+        INSERT INTO organizer_feed_tombstone_events (organizer_id, event_id, title_de, title_en, start_date_time, end_date_time, location, revision)
+        SELECT organizer_id, id, title_de, title_en, start_date_time, end_date_time, location, revision + 1
+        FROM events
+        WHERE organizer_id = $1 AND publish_in_ical = true AND status = 'PUBLISHED'
+        AND visibility = 'PUBLIC'
+        AND (publish_at IS NULL OR publish_at <= NOW())
+        AND end_date_time >= $2
+        "#,
+        organizer_id,
+        Utc::now() - Duration::days(ORGANIZER_FEED_TOMBSTONE_LOOKBACK_DAYS)
+    )
+    .execute(&mut **transaction)
+    .await?;
+
+    Ok(())
+}
+
+async fn organizer_tombstone_response(
+    state: &AppState,
+    organizer_id: i64,
+) -> Result<Response, AppError> {
+    let tombstone = sqlx::query!(
+        r#"
+        SELECT organizer_name, deleted_at
+        FROM organizer_feed_tombstones
+        WHERE organizer_id = $1 AND expires_at > NOW()
+        "#,
+        organizer_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("Organizer not found"))?;
+
+    let removed_events = sqlx::query!(
+        r#"
+        SELECT event_id, title_de, title_en, start_date_time, end_date_time, location, revision
+        FROM organizer_feed_tombstone_events
+        WHERE organizer_id = $1
+        ORDER BY start_date_time ASC
+        "#,
+        organizer_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut calendar = Calendar::new();
+    calendar.name(&format!("{} Events", tombstone.organizer_name));
+    calendar.description(&format!(
+        "{} is no longer listed on Campus Life Events",
+        tombstone.organizer_name
+    ));
+    calendar.ttl(&chrono::Duration::hours(1));
+    calendar.timezone(BERLIN_TZID);
+    calendar.append_property(relcalid(organizer_id));
+
+    for removed in removed_events {
+        let event = IcalEventWithOrganizer {
+            id: removed.event_id,
+            title_de: removed.title_de,
+            title_en: removed.title_en,
+            description_de: None,
+            description_en: None,
+            start_date_time: removed.start_date_time,
+            end_date_time: removed.end_date_time,
+            event_url: None,
+            location: removed.location,
+            organizer_location: None,
+            cancelled_at: Some(tombstone.deleted_at),
+            all_day: false,
+            revision: removed.revision,
+            updated_at: tombstone.deleted_at,
+            organizer_name: tombstone.organizer_name.clone(),
+            organizer_website: None,
+            organizer_kind: None,
+            audience: Vec::new(),
+        };
+        calendar.push(event.to_ical_event());
+    }
+
+    let file_name = tombstone.organizer_name.to_lowercase().replace(' ', "-");
+    build_ical_response_with_filename(
+        render_calendar(&mut calendar),
+        format!("attachment; filename=\"{file_name}-events.ics\""),
+    )
+}
+
+fn with_berlin_vtimezone(ics: String) -> String {
+    let position = ics
+        .find("BEGIN:VEVENT")
+        .or_else(|| ics.find("END:VCALENDAR"));
+    match position {
+        Some(position) => {
+            let mut output = String::with_capacity(ics.len() + BERLIN_VTIMEZONE.len());
+            output.push_str(&ics[..position]);
+            output.push_str(BERLIN_VTIMEZONE);
+            output.push_str(&ics[position..]);
+            output
+        }
+        None => ics,
+    }
+}
+
+fn quoted_parameter(value: &str) -> String {
+    let value = value.replace('"', "");
+    if value.contains([':', ';', ',']) {
+        format!("\"{value}\"")
+    } else {
+        value
+    }
+}
+
+fn category_value(value: &str) -> String {
+    value
+        .replace([',', ';', '\\'], " ")
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn relcalid(organizer_id: i64) -> Property {
+    Property::new(
+        "X-WR-RELCALID",
+        format!("campus-life-organizer-{organizer_id}"),
+    )
+}
+
+fn render_calendar(calendar: &mut Calendar) -> String {
+    with_berlin_vtimezone(calendar.done().to_string())
+}
+
+fn default_window_days(key: &str, default: u32) -> u32 {
+    std::env::var(key)
+        .ok()
+        .and_then(|value| value.trim().parse::<u32>().ok())
+        .filter(|days| (1..=MAX_WINDOW_DAYS).contains(days))
+        .unwrap_or(default)
+}
+
+#[derive(Debug, Clone, Copy)]
+struct FeedWindow {
+    past_days: u32,
+    future_days: u32,
+}
+
+impl FeedWindow {
+    fn from_query(query: &IcalFeedQuery) -> Result<Self, AppError> {
+        let (past_name, past_days) = match (query.past_days, query.window_days) {
+            (Some(days), _) => ("past_days", days),
+            (None, Some(days)) => ("window_days", days),
+            (None, None) => (
+                "past_days",
+                default_window_days("ICAL_DEFAULT_PAST_DAYS", DEFAULT_PAST_DAYS),
+            ),
+        };
+        let future_days = query.future_days.unwrap_or_else(|| {
+            default_window_days("ICAL_DEFAULT_FUTURE_DAYS", DEFAULT_FUTURE_DAYS)
+        });
+        for (name, days) in [(past_name, past_days), ("future_days", future_days)] {
+            if days == 0 || days > MAX_WINDOW_DAYS {
+                return Err(AppError::validation(format!(
+                    "{name} must be between 1 and {MAX_WINDOW_DAYS}"
+                )));
+            }
+        }
+        Ok(Self {
+            past_days,
+            future_days,
+        })
+    }
+
+    fn ends_after(self) -> DateTime<Utc> {
+        Utc::now() - Duration::days(i64::from(self.past_days))
+    }
+
+    fn starts_before(self) -> DateTime<Utc> {
+        Utc::now() + Duration::days(i64::from(self.future_days))
+    }
+
+    fn cache_key(self, base: &str) -> String {
+        format!("{base}:window:{}:{}", self.past_days, self.future_days)
+    }
+}
 
 #[derive(Debug, Clone, sqlx::FromRow)]
 struct EventWithOrganizerRow {
     pub id: i64,
+    pub organizer_name: String,
+    pub organizer_website: Option<String>,
+    pub organizer_kind: OrganizerKind,
+    pub audience: Vec<EventAudience>,
     pub title_de: String,
     pub title_en: String,
     pub description_de: Option<String>,
@@ -100,6 +431,10 @@ struct EventWithOrganizerRow {
     pub event_url: Option<String>,
     pub location: Option<String>,
     pub organizer_location: Option<String>,
+    pub cancelled_at: Option<DateTime<Utc>>,
+    pub all_day: bool,
+    pub revision: i32,
+    pub updated_at: DateTime<Utc>,
 }
 
 impl From<EventWithOrganizerRow> for IcalEventWithOrganizer {
@@ -115,6 +450,14 @@ impl From<EventWithOrganizerRow> for IcalEventWithOrganizer {
             event_url: row.event_url,
             location: row.location,
             organizer_location: row.organizer_location,
+            cancelled_at: row.cancelled_at,
+            all_day: row.all_day,
+            revision: row.revision,
+            updated_at: row.updated_at,
+            organizer_name: row.organizer_name,
+            organizer_website: row.organizer_website,
+            organizer_kind: Some(row.organizer_kind),
+            audience: row.audience,
         }
     }
 }
@@ -122,13 +465,16 @@ impl From<EventWithOrganizerRow> for IcalEventWithOrganizer {
 async fn ical_response_for_organizer_kind(
     state: &AppState,
     kind: OrganizerKind,
-    cache_key: &'static str,
+    cache_key_base: &str,
+    window: FeedWindow,
     calendar_name: &str,
     calendar_description: &str,
     content_disposition: String,
 ) -> Result<Response, AppError> {
+    let cache_key = window.cache_key(cache_key_base);
+
     if let Some(cache) = &state.cache {
-        match cache.get_string(cache_key).await {
+        match cache.get_string(&cache_key).await {
             Ok(Some(cached)) => {
                 return build_ical_response_with_filename(cached, content_disposition);
             }
@@ -144,15 +490,23 @@ async fn ical_response_for_organizer_kind(
         r#"
         SELECT
             e.id, e.title_de, e.title_en, e.description_de, e.description_en,
-            e.start_date_time, e.end_date_time, e.event_url, e.location,
+            e.start_date_time, e.end_date_time, e.event_url, e.location, e.cancelled_at, e.all_day,
+            e.revision, e.updated_at, e.audience as "audience: Vec<EventAudience>",
+            o.name as organizer_name, o.website_url as organizer_website,
+            o.organizer_kind as "organizer_kind: OrganizerKind",
             o.location as organizer_location
         FROM events e
         JOIN organizers o ON e.organizer_id = o.id
-        WHERE e.publish_in_ical = true AND e.publish_app = true
+        WHERE e.publish_in_ical = true AND e.publish_app = true AND e.status = 'PUBLISHED'
+        AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
         AND o.organizer_kind = $1
+        AND e.end_date_time >= $2 AND e.start_date_time < $3
         ORDER BY e.start_date_time ASC
         "#,
-        kind as OrganizerKind
+        kind as OrganizerKind,
+        window.ends_after(),
+        window.starts_before()
     )
     .fetch_all(&state.db)
     .await?;
@@ -169,10 +523,10 @@ async fn ical_response_for_organizer_kind(
         calendar.push(ical_event);
     }
 
-    let ical_content = calendar.done().to_string();
+    let ical_content = render_calendar(&mut calendar);
 
     if let Some(cache) = &state.cache
-        && let Err(err) = cache.set_string(cache_key, &ical_content).await
+        && let Err(err) = cache.set_string(&cache_key, &ical_content).await
     {
         warn!(target: "cache", action = "set", scope = "ical_kind", cache_key, %err, "Failed to store iCal feed in cache");
     }
@@ -184,17 +538,20 @@ async fn ical_response_for_organizer_kind(
     get,
     path = "/api/ical",
     tag = "iCal",
+    params(IcalFeedQuery),
     responses((status = 200, description = "Legacy iCal: Campus Life (student associations) only", content_type = "text/calendar"))
 )]
 #[instrument(skip(state))]
 pub(crate) async fn get_all_events_ical(
     State(state): State<AppState>,
+    Query(query): Query<IcalFeedQuery>,
     _headers: HeaderMap,
 ) -> Result<impl IntoResponse, AppError> {
     ical_response_for_organizer_kind(
         &state,
         OrganizerKind::StudentAssociation,
         "ical:kind:student_association",
+        FeedWindow::from_query(&query)?,
         "Campus Life Events",
         "Campus Life events from student associations (legacy URL; same content as /api/ical/cl)",
         "attachment; filename=\"campus-life-events.ics\"".to_string(),
@@ -206,17 +563,20 @@ pub(crate) async fn get_all_events_ical(
     get,
     path = "/api/ical/cl",
     tag = "iCal",
+    params(IcalFeedQuery),
     responses((status = 200, description = "iCal: all Campus Life (student association) events", content_type = "text/calendar"))
 )]
 #[instrument(skip(state))]
 pub(crate) async fn get_cl_events_ical(
     State(state): State<AppState>,
+    Query(query): Query<IcalFeedQuery>,
     _headers: HeaderMap,
 ) -> Result<impl IntoResponse, AppError> {
     ical_response_for_organizer_kind(
         &state,
         OrganizerKind::StudentAssociation,
         "ical:kind:student_association",
+        FeedWindow::from_query(&query)?,
         "Campus Life Events",
         "All public Campus Life events from student associations",
         "attachment; filename=\"campus-life-cl-events.ics\"".to_string(),
@@ -228,17 +588,20 @@ pub(crate) async fn get_cl_events_ical(
     get,
     path = "/api/ical/thi",
     tag = "iCal",
+    params(IcalFeedQuery),
     responses((status = 200, description = "iCal: all THI department events", content_type = "text/calendar"))
 )]
 #[instrument(skip(state))]
 pub(crate) async fn get_thi_events_ical(
     State(state): State<AppState>,
+    Query(query): Query<IcalFeedQuery>,
     _headers: HeaderMap,
 ) -> Result<impl IntoResponse, AppError> {
     ical_response_for_organizer_kind(
         &state,
         OrganizerKind::ThiDepartment,
         "ical:kind:thi_department",
+        FeedWindow::from_query(&query)?,
         "THI Services Events",
         "All public THI department and institution events",
         "attachment; filename=\"thi-services-events.ics\"".to_string(),
@@ -250,18 +613,34 @@ pub(crate) async fn get_thi_events_ical(
     get,
     path = "/api/ical/{organizer_id}",
     tag = "iCal",
-    params(("organizer_id" = i64, Path, description = "Organizer identifier")),
-    responses((status = 200, description = "iCal calendar with events for specific organizer", content_type = "text/calendar"))
+    params(
+        ("organizer_id" = i64, Path, description = "Organizer identifier"),
+        IcalFeedQuery,
+    ),
+    responses(
+        (status = 200, description = "iCal calendar with events for specific organizer; deleted organizers keep serving cancellations for a grace period", content_type = "text/calendar"),
+        (status = 404, description = "Organizer not found"),
+    )
 )]
 #[instrument(skip(state))]
 pub(crate) async fn get_organizer_events_ical(
     State(state): State<AppState>,
     Path(organizer_id): Path<i64>,
+    Query(query): Query<IcalFeedQuery>,
 ) -> Result<impl IntoResponse, AppError> {
+    organizer_feed_response(&state, organizer_id, FeedWindow::from_query(&query)?, false).await
+}
+
+async fn organizer_feed_response(
+    state: &AppState,
+    organizer_id: i64,
+    window: FeedWindow,
+    include_unlisted: bool,
+) -> Result<Response, AppError> {
     let organizer = sqlx::query_as!(
         Organizer,
         r#"
-        SELECT id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, organizer_kind as "organizer_kind: OrganizerKind", created_at, updated_at
+        SELECT id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, event_footer_de, event_footer_en, calendar_name, calendar_color, calendar_refresh_minutes, organizer_kind as "organizer_kind: OrganizerKind", created_at, updated_at
         FROM organizers
         WHERE id = $1
         "#,
@@ -271,17 +650,28 @@ pub(crate) async fn get_organizer_events_ical(
     .await?;
 
     let Some(organizer) = organizer else {
-        return Err(AppError::not_found("Organizer not found"));
+        return organizer_tombstone_response(state, organizer_id).await;
     };
 
-    let cache_key = format!("ical:organizer:{organizer_id}");
+    let (cache_key_base, cache_control) = if include_unlisted {
+        (
+            format!("ical:organizer:{organizer_id}:signed"),
+            "private, max-age=3600",
+        )
+    } else {
+        (
+            format!("ical:organizer:{organizer_id}"),
+            "public, max-age=3600",
+        )
+    };
+    let cache_key = window.cache_key(&cache_key_base);
     let file_name = organizer.name.to_lowercase().replace(' ', "-");
     let content_disposition = format!("attachment; filename=\"{file_name}-events.ics\"");
 
     if let Some(cache) = &state.cache {
         match cache.get_string(&cache_key).await {
             Ok(Some(cached)) => {
-                return build_ical_response_with_filename(cached, content_disposition.clone());
+                return build_ical_response(cached, content_disposition, cache_control);
             }
             Ok(None) => {}
             Err(err) => {
@@ -295,23 +685,43 @@ pub(crate) async fn get_organizer_events_ical(
         r#"
         SELECT
             e.id, e.title_de, e.title_en, e.description_de, e.description_en,
-            e.start_date_time, e.end_date_time, e.event_url, e.location,
+            e.start_date_time, e.end_date_time, e.event_url, e.location, e.cancelled_at, e.all_day,
+            e.revision, e.updated_at, e.audience as "audience: Vec<EventAudience>",
+            o.name as organizer_name, o.website_url as organizer_website,
+            o.organizer_kind as "organizer_kind: OrganizerKind",
             o.location as organizer_location
         FROM events e
         JOIN organizers o ON e.organizer_id = o.id
-        WHERE e.organizer_id = $1 AND e.publish_in_ical = true
+        WHERE e.organizer_id = $1 AND e.publish_in_ical = true AND e.status = 'PUBLISHED'
+        AND (e.visibility = 'PUBLIC' OR $4)
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        AND e.end_date_time >= $2 AND e.start_date_time < $3
         ORDER BY e.start_date_time ASC
         "#,
-        organizer_id
+        organizer_id,
+        window.ends_after(),
+        window.starts_before(),
+        include_unlisted
     )
     .fetch_all(&state.db)
     .await?;
 
     let mut calendar = Calendar::new();
-    calendar.name(&format!("{} Events", organizer.name));
+    calendar.name(
+        &organizer
+            .calendar_name
+            .clone()
+            .unwrap_or_else(|| format!("{} Events", organizer.name)),
+    );
     calendar.description(&format!("Events organized by {}", organizer.name));
-    calendar.ttl(&chrono::Duration::hours(1));
+    calendar.ttl(&chrono::Duration::minutes(i64::from(
+        organizer.calendar_refresh_minutes.unwrap_or(60),
+    )));
     calendar.timezone(BERLIN_TZID);
+    calendar.append_property(relcalid(organizer_id));
+    if let Some(color) = &organizer.calendar_color {
+        calendar.append_property(Property::new("X-APPLE-CALENDAR-COLOR", color.as_str()));
+    }
 
     for row in events_with_organizers {
         let event_with_organizer = IcalEventWithOrganizer::from(row);
@@ -319,7 +729,7 @@ pub(crate) async fn get_organizer_events_ical(
         calendar.push(ical_event);
     }
 
-    let ical_content = calendar.done().to_string();
+    let ical_content = render_calendar(&mut calendar);
 
     if let Some(cache) = &state.cache
         && let Err(err) = cache.set_string(&cache_key, &ical_content).await
@@ -327,18 +737,391 @@ pub(crate) async fn get_organizer_events_ical(
         warn!(target: "cache", action = "set", scope = "ical_organizer", organizer_id, %err, "Failed to store organizer iCal feed in cache");
     }
 
+    build_ical_response(ical_content, content_disposition, cache_control)
+}
+
+fn feed_token_mac(key: &[u8; 32], context: &[u8], subject_id: i64, version: i32) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key.as_slice()).expect("HMAC accepts 32-byte key");
+    mac.update(context);
+    mac.update(format!("{subject_id}:{version}").as_bytes());
+    mac
+}
+
+fn signed_feed_token(key: &[u8; 32], context: &[u8], subject_id: i64, version: i32) -> String {
+    let signature = feed_token_mac(key, context, subject_id, version)
+        .finalize()
+        .into_bytes();
+    format!(
+        "{subject_id}.{version}.{}",
+        URL_SAFE_NO_PAD.encode(signature)
+    )
+}
+
+fn verify_signed_feed_token(key: &[u8; 32], context: &[u8], token: &str) -> Option<(i64, i32)> {
+    let mut parts = token.splitn(3, '.');
+    let subject_id = parts.next()?.parse::<i64>().ok()?;
+    let version = parts.next()?.parse::<i32>().ok()?;
+    let signature = URL_SAFE_NO_PAD.decode(parts.next()?).ok()?;
+    feed_token_mac(key, context, subject_id, version)
+        .verify_slice(&signature)
+        .ok()
+        .map(|_| (subject_id, version))
+}
+
+pub(crate) fn organizer_feed_token(key: &[u8; 32], organizer_id: i64, version: i32) -> String {
+    signed_feed_token(key, ORGANIZER_FEED_TOKEN_CONTEXT, organizer_id, version)
+}
+
+fn verify_organizer_feed_token(key: &[u8; 32], token: &str) -> Option<(i64, i32)> {
+    verify_signed_feed_token(key, ORGANIZER_FEED_TOKEN_CONTEXT, token)
+}
+
+pub(crate) fn newsletter_feed_token(key: &[u8; 32], account_id: i64, version: i32) -> String {
+    signed_feed_token(key, NEWSLETTER_FEED_TOKEN_CONTEXT, account_id, version)
+}
+
+fn verify_newsletter_feed_token(key: &[u8; 32], token: &str) -> Option<(i64, i32)> {
+    verify_signed_feed_token(key, NEWSLETTER_FEED_TOKEN_CONTEXT, token)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/ical/feeds/{token}",
+    tag = "iCal",
+    params(
+        ("token" = String, Path, description = "Signed organizer feed token"),
+        IcalFeedQuery,
+    ),
+    responses(
+        (status = 200, description = "iCal calendar with the organizer's published events including unlisted ones", content_type = "text/calendar"),
+        (status = 404, description = "Feed not found or revoked"),
+    )
+)]
+#[instrument(skip(state, token))]
+pub(crate) async fn get_signed_organizer_ical(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    Query(query): Query<IcalFeedQuery>,
+) -> Result<Response, AppError> {
+    let Some((organizer_id, version)) = state
+        .api_token_hmac_key
+        .as_ref()
+        .and_then(|key| verify_organizer_feed_token(key, &token))
+    else {
+        return Err(AppError::not_found("Feed not found or revoked"));
+    };
+
+    let active = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM organizer_ical_tokens WHERE organizer_id = $1 AND version = $2) AS "active!""#,
+        organizer_id,
+        version
+    )
+    .fetch_one(&state.db)
+    .await?;
+    if !active {
+        return Err(AppError::not_found("Feed not found or revoked"));
+    }
+
+    organizer_feed_response(&state, organizer_id, FeedWindow::from_query(&query)?, true).await
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/ical/newsletter/{token}",
+    tag = "iCal",
+    params(("token" = String, Path, description = "Signed newsletter editor feed token")),
+    responses(
+        (status = 200, description = "iCal calendar with the events of the next two newsletter weeks", content_type = "text/calendar"),
+        (status = 401, description = "Account no longer has newsletter access"),
+        (status = 404, description = "Feed not found or revoked"),
+    )
+)]
+#[instrument(skip(state, token))]
+pub(crate) async fn get_newsletter_ical(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<Response, AppError> {
+    let Some((account_id, version)) = state
+        .api_token_hmac_key
+        .as_ref()
+        .and_then(|key| verify_newsletter_feed_token(key, &token))
+    else {
+        return Err(AppError::not_found("Feed not found or revoked"));
+    };
+
+    let account = sqlx::query!(
+        r#"
+        SELECT a.id, a.account_type as "account_type: AccountType", a.organizer_id
+        FROM newsletter_ical_tokens t
+        JOIN accounts a ON a.id = t.account_id
+        WHERE t.account_id = $1 AND t.version = $2
+        "#,
+        account_id,
+        version
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("Feed not found or revoked"))?;
+    let user = AuthedUser {
+        account_id: account.id,
+        account_type: account.account_type,
+        organizer_id: account.organizer_id,
+    };
+    ensure_newsletter_access(&user, &state).await?;
+
+    let week_start = next_week_monday(Utc::now());
+    let (next_week_start, _, week_after_end) = compute_week_boundaries(week_start);
+
+    let rows = sqlx::query_as!(
+        EventWithOrganizerRow,
+        r#"
+        SELECT
+            e.id, e.title_de, e.title_en, e.description_de, e.description_en,
+            e.start_date_time, e.end_date_time, e.event_url, e.location, e.cancelled_at, e.all_day,
+            e.revision, e.updated_at, e.audience as "audience: Vec<EventAudience>",
+            o.name as organizer_name, o.website_url as organizer_website,
+            o.organizer_kind as "organizer_kind: OrganizerKind",
+            o.location as organizer_location
+        FROM events e
+        JOIN organizers o ON e.organizer_id = o.id
+        WHERE e.publish_newsletter = true AND e.status = 'PUBLISHED'
+        AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        AND e.start_date_time >= $1 AND e.start_date_time < $2
+        AND o.organizer_kind = $3
+        ORDER BY e.start_date_time ASC
+        "#,
+        next_week_start,
+        week_after_end,
+        OrganizerKind::StudentAssociation as OrganizerKind
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut calendar = Calendar::new();
+    calendar.name("Campus Life Newsletter (Vorschau)");
+    calendar.description(&format!(
+        "Events of the newsletter issues starting {}",
+        week_start.format("%d.%m.%Y")
+    ));
+    calendar.ttl(&chrono::Duration::hours(1));
+    calendar.timezone(BERLIN_TZID);
+
+    for row in rows {
+        calendar.push(IcalEventWithOrganizer::from(row).to_ical_event());
+    }
+
+    build_ical_response(
+        render_calendar(&mut calendar),
+        "attachment; filename=\"newsletter-events.ics\"".to_string(),
+        "private, no-store",
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/ical/events/{id}.ics",
+    tag = "iCal",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "iCal calendar containing a single event for \"Add to calendar\" buttons", content_type = "text/calendar"),
+        (status = 404, description = "Event not found or not published"),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_event_ical(
+    State(state): State<AppState>,
+    Path(file_name): Path<String>,
+) -> Result<Response, AppError> {
+    let event_id = file_name
+        .strip_suffix(".ics")
+        .and_then(|id| id.parse::<i64>().ok())
+        .ok_or_else(|| AppError::not_found("Event not found or not published"))?;
+
+    let cache_key = format!("ical:event:{event_id}");
+    let content_disposition = format!("attachment; filename=\"campus-life-event-{event_id}.ics\"");
+
+    if let Some(cache) = &state.cache {
+        match cache.get_string(&cache_key).await {
+            Ok(Some(cached)) => {
+                return build_ical_response_with_filename(cached, content_disposition);
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "cache", action = "get", scope = "ical_event", event_id, %err, "Failed to read event iCal from cache")
+            }
+        }
+    }
+
+    let row = sqlx::query_as!(
+        EventWithOrganizerRow,
+        r#"
+        SELECT
+            e.id, e.title_de, e.title_en, e.description_de, e.description_en,
+            e.start_date_time, e.end_date_time, e.event_url, e.location, e.cancelled_at, e.all_day,
+            e.revision, e.updated_at, e.audience as "audience: Vec<EventAudience>",
+            o.name as organizer_name, o.website_url as organizer_website,
+            o.organizer_kind as "organizer_kind: OrganizerKind",
+            o.location as organizer_location
+        FROM events e
+        JOIN organizers o ON e.organizer_id = o.id
+        WHERE e.id = $1 AND (e.publish_in_ical = true OR e.publish_web = true)
+        AND e.status = 'PUBLISHED'
+        AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        "#,
+        event_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("Event not found or not published"))?;
+
+    let event = IcalEventWithOrganizer::from(row);
+    let mut calendar = Calendar::new();
+    calendar.name(if event.title_en.is_empty() {
+        &event.title_de
+    } else {
+        &event.title_en
+    });
+    calendar.timezone(BERLIN_TZID);
+    calendar.push(event.to_ical_event());
+
+    let ical_content = render_calendar(&mut calendar);
+
+    if let Some(cache) = &state.cache
+        && let Err(err) = cache.set_string(&cache_key, &ical_content).await
+    {
+        warn!(target: "cache", action = "set", scope = "ical_event", event_id, %err, "Failed to store event iCal in cache");
+    }
+
     build_ical_response_with_filename(ical_content, content_disposition)
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/ical/private/{token}",
+    tag = "iCal",
+    params(
+        ("token" = String, Path, description = "Secret private feed token"),
+        IcalFeedQuery,
+    ),
+    responses(
+        (status = 200, description = "iCal calendar with all events of the organizer, including unpublished and draft events (marked tentative)", content_type = "text/calendar"),
+        (status = 404, description = "Private feed not found"),
+    )
+)]
+#[instrument(skip(state, token))]
+pub(crate) async fn get_private_organizer_ical(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+    Query(query): Query<IcalFeedQuery>,
+) -> Result<Response, AppError> {
+    let window = FeedWindow::from_query(&query)?;
+    let organizer = match state.api_token_hmac_key.as_ref() {
+        Some(key) if token.starts_with(PRIVATE_FEED_TOKEN_PREFIX) => {
+            sqlx::query!(
+                r#"
+                UPDATE organizer_private_feeds f
+                SET last_used_at = NOW()
+                FROM organizers o
+                WHERE f.token_hmac = $1 AND o.id = f.organizer_id
+                RETURNING o.id, o.name
+                "#,
+                &api_token::hash_raw_token(key, &token)[..]
+            )
+            .fetch_optional(&state.db)
+            .await?
+        }
+        _ => None,
+    };
+    let Some(organizer) = organizer else {
+        return Err(AppError::not_found("Private feed not found"));
+    };
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            e.id, e.title_de, e.title_en, e.description_de, e.description_en,
+            e.start_date_time, e.end_date_time, e.event_url, e.location, e.cancelled_at, e.all_day,
+            e.revision, e.updated_at, e.audience as "audience: Vec<EventAudience>",
+            o.name as organizer_name, o.website_url as organizer_website,
+            o.organizer_kind as "organizer_kind: OrganizerKind",
+            e.status as "status: EventStatus", o.location as organizer_location
+        FROM events e
+        JOIN organizers o ON e.organizer_id = o.id
+        WHERE e.organizer_id = $1 AND e.status <> 'REJECTED'
+        AND e.end_date_time >= $2 AND e.start_date_time < $3
+        ORDER BY e.start_date_time ASC
+        "#,
+        organizer.id,
+        window.ends_after(),
+        window.starts_before()
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut calendar = Calendar::new();
+    calendar.name(&format!("{} (intern)", organizer.name));
+    calendar.description(&format!(
+        "All events of {}, including unpublished drafts",
+        organizer.name
+    ));
+    calendar.ttl(&chrono::Duration::minutes(15));
+    calendar.timezone(BERLIN_TZID);
+    calendar.append_property(relcalid(organizer.id));
+
+    for row in rows {
+        let event = IcalEventWithOrganizer {
+            id: row.id,
+            title_de: row.title_de,
+            title_en: row.title_en,
+            description_de: row.description_de,
+            description_en: row.description_en,
+            start_date_time: row.start_date_time,
+            end_date_time: row.end_date_time,
+            event_url: row.event_url,
+            location: row.location,
+            organizer_location: row.organizer_location,
+            cancelled_at: row.cancelled_at,
+            all_day: row.all_day,
+            revision: row.revision,
+            updated_at: row.updated_at,
+            organizer_name: row.organizer_name,
+            organizer_website: row.organizer_website,
+            organizer_kind: Some(row.organizer_kind),
+            audience: row.audience,
+        };
+        let mut ical_event = event.to_ical_event();
+        if row.status != EventStatus::Published && event.cancelled_at.is_none() {
+            ical_event.status(ICalEventStatus::Tentative);
+        }
+        calendar.push(ical_event);
+    }
+
+    build_ical_response(
+        render_calendar(&mut calendar),
+        "attachment; filename=\"private-events.ics\"".to_string(),
+        "private, no-store",
+    )
+}
+
 fn build_ical_response_with_filename(
     body: String,
     content_disposition: String,
+) -> Result<Response, AppError> {
+    build_ical_response(body, content_disposition, "public, max-age=3600")
+}
+
+fn build_ical_response(
+    body: String,
+    content_disposition: String,
+    cache_control: &'static str,
 ) -> Result<Response, AppError> {
     Response::builder()
         .status(StatusCode::OK)
         .header("Content-Type", "text/calendar; charset=utf-8")
         .header("Content-Disposition", content_disposition)
-        .header("Cache-Control", "public, max-age=3600")
+        .header("Cache-Control", cache_control)
         .body(axum::body::Body::from(body))
         .map_err(|_| AppError::internal("Failed to build response"))
 }
@@ -366,7 +1149,11 @@ fn extract_bearer_token(headers: &HeaderMap) -> Result<String, AppError> {
 
 async fn validate_api_token(state: &AppState, headers: &HeaderMap) -> Result<(), AppError> {
     let token = extract_bearer_token(headers)?;
-    api_token::authed_user_from_bearer(&token, state).await?;
+    if token.starts_with(api_token::DEVELOPER_KEY_PREFIX) {
+        api_token::authorize_developer_key(&token, state).await?;
+    } else {
+        api_token::authed_user_from_bearer(&token, state, ApiTokenScope::IcalRead).await?;
+    }
     Ok(())
 }
 
@@ -377,6 +1164,7 @@ async fn validate_api_token(state: &AppState, headers: &HeaderMap) -> Result<(),
     params(
         ("organizer_id" = i64, Path, description = "Organizer identifier"),
         ("Authorization" = String, Header, description = "Bearer API token"),
+        IcalFeedQuery,
     ),
     responses((status = 200, description = "Events for organizer that are iCal eligible", body = [IcalEventResponse])),
 )]
@@ -384,9 +1172,11 @@ async fn validate_api_token(state: &AppState, headers: &HeaderMap) -> Result<(),
 pub(crate) async fn list_organizer_ical_events(
     State(state): State<AppState>,
     Path(organizer_id): Path<i64>,
+    Query(query): Query<IcalFeedQuery>,
     headers: HeaderMap,
 ) -> Result<Json<Vec<IcalEventResponse>>, AppError> {
     validate_api_token(&state, &headers).await?;
+    let window = FeedWindow::from_query(&query)?;
 
     let organizer = sqlx::query!(
         r#"
@@ -405,9 +1195,11 @@ pub(crate) async fn list_organizer_ical_events(
     };
 
     let events = sqlx::query_as::<_, Event>(
-        "SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, created_at, updated_at FROM events WHERE organizer_id = $1 AND publish_in_ical = true ORDER BY start_date_time ASC",
+        "SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status, cancelled_at, visibility, series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience, venue_id, featured_until, term, all_day, created_at, updated_at FROM events WHERE organizer_id = $1 AND publish_in_ical = true AND status = 'PUBLISHED' AND visibility = 'PUBLIC' AND (publish_at IS NULL OR publish_at <= NOW()) AND end_date_time >= $2 AND start_date_time < $3 ORDER BY start_date_time ASC",
     )
     .bind(organizer_id)
+    .bind(window.ends_after())
+    .bind(window.starts_before())
     .fetch_all(&state.db)
     .await?;
 
@@ -431,6 +1223,8 @@ pub(crate) async fn list_organizer_ical_events(
                 event_url: event.event_url,
                 location: event.location,
                 is_internal,
+                cancelled_at: event.cancelled_at,
+                all_day: event.all_day,
             }
         })
         .collect();
@@ -443,6 +1237,52 @@ pub(crate) fn router() -> Router<AppState> {
         .route("/", get(get_all_events_ical))
         .route("/cl", get(get_cl_events_ical))
         .route("/thi", get(get_thi_events_ical))
+        .route("/events/{file_name}", get(get_event_ical))
+        .route("/private/{token}", get(get_private_organizer_ical))
+        .route("/feeds/{token}", get(get_signed_organizer_ical))
+        .route("/newsletter/{token}", get(get_newsletter_ical))
         .route("/{organizer_id}", get(get_organizer_events_ical))
         .route("/{organizer_id}/events", get(list_organizer_ical_events))
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn embeds_berlin_vtimezone_before_first_event() {
+        let ics = "BEGIN:VCALENDAR\r\nVERSION:2.0\r\nBEGIN:VEVENT\r\nUID:a\r\nEND:VEVENT\r\nEND:VCALENDAR\r\n";
+        let output = with_berlin_vtimezone(ics.to_string());
+        let timezone = output.find("BEGIN:VTIMEZONE").unwrap();
+        assert!(timezone < output.find("BEGIN:VEVENT").unwrap());
+        assert!(output.contains("TZID:Europe/Berlin\r\n"));
+        assert!(output.ends_with("END:VEVENT\r\nEND:VCALENDAR\r\n"));
+
+        let empty = with_berlin_vtimezone("BEGIN:VCALENDAR\r\nEND:VCALENDAR\r\n".to_string());
+        assert!(empty.contains("END:VTIMEZONE\r\nEND:VCALENDAR"));
+    }
+
+    #[test]
+    fn event_uids_use_configured_domain() {
+        assert_eq!(event_uid(7, None), "campus-life-event-7");
+        assert_eq!(event_uid(7, Some("events.thi.de")), "7@events.thi.de");
+    }
+
+    #[test]
+    fn organizer_feed_tokens_roundtrip_and_reject_tampering() {
+        let key = [3u8; 32];
+        let token = organizer_feed_token(&key, 42, 2);
+        assert_eq!(verify_organizer_feed_token(&key, &token), Some((42, 2)));
+        assert_eq!(verify_organizer_feed_token(&[4u8; 32], &token), None);
+        let forged = token.replacen("42.2.", "43.2.", 1);
+        assert_eq!(verify_organizer_feed_token(&key, &forged), None);
+        let downgraded = token.replacen("42.2.", "42.1.", 1);
+        assert_eq!(verify_organizer_feed_token(&key, &downgraded), None);
+        assert_eq!(verify_newsletter_feed_token(&key, &token), None);
+        let newsletter = newsletter_feed_token(&key, 42, 2);
+        assert_eq!(
+            verify_newsletter_feed_token(&key, &newsletter),
+            Some((42, 2))
+        );
+    }
+}
@@ -0,0 +1,86 @@
+use axum::{Json, Router, extract::State, http::HeaderMap, routing::get};
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    error::AppError,
+    responses::{BrokenLinkResponse, BrokenLinkSource, ErrorResponse},
+};
+
+use super::shared::current_user_from_headers;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/broken-links",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Upcoming event and organizer links that failed their last check", body = [BrokenLinkResponse]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_broken_links(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<BrokenLinkResponse>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let event_links = sqlx::query!(
+        r#"
+        SELECT e.id, e.organizer_id, e.title_de, e.start_date_time, l.url, l.status_code, l.error, l.checked_at
+        FROM events e
+        INNER JOIN link_checks l ON l.url = TRIM(e.event_url)
+        WHERE NOT l.ok AND e.end_date_time >= NOW() AND e.cancelled_at IS NULL
+        ORDER BY e.start_date_time ASC, e.id ASC
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let organizer_links = sqlx::query!(
+        r#"
+        SELECT o.id, o.name, l.url, l.status_code, l.error, l.checked_at
+        FROM organizers o
+        INNER JOIN link_checks l ON l.url = TRIM(o.website_url)
+        WHERE NOT l.ok
+        ORDER BY o.name ASC, o.id ASC
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut links: Vec<BrokenLinkResponse> = event_links
+        .into_iter()
+        .map(|row| BrokenLinkResponse {
+            source: BrokenLinkSource::Event,
+            source_id: row.id,
+            organizer_id: row.organizer_id,
+            name: row.title_de,
+            start_date_time: Some(row.start_date_time),
+            url: row.url,
+            status_code: row.status_code,
+            error: row.error,
+            checked_at: row.checked_at,
+        })
+        .collect();
+    links.extend(organizer_links.into_iter().map(|row| BrokenLinkResponse {
+        source: BrokenLinkSource::Organizer,
+        source_id: row.id,
+        organizer_id: row.id,
+        name: row.name,
+        start_date_time: None,
+        url: row.url,
+        status_code: row.status_code,
+        error: row.error,
+        checked_at: row.checked_at,
+    }));
+
+    Ok(Json(links))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/broken-links", get(list_broken_links))
+}
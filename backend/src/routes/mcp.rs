@@ -18,14 +18,14 @@ use crate::{
     },
     error::AppError,
     models::{
-        AccountType, AdminInviteRow, AdminWithInvite, Event, Organizer, OrganizerInviteRow,
-        OrganizerKind, OrganizerWithInvite,
+        AccountType, AdminInviteRow, AdminWithInvite, ApiTokenScope, Event, Organizer,
+        OrganizerInviteRow, OrganizerKind, OrganizerWithInvite,
     },
 };
 
 use super::events::{
-    create_event_with_user, delete_event_with_user, get_event_with_user, list_events_for_organizer,
-    newsletter_data_with_user, update_event_with_user,
+    cancel_event_with_user, create_event_with_user, delete_event_with_user, get_event_with_user,
+    list_events_for_organizer, newsletter_data_with_user, update_event_with_user,
 };
 use super::organizers::update_organizer_with_user;
 use super::shared::{AuthedUser, generate_setup_token_value, refresh_organizer_activity_stats};
@@ -205,7 +205,7 @@ async fn organizer_or_admin_authed_from_bearer(
     state: &AppState,
 ) -> Result<AuthedUser, AppError> {
     let raw = bearer_token(headers)?;
-    let user = api_token::authed_user_from_bearer(raw, state).await?;
+    let user = api_token::authed_user_from_bearer(raw, state, ApiTokenScope::Full).await?;
     match user.account_type {
         AccountType::Admin => Ok(user),
         AccountType::Organizer if user.organizer_id.is_some() => Ok(user),
@@ -218,7 +218,7 @@ async fn organizer_or_admin_authed_from_bearer(
 async fn fetch_my_club_info(state: &AppState, organizer_id: i64) -> Result<Organizer, AppError> {
     let row = sqlx::query_as::<_, Organizer>(
         r#"
-		SELECT id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, organizer_kind, created_at, updated_at
+		SELECT id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, event_footer_de, event_footer_en, calendar_name, calendar_color, calendar_refresh_minutes, organizer_kind, created_at, updated_at
 		FROM organizers
 		WHERE id = $1
 		"#,
@@ -295,6 +295,7 @@ async fn fetch_clubs_with_invites(state: &AppState) -> Result<Vec<OrganizerWithI
             a.id AS account_id,
             a.email AS account_email,
             o.newsletter AS newsletter,
+            o.needs_review,
             o.organizer_kind,
             o.created_at,
             o.updated_at,
@@ -318,7 +319,7 @@ async fn fetch_clubs_with_invites(state: &AppState) -> Result<Vec<OrganizerWithI
 
 async fn fetch_my_events(state: &AppState, organizer_id: i64) -> Result<Vec<Event>, AppError> {
     let rows = sqlx::query_as::<_, Event>(
-		"SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, created_at, updated_at FROM events WHERE organizer_id = $1 ORDER BY start_date_time ASC",
+		"SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status, cancelled_at, visibility, series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience, venue_id, featured_until, term, all_day, created_at, updated_at FROM events WHERE organizer_id = $1 ORDER BY start_date_time ASC",
 	)
 	.bind(organizer_id)
 	.fetch_all(&state.db)
@@ -401,7 +402,20 @@ fn tool_schema_create_my_event() -> Value {
                 "publish_app": { "type": "boolean" },
                 "publish_newsletter": { "type": "boolean" },
                 "publish_in_ical": { "type": "boolean" },
-                "publish_web": { "type": "boolean" }
+                "publish_web": { "type": "boolean" },
+                "max_participants": { "type": "integer", "minimum": 1 },
+                "status": { "type": "string", "enum": ["DRAFT", "PUBLISHED"] },
+                "visibility": { "type": "string", "enum": ["PUBLIC", "UNLISTED"] },
+                "series_id": { "type": "integer" },
+                "publish_at": { "type": "string", "format": "date-time", "description": "Keep the event hidden from public listings, iCal and newsletter until this time" },
+                "custom_fields": { "type": "object", "description": "Values for admin-defined custom fields keyed by field key" },
+                "price_cents": { "type": "integer", "minimum": 0, "description": "Admission price in cents" },
+                "currency": { "type": "string", "description": "ISO 4217 currency code (default EUR)" },
+                "free_entry": { "type": "boolean", "description": "Whether admission is free" },
+                "audience": { "type": "array", "items": { "type": "string", "enum": ["STUDENTS", "STAFF", "ALUMNI", "PUBLIC"] }, "description": "Target audiences (default: all)" },
+                "venue_id": { "type": "integer", "description": "Structured venue identifier; fills location when omitted" },
+                "term": { "type": "string", "description": "Semester such as SS26 or WS25/26 (default: derived from start_date_time)" },
+                "all_day": { "type": "boolean", "description": "Whole-day event; times are widened to full days in Europe/Berlin" }
             },
             "additionalProperties": false
         }
@@ -441,7 +455,20 @@ fn tool_schema_update_my_event() -> Value {
                 "publish_app": { "type": "boolean" },
                 "publish_newsletter": { "type": "boolean" },
                 "publish_in_ical": { "type": "boolean" },
-                "publish_web": { "type": "boolean" }
+                "publish_web": { "type": "boolean" },
+                "max_participants": { "type": ["integer", "null"], "minimum": 1, "description": "Registration limit; null removes it. Must not be below the current number of registrations" },
+                "status": { "type": "string", "enum": ["DRAFT", "PUBLISHED"] },
+                "visibility": { "type": "string", "enum": ["PUBLIC", "UNLISTED"] },
+                "series_id": { "type": "integer" },
+                "publish_at": { "type": "string", "format": "date-time", "description": "Keep the event hidden from public listings, iCal and newsletter until this time" },
+                "custom_fields": { "type": "object", "description": "Values for admin-defined custom fields keyed by field key" },
+                "price_cents": { "type": "integer", "minimum": 0, "description": "Admission price in cents" },
+                "currency": { "type": "string", "description": "ISO 4217 currency code (default EUR)" },
+                "free_entry": { "type": "boolean", "description": "Whether admission is free" },
+                "audience": { "type": "array", "items": { "type": "string", "enum": ["STUDENTS", "STAFF", "ALUMNI", "PUBLIC"] }, "description": "Target audiences (default: all)" },
+                "venue_id": { "type": "integer", "description": "Structured venue identifier; fills location when omitted" },
+                "term": { "type": "string", "description": "Semester such as SS26 or WS25/26 (default: derived from start_date_time)" },
+                "all_day": { "type": "boolean", "description": "Whole-day event; times are widened to full days in Europe/Berlin" }
             },
             "additionalProperties": false
         }
@@ -461,6 +488,19 @@ fn tool_schema_delete_my_event() -> Value {
     })
 }
 
+fn tool_schema_cancel_my_event() -> Value {
+    json!({
+        "name": "cancel_my_event",
+        "description": "Mark an event as cancelled (your club only). The event stays visible with a cancellation notice.",
+        "inputSchema": {
+            "type": "object",
+            "required": ["id"],
+            "properties": { "id": { "type": "integer" } },
+            "additionalProperties": false
+        }
+    })
+}
+
 fn tool_schema_list_my_events_filtered() -> Value {
     json!({
         "name": "list_my_events_filtered",
@@ -484,7 +524,9 @@ fn tool_schema_newsletter_upcoming_summary() -> Value {
         "inputSchema": {
             "type": "object",
             "properties": {
-                "week_start": { "type": "string", "description": "YYYY-MM-DD" }
+                "week_start": { "type": "string", "description": "YYYY-MM-DD" },
+                "grouping": { "type": "string", "enum": ["DAY", "ORGANIZER"], "description": "Overrides the grouping stored for the issue" },
+                "ordering": { "type": "string", "enum": ["START_TIME", "TITLE", "ORGANIZER"], "description": "Overrides the ordering stored for the issue" }
             },
             "additionalProperties": false
         }
@@ -506,7 +548,12 @@ fn tool_schema_update_my_club_profile() -> Value {
                 "location": { "type": "string" },
                 "linkedin_url": { "type": "string" },
                 "registration_number": { "type": "string" },
-                "non_profit": { "type": "boolean" }
+                "non_profit": { "type": "boolean" },
+                "event_footer_de": { "type": "string", "description": "Text appended to every event description in newsletter and public listings; empty string clears it" },
+                "event_footer_en": { "type": "string", "description": "English variant of event_footer_de" },
+                "calendar_name": { "type": "string", "description": "Display name of your iCal feed; empty string restores the default" },
+                "calendar_color": { "type": "string", "description": "Calendar color hint as #RRGGBB; empty string clears it" },
+                "calendar_refresh_minutes": { "type": "integer", "minimum": 0, "maximum": 10080, "description": "Suggested feed refresh interval in minutes (15-10080); 0 restores the default" }
             },
             "additionalProperties": false
         }
@@ -536,6 +583,7 @@ fn mcp_tools_list_result() -> Value {
             tool_schema_get_my_event(),
             tool_schema_update_my_event(),
             tool_schema_delete_my_event(),
+            tool_schema_cancel_my_event(),
             tool_schema_list_my_events_filtered(),
             tool_schema_newsletter_upcoming_summary(),
             tool_schema_update_my_club_profile(),
@@ -711,7 +759,7 @@ async fn handle_post(
                         r#"
                         INSERT INTO organizers (name, organizer_kind)
                         VALUES ($1, $2)
-                        RETURNING id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, organizer_kind, created_at, updated_at
+                        RETURNING id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, event_footer_de, event_footer_en, calendar_name, calendar_color, calendar_refresh_minutes, organizer_kind, created_at, updated_at
                         "#,
                     )
                     .bind(&payload.name)
@@ -819,6 +867,16 @@ async fn handle_post(
                         .map_err(|_| internal_error(id.clone(), "serialize"))?;
                     tool_text_result(v).map_err(|e| internal_error(id.clone(), e))
                 }
+                "cancel_my_event" => {
+                    let args: EventIdArgs = serde_json::from_value(params.arguments)
+                        .map_err(|_| invalid_request(id.clone(), "invalid arguments"))?;
+                    let event = cancel_event_with_user(&state, &user, args.id)
+                        .await
+                        .map_err(|e| mcp_from_app_error(id.clone(), e))?;
+                    let v = serde_json::to_value(event)
+                        .map_err(|_| internal_error(id.clone(), "serialize"))?;
+                    tool_text_result(v).map_err(|e| internal_error(id.clone(), e))
+                }
                 "list_my_events_filtered" => {
                     let Some(organizer_id) = organizer_id else {
                         return Err(mcp_from_app_error(
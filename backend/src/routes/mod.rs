@@ -1,14 +1,50 @@
+pub(crate) mod abuse;
+pub(crate) mod academic_periods;
 pub(crate) mod admin;
+pub(crate) mod agenda;
 pub(crate) mod api_tokens;
 pub(crate) mod audit;
 pub(crate) mod auth;
+pub(crate) mod conflicts;
+pub(crate) mod custom_fields;
+pub(crate) mod debug_timings;
+pub(crate) mod developers;
+pub(crate) mod email_log;
+pub(crate) mod event_clone;
+pub(crate) mod event_import;
+pub(crate) mod event_og_image;
+pub(crate) mod event_quota;
+pub(crate) mod event_slugs;
+pub(crate) mod event_stream;
+pub(crate) mod event_templates;
+pub(crate) mod event_views;
 pub(crate) mod events;
 pub(crate) mod health;
 pub(crate) mod ical;
+pub(crate) mod link_checks;
 pub(crate) mod mcp;
+pub(crate) mod moderation;
+pub(crate) mod newsletter;
+pub(crate) mod newsletter_changes;
+pub(crate) mod newsletter_template;
+pub(crate) mod onboarding;
+pub(crate) mod organizer_snapshots;
 pub(crate) mod organizers;
+pub(crate) mod price_tiers;
+pub(crate) mod private_feeds;
 pub(crate) mod public_events;
+pub(crate) mod public_search;
+pub(crate) mod registrations;
+pub(crate) mod series;
 mod shared;
+pub(crate) mod short_links;
+pub(crate) mod sitemap;
+pub(crate) mod speakers;
+pub(crate) mod subscriptions;
+pub(crate) mod translation_report;
+pub(crate) mod translations;
+pub(crate) mod venues;
+pub(crate) mod webhooks;
 
 use axum::Router;
 
@@ -23,4 +59,13 @@ pub fn api_router() -> Router<AppState> {
         .nest("/organizers", organizers::router())
         .nest("/audit-logs", audit::router())
         .nest("/public", public_events::router())
+        .nest("/short-links", short_links::router())
+        .nest("/speakers", speakers::router())
+        .nest("/series", series::router())
+        .nest("/event-templates", event_templates::router())
+        .nest("/custom-fields", custom_fields::router())
+        .nest("/developer", developers::router())
+        .nest("/newsletter", newsletter::router())
+        .nest("/venues", venues::router())
+        .nest("/webhooks", webhooks::router())
 }
@@ -0,0 +1,356 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::HeaderMap,
+    routing::{get, post, put},
+};
+use chrono::Utc;
+use tracing::{error, info, instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::{FeatureEventRequest, RejectEventRequest},
+    error::AppError,
+    models::{AuditType, Event, EventAudience, EventStatus, EventVisibility, EventWithOrganizer},
+    responses::ErrorResponse,
+};
+
+use super::{
+    events::{invalidate_public_event_caches, record_audit},
+    shared::{AuthedUser, current_user_from_headers},
+};
+
+const MAX_REJECTION_REASON_LEN: usize = 2000;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/events/pending",
+    tag = "Admin",
+    responses(
+        (status = 200, description = "Events waiting for review", body = [EventWithOrganizer]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_pending_events(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<EventWithOrganizer>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let events = sqlx::query_as!(
+        EventWithOrganizer,
+        r#"
+        SELECT e.id, e.organizer_id, e.title_de, e.title_en, e.description_de, e.description_en,
+               e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_app,
+               e.publish_newsletter, e.publish_in_ical, e.publish_web, e.max_participants, e.status as "status: EventStatus", e.cancelled_at, e.visibility as "visibility: EventVisibility", e.series_id, e.publish_at, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.venue_id, e.featured_until, e.term, e.all_day, e.created_at, e.updated_at,
+               o.name as organizer_name, o.website_url as organizer_website
+        FROM events e
+        JOIN organizers o ON e.organizer_id = o.id
+        WHERE e.status = 'PENDING_REVIEW'
+        ORDER BY e.updated_at ASC
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(events))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/events/{id}/approve",
+    tag = "Admin",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Event approved and published", body = Event),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "Event is not pending review", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn approve_event(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<Event>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let event = review_event(&state, &user, id, EventStatus::Published).await?;
+
+    notify_organizer(&state, &event, true, None).await;
+
+    Ok(Json(event))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/admin/events/{id}/reject",
+    tag = "Admin",
+    params(("id" = i64, Path, description = "Event identifier")),
+    request_body = RejectEventRequest,
+    responses(
+        (status = 200, description = "Event rejected", body = Event),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 409, description = "Event is not pending review", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn reject_event(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<RejectEventRequest>,
+) -> Result<Json<Event>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let reason = payload
+        .reason
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    if let Some(reason) = reason
+        && reason.chars().count() > MAX_REJECTION_REASON_LEN
+    {
+        return Err(AppError::validation(format!(
+            "reason must be at most {MAX_REJECTION_REASON_LEN} characters"
+        )));
+    }
+
+    let event = review_event(&state, &user, id, EventStatus::Rejected).await?;
+
+    notify_organizer(&state, &event, false, reason).await;
+
+    Ok(Json(event))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/events/{id}/featured",
+    tag = "Admin",
+    params(("id" = i64, Path, description = "Event identifier")),
+    request_body = FeatureEventRequest,
+    responses(
+        (status = 200, description = "Event pinned until the given time, or unpinned when null", body = Event),
+        (status = 400, description = "featured_until lies in the past", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn set_event_featured(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<FeatureEventRequest>,
+) -> Result<Json<Event>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+    if payload
+        .featured_until
+        .is_some_and(|featured_until| featured_until <= Utc::now())
+    {
+        return Err(AppError::validation("featured_until must be in the future"));
+    }
+
+    let mut transaction = state.db.begin().await?;
+
+    let existing_event = sqlx::query_as!(
+        Event,
+        r#"
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+        FROM events
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_optional(&mut *transaction)
+    .await?
+    .ok_or_else(|| AppError::not_found("event not found"))?;
+
+    let updated_event = sqlx::query_as!(
+        Event,
+        r#"
+        UPDATE events
+        SET featured_until = $1, updated_at = NOW()
+        WHERE id = $2
+        RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+        "#,
+        payload.featured_until,
+        id
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+
+    record_audit(
+        &mut transaction,
+        updated_event.id,
+        updated_event.organizer_id,
+        user.account_id,
+        AuditType::Update,
+        Some(&existing_event),
+        Some(&updated_event),
+    )
+    .await?;
+
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(
+        target: "moderation",
+        action = "feature",
+        event_id = id,
+        featured_until = ?updated_event.featured_until,
+        "Event featuring updated"
+    );
+
+    Ok(Json(updated_event))
+}
+
+async fn review_event(
+    state: &AppState,
+    user: &AuthedUser,
+    id: i64,
+    decision: EventStatus,
+) -> Result<Event, AppError> {
+    let mut transaction = state.db.begin().await?;
+
+    let existing_event = sqlx::query_as!(
+        Event,
+        r#"
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+        FROM events
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
+
+    let Some(existing_event) = existing_event else {
+        return Err(AppError::not_found("event not found"));
+    };
+
+    if existing_event.status != EventStatus::PendingReview {
+        return Err(AppError::conflict("event is not pending review"));
+    }
+
+    let updated_event = sqlx::query_as!(
+        Event,
+        r#"
+        UPDATE events
+        SET status = $1, updated_at = NOW(), revision = revision + 1
+        WHERE id = $2
+        RETURNING id, organizer_id, title_de, title_en, description_de, description_en, start_date_time, end_date_time, event_url, location, publish_app, publish_newsletter, publish_in_ical, publish_web, max_participants, status as "status: EventStatus", cancelled_at, visibility as "visibility: EventVisibility", series_id, publish_at, custom_fields, price_cents, currency, free_entry, audience as "audience: Vec<EventAudience>", venue_id, featured_until, term, all_day, created_at, updated_at
+        "#,
+        decision as EventStatus,
+        id
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+
+    record_audit(
+        &mut transaction,
+        updated_event.id,
+        updated_event.organizer_id,
+        user.account_id,
+        AuditType::Update,
+        Some(&existing_event),
+        Some(&updated_event),
+    )
+    .await?;
+
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(state).await;
+
+    info!(
+        target: "moderation",
+        action = "review",
+        event_id = id,
+        decision = ?decision,
+        "Event review decision recorded"
+    );
+
+    Ok(updated_event)
+}
+
+async fn notify_organizer(state: &AppState, event: &Event, approved: bool, reason: Option<&str>) {
+    let Some(email_client) = &state.email else {
+        warn!("email client not configured; event review notification not sent");
+        return;
+    };
+
+    let recipient = match sqlx::query!(
+        r#"
+        SELECT o.name, a.email as "email?"
+        FROM organizers o
+        LEFT JOIN accounts a
+            ON a.organizer_id = o.id AND a.account_type = 'ORGANIZER'
+        WHERE o.id = $1
+        "#,
+        event.organizer_id
+    )
+    .fetch_optional(&state.db)
+    .await
+    {
+        Ok(recipient) => recipient,
+        Err(err) => {
+            error!(error = %err, event_id = event.id, "failed to load organizer for review notification");
+            return;
+        }
+    };
+
+    let Some((organizer_name, Some(recipient_email))) = recipient.map(|row| (row.name, row.email))
+    else {
+        warn!(
+            event_id = event.id,
+            "organizer has no account email; event review notification not sent"
+        );
+        return;
+    };
+
+    let event_title = if event.title_de.is_empty() {
+        &event.title_en
+    } else {
+        &event.title_de
+    };
+
+    if let Err(err) = email_client
+        .send_event_review_decision(
+            &recipient_email,
+            &organizer_name,
+            event_title,
+            approved,
+            reason,
+        )
+        .await
+    {
+        error!(error = %err, event_id = event.id, "failed to send event review notification");
+    }
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/events/pending", get(list_pending_events))
+        .route("/events/{id}/approve", post(approve_event))
+        .route("/events/{id}/featured", put(set_event_featured))
+        .route("/events/{id}/reject", post(reject_event))
+}
@@ -0,0 +1,1019 @@
+use std::cmp::Ordering;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    routing::{get, post, put},
+};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use chrono_tz::Europe::Berlin;
+use tracing::{info, instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::{
+        NewsletterArchiveQuery, NewsletterDataQuery, NewsletterSponsorRequest,
+        SendNewsletterRequest, UpdateNewsletterIssueRequest, UpdateNewsletterIssueTextsRequest,
+    },
+    email::recipient_hash,
+    error::AppError,
+    models::{
+        EventWithOrganizer, NewsletterGrouping, NewsletterLayout, NewsletterOrdering,
+        NewsletterSponsor,
+    },
+    newsletter_delivery::{
+        NewsletterDeliverySettings, active_newsletter_recipients, deliver_newsletter,
+        verify_unsubscribe_token,
+    },
+    newsletter_render::{NewsletterVariant, RenderedNewsletter, german_weekday, render_newsletter},
+    responses::{
+        ErrorResponse, NewsletterArchiveEntry, NewsletterDataResponse, NewsletterEventGroup,
+        NewsletterEventResponse, NewsletterIssueResponse, NewsletterIssueTextsResponse,
+        NewsletterSendResponse, PublicNewsletterIssueResponse, PublicNewsletterIssueSummary,
+    },
+};
+
+use super::{
+    events::{ensure_newsletter_access, newsletter_data},
+    newsletter_template::current_newsletter_templates,
+    shared::{current_user_from_headers, validate_http_url},
+};
+
+const MAX_SPONSOR_NAME_LEN: usize = 100;
+const STALE_SEND_CLAIM_MINUTES: i32 = 60;
+const MAX_ISSUE_TEXT_LEN: usize = 5000;
+const DEFAULT_ARCHIVE_LIMIT: i64 = 20;
+const MAX_ARCHIVE_LIMIT: i64 = 100;
+
+struct NormalizedSponsor {
+    name: String,
+    logo_url: Option<String>,
+    website_url: Option<String>,
+}
+
+fn normalize_sponsor(payload: &NewsletterSponsorRequest) -> Result<NormalizedSponsor, AppError> {
+    let name = payload.name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_SPONSOR_NAME_LEN {
+        return Err(AppError::validation(format!(
+            "name must be between 1 and {MAX_SPONSOR_NAME_LEN} characters"
+        )));
+    }
+    if let (Some(active_from), Some(active_until)) = (payload.active_from, payload.active_until)
+        && active_until < active_from
+    {
+        return Err(AppError::validation(
+            "active_until must not be before active_from",
+        ));
+    }
+    let optional_url = |value: Option<&str>, field: &str| {
+        value
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(|value| validate_http_url(value, field))
+            .transpose()
+    };
+
+    Ok(NormalizedSponsor {
+        name,
+        logo_url: optional_url(payload.logo_url.as_deref(), "logo_url")?,
+        website_url: optional_url(payload.website_url.as_deref(), "website_url")?,
+    })
+}
+
+pub(crate) async fn active_newsletter_sponsors(
+    state: &AppState,
+    week_start: NaiveDate,
+) -> Result<Vec<NewsletterSponsor>, AppError> {
+    let sponsors = sqlx::query_as!(
+        NewsletterSponsor,
+        r#"
+        SELECT id, name, logo_url, website_url, position, active_from, active_until, created_at, updated_at
+        FROM newsletter_sponsors
+        WHERE (active_from IS NULL OR active_from <= $1)
+          AND (active_until IS NULL OR active_until >= $1)
+        ORDER BY position ASC, name ASC, id ASC
+        "#,
+        week_start
+    )
+    .fetch_all(&state.db)
+    .await?;
+    Ok(sponsors)
+}
+
+pub(crate) fn parse_newsletter_week_start(value: &str) -> Result<NaiveDate, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(AppError::validation("week_start cannot be empty"));
+    }
+    let parsed_date = NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| AppError::validation("invalid week_start, expected YYYY-MM-DD"))?;
+    Ok(parsed_date - Duration::days(parsed_date.weekday().num_days_from_monday() as i64))
+}
+
+pub(crate) async fn newsletter_issue_layout(
+    state: &AppState,
+    week_start: NaiveDate,
+) -> Result<(NewsletterGrouping, NewsletterOrdering), AppError> {
+    let layout = sqlx::query!(
+        r#"
+        SELECT grouping AS "grouping: NewsletterGrouping", ordering AS "ordering: NewsletterOrdering"
+        FROM newsletter_issues
+        WHERE week_start = $1
+        "#,
+        week_start
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    Ok(layout
+        .map(|layout| (layout.grouping, layout.ordering))
+        .unwrap_or_default())
+}
+
+pub(crate) async fn newsletter_issue_texts(
+    state: &AppState,
+    week_start: NaiveDate,
+) -> Result<NewsletterIssueTextsResponse, AppError> {
+    let texts = sqlx::query_as!(
+        NewsletterIssueTextsResponse,
+        r#"
+        SELECT week_start, intro_de, intro_en, outro_de, outro_en, updated_at AS "updated_at?"
+        FROM newsletter_issues
+        WHERE week_start = $1
+        "#,
+        week_start
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    Ok(texts.unwrap_or(NewsletterIssueTextsResponse {
+        week_start,
+        ..Default::default()
+    }))
+}
+
+fn normalize_issue_text(name: &str, value: Option<String>) -> Result<Option<String>, AppError> {
+    let value = value
+        .map(|value| value.trim().to_string())
+        .filter(|value| !value.is_empty());
+    if value
+        .as_ref()
+        .is_some_and(|value| value.chars().count() > MAX_ISSUE_TEXT_LEN)
+    {
+        return Err(AppError::validation(format!(
+            "{name} must be at most {MAX_ISSUE_TEXT_LEN} characters"
+        )));
+    }
+    Ok(value)
+}
+
+fn local_day(event: &NewsletterEventResponse) -> NaiveDate {
+    event
+        .event
+        .start_date_time
+        .with_timezone(&Berlin)
+        .date_naive()
+}
+
+fn compare_events(
+    a: &NewsletterEventResponse,
+    b: &NewsletterEventResponse,
+    grouping: NewsletterGrouping,
+    ordering: NewsletterOrdering,
+) -> Ordering {
+    let group = match grouping {
+        NewsletterGrouping::Day => local_day(a).cmp(&local_day(b)),
+        NewsletterGrouping::Organizer => a
+            .event
+            .organizer_name
+            .to_lowercase()
+            .cmp(&b.event.organizer_name.to_lowercase())
+            .then(a.event.organizer_id.cmp(&b.event.organizer_id)),
+    };
+    let within_group = match ordering {
+        NewsletterOrdering::StartTime => Ordering::Equal,
+        NewsletterOrdering::Title => a
+            .event
+            .title_de
+            .to_lowercase()
+            .cmp(&b.event.title_de.to_lowercase()),
+        NewsletterOrdering::Organizer => a
+            .event
+            .organizer_name
+            .to_lowercase()
+            .cmp(&b.event.organizer_name.to_lowercase()),
+    };
+    group
+        .then(within_group)
+        .then(a.event.start_date_time.cmp(&b.event.start_date_time))
+        .then(a.event.id.cmp(&b.event.id))
+}
+
+pub(crate) fn arrange_newsletter_events(
+    events: &mut [NewsletterEventResponse],
+    grouping: NewsletterGrouping,
+    ordering: NewsletterOrdering,
+) -> Vec<NewsletterEventGroup> {
+    events.sort_by(|a, b| compare_events(a, b, grouping, ordering));
+
+    let mut groups: Vec<NewsletterEventGroup> = Vec::new();
+    for event in events.iter() {
+        let (key, label_de, label_en) = match grouping {
+            NewsletterGrouping::Day => {
+                let day = local_day(event);
+                (
+                    day.format("%Y-%m-%d").to_string(),
+                    format!(
+                        "{}, {}",
+                        german_weekday(day.weekday()),
+                        day.format("%d.%m.%Y")
+                    ),
+                    day.format("%A, %-d %B %Y").to_string(),
+                )
+            }
+            NewsletterGrouping::Organizer => (
+                event.event.organizer_id.to_string(),
+                event.event.organizer_name.clone(),
+                event.event.organizer_name.clone(),
+            ),
+        };
+
+        match groups.last_mut() {
+            Some(group) if group.key == key => group.event_ids.push(event.event.id),
+            _ => groups.push(NewsletterEventGroup {
+                key,
+                label_de,
+                label_en,
+                event_ids: vec![event.event.id],
+            }),
+        }
+    }
+    groups
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/newsletter/issues/{week_start}",
+    tag = "Events",
+    params(("week_start" = String, Path, description = "Any date in the issue week (YYYY-MM-DD)")),
+    responses(
+        (status = 200, description = "Grouping and ordering of the newsletter issue", body = NewsletterIssueResponse),
+        (status = 400, description = "Invalid week_start", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_newsletter_issue(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(week_start): Path<String>,
+) -> Result<Json<NewsletterIssueResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_newsletter_access(&user, &state).await?;
+    let week_start = parse_newsletter_week_start(&week_start)?;
+
+    let issue = sqlx::query_as!(
+        NewsletterIssueResponse,
+        r#"
+        SELECT week_start, grouping AS "grouping: NewsletterGrouping", ordering AS "ordering: NewsletterOrdering", updated_at AS "updated_at?"
+        FROM newsletter_issues
+        WHERE week_start = $1
+        "#,
+        week_start
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    Ok(Json(issue.unwrap_or(NewsletterIssueResponse {
+        week_start,
+        grouping: NewsletterGrouping::default(),
+        ordering: NewsletterOrdering::default(),
+        updated_at: None,
+    })))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/newsletter/issues/{week_start}",
+    tag = "Events",
+    params(("week_start" = String, Path, description = "Any date in the issue week (YYYY-MM-DD)")),
+    request_body = UpdateNewsletterIssueRequest,
+    responses(
+        (status = 200, description = "Newsletter issue layout stored", body = NewsletterIssueResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_newsletter_issue(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(week_start): Path<String>,
+    Json(payload): Json<UpdateNewsletterIssueRequest>,
+) -> Result<Json<NewsletterIssueResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+    let week_start = parse_newsletter_week_start(&week_start)?;
+
+    let issue = sqlx::query_as!(
+        NewsletterIssueResponse,
+        r#"
+        INSERT INTO newsletter_issues (week_start, grouping, ordering, updated_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (week_start) DO UPDATE
+        SET grouping = EXCLUDED.grouping,
+            ordering = EXCLUDED.ordering,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        RETURNING week_start, grouping AS "grouping: NewsletterGrouping", ordering AS "ordering: NewsletterOrdering", updated_at AS "updated_at?"
+        "#,
+        week_start,
+        payload.grouping as NewsletterGrouping,
+        payload.ordering as NewsletterOrdering,
+        user.account_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(
+        target: "newsletter",
+        action = "update_issue",
+        week_start = %week_start,
+        grouping = ?issue.grouping,
+        ordering = ?issue.ordering,
+        "Newsletter issue layout updated"
+    );
+
+    Ok(Json(issue))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/newsletter/issues/{week_start}/texts",
+    tag = "Events",
+    params(("week_start" = String, Path, description = "Any date in the issue week (YYYY-MM-DD)")),
+    responses(
+        (status = 200, description = "Intro and outro texts of the newsletter issue", body = NewsletterIssueTextsResponse),
+        (status = 400, description = "Invalid week_start", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_newsletter_issue_texts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(week_start): Path<String>,
+) -> Result<Json<NewsletterIssueTextsResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_newsletter_access(&user, &state).await?;
+    let week_start = parse_newsletter_week_start(&week_start)?;
+
+    Ok(Json(newsletter_issue_texts(&state, week_start).await?))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/newsletter/issues/{week_start}/texts",
+    tag = "Events",
+    params(("week_start" = String, Path, description = "Any date in the issue week (YYYY-MM-DD)")),
+    request_body = UpdateNewsletterIssueTextsRequest,
+    responses(
+        (status = 200, description = "Intro and outro texts stored", body = NewsletterIssueTextsResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Newsletter for this week was already sent", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_newsletter_issue_texts(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(week_start): Path<String>,
+    Json(payload): Json<UpdateNewsletterIssueTextsRequest>,
+) -> Result<Json<NewsletterIssueTextsResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_newsletter_access(&user, &state).await?;
+    let week_start = parse_newsletter_week_start(&week_start)?;
+
+    let intro_de = normalize_issue_text("intro_de", payload.intro_de)?;
+    let intro_en = normalize_issue_text("intro_en", payload.intro_en)?;
+    let outro_de = normalize_issue_text("outro_de", payload.outro_de)?;
+    let outro_en = normalize_issue_text("outro_en", payload.outro_en)?;
+
+    let texts = sqlx::query_as!(
+        NewsletterIssueTextsResponse,
+        r#"
+        INSERT INTO newsletter_issues (week_start, intro_de, intro_en, outro_de, outro_en, updated_by)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        ON CONFLICT (week_start) DO UPDATE
+        SET intro_de = EXCLUDED.intro_de,
+            intro_en = EXCLUDED.intro_en,
+            outro_de = EXCLUDED.outro_de,
+            outro_en = EXCLUDED.outro_en,
+            updated_by = EXCLUDED.updated_by,
+            updated_at = NOW()
+        WHERE newsletter_issues.sent_at IS NULL
+        RETURNING week_start, intro_de, intro_en, outro_de, outro_en, updated_at AS "updated_at?"
+        "#,
+        week_start,
+        intro_de,
+        intro_en,
+        outro_de,
+        outro_en,
+        user.account_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::conflict("newsletter for this week was already sent"))?;
+
+    info!(
+        target: "newsletter",
+        action = "update_issue_texts",
+        account_id = user.account_id,
+        week_start = %week_start,
+        "Newsletter issue texts updated"
+    );
+
+    Ok(Json(texts))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/newsletter/sponsors",
+    tag = "Events",
+    responses(
+        (status = 200, description = "All sponsor and partner entries for the newsletter footer", body = [NewsletterSponsor]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_newsletter_sponsors(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<NewsletterSponsor>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let sponsors = sqlx::query_as!(
+        NewsletterSponsor,
+        r#"
+        SELECT id, name, logo_url, website_url, position, active_from, active_until, created_at, updated_at
+        FROM newsletter_sponsors
+        ORDER BY position ASC, name ASC, id ASC
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(sponsors))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/newsletter/sponsors",
+    tag = "Events",
+    request_body = NewsletterSponsorRequest,
+    responses(
+        (status = 201, description = "Sponsor created", body = NewsletterSponsor),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_newsletter_sponsor(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<NewsletterSponsorRequest>,
+) -> Result<(StatusCode, Json<NewsletterSponsor>), AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let sponsor = normalize_sponsor(&payload)?;
+    let sponsor = sqlx::query_as!(
+        NewsletterSponsor,
+        r#"
+        INSERT INTO newsletter_sponsors (name, logo_url, website_url, position, active_from, active_until)
+        VALUES ($1, $2, $3, $4, $5, $6)
+        RETURNING id, name, logo_url, website_url, position, active_from, active_until, created_at, updated_at
+        "#,
+        sponsor.name,
+        sponsor.logo_url,
+        sponsor.website_url,
+        payload.position,
+        payload.active_from,
+        payload.active_until
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "newsletter", action = "create_sponsor", sponsor_id = sponsor.id, "Newsletter sponsor created");
+
+    Ok((StatusCode::CREATED, Json(sponsor)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/newsletter/sponsors/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Sponsor identifier")),
+    request_body = NewsletterSponsorRequest,
+    responses(
+        (status = 200, description = "Sponsor updated", body = NewsletterSponsor),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Sponsor not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_newsletter_sponsor(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<NewsletterSponsorRequest>,
+) -> Result<Json<NewsletterSponsor>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let sponsor = normalize_sponsor(&payload)?;
+    let sponsor = sqlx::query_as!(
+        NewsletterSponsor,
+        r#"
+        UPDATE newsletter_sponsors
+        SET name = $2, logo_url = $3, website_url = $4, position = $5, active_from = $6, active_until = $7, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, name, logo_url, website_url, position, active_from, active_until, created_at, updated_at
+        "#,
+        id,
+        sponsor.name,
+        sponsor.logo_url,
+        sponsor.website_url,
+        payload.position,
+        payload.active_from,
+        payload.active_until
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("sponsor not found"))?;
+
+    info!(target: "newsletter", action = "update_sponsor", sponsor_id = id, "Newsletter sponsor updated");
+
+    Ok(Json(sponsor))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/newsletter/sponsors/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Sponsor identifier")),
+    responses(
+        (status = 204, description = "Sponsor deleted"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Sponsor not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_newsletter_sponsor(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let result = sqlx::query!("DELETE FROM newsletter_sponsors WHERE id = $1", id)
+        .execute(&state.db)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("sponsor not found"));
+    }
+
+    info!(target: "newsletter", action = "delete_sponsor", sponsor_id = id, "Newsletter sponsor deleted");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/newsletter/send",
+    tag = "Events",
+    request_body = SendNewsletterRequest,
+    responses(
+        (status = 200, description = "Newsletter rendered and delivered to the configured recipients", body = NewsletterSendResponse),
+        (status = 400, description = "Invalid week_start", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Newsletter for this week already sent or currently sending", body = ErrorResponse),
+        (status = 500, description = "Delivery failed", body = ErrorResponse),
+        (status = 503, description = "Email delivery or newsletter recipients not configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn send_newsletter(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<SendNewsletterRequest>,
+) -> Result<Json<NewsletterSendResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_newsletter_access(&user, &state).await?;
+
+    let data = newsletter_data(
+        &state,
+        NewsletterDataQuery {
+            week_start: payload.week_start,
+            grouping: None,
+            ordering: None,
+        },
+    )
+    .await?;
+    let sent = send_newsletter_issue(&state, data, Some(user.account_id)).await?;
+
+    Ok(Json(sent))
+}
+
+pub(crate) struct NewsletterRenderings {
+    pub primary: RenderedNewsletter,
+    pub german: RenderedNewsletter,
+    pub english: RenderedNewsletter,
+}
+
+pub(crate) async fn render_newsletter_variants(
+    state: &AppState,
+    data: &NewsletterDataResponse,
+    layout: NewsletterLayout,
+) -> Result<NewsletterRenderings, AppError> {
+    let (german_copy, english_copy) = current_newsletter_templates(state).await?;
+    let render = |variant| {
+        render_newsletter(data, variant, &german_copy, &english_copy)
+            .map_err(|err| AppError::internal(format!("failed to render newsletter: {err}")))
+    };
+    let german = render(NewsletterVariant::German)?;
+    let english = render(NewsletterVariant::English)?;
+    let primary = match layout {
+        NewsletterLayout::Separate => german.clone(),
+        NewsletterLayout::Combined => render(NewsletterVariant::Combined)?,
+    };
+    Ok(NewsletterRenderings {
+        primary,
+        german,
+        english,
+    })
+}
+
+async fn record_issue_events(
+    state: &AppState,
+    week_start: NaiveDate,
+    events: &[&EventWithOrganizer],
+) -> Result<(), AppError> {
+    let event_ids: Vec<i64> = events.iter().map(|event| event.id).collect();
+    let titles_de: Vec<String> = events.iter().map(|event| event.title_de.clone()).collect();
+    let titles_en: Vec<String> = events.iter().map(|event| event.title_en.clone()).collect();
+    let starts: Vec<DateTime<Utc>> = events.iter().map(|event| event.start_date_time).collect();
+    let ends: Vec<DateTime<Utc>> = events.iter().map(|event| event.end_date_time).collect();
+
+    sqlx::query!(
+        r#"
+        INSERT INTO newsletter_issue_events (week_start, event_id, title_de, title_en, start_date_time, end_date_time)
+        SELECT $1, s.event_id, s.title_de, s.title_en, s.start_date_time, s.end_date_time
+        FROM UNNEST($2::BIGINT[], $3::TEXT[], $4::TEXT[], $5::TIMESTAMPTZ[], $6::TIMESTAMPTZ[])
+            AS s(event_id, title_de, title_en, start_date_time, end_date_time)
+        ON CONFLICT (week_start, event_id) DO NOTHING
+        "#,
+        week_start,
+        &event_ids,
+        &titles_de,
+        &titles_en,
+        &starts,
+        &ends
+    )
+    .execute(&state.db)
+    .await?;
+    Ok(())
+}
+
+pub(crate) async fn send_newsletter_issue(
+    state: &AppState,
+    data: NewsletterDataResponse,
+    sent_by: Option<i64>,
+) -> Result<NewsletterSendResponse, AppError> {
+    let Some(email_client) = &state.email else {
+        return Err(AppError::service_unavailable(
+            "email delivery not configured",
+        ));
+    };
+    let settings = NewsletterDeliverySettings::from_env();
+    let recipients = active_newsletter_recipients(&state.db, &settings).await?;
+    if recipients.is_empty() {
+        return Err(AppError::service_unavailable(
+            "no newsletter recipients configured",
+        ));
+    }
+
+    let week_start = data.next_week_start.with_timezone(&Berlin).date_naive();
+    let renderings = render_newsletter_variants(state, &data, settings.layout).await?;
+
+    let claimed = sqlx::query_scalar!(
+        r#"
+        INSERT INTO newsletter_issues (week_start, send_started_at, sent_by)
+        VALUES ($1, NOW(), $2)
+        ON CONFLICT (week_start) DO UPDATE
+        SET send_started_at = NOW(), sent_by = EXCLUDED.sent_by
+        WHERE newsletter_issues.sent_at IS NULL
+          AND (newsletter_issues.send_started_at IS NULL
+               OR newsletter_issues.send_started_at < NOW() - make_interval(mins => $3))
+        RETURNING week_start
+        "#,
+        week_start,
+        sent_by,
+        STALE_SEND_CLAIM_MINUTES
+    )
+    .fetch_optional(&state.db)
+    .await?;
+    if claimed.is_none() {
+        return Err(AppError::conflict(
+            "newsletter for this week was already sent or is currently being sent",
+        ));
+    }
+
+    let outcome = deliver_newsletter(
+        email_client,
+        &settings,
+        &recipients,
+        &data.subject,
+        &renderings.primary,
+        state.api_token_hmac_key.as_ref(),
+    )
+    .await;
+    if outcome.sent == 0 {
+        sqlx::query!(
+            "UPDATE newsletter_issues SET send_started_at = NULL WHERE week_start = $1",
+            week_start
+        )
+        .execute(&state.db)
+        .await?;
+        warn!(target: "newsletter", action = "send", week_start = %week_start, failed = outcome.failed, "Newsletter delivery failed for every recipient");
+        return Err(AppError::internal("newsletter delivery failed"));
+    }
+
+    let events: Vec<_> = data
+        .next_week_events
+        .iter()
+        .chain(data.following_week_events.iter())
+        .map(|event| &event.event)
+        .collect();
+    let event_ids: Vec<i64> = events.iter().map(|event| event.id).collect();
+    let sent = sqlx::query!(
+        r#"
+        UPDATE newsletter_issues
+        SET sent_at = NOW(), sent_subject = $2, recipient_count = $3, failed_count = $4,
+            sent_html = $5, sent_event_ids = $6, sent_html_en = $7
+        WHERE week_start = $1
+        RETURNING sent_at AS "sent_at!"
+        "#,
+        week_start,
+        data.subject,
+        outcome.sent as i32,
+        outcome.failed as i32,
+        renderings.primary.html,
+        &event_ids,
+        renderings.english.html
+    )
+    .fetch_one(&state.db)
+    .await?;
+    record_issue_events(state, week_start, &events).await?;
+
+    info!(
+        target: "newsletter",
+        action = "send",
+        sent_by = ?sent_by,
+        week_start = %week_start,
+        recipients = outcome.sent,
+        failed = outcome.failed,
+        "Newsletter sent"
+    );
+
+    Ok(NewsletterSendResponse {
+        week_start,
+        subject: data.subject,
+        recipient_count: outcome.sent as i32,
+        failed_count: outcome.failed as i32,
+        sent_at: sent.sent_at,
+    })
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/newsletter/issues",
+    tag = "Events",
+    params(NewsletterArchiveQuery),
+    responses(
+        (status = 200, description = "Sent newsletter issues, newest first", body = [NewsletterArchiveEntry]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_newsletter_archive(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<NewsletterArchiveQuery>,
+) -> Result<Json<Vec<NewsletterArchiveEntry>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_newsletter_access(&user, &state).await?;
+    let (limit, offset) = archive_page(&query);
+
+    let issues = sqlx::query_as!(
+        NewsletterArchiveEntry,
+        r#"
+        SELECT week_start, sent_subject AS "subject!", sent_event_ids AS event_ids,
+               recipient_count, failed_count, sent_by, sent_at AS "sent_at!"
+        FROM newsletter_issues
+        WHERE sent_at IS NOT NULL AND sent_subject IS NOT NULL
+        ORDER BY sent_at DESC
+        LIMIT $1 OFFSET $2
+        "#,
+        limit,
+        offset
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(issues))
+}
+
+fn archive_page(query: &NewsletterArchiveQuery) -> (i64, i64) {
+    (
+        query
+            .limit
+            .unwrap_or(DEFAULT_ARCHIVE_LIMIT)
+            .clamp(1, MAX_ARCHIVE_LIMIT),
+        query.offset.unwrap_or(0).max(0),
+    )
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/newsletter/issues",
+    tag = "Public",
+    params(NewsletterArchiveQuery),
+    responses(
+        (status = 200, description = "Published newsletter issues, newest first", body = [PublicNewsletterIssueSummary]),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn list_public_newsletter_issues(
+    State(state): State<AppState>,
+    Query(query): Query<NewsletterArchiveQuery>,
+) -> Result<Json<Vec<PublicNewsletterIssueSummary>>, AppError> {
+    let (limit, offset) = archive_page(&query);
+
+    let issues = sqlx::query_as!(
+        PublicNewsletterIssueSummary,
+        r#"
+        SELECT week_start, sent_subject AS "subject!", sent_event_ids AS event_ids, sent_at AS "sent_at!"
+        FROM newsletter_issues
+        WHERE sent_at IS NOT NULL AND sent_subject IS NOT NULL AND sent_html IS NOT NULL
+        ORDER BY sent_at DESC
+        LIMIT $1 OFFSET $2
+        "#,
+        limit,
+        offset
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(issues))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/newsletter/issues/{week_start}",
+    tag = "Public",
+    params(("week_start" = String, Path, description = "Any date in the issue week (YYYY-MM-DD)")),
+    responses(
+        (status = 200, description = "Sent newsletter issue including its HTML", body = PublicNewsletterIssueResponse),
+        (status = 400, description = "Invalid week_start", body = ErrorResponse),
+        (status = 404, description = "No newsletter was sent for this week", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_public_newsletter_issue(
+    State(state): State<AppState>,
+    Path(week_start): Path<String>,
+) -> Result<Json<PublicNewsletterIssueResponse>, AppError> {
+    let week_start = parse_newsletter_week_start(&week_start)?;
+
+    let issue = sqlx::query_as!(
+        PublicNewsletterIssueResponse,
+        r#"
+        SELECT week_start, sent_subject AS "subject!", sent_html AS "html!", sent_html_en AS html_en, sent_event_ids AS event_ids, sent_at AS "sent_at!"
+        FROM newsletter_issues
+        WHERE week_start = $1 AND sent_at IS NOT NULL AND sent_subject IS NOT NULL AND sent_html IS NOT NULL
+        "#,
+        week_start
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("No newsletter was sent for this week"))?;
+
+    Ok(Json(issue))
+}
+
+async fn unsubscribe_recipient(state: &AppState, token: &str) -> Result<(), AppError> {
+    let email = state
+        .api_token_hmac_key
+        .as_ref()
+        .and_then(|key| verify_unsubscribe_token(key, token))
+        .ok_or_else(|| AppError::not_found("unsubscribe link invalid"))?;
+    sqlx::query!(
+        "INSERT INTO newsletter_unsubscribes (recipient_hash) VALUES ($1) ON CONFLICT (recipient_hash) DO NOTHING",
+        recipient_hash(&email)
+    )
+    .execute(&state.db)
+    .await?;
+    info!(target: "newsletter", action = "unsubscribe", "Newsletter recipient unsubscribed");
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/newsletter/unsubscribe/{token}",
+    tag = "Public",
+    params(("token" = String, Path, description = "Signed per-recipient unsubscribe token")),
+    responses(
+        (status = 200, description = "Recipient unsubscribed from the newsletter", body = String, content_type = "text/plain"),
+        (status = 404, description = "Unsubscribe link invalid", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, token))]
+pub(crate) async fn unsubscribe_newsletter_via_link(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<&'static str, AppError> {
+    unsubscribe_recipient(&state, &token).await?;
+    Ok("Du erhältst den Campus Life Newsletter nicht mehr.")
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/newsletter/unsubscribe/{token}",
+    tag = "Public",
+    params(("token" = String, Path, description = "Signed per-recipient unsubscribe token")),
+    responses(
+        (status = 204, description = "Recipient unsubscribed via one-click List-Unsubscribe-Post"),
+        (status = 404, description = "Unsubscribe link invalid", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, token))]
+pub(crate) async fn unsubscribe_newsletter_one_click(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<StatusCode, AppError> {
+    unsubscribe_recipient(&state, &token).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/send", post(send_newsletter))
+        .route(
+            "/unsubscribe/{token}",
+            get(unsubscribe_newsletter_via_link).post(unsubscribe_newsletter_one_click),
+        )
+        .route("/issues", get(list_newsletter_archive))
+        .route(
+            "/issues/{week_start}/texts",
+            get(get_newsletter_issue_texts).put(update_newsletter_issue_texts),
+        )
+        .route(
+            "/issues/{week_start}",
+            get(get_newsletter_issue).put(update_newsletter_issue),
+        )
+        .route(
+            "/sponsors",
+            get(list_newsletter_sponsors).post(create_newsletter_sponsor),
+        )
+        .route(
+            "/sponsors/{id}",
+            put(update_newsletter_sponsor).delete(delete_newsletter_sponsor),
+        )
+        .merge(super::newsletter_changes::router())
+        .merge(super::private_feeds::newsletter_router())
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new()
+        .route("/newsletter/issues", get(list_public_newsletter_issues))
+        .route(
+            "/newsletter/issues/{week_start}",
+            get(get_public_newsletter_issue),
+        )
+}
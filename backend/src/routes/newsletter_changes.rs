@@ -0,0 +1,202 @@
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::HeaderMap,
+    routing::get,
+};
+use chrono::{DateTime, Utc};
+use chrono_tz::Europe::Berlin;
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    dto::{NewsletterChangesQuery, NewsletterDataQuery},
+    error::AppError,
+    models::EventWithOrganizer,
+    responses::{ErrorResponse, NewsletterChangeEntry, NewsletterChangeReportResponse},
+};
+
+use super::{
+    events::{ensure_newsletter_access, newsletter_data},
+    shared::current_user_from_headers,
+};
+
+struct IssueEventSnapshot {
+    event_id: i64,
+    title_de: String,
+    title_en: String,
+    start_date_time: DateTime<Utc>,
+    end_date_time: DateTime<Utc>,
+}
+
+#[derive(Default)]
+struct NewsletterChanges {
+    added: Vec<NewsletterChangeEntry>,
+    rescheduled: Vec<NewsletterChangeEntry>,
+    cancelled: Vec<NewsletterChangeEntry>,
+    removed: Vec<NewsletterChangeEntry>,
+}
+
+fn event_entry(
+    event: &EventWithOrganizer,
+    previous: Option<&IssueEventSnapshot>,
+) -> NewsletterChangeEntry {
+    NewsletterChangeEntry {
+        event_id: event.id,
+        title_de: event.title_de.clone(),
+        title_en: event.title_en.clone(),
+        start_date_time: event.start_date_time,
+        end_date_time: event.end_date_time,
+        previous_start_date_time: previous.map(|snapshot| snapshot.start_date_time),
+        previous_end_date_time: previous.map(|snapshot| snapshot.end_date_time),
+    }
+}
+
+fn snapshot_entry(snapshot: &IssueEventSnapshot) -> NewsletterChangeEntry {
+    NewsletterChangeEntry {
+        event_id: snapshot.event_id,
+        title_de: snapshot.title_de.clone(),
+        title_en: snapshot.title_en.clone(),
+        start_date_time: snapshot.start_date_time,
+        end_date_time: snapshot.end_date_time,
+        previous_start_date_time: Some(snapshot.start_date_time),
+        previous_end_date_time: Some(snapshot.end_date_time),
+    }
+}
+
+fn diff_issue_events(
+    previous: &[IssueEventSnapshot],
+    current: &[&EventWithOrganizer],
+    window_start: DateTime<Utc>,
+    cancelled_ids: &[i64],
+) -> NewsletterChanges {
+    let mut changes = NewsletterChanges::default();
+    for event in current {
+        let snapshot = previous
+            .iter()
+            .find(|snapshot| snapshot.event_id == event.id);
+        match snapshot {
+            None if event.cancelled_at.is_none() => changes.added.push(event_entry(event, None)),
+            None => {}
+            Some(snapshot) if event.cancelled_at.is_some() => {
+                changes.cancelled.push(event_entry(event, Some(snapshot)))
+            }
+            Some(snapshot)
+                if snapshot.start_date_time != event.start_date_time
+                    || snapshot.end_date_time != event.end_date_time =>
+            {
+                changes.rescheduled.push(event_entry(event, Some(snapshot)))
+            }
+            Some(_) => {}
+        }
+    }
+    for snapshot in previous {
+        if snapshot.start_date_time < window_start
+            || current.iter().any(|event| event.id == snapshot.event_id)
+        {
+            continue;
+        }
+        if cancelled_ids.contains(&snapshot.event_id) {
+            changes.cancelled.push(snapshot_entry(snapshot));
+        } else {
+            changes.removed.push(snapshot_entry(snapshot));
+        }
+    }
+    changes
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/newsletter/changes",
+    tag = "Events",
+    params(NewsletterChangesQuery),
+    responses(
+        (status = 200, description = "Events added, rescheduled, cancelled or removed since the previous sent issue", body = NewsletterChangeReportResponse),
+        (status = 400, description = "Invalid week_start", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_newsletter_changes(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<NewsletterChangesQuery>,
+) -> Result<Json<NewsletterChangeReportResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_newsletter_access(&user, &state).await?;
+
+    let data = newsletter_data(
+        &state,
+        NewsletterDataQuery {
+            week_start: query.week_start,
+            grouping: None,
+            ordering: None,
+        },
+    )
+    .await?;
+    let week_start = data.next_week_start.with_timezone(&Berlin).date_naive();
+
+    let previous = sqlx::query!(
+        r#"
+        SELECT week_start, sent_at AS "sent_at!"
+        FROM newsletter_issues
+        WHERE sent_at IS NOT NULL AND week_start < $1
+        ORDER BY week_start DESC
+        LIMIT 1
+        "#,
+        week_start
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let snapshots = match &previous {
+        Some(previous) => {
+            sqlx::query_as!(
+                IssueEventSnapshot,
+                r#"
+                SELECT event_id, title_de, title_en, start_date_time, end_date_time
+                FROM newsletter_issue_events
+                WHERE week_start = $1
+                "#,
+                previous.week_start
+            )
+            .fetch_all(&state.db)
+            .await?
+        }
+        None => Vec::new(),
+    };
+
+    let current: Vec<_> = data
+        .next_week_events
+        .iter()
+        .chain(data.following_week_events.iter())
+        .map(|event| &event.event)
+        .collect();
+    let missing_ids: Vec<i64> = snapshots
+        .iter()
+        .map(|snapshot| snapshot.event_id)
+        .filter(|id| !current.iter().any(|event| event.id == *id))
+        .collect();
+    let cancelled_ids = sqlx::query_scalar!(
+        "SELECT id FROM events WHERE id = ANY($1) AND cancelled_at IS NOT NULL",
+        &missing_ids
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let changes = diff_issue_events(&snapshots, &current, data.next_week_start, &cancelled_ids);
+
+    Ok(Json(NewsletterChangeReportResponse {
+        week_start,
+        previous_week_start: previous.as_ref().map(|previous| previous.week_start),
+        previous_sent_at: previous.map(|previous| previous.sent_at),
+        added: changes.added,
+        rescheduled: changes.rescheduled,
+        cancelled: changes.cancelled,
+        removed: changes.removed,
+    }))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/changes", get(get_newsletter_changes))
+}
@@ -0,0 +1,197 @@
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::HeaderMap,
+    routing::get,
+};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::{NewsletterTemplateQuery, UpdateNewsletterTemplateRequest},
+    error::AppError,
+    models::{NewsletterLanguage, NewsletterTemplate},
+    responses::ErrorResponse,
+};
+
+use super::shared::current_user_from_headers;
+
+const MAX_TEMPLATE_FIELD_LEN: usize = 2000;
+
+pub(crate) async fn current_newsletter_template(
+    state: &AppState,
+    language: NewsletterLanguage,
+) -> Result<NewsletterTemplate, AppError> {
+    sqlx::query_as!(
+        NewsletterTemplate,
+        r#"
+        SELECT version, language AS "language: NewsletterLanguage", header_title, greeting, intro,
+               events_heading, outlook_heading, no_events_text, no_outlook_text, sponsors_heading,
+               footer_title, footer_tagline, footer_note, created_by, created_at
+        FROM newsletter_templates
+        WHERE language = $1
+        ORDER BY version DESC
+        LIMIT 1
+        "#,
+        language as NewsletterLanguage
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::internal("newsletter template missing"))
+}
+
+pub(crate) async fn current_newsletter_templates(
+    state: &AppState,
+) -> Result<(NewsletterTemplate, NewsletterTemplate), AppError> {
+    Ok((
+        current_newsletter_template(state, NewsletterLanguage::De).await?,
+        current_newsletter_template(state, NewsletterLanguage::En).await?,
+    ))
+}
+
+fn normalize_field(name: &str, value: &str, required: bool) -> Result<String, AppError> {
+    let value = value.trim();
+    if required && value.is_empty() {
+        return Err(AppError::validation(format!("{name} is required")));
+    }
+    if value.chars().count() > MAX_TEMPLATE_FIELD_LEN {
+        return Err(AppError::validation(format!(
+            "{name} must be at most {MAX_TEMPLATE_FIELD_LEN} characters"
+        )));
+    }
+    Ok(value.to_string())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/newsletter-template",
+    tag = "Admin",
+    params(NewsletterTemplateQuery),
+    responses(
+        (status = 200, description = "Current newsletter header, footer and section wording for the language (German by default)", body = NewsletterTemplate),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_newsletter_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<NewsletterTemplateQuery>,
+) -> Result<Json<NewsletterTemplate>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    Ok(Json(
+        current_newsletter_template(&state, query.language.unwrap_or_default()).await?,
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/admin/newsletter-template",
+    tag = "Admin",
+    request_body = UpdateNewsletterTemplateRequest,
+    responses(
+        (status = 200, description = "New newsletter template version stored", body = NewsletterTemplate),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_newsletter_template(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<UpdateNewsletterTemplateRequest>,
+) -> Result<Json<NewsletterTemplate>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let template = sqlx::query_as!(
+        NewsletterTemplate,
+        r#"
+        INSERT INTO newsletter_templates (
+            header_title, greeting, intro, events_heading, outlook_heading, no_events_text,
+            no_outlook_text, sponsors_heading, footer_title, footer_tagline, footer_note, created_by,
+            language
+        )
+        VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13)
+        RETURNING version, language AS "language: NewsletterLanguage", header_title, greeting, intro,
+                  events_heading, outlook_heading, no_events_text, no_outlook_text, sponsors_heading,
+                  footer_title, footer_tagline, footer_note, created_by, created_at
+        "#,
+        normalize_field("header_title", &payload.header_title, true)?,
+        normalize_field("greeting", &payload.greeting, false)?,
+        normalize_field("intro", &payload.intro, false)?,
+        normalize_field("events_heading", &payload.events_heading, true)?,
+        normalize_field("outlook_heading", &payload.outlook_heading, true)?,
+        normalize_field("no_events_text", &payload.no_events_text, true)?,
+        normalize_field("no_outlook_text", &payload.no_outlook_text, true)?,
+        normalize_field("sponsors_heading", &payload.sponsors_heading, true)?,
+        normalize_field("footer_title", &payload.footer_title, true)?,
+        normalize_field("footer_tagline", &payload.footer_tagline, false)?,
+        normalize_field("footer_note", &payload.footer_note, false)?,
+        user.account_id,
+        payload.language as NewsletterLanguage
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "newsletter", action = "update_template", account_id = user.account_id, version = template.version, language = ?template.language, "Newsletter template updated");
+
+    Ok(Json(template))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/newsletter-template/versions",
+    tag = "Admin",
+    params(NewsletterTemplateQuery),
+    responses(
+        (status = 200, description = "Stored newsletter template versions, newest first", body = [NewsletterTemplate]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_newsletter_template_versions(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<NewsletterTemplateQuery>,
+) -> Result<Json<Vec<NewsletterTemplate>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let versions = sqlx::query_as!(
+        NewsletterTemplate,
+        r#"
+        SELECT version, language AS "language: NewsletterLanguage", header_title, greeting, intro,
+               events_heading, outlook_heading, no_events_text, no_outlook_text, sponsors_heading,
+               footer_title, footer_tagline, footer_note, created_by, created_at
+        FROM newsletter_templates
+        WHERE ($1::newsletter_language IS NULL OR language = $1)
+        ORDER BY version DESC
+        "#,
+        query.language as Option<NewsletterLanguage>
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(versions))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/newsletter-template",
+            get(get_newsletter_template).put(update_newsletter_template),
+        )
+        .route(
+            "/newsletter-template/versions",
+            get(list_newsletter_template_versions),
+        )
+}
@@ -0,0 +1,176 @@
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::HeaderMap,
+    routing::get,
+};
+use tracing::{instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::OnboardingReportQuery,
+    error::AppError,
+    models::{OnboardingMilestone, OrganizerKind},
+    responses::{
+        ErrorResponse, OnboardingFunnelResponse, OnboardingStageResponse,
+        OrganizerOnboardingResponse,
+    },
+};
+
+use super::shared::current_user_from_headers;
+
+const FUNNEL_STAGES: [OnboardingMilestone; 4] = [
+    OnboardingMilestone::AccountInitialized,
+    OnboardingMilestone::ProfileCompleted,
+    OnboardingMilestone::FirstEventCreated,
+    OnboardingMilestone::FirstNewsletterInclusion,
+];
+
+pub(crate) async fn record_onboarding_milestone(
+    state: &AppState,
+    organizer_ids: &[i64],
+    milestone: OnboardingMilestone,
+) {
+    if organizer_ids.is_empty() {
+        return;
+    }
+
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO organizer_onboarding_milestones (organizer_id, milestone)
+        SELECT DISTINCT organizer_id, $2::onboarding_milestone
+        FROM UNNEST($1::BIGINT[]) AS organizer_id
+        ON CONFLICT (organizer_id, milestone) DO NOTHING
+        "#,
+        organizer_ids,
+        milestone as OnboardingMilestone
+    )
+    .execute(&state.db)
+    .await;
+
+    if let Err(err) = result {
+        warn!(
+            target: "onboarding",
+            action = "record",
+            milestone = ?milestone,
+            %err,
+            "Failed to record onboarding milestone"
+        );
+    }
+}
+
+pub(crate) async fn record_profile_completion(state: &AppState, organizer_id: i64) {
+    let result = sqlx::query!(
+        r#"
+        INSERT INTO organizer_onboarding_milestones (organizer_id, milestone)
+        SELECT id, 'PROFILE_COMPLETED'
+        FROM organizers
+        WHERE id = $1
+          AND NULLIF(TRIM(description_de), '') IS NOT NULL
+          AND NULLIF(TRIM(description_en), '') IS NOT NULL
+          AND COALESCE(NULLIF(TRIM(website_url), ''), NULLIF(TRIM(instagram_url), ''), NULLIF(TRIM(linkedin_url), '')) IS NOT NULL
+        ON CONFLICT (organizer_id, milestone) DO NOTHING
+        "#,
+        organizer_id
+    )
+    .execute(&state.db)
+    .await;
+
+    if let Err(err) = result {
+        warn!(
+            target: "onboarding",
+            action = "record",
+            milestone = ?OnboardingMilestone::ProfileCompleted,
+            %err,
+            "Failed to record onboarding milestone"
+        );
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/onboarding",
+    tag = "Admin",
+    params(OnboardingReportQuery),
+    responses(
+        (status = 200, description = "Onboarding funnel across invited organizers", body = OnboardingFunnelResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_onboarding_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<OnboardingReportQuery>,
+) -> Result<Json<OnboardingFunnelResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let organizers = sqlx::query_as!(
+        OrganizerOnboardingResponse,
+        r#"
+        SELECT
+            o.id AS organizer_id,
+            o.name AS organizer_name,
+            o.organizer_kind AS "organizer_kind: OrganizerKind",
+            o.created_at AS invited_at,
+            MAX(m.reached_at) FILTER (WHERE m.milestone = 'ACCOUNT_INITIALIZED') AS account_initialized_at,
+            MAX(m.reached_at) FILTER (WHERE m.milestone = 'PROFILE_COMPLETED') AS profile_completed_at,
+            MAX(m.reached_at) FILTER (WHERE m.milestone = 'FIRST_EVENT_CREATED') AS first_event_created_at,
+            MAX(m.reached_at) FILTER (WHERE m.milestone = 'FIRST_NEWSLETTER_INCLUSION') AS first_newsletter_inclusion_at
+        FROM organizers o
+        LEFT JOIN organizer_onboarding_milestones m ON m.organizer_id = o.id
+        WHERE ($1::organizer_kind IS NULL OR o.organizer_kind = $1)
+        GROUP BY o.id
+        ORDER BY o.created_at ASC, o.id ASC
+        "#,
+        query.organizer_kind as Option<OrganizerKind>
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let reached = |milestone: OnboardingMilestone, organizer: &OrganizerOnboardingResponse| {
+        match milestone {
+            OnboardingMilestone::AccountInitialized => organizer.account_initialized_at,
+            OnboardingMilestone::ProfileCompleted => organizer.profile_completed_at,
+            OnboardingMilestone::FirstEventCreated => organizer.first_event_created_at,
+            OnboardingMilestone::FirstNewsletterInclusion => {
+                organizer.first_newsletter_inclusion_at
+            }
+        }
+        .is_some()
+    };
+
+    let stages = FUNNEL_STAGES
+        .into_iter()
+        .map(|milestone| OnboardingStageResponse {
+            milestone,
+            organizer_count: organizers
+                .iter()
+                .filter(|organizer| reached(milestone, organizer))
+                .count() as i64,
+        })
+        .collect();
+    let total_organizers = organizers.len() as i64;
+
+    let organizers = if query.stalled_only.unwrap_or(false) {
+        organizers
+            .into_iter()
+            .filter(|organizer| organizer.first_event_created_at.is_none())
+            .collect()
+    } else {
+        organizers
+    };
+
+    Ok(Json(OnboardingFunnelResponse {
+        total_organizers,
+        stages,
+        organizers,
+    }))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/onboarding", get(get_onboarding_report))
+}
@@ -0,0 +1,214 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::HeaderMap,
+    routing::{get, post},
+};
+use serde::Deserialize;
+use sqlx::{Postgres, Transaction};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    error::AppError,
+    models::{Organizer, OrganizerKind},
+    responses::{ErrorResponse, OrganizerSnapshotEntry},
+};
+
+use super::{
+    onboarding::record_profile_completion, organizers::invalidate_public_organizer_caches,
+    shared::current_user_from_headers,
+};
+
+const MAX_SNAPSHOTS_LISTED: i64 = 100;
+
+#[derive(Debug, Deserialize)]
+struct OrganizerSnapshot {
+    name: String,
+    description_de: Option<String>,
+    description_en: Option<String>,
+    website_url: Option<String>,
+    instagram_url: Option<String>,
+    location: Option<String>,
+    linkedin_url: Option<String>,
+    registration_number: Option<String>,
+    #[serde(default)]
+    non_profit: bool,
+    event_footer_de: Option<String>,
+    event_footer_en: Option<String>,
+    calendar_name: Option<String>,
+    calendar_color: Option<String>,
+    calendar_refresh_minutes: Option<i32>,
+}
+
+pub(crate) async fn record_organizer_snapshot(
+    transaction: &mut Transaction<'_, Postgres>,
+    organizer: &Organizer,
+    account_id: i64,
+) -> Result<(), AppError> {
+    sqlx::query!(
+        "INSERT INTO organizer_snapshots (organizer_id, account_id, data) VALUES ($1, $2, $3)",
+        organizer.id,
+        account_id,
+        serde_json::to_value(organizer)?
+    )
+    .execute(&mut **transaction)
+    .await?;
+    Ok(())
+}
+
+pub(crate) async fn lock_organizer(
+    transaction: &mut Transaction<'_, Postgres>,
+    id: i64,
+) -> Result<Organizer, AppError> {
+    sqlx::query_as!(
+        Organizer,
+        r#"
+        SELECT id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, event_footer_de, event_footer_en, calendar_name, calendar_color, calendar_refresh_minutes, organizer_kind as "organizer_kind: OrganizerKind", created_at, updated_at
+        FROM organizers
+        WHERE id = $1
+        FOR UPDATE
+        "#,
+        id
+    )
+    .fetch_optional(&mut **transaction)
+    .await?
+    .ok_or_else(|| AppError::not_found("Organizer not found"))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/organizers/{id}/snapshots",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier")),
+    responses(
+        (status = 200, description = "Profile states captured before each update, newest first", body = [OrganizerSnapshotEntry]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_organizer_snapshots(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<OrganizerSnapshotEntry>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let snapshots = sqlx::query_as!(
+        OrganizerSnapshotEntry,
+        r#"
+        SELECT id, organizer_id, account_id, data, created_at
+        FROM organizer_snapshots
+        WHERE organizer_id = $1
+        ORDER BY created_at DESC, id DESC
+        LIMIT $2
+        "#,
+        id,
+        MAX_SNAPSHOTS_LISTED
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(snapshots))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/organizers/{id}/revert/{snapshot_id}",
+    tag = "Organizers",
+    params(
+        ("id" = i64, Path, description = "Organizer identifier"),
+        ("snapshot_id" = i64, Path, description = "Snapshot to restore"),
+    ),
+    responses(
+        (status = 200, description = "Profile restored from the snapshot; permissions and newsletter access are left unchanged", body = Organizer),
+        (status = 400, description = "Snapshot cannot be restored", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organizer or snapshot not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn revert_organizer_snapshot(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((id, snapshot_id)): Path<(i64, i64)>,
+) -> Result<Json<Organizer>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let mut transaction = state.db.begin().await?;
+    let current = lock_organizer(&mut transaction, id).await?;
+
+    let data = sqlx::query_scalar!(
+        "SELECT data FROM organizer_snapshots WHERE id = $1 AND organizer_id = $2",
+        snapshot_id,
+        id
+    )
+    .fetch_optional(&mut *transaction)
+    .await?
+    .ok_or_else(|| AppError::not_found("snapshot not found"))?;
+    let snapshot: OrganizerSnapshot = serde_json::from_value(data)
+        .map_err(|_| AppError::validation("snapshot has no valid organizer profile"))?;
+
+    record_organizer_snapshot(&mut transaction, &current, user.account_id).await?;
+
+    let organizer = sqlx::query_as!(
+        Organizer,
+        r#"
+        UPDATE organizers
+        SET name = $2, description_de = $3, description_en = $4, website_url = $5, instagram_url = $6,
+            location = $7, linkedin_url = $8, registration_number = $9, non_profit = $10,
+            event_footer_de = $11, event_footer_en = $12, calendar_name = $13, calendar_color = $14,
+            calendar_refresh_minutes = $15, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, event_footer_de, event_footer_en, calendar_name, calendar_color, calendar_refresh_minutes, organizer_kind as "organizer_kind: OrganizerKind", created_at, updated_at
+        "#,
+        id,
+        snapshot.name,
+        snapshot.description_de,
+        snapshot.description_en,
+        snapshot.website_url,
+        snapshot.instagram_url,
+        snapshot.location,
+        snapshot.linkedin_url,
+        snapshot.registration_number,
+        snapshot.non_profit,
+        snapshot.event_footer_de,
+        snapshot.event_footer_en,
+        snapshot.calendar_name,
+        snapshot.calendar_color,
+        snapshot.calendar_refresh_minutes
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    invalidate_public_organizer_caches(&state).await;
+    record_profile_completion(&state, organizer.id).await;
+
+    info!(
+        target: "organizers",
+        action = "revert",
+        account_id = user.account_id,
+        organizer_id = id,
+        snapshot_id,
+        "Organizer profile restored from snapshot"
+    );
+
+    Ok(Json(organizer))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/snapshots", get(list_organizer_snapshots))
+        .route(
+            "/{id}/revert/{snapshot_id}",
+            post(revert_organizer_snapshot),
+        )
+}
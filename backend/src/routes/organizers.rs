@@ -1,26 +1,93 @@
 use axum::{
     Json, Router,
-    extract::{Path, State},
+    extract::{Path, Query, State},
     http::{HeaderMap, StatusCode},
     response::IntoResponse,
     routing::get,
 };
+use chrono::{Duration, Utc};
 use sqlx::{Postgres, QueryBuilder};
 use tracing::{error, info, instrument, warn};
 
 use crate::{
     app_state::AppState,
-    dto::{CreateOrganizerRequest, UpdateOrganizerRequest},
+    dto::{ApiUsageQuery, CreateOrganizerRequest, UpdateOrganizerRequest},
     error::AppError,
     models::{AccountType, Organizer, OrganizerInviteRow, OrganizerKind, OrganizerWithInvite},
-    responses::{ErrorResponse, OrganizerWithStatsResponse, SetupTokenResponse},
+    responses::{
+        ApiTokenUsageResponse, ApiUsageDayResponse, ErrorResponse, OrganizerApiUsageResponse,
+        OrganizerWithStatsResponse, SetupTokenResponse,
+    },
 };
 
-use super::shared::{
-    AuthedUser, SessionOrganizerKindScope, current_user_from_headers, generate_setup_token_value,
-    refresh_organizer_activity_stats, session_organizer_kind_scope,
+use super::{
+    ical::record_organizer_feed_tombstone,
+    onboarding::record_profile_completion,
+    organizer_snapshots::{lock_organizer, record_organizer_snapshot},
+    shared::{
+        AuthedUser, SessionOrganizerKindScope, current_user_from_headers,
+        generate_setup_token_value, refresh_organizer_activity_stats, session_organizer_kind_scope,
+    },
 };
 
+const MAX_EVENT_FOOTER_LEN: usize = 1000;
+const MAX_CALENDAR_NAME_LEN: usize = 100;
+const MIN_CALENDAR_REFRESH_MINUTES: i32 = 15;
+const MAX_CALENDAR_REFRESH_MINUTES: i32 = 7 * 24 * 60;
+
+fn normalize_event_footer(value: String, field: &str) -> Result<Option<String>, AppError> {
+    let value = value.trim();
+    if value.chars().count() > MAX_EVENT_FOOTER_LEN {
+        return Err(AppError::validation(format!(
+            "{field} must be at most {MAX_EVENT_FOOTER_LEN} characters"
+        )));
+    }
+    Ok((!value.is_empty()).then(|| value.to_string()))
+}
+
+fn normalize_calendar_name(value: String) -> Result<Option<String>, AppError> {
+    let value = value.trim();
+    if value.chars().count() > MAX_CALENDAR_NAME_LEN {
+        return Err(AppError::validation(format!(
+            "calendar_name must be at most {MAX_CALENDAR_NAME_LEN} characters"
+        )));
+    }
+    if value.chars().any(char::is_control) {
+        return Err(AppError::validation(
+            "calendar_name must not contain control characters",
+        ));
+    }
+    Ok((!value.is_empty()).then(|| value.to_string()))
+}
+
+fn normalize_calendar_color(value: &str) -> Result<Option<String>, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(None);
+    }
+    let valid = value
+        .strip_prefix('#')
+        .is_some_and(|hex| hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()));
+    if !valid {
+        return Err(AppError::validation(
+            "calendar_color must be a hex color such as #1A73E8",
+        ));
+    }
+    Ok(Some(value.to_ascii_uppercase()))
+}
+
+fn normalize_calendar_refresh(minutes: i32) -> Result<Option<i32>, AppError> {
+    if minutes == 0 {
+        return Ok(None);
+    }
+    if !(MIN_CALENDAR_REFRESH_MINUTES..=MAX_CALENDAR_REFRESH_MINUTES).contains(&minutes) {
+        return Err(AppError::validation(format!(
+            "calendar_refresh_minutes must be between {MIN_CALENDAR_REFRESH_MINUTES} and {MAX_CALENDAR_REFRESH_MINUTES}, or 0 for the default"
+        )));
+    }
+    Ok(Some(minutes))
+}
+
 pub(crate) async fn update_organizer_with_user(
     state: &AppState,
     user: &AuthedUser,
@@ -42,6 +109,11 @@ pub(crate) async fn update_organizer_with_user(
         linkedin_url,
         registration_number,
         non_profit,
+        event_footer_de,
+        event_footer_en,
+        calendar_name,
+        calendar_color,
+        calendar_refresh_minutes,
     } = payload;
 
     if !has_updates {
@@ -82,18 +154,50 @@ pub(crate) async fn update_organizer_with_user(
     if let Some(non_profit) = non_profit {
         builder.push(", non_profit = ").push_bind(non_profit);
     }
+    if let Some(event_footer_de) = event_footer_de {
+        builder
+            .push(", event_footer_de = ")
+            .push_bind(normalize_event_footer(event_footer_de, "event_footer_de")?);
+    }
+    if let Some(event_footer_en) = event_footer_en {
+        builder
+            .push(", event_footer_en = ")
+            .push_bind(normalize_event_footer(event_footer_en, "event_footer_en")?);
+    }
+    if let Some(calendar_name) = calendar_name {
+        builder
+            .push(", calendar_name = ")
+            .push_bind(normalize_calendar_name(calendar_name)?);
+    }
+    if let Some(calendar_color) = calendar_color {
+        builder
+            .push(", calendar_color = ")
+            .push_bind(normalize_calendar_color(&calendar_color)?);
+    }
+    if let Some(calendar_refresh_minutes) = calendar_refresh_minutes {
+        builder
+            .push(", calendar_refresh_minutes = ")
+            .push_bind(normalize_calendar_refresh(calendar_refresh_minutes)?);
+    }
 
     builder.push(" WHERE id = ").push_bind(id);
     builder.push(
-        " RETURNING id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, organizer_kind, created_at, updated_at",
+        " RETURNING id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, event_footer_de, event_footer_en, calendar_name, calendar_color, calendar_refresh_minutes, organizer_kind, created_at, updated_at",
     );
 
+    let mut transaction = state.db.begin().await?;
+    let previous = lock_organizer(&mut transaction, id).await?;
+    record_organizer_snapshot(&mut transaction, &previous, user.account_id).await?;
+
     let organizer = builder
         .build_query_as::<Organizer>()
-        .fetch_one(&state.db)
+        .fetch_one(&mut *transaction)
         .await?;
 
+    transaction.commit().await?;
+
     invalidate_public_organizer_caches(state).await;
+    record_profile_completion(state, organizer.id).await;
 
     Ok(organizer)
 }
@@ -132,6 +236,11 @@ pub(crate) async fn list_organizers(
             o.registration_number,
             o.non_profit,
             o.newsletter,
+            o.event_footer_de,
+            o.event_footer_en,
+            o.calendar_name,
+            o.calendar_color,
+            o.calendar_refresh_minutes,
             o.organizer_kind as "organizer_kind: OrganizerKind",
             o.created_at,
             o.updated_at,
@@ -157,6 +266,11 @@ pub(crate) async fn list_organizers(
             registration_number: row.registration_number,
             non_profit: row.non_profit,
             newsletter: row.newsletter,
+            event_footer_de: row.event_footer_de,
+            event_footer_en: row.event_footer_en,
+            calendar_name: row.calendar_name,
+            calendar_color: row.calendar_color,
+            calendar_refresh_minutes: row.calendar_refresh_minutes,
             organizer_kind: row.organizer_kind,
             created_at: row.created_at,
             updated_at: row.updated_at,
@@ -178,6 +292,11 @@ pub(crate) async fn list_organizers(
             o.registration_number,
             o.non_profit,
             o.newsletter,
+            o.event_footer_de,
+            o.event_footer_en,
+            o.calendar_name,
+            o.calendar_color,
+            o.calendar_refresh_minutes,
             o.organizer_kind as "organizer_kind: OrganizerKind",
             o.created_at,
             o.updated_at,
@@ -205,6 +324,11 @@ pub(crate) async fn list_organizers(
             registration_number: row.registration_number,
             non_profit: row.non_profit,
             newsletter: row.newsletter,
+            event_footer_de: row.event_footer_de,
+            event_footer_en: row.event_footer_en,
+            calendar_name: row.calendar_name,
+            calendar_color: row.calendar_color,
+            calendar_refresh_minutes: row.calendar_refresh_minutes,
             organizer_kind: row.organizer_kind,
             created_at: row.created_at,
             updated_at: row.updated_at,
@@ -242,7 +366,7 @@ pub(crate) async fn create_organizer(
         r#"
         INSERT INTO organizers (name, organizer_kind)
         VALUES ($1, $2)
-        RETURNING id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, organizer_kind as "organizer_kind: OrganizerKind", created_at, updated_at
+        RETURNING id, name, description_de, description_en, website_url, instagram_url, location, linkedin_url, registration_number, non_profit, newsletter, event_footer_de, event_footer_en, calendar_name, calendar_color, calendar_refresh_minutes, organizer_kind as "organizer_kind: OrganizerKind", created_at, updated_at
         "#,
         &payload.name,
         payload.organizer_kind as OrganizerKind
@@ -330,6 +454,7 @@ pub(crate) async fn list_organizers_admin(
             a.id AS account_id,
             a.email AS account_email,
             o.newsletter AS newsletter,
+            o.needs_review,
             o.organizer_kind as "organizer_kind: OrganizerKind",
             o.created_at,
             o.updated_at,
@@ -387,6 +512,11 @@ pub(crate) async fn get_organizer(
             o.registration_number,
             o.non_profit,
             o.newsletter,
+            o.event_footer_de,
+            o.event_footer_en,
+            o.calendar_name,
+            o.calendar_color,
+            o.calendar_refresh_minutes,
             o.organizer_kind as "organizer_kind: OrganizerKind",
             o.created_at,
             o.updated_at,
@@ -423,6 +553,11 @@ pub(crate) async fn get_organizer(
         registration_number: row.registration_number,
         non_profit: row.non_profit,
         newsletter: row.newsletter,
+        event_footer_de: row.event_footer_de,
+        event_footer_en: row.event_footer_en,
+        calendar_name: row.calendar_name,
+        calendar_color: row.calendar_color,
+        calendar_refresh_minutes: row.calendar_refresh_minutes,
         organizer_kind: row.organizer_kind,
         created_at: row.created_at,
         updated_at: row.updated_at,
@@ -469,14 +604,20 @@ pub(crate) async fn delete_organizer(
     if !deleting_self && !user.is_admin() {
         return Err(AppError::unauthorized("cannot delete another organizer"));
     }
+    let mut transaction = state.db.begin().await?;
+
+    record_organizer_feed_tombstone(&mut transaction, id).await?;
+
     let result = sqlx::query!("DELETE FROM organizers WHERE id = $1", id)
-        .execute(&state.db)
+        .execute(&mut *transaction)
         .await?;
 
     if result.rows_affected() == 0 {
         return Err(AppError::not_found("Organizer not found"));
     }
 
+    transaction.commit().await?;
+
     invalidate_public_organizer_caches(&state).await;
 
     Ok(StatusCode::NO_CONTENT)
@@ -537,6 +678,109 @@ pub(crate) async fn invalidate_public_organizer_caches(state: &AppState) {
     refresh_organizer_activity_stats(state).await;
 }
 
+const DEFAULT_API_USAGE_DAYS: i64 = 30;
+const MAX_API_USAGE_DAYS: i64 = 365;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/organizers/{id}/api-usage",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier"), ApiUsageQuery),
+    responses(
+        (status = 200, description = "Daily API token usage for the organizer", body = OrganizerApiUsageResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organizer not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_organizer_api_usage(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<ApiUsageQuery>,
+) -> Result<Json<OrganizerApiUsageResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if user.organizer_id() != Some(id) && !user.is_admin() {
+        return Err(AppError::unauthorized(
+            "cannot view API usage of another organizer",
+        ));
+    }
+
+    let days = query.days.unwrap_or(DEFAULT_API_USAGE_DAYS);
+    if !(1..=MAX_API_USAGE_DAYS).contains(&days) {
+        return Err(AppError::validation(format!(
+            "days must be between 1 and {MAX_API_USAGE_DAYS}"
+        )));
+    }
+
+    let organizer_exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS(SELECT 1 FROM organizers WHERE id = $1) AS "exists!""#,
+        id
+    )
+    .fetch_one(&state.db)
+    .await?;
+    if !organizer_exists {
+        return Err(AppError::not_found("Organizer not found"));
+    }
+
+    let since = Utc::now().date_naive() - Duration::days(days - 1);
+
+    let rows = sqlx::query!(
+        r#"
+        SELECT
+            t.id AS token_id,
+            t.label,
+            t.token_last_four,
+            t.last_used_at,
+            u.usage_date AS "usage_date?",
+            u.request_count AS "request_count?"
+        FROM api_tokens t
+        JOIN accounts a ON a.id = t.account_id
+        LEFT JOIN api_token_usage u ON u.token_id = t.id AND u.usage_date >= $2
+        WHERE a.organizer_id = $1
+        ORDER BY t.id, u.usage_date
+        "#,
+        id,
+        since
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut tokens: Vec<ApiTokenUsageResponse> = Vec::new();
+    for row in rows {
+        if tokens.last().map(|token| token.token_id) != Some(row.token_id) {
+            tokens.push(ApiTokenUsageResponse {
+                token_id: row.token_id,
+                label: row.label,
+                token_last_four: row.token_last_four,
+                last_used_at: row.last_used_at,
+                total_requests: 0,
+                daily: Vec::new(),
+            });
+        }
+
+        if let (Some(date), Some(request_count)) = (row.usage_date, row.request_count)
+            && let Some(token) = tokens.last_mut()
+        {
+            token.total_requests += request_count;
+            token.daily.push(ApiUsageDayResponse {
+                date,
+                request_count,
+            });
+        }
+    }
+
+    let total_requests = tokens.iter().map(|token| token.total_requests).sum();
+
+    Ok(Json(OrganizerApiUsageResponse {
+        organizer_id: id,
+        since,
+        total_requests,
+        tokens,
+    }))
+}
+
 pub(crate) fn router() -> Router<AppState> {
     Router::new()
         .route("/", get(list_organizers).post(create_organizer))
@@ -551,4 +795,8 @@ pub(crate) fn router() -> Router<AppState> {
             "/{id}/setup-token",
             get(generate_setup_token).post(generate_setup_token),
         )
+        .route("/{id}/api-usage", get(get_organizer_api_usage))
+        .merge(super::event_quota::router())
+        .merge(super::organizer_snapshots::router())
+        .merge(super::private_feeds::router())
 }
@@ -0,0 +1,286 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{get, put},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState, dto::PriceTierRequest, error::AppError, models::EventPriceTier,
+    responses::ErrorResponse,
+};
+
+use super::{
+    events::invalidate_public_event_caches,
+    shared::{AuthedUser, current_user_from_headers},
+};
+
+const MAX_PRICE_TIER_LABEL_LEN: usize = 100;
+const MAX_PRICE_TIER_DESCRIPTION_LEN: usize = 500;
+
+struct ValidatedPriceTier {
+    label: String,
+    price_cents: i32,
+    description: Option<String>,
+    position: i32,
+}
+
+fn validate_price_tier(payload: PriceTierRequest) -> Result<ValidatedPriceTier, AppError> {
+    let label = payload.label.trim();
+    if label.is_empty() {
+        return Err(AppError::validation("label is required"));
+    }
+    if label.chars().count() > MAX_PRICE_TIER_LABEL_LEN {
+        return Err(AppError::validation(format!(
+            "label must be at most {MAX_PRICE_TIER_LABEL_LEN} characters"
+        )));
+    }
+    if payload.price_cents < 0 {
+        return Err(AppError::validation("price_cents must not be negative"));
+    }
+
+    let description = payload
+        .description
+        .map(|description| description.trim().to_string())
+        .filter(|description| !description.is_empty());
+    if let Some(description) = &description
+        && description.chars().count() > MAX_PRICE_TIER_DESCRIPTION_LEN
+    {
+        return Err(AppError::validation(format!(
+            "description must be at most {MAX_PRICE_TIER_DESCRIPTION_LEN} characters"
+        )));
+    }
+
+    Ok(ValidatedPriceTier {
+        label: label.to_string(),
+        price_cents: payload.price_cents,
+        description,
+        position: payload.position,
+    })
+}
+
+async fn ensure_can_manage_event(
+    state: &AppState,
+    user: &AuthedUser,
+    event_id: i64,
+) -> Result<(), AppError> {
+    let event = sqlx::query!("SELECT organizer_id FROM events WHERE id = $1", event_id)
+        .fetch_optional(&state.db)
+        .await?;
+
+    let Some(event) = event else {
+        return Err(AppError::not_found("event not found"));
+    };
+
+    if !user.is_admin() && user.organizer_id() != Some(event.organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot manage another organizer's price tiers",
+        ));
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn fetch_price_tiers(
+    state: &AppState,
+    event_ids: &[i64],
+) -> Result<Vec<EventPriceTier>, AppError> {
+    let tiers = sqlx::query_as!(
+        EventPriceTier,
+        r#"
+        SELECT id, event_id, label, price_cents, description, position, created_at, updated_at
+        FROM event_price_tiers
+        WHERE event_id = ANY($1)
+        ORDER BY event_id ASC, position ASC, price_cents ASC, id ASC
+        "#,
+        event_ids
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(tiers)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/price-tiers",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Price tiers for the event", body = [EventPriceTier]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_price_tiers(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<EventPriceTier>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_can_manage_event(&state, &user, id).await?;
+
+    Ok(Json(fetch_price_tiers(&state, &[id]).await?))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/price-tiers",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    request_body = PriceTierRequest,
+    responses(
+        (status = 201, description = "Price tier created", body = EventPriceTier),
+        (status = 400, description = "Invalid price tier", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_price_tier(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<PriceTierRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_can_manage_event(&state, &user, id).await?;
+    let tier = validate_price_tier(payload)?;
+
+    let tier = sqlx::query_as!(
+        EventPriceTier,
+        r#"
+        INSERT INTO event_price_tiers (event_id, label, price_cents, description, position)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, event_id, label, price_cents, description, position, created_at, updated_at
+        "#,
+        id,
+        tier.label,
+        tier.price_cents,
+        tier.description,
+        tier.position
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(
+        target: "price_tiers",
+        action = "create",
+        event_id = id,
+        price_tier_id = tier.id,
+        "Price tier created"
+    );
+
+    Ok((StatusCode::CREATED, Json(tier)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/price-tiers/{tier_id}",
+    tag = "Events",
+    params(
+        ("id" = i64, Path, description = "Event identifier"),
+        ("tier_id" = i64, Path, description = "Price tier identifier")
+    ),
+    request_body = PriceTierRequest,
+    responses(
+        (status = 200, description = "Price tier updated", body = EventPriceTier),
+        (status = 400, description = "Invalid price tier", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event or price tier not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_price_tier(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((id, tier_id)): Path<(i64, i64)>,
+    Json(payload): Json<PriceTierRequest>,
+) -> Result<Json<EventPriceTier>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_can_manage_event(&state, &user, id).await?;
+    let tier = validate_price_tier(payload)?;
+
+    let tier = sqlx::query_as!(
+        EventPriceTier,
+        r#"
+        UPDATE event_price_tiers
+        SET label = $3, price_cents = $4, description = $5, position = $6, updated_at = NOW()
+        WHERE id = $1 AND event_id = $2
+        RETURNING id, event_id, label, price_cents, description, position, created_at, updated_at
+        "#,
+        tier_id,
+        id,
+        tier.label,
+        tier.price_cents,
+        tier.description,
+        tier.position
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let Some(tier) = tier else {
+        return Err(AppError::not_found("price tier not found"));
+    };
+
+    invalidate_public_event_caches(&state).await;
+
+    Ok(Json(tier))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/events/{id}/price-tiers/{tier_id}",
+    tag = "Events",
+    params(
+        ("id" = i64, Path, description = "Event identifier"),
+        ("tier_id" = i64, Path, description = "Price tier identifier")
+    ),
+    responses(
+        (status = 204, description = "Price tier removed"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event or price tier not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_price_tier(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path((id, tier_id)): Path<(i64, i64)>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_can_manage_event(&state, &user, id).await?;
+
+    let result = sqlx::query!(
+        "DELETE FROM event_price_tiers WHERE id = $1 AND event_id = $2",
+        tier_id,
+        id
+    )
+    .execute(&state.db)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("price tier not found"));
+    }
+
+    invalidate_public_event_caches(&state).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/price-tiers",
+            get(list_price_tiers).post(create_price_tier),
+        )
+        .route(
+            "/{id}/price-tiers/{tier_id}",
+            put(update_price_tier).delete(delete_price_tier),
+        )
+}
@@ -0,0 +1,381 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    routing::{get, post},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    api_token,
+    app_state::AppState,
+    authed_user::AuthedUser,
+    error::AppError,
+    responses::{
+        ErrorResponse, NewsletterIcalTokenResponse, OrganizerIcalTokenResponse,
+        PrivateFeedCreatedResponse, PrivateFeedResponse,
+    },
+};
+
+use super::{
+    events::ensure_newsletter_access,
+    ical::{newsletter_feed_token, organizer_feed_token},
+    shared::{current_user_from_headers, frontend_base_url},
+};
+
+pub(crate) const PRIVATE_FEED_TOKEN_PREFIX: &str = "cle_cal_";
+
+fn ensure_feed_access(user: &AuthedUser, organizer_id: i64) -> Result<(), AppError> {
+    if user.organizer_id() != Some(organizer_id) && !user.is_admin() {
+        return Err(AppError::unauthorized(
+            "cannot manage the private feed of another organizer",
+        ));
+    }
+    Ok(())
+}
+
+async fn ensure_organizer_exists(state: &AppState, organizer_id: i64) -> Result<(), AppError> {
+    let exists = sqlx::query_scalar!(
+        r#"SELECT EXISTS (SELECT 1 FROM organizers WHERE id = $1) AS "exists!""#,
+        organizer_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+    if !exists {
+        return Err(AppError::not_found("Organizer not found"));
+    }
+    Ok(())
+}
+
+fn webcal_url(url: &str) -> String {
+    let without_scheme = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+    format!("webcal://{without_scheme}")
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/organizers/{id}/private-feed",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier")),
+    responses(
+        (status = 200, description = "Private iCal feed metadata (the token itself is only shown on creation)", body = PrivateFeedResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "No private feed configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_private_feed(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<PrivateFeedResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_feed_access(&user, id)?;
+
+    let feed = sqlx::query_as!(
+        PrivateFeedResponse,
+        r#"
+        SELECT organizer_id, token_last_four, created_at, last_used_at
+        FROM organizer_private_feeds
+        WHERE organizer_id = $1
+        "#,
+        id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("No private feed configured"))?;
+
+    Ok(Json(feed))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/organizers/{id}/private-feed",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier")),
+    responses(
+        (status = 200, description = "New private iCal feed URL including unpublished and draft events; replaces any previous URL", body = PrivateFeedCreatedResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organizer not found", body = ErrorResponse),
+        (status = 503, description = "Server not configured for private feeds", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn create_private_feed(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<PrivateFeedCreatedResponse>, AppError> {
+    let Some(key) = state.api_token_hmac_key.as_ref() else {
+        return Err(AppError::service_unavailable(
+            "private feeds are not configured (set API_TOKEN_SECRET)",
+        ));
+    };
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_feed_access(&user, id)?;
+    ensure_organizer_exists(&state, id).await?;
+
+    let token = api_token::generate_prefixed_token(PRIVATE_FEED_TOKEN_PREFIX);
+    let digest = api_token::hash_raw_token(key, &token);
+    let token_last_four = api_token::token_last_four(&token);
+
+    let created_at = sqlx::query_scalar!(
+        r#"
+        INSERT INTO organizer_private_feeds (organizer_id, token_hmac, token_last_four, created_by)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (organizer_id) DO UPDATE
+        SET token_hmac = EXCLUDED.token_hmac, token_last_four = EXCLUDED.token_last_four,
+            created_by = EXCLUDED.created_by, created_at = NOW(), last_used_at = NULL
+        RETURNING created_at
+        "#,
+        id,
+        &digest[..],
+        &token_last_four,
+        user.account_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "ical", action = "create_private_feed", organizer_id = id, account_id = user.account_id, "Private iCal feed created");
+
+    Ok(Json(PrivateFeedCreatedResponse {
+        organizer_id: id,
+        url: format!("{}/api/ical/private/{token}", frontend_base_url()),
+        token,
+        token_last_four,
+        created_at,
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/organizers/{id}/private-feed",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier")),
+    responses(
+        (status = 204, description = "Private iCal feed revoked"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "No private feed configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn revoke_private_feed(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_feed_access(&user, id)?;
+
+    let result = sqlx::query!(
+        "DELETE FROM organizer_private_feeds WHERE organizer_id = $1",
+        id
+    )
+    .execute(&state.db)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("No private feed configured"));
+    }
+
+    info!(target: "ical", action = "revoke_private_feed", organizer_id = id, account_id = user.account_id, "Private iCal feed revoked");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/organizers/{id}/ical-token",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier")),
+    responses(
+        (status = 200, description = "Signed webcal subscription URL including unlisted events; replaces any previous URL", body = OrganizerIcalTokenResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Organizer not found", body = ErrorResponse),
+        (status = 503, description = "Server not configured for signed feeds", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn create_ical_token(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<OrganizerIcalTokenResponse>, AppError> {
+    let Some(key) = state.api_token_hmac_key.as_ref() else {
+        return Err(AppError::service_unavailable(
+            "signed feeds are not configured (set API_TOKEN_SECRET)",
+        ));
+    };
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_feed_access(&user, id)?;
+    ensure_organizer_exists(&state, id).await?;
+
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO organizer_ical_tokens (organizer_id, created_by)
+        VALUES ($1, $2)
+        ON CONFLICT (organizer_id) DO UPDATE
+        SET version = organizer_ical_tokens.version + 1, created_by = EXCLUDED.created_by,
+            created_at = NOW()
+        RETURNING version, created_at
+        "#,
+        id,
+        user.account_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "ical", action = "create_ical_token", organizer_id = id, account_id = user.account_id, version = row.version, "Signed iCal feed URL created");
+
+    let url = format!(
+        "{}/api/ical/feeds/{}",
+        frontend_base_url(),
+        organizer_feed_token(key, id, row.version)
+    );
+    Ok(Json(OrganizerIcalTokenResponse {
+        organizer_id: id,
+        webcal_url: webcal_url(&url),
+        url,
+        created_at: row.created_at,
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/organizers/{id}/ical-token",
+    tag = "Organizers",
+    params(("id" = i64, Path, description = "Organizer identifier")),
+    responses(
+        (status = 204, description = "Signed webcal subscription URL revoked"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "No signed feed URL configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn revoke_ical_token(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_feed_access(&user, id)?;
+
+    let result = sqlx::query!(
+        "DELETE FROM organizer_ical_tokens WHERE organizer_id = $1",
+        id
+    )
+    .execute(&state.db)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("No signed feed URL configured"));
+    }
+
+    info!(target: "ical", action = "revoke_ical_token", organizer_id = id, account_id = user.account_id, "Signed iCal feed URL revoked");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/newsletter/ical-token",
+    tag = "Events",
+    responses(
+        (status = 200, description = "Signed webcal URL with the events of the next two newsletter weeks for the current account; replaces any previous URL", body = NewsletterIcalTokenResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 503, description = "Server not configured for signed feeds", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn create_newsletter_ical_token(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<NewsletterIcalTokenResponse>, AppError> {
+    let Some(key) = state.api_token_hmac_key.as_ref() else {
+        return Err(AppError::service_unavailable(
+            "signed feeds are not configured (set API_TOKEN_SECRET)",
+        ));
+    };
+    let user = current_user_from_headers(&headers, &state).await?;
+    ensure_newsletter_access(&user, &state).await?;
+
+    let row = sqlx::query!(
+        r#"
+        INSERT INTO newsletter_ical_tokens (account_id)
+        VALUES ($1)
+        ON CONFLICT (account_id) DO UPDATE
+        SET version = newsletter_ical_tokens.version + 1, created_at = NOW()
+        RETURNING version, created_at
+        "#,
+        user.account_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "ical", action = "create_newsletter_ical_token", account_id = user.account_id, version = row.version, "Newsletter iCal feed URL created");
+
+    let url = format!(
+        "{}/api/ical/newsletter/{}",
+        frontend_base_url(),
+        newsletter_feed_token(key, user.account_id, row.version)
+    );
+    Ok(Json(NewsletterIcalTokenResponse {
+        webcal_url: webcal_url(&url),
+        url,
+        created_at: row.created_at,
+    }))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/newsletter/ical-token",
+    tag = "Events",
+    responses(
+        (status = 204, description = "Newsletter webcal URL of the current account revoked"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "No newsletter feed URL configured", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn revoke_newsletter_ical_token(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let result = sqlx::query!(
+        "DELETE FROM newsletter_ical_tokens WHERE account_id = $1",
+        user.account_id
+    )
+    .execute(&state.db)
+    .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("No newsletter feed URL configured"));
+    }
+
+    info!(target: "ical", action = "revoke_newsletter_ical_token", account_id = user.account_id, "Newsletter iCal feed URL revoked");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn newsletter_router() -> Router<AppState> {
+    Router::new().route(
+        "/ical-token",
+        post(create_newsletter_ical_token).delete(revoke_newsletter_ical_token),
+    )
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/{id}/private-feed",
+            get(get_private_feed)
+                .post(create_private_feed)
+                .delete(revoke_private_feed),
+        )
+        .route(
+            "/{id}/ical-token",
+            post(create_ical_token).delete(revoke_ical_token),
+        )
+}
@@ -1,26 +1,59 @@
 use axum::{
-    Json, Router,
-    extract::{Path, Query, State},
+    Extension, Router,
+    extract::{OriginalUri, Path, Query, State},
+    http::HeaderMap,
+    middleware,
     routing::get,
 };
 use tracing::{instrument, warn};
 
 use crate::{
+    app_links::{event_app_link, organizer_app_link},
     app_state::AppState,
-    dto::{ListEventsQuery, ListPublicOrganizersQuery},
+    dto::{
+        ChannelQuery, EventArchiveQuery, EventChangesQuery, EventSnapshotQuery, LanguageQuery,
+        ListEventsQuery, ListPublicOrganizersQuery, PublicChannel,
+    },
     error::AppError,
-    models::OrganizerKind,
-    responses::{PublicEventResponse, PublicOrganizerResponse},
+    i18n::{self, Locale},
+    markdown::render_optional_markdown,
+    models::{EventAudience, OrganizerKind},
+    pagination::pagination_headers,
+    responses::{
+        ErrorResponse, EventSnapshotStatus, PublicEventArchiveMonth, PublicEventArchiveResponse,
+        PublicEventChangesResponse, PublicEventDetailResponse, PublicEventResponse,
+        PublicEventSeriesResponse, PublicEventSnapshotResponse, PublicOrganizerResponse,
+        PublicRelatedEventsResponse, PublicVenueResponse,
+    },
+    term::normalize_term,
+    timings::{Timed, TimedJson, TimingPhase},
 };
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveDate, TimeZone, Utc};
+use chrono_tz::Europe::Berlin;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
 use sqlx::{FromRow, Postgres, QueryBuilder};
 
+use super::{
+    agenda::fetch_agenda_items,
+    event_views::record_event_view,
+    events::{ensure_valid_start_range, push_search_filter, search_term},
+    price_tiers::fetch_price_tiers,
+    shared::append_event_footer,
+    speakers::fetch_event_speakers,
+};
+
+const MAX_RELATED_EVENTS: i64 = 4;
+const RELATED_EVENTS_WINDOW_DAYS: i64 = 7;
+
 #[derive(Debug, FromRow)]
 struct PublicEventWithOrganizer {
     id: i64,
     organizer_id: i64,
     organizer_name: String,
     organizer_kind: OrganizerKind,
+    organizer_event_footer_de: Option<String>,
+    organizer_event_footer_en: Option<String>,
     title_de: String,
     title_en: String,
     description_de: Option<String>,
@@ -30,6 +63,194 @@ struct PublicEventWithOrganizer {
     event_url: Option<String>,
     location: Option<String>,
     publish_web: bool,
+    max_participants: Option<i32>,
+    cancelled_at: Option<DateTime<Utc>>,
+    series_id: Option<i64>,
+    custom_fields: Value,
+    price_cents: Option<i32>,
+    currency: String,
+    free_entry: bool,
+    audience: Vec<EventAudience>,
+    term: String,
+    all_day: bool,
+    slug: Option<String>,
+    venue_id: Option<i64>,
+    venue_name: Option<String>,
+    venue_building: Option<String>,
+    venue_room: Option<String>,
+    venue_latitude: Option<f64>,
+    venue_longitude: Option<f64>,
+}
+
+fn preferred_language(locale: Locale, has_de: bool, has_en: bool) -> Option<Locale> {
+    match (locale, has_de, has_en) {
+        (Locale::En, _, true) | (Locale::De, false, true) => Some(Locale::En),
+        (_, true, _) => Some(Locale::De),
+        _ => None,
+    }
+}
+
+fn is_filled(value: Option<&str>) -> bool {
+    value.is_some_and(|value| !value.trim().is_empty())
+}
+
+pub(crate) fn resolve_title(locale: Locale, title_de: &str, title_en: &str) -> String {
+    match preferred_language(locale, is_filled(Some(title_de)), is_filled(Some(title_en))) {
+        Some(Locale::En) => title_en.to_string(),
+        _ => title_de.to_string(),
+    }
+}
+
+fn resolve_description(
+    locale: Locale,
+    description_de: Option<&String>,
+    description_en: Option<&String>,
+) -> Option<String> {
+    match preferred_language(
+        locale,
+        is_filled(description_de.map(String::as_str)),
+        is_filled(description_en.map(String::as_str)),
+    )? {
+        Locale::De => description_de.cloned(),
+        Locale::En => description_en.cloned(),
+    }
+}
+
+pub(crate) trait Localize {
+    fn localize(&mut self, locale: Locale);
+}
+
+impl<T: Localize> Localize for Vec<T> {
+    fn localize(&mut self, locale: Locale) {
+        for item in self {
+            item.localize(locale);
+        }
+    }
+}
+
+impl Localize for PublicEventResponse {
+    fn localize(&mut self, locale: Locale) {
+        self.title = resolve_title(locale, &self.title_de, &self.title_en);
+        let language = preferred_language(
+            locale,
+            is_filled(self.description_de.as_deref()),
+            is_filled(self.description_en.as_deref()),
+        );
+        (self.description, self.description_html) = match language {
+            Some(Locale::De) => (
+                self.description_de.clone(),
+                self.description_de_html.clone(),
+            ),
+            Some(Locale::En) => (
+                self.description_en.clone(),
+                self.description_en_html.clone(),
+            ),
+            None => (None, None),
+        };
+    }
+}
+
+impl Localize for PublicRelatedEventsResponse {
+    fn localize(&mut self, locale: Locale) {
+        self.same_organizer.localize(locale);
+        self.nearby.localize(locale);
+    }
+}
+
+impl Localize for PublicEventDetailResponse {
+    fn localize(&mut self, locale: Locale) {
+        self.event.localize(locale);
+        self.related.localize(locale);
+    }
+}
+
+impl Localize for PublicEventSeriesResponse {
+    fn localize(&mut self, locale: Locale) {
+        self.title = resolve_title(locale, &self.title_de, &self.title_en);
+        self.description = resolve_description(
+            locale,
+            self.description_de.as_ref(),
+            self.description_en.as_ref(),
+        );
+        self.events.localize(locale);
+    }
+}
+
+impl Localize for PublicOrganizerResponse {
+    fn localize(&mut self, locale: Locale) {
+        self.description = resolve_description(
+            locale,
+            self.description_de.as_ref(),
+            self.description_en.as_ref(),
+        );
+    }
+}
+
+impl Localize for PublicEventArchiveResponse {
+    fn localize(&mut self, locale: Locale) {
+        for month in &mut self.months {
+            month.events.localize(locale);
+        }
+    }
+}
+
+impl From<PublicEventWithOrganizer> for PublicEventResponse {
+    fn from(event: PublicEventWithOrganizer) -> Self {
+        let description_de = append_event_footer(
+            event.description_de,
+            event.organizer_event_footer_de.as_deref(),
+        );
+        let description_en = append_event_footer(
+            event.description_en,
+            event.organizer_event_footer_en.as_deref(),
+        );
+        let venue = event
+            .venue_id
+            .zip(event.venue_name)
+            .map(|(id, name)| PublicVenueResponse {
+                id,
+                name,
+                building: event.venue_building,
+                room: event.venue_room,
+                latitude: event.venue_latitude,
+                longitude: event.venue_longitude,
+            });
+        let mut response = Self {
+            id: event.id,
+            organizer_id: event.organizer_id,
+            organizer_name: event.organizer_name,
+            organizer_kind: event.organizer_kind,
+            title_de: event.title_de,
+            title_en: event.title_en,
+            description_de_html: render_optional_markdown(description_de.as_deref()),
+            description_en_html: render_optional_markdown(description_en.as_deref()),
+            description_de,
+            description_en,
+            title: String::new(),
+            description: None,
+            description_html: None,
+            start_date_time: event.start_date_time,
+            end_date_time: event.end_date_time,
+            event_url: event.event_url,
+            location: event.location,
+            publish_web: event.publish_web,
+            max_participants: event.max_participants,
+            cancelled_at: event.cancelled_at,
+            series_id: event.series_id,
+            custom_fields: event.custom_fields,
+            price_cents: event.price_cents,
+            currency: event.currency,
+            free_entry: event.free_entry,
+            audience: event.audience,
+            term: event.term,
+            all_day: event.all_day,
+            slug: event.slug,
+            venue,
+            app_link: event_app_link(event.id),
+        };
+        response.localize(Locale::default());
+        response
+    }
 }
 
 #[derive(Debug, FromRow)]
@@ -49,35 +270,24 @@ struct PublicOrganizerWithStats {
     activity_score: f64,
 }
 
-#[utoipa::path(
-    get,
-    path = "/api/v1/public/events",
-    tag = "Public",
-    params(ListEventsQuery),
-    responses((status = 200, description = "List public events", body = [PublicEventResponse]))
-)]
-#[instrument(skip(state, query_params))]
-pub(crate) async fn list_public_events(
-    State(state): State<AppState>,
-    Query(query_params): Query<ListEventsQuery>,
-) -> Result<Json<Vec<PublicEventResponse>>, AppError> {
-    let cache_key = format!("public:events:list:{query_params:?}");
-    if let Some(cache) = &state.cache {
-        match cache.get_json::<Vec<PublicEventResponse>>(&cache_key).await {
-            Ok(Some(cached)) => return Ok(Json(cached)),
-            Ok(None) => {}
-            Err(err) => {
-                warn!(target: "cache", action = "get", scope = "public_events_list", %err, "Failed to read public events list from cache")
-            }
-        }
-    }
-
-    let mut builder = QueryBuilder::<Postgres>::new(
-        "SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web FROM events e INNER JOIN organizers o ON e.organizer_id = o.id",
+fn public_events_query(
+    select: &str,
+    query_params: &ListEventsQuery,
+) -> Result<QueryBuilder<'static, Postgres>, AppError> {
+    let mut builder = QueryBuilder::<Postgres>::new(select);
+    builder.push(
+        " FROM events e INNER JOIN organizers o ON e.organizer_id = o.id LEFT JOIN venues v ON v.id = e.venue_id",
     );
 
-    // Only show events that are published in the app
-    builder.push(" WHERE e.publish_app = true");
+    // Only show events that are published for the requested channel
+    let channel_column = match query_params.channel.unwrap_or_default() {
+        PublicChannel::App => "e.publish_app",
+        PublicChannel::Web => "e.publish_web",
+    };
+    builder
+        .push(" WHERE ")
+        .push(channel_column)
+        .push(" = true AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC' AND (e.publish_at IS NULL OR e.publish_at <= NOW())");
 
     if let Some(organizer_id) = query_params.organizer_id {
         builder
@@ -91,12 +301,75 @@ pub(crate) async fn list_public_events(
             .push_bind(organizer_kind);
     }
 
+    if let Some(series_id) = query_params.series_id {
+        builder.push(" AND e.series_id = ").push_bind(series_id);
+    }
+
+    if let Some(free_entry) = query_params.free_entry {
+        builder.push(" AND e.free_entry = ").push_bind(free_entry);
+    }
+
+    if let Some(audience) = query_params.audience {
+        builder
+            .push(" AND ")
+            .push_bind(audience)
+            .push(" = ANY(e.audience)");
+    }
+
+    if let Some(term) = query_params.term.as_deref() {
+        builder
+            .push(" AND e.term = ")
+            .push_bind(normalize_term(term)?);
+    }
+
+    if let Some(search) = search_term(query_params.q.as_deref())? {
+        builder.push(" AND ");
+        push_search_filter(&mut builder, search);
+    }
+
+    ensure_valid_start_range(query_params)?;
+    if let Some(start_after) = query_params.start_after {
+        builder
+            .push(" AND e.start_date_time >= ")
+            .push_bind(start_after);
+    }
+    if let Some(start_before) = query_params.start_before {
+        builder
+            .push(" AND e.start_date_time < ")
+            .push_bind(start_before);
+    }
+
     if query_params.upcoming_only.unwrap_or(false) {
         builder
             .push(" AND e.end_date_time >= ")
             .push_bind(Utc::now());
     }
 
+    Ok(builder)
+}
+
+async fn cached_public_events(
+    state: &AppState,
+    query_params: &ListEventsQuery,
+) -> Result<(i64, Vec<PublicEventResponse>), AppError> {
+    let cache_key = format!("public:events:list:{query_params:?}");
+    if let Some(cache) = &state.cache {
+        match cache
+            .get_json::<(i64, Vec<PublicEventResponse>)>(&cache_key)
+            .await
+        {
+            Ok(Some(cached)) => return Ok(cached),
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "cache", action = "get", scope = "public_events_list", %err, "Failed to read public events list from cache")
+            }
+        }
+    }
+
+    let mut builder = public_events_query(
+        "SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind, o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience, e.term, e.all_day, e.slug, v.id AS venue_id, v.name AS venue_name, v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude",
+        query_params,
+    )?;
     builder.push(" ORDER BY e.start_date_time ASC");
 
     if let Some(limit) = query_params.limit {
@@ -109,34 +382,92 @@ pub(crate) async fn list_public_events(
     let events = builder
         .build_query_as::<PublicEventWithOrganizer>()
         .fetch_all(&state.db)
+        .timed(TimingPhase::Database)
+        .await?;
+    let total = public_events_query("SELECT COUNT(*)", query_params)?
+        .build_query_scalar::<i64>()
+        .fetch_one(&state.db)
+        .timed(TimingPhase::Database)
         .await?;
 
-    let public_events: Vec<PublicEventResponse> = events
-        .into_iter()
-        .map(|event| PublicEventResponse {
-            id: event.id,
-            organizer_id: event.organizer_id,
-            organizer_name: event.organizer_name,
-            organizer_kind: event.organizer_kind,
-            title_de: event.title_de,
-            title_en: event.title_en,
-            description_de: event.description_de,
-            description_en: event.description_en,
-            start_date_time: event.start_date_time,
-            end_date_time: event.end_date_time,
-            event_url: event.event_url,
-            location: event.location,
-            publish_web: event.publish_web,
-        })
-        .collect();
+    let public_events: Vec<PublicEventResponse> =
+        events.into_iter().map(PublicEventResponse::from).collect();
 
     if let Some(cache) = &state.cache
-        && let Err(err) = cache.set_json(&cache_key, &public_events).await
+        && let Err(err) = cache.set_json(&cache_key, &(total, &public_events)).await
     {
         warn!(target: "cache", action = "set", scope = "public_events_list", %err, "Failed to store public events list in cache");
     }
 
-    Ok(Json(public_events))
+    Ok((total, public_events))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events",
+    tag = "Public",
+    params(ListEventsQuery, LanguageQuery),
+    responses((status = 200, description = "List public events", body = [PublicEventResponse], headers(
+        ("X-Total-Count" = i64, description = "Number of events matching the filters, ignoring limit and offset"),
+        ("Link" = String, description = "RFC 8288 first, prev, next and last page links when limit is set"),
+    )))
+)]
+#[instrument(skip(state, query_params))]
+pub(crate) async fn list_public_events(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Extension(locale): Extension<Locale>,
+    Query(query_params): Query<ListEventsQuery>,
+) -> Result<(HeaderMap, TimedJson<Vec<PublicEventResponse>>), AppError> {
+    let (total, mut public_events) = cached_public_events(&state, &query_params).await?;
+    public_events.localize(locale);
+
+    Ok((
+        pagination_headers(&uri, total, query_params.limit, query_params.offset),
+        TimedJson(public_events),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/organizers/{id}/events",
+    tag = "Public",
+    params(("id" = i64, Path, description = "Organizer identifier"), ListEventsQuery, LanguageQuery),
+    responses(
+        (status = 200, description = "List public events of one organizer", body = [PublicEventResponse], headers(
+            ("X-Total-Count" = i64, description = "Number of events matching the filters, ignoring limit and offset"),
+            ("Link" = String, description = "RFC 8288 first, prev, next and last page links when limit is set"),
+        )),
+        (status = 404, description = "Organizer not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, query_params))]
+pub(crate) async fn list_public_organizer_events(
+    State(state): State<AppState>,
+    OriginalUri(uri): OriginalUri,
+    Extension(locale): Extension<Locale>,
+    Path(id): Path<i64>,
+    Query(mut query_params): Query<ListEventsQuery>,
+) -> Result<(HeaderMap, TimedJson<Vec<PublicEventResponse>>), AppError> {
+    query_params.organizer_id = Some(id);
+    let (total, mut public_events) = cached_public_events(&state, &query_params).await?;
+
+    if total == 0 {
+        let exists =
+            sqlx::query_scalar!("SELECT EXISTS(SELECT 1 FROM organizers WHERE id = $1)", id)
+                .fetch_one(&state.db)
+                .timed(TimingPhase::Database)
+                .await?;
+        if exists != Some(true) {
+            return Err(AppError::not_found("Organizer not found"));
+        }
+    }
+    public_events.localize(locale);
+
+    Ok((
+        pagination_headers(&uri, total, query_params.limit, query_params.offset),
+        TimedJson(public_events),
+    ))
 }
 
 #[utoipa::path(
@@ -149,15 +480,19 @@ pub(crate) async fn list_public_events(
 #[instrument(skip(state, query_params))]
 pub(crate) async fn list_public_organizers(
     State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
     Query(query_params): Query<ListPublicOrganizersQuery>,
-) -> Result<Json<Vec<PublicOrganizerResponse>>, AppError> {
-    let cache_key = format!("public:organizers:list:{query_params:?}");
+) -> Result<TimedJson<Vec<PublicOrganizerResponse>>, AppError> {
+    let cache_key = format!("public:organizers:list:{:?}", query_params.organizer_kind);
     if let Some(cache) = &state.cache {
         match cache
             .get_json::<Vec<PublicOrganizerResponse>>(&cache_key)
             .await
         {
-            Ok(Some(cached)) => return Ok(Json(cached)),
+            Ok(Some(mut cached)) => {
+                cached.localize(locale);
+                return Ok(TimedJson(cached));
+            }
             Ok(None) => {}
             Err(err) => {
                 warn!(target: "cache", action = "get", scope = "public_organizers_list", %err, "Failed to read public organizers list from cache")
@@ -196,15 +531,17 @@ pub(crate) async fn list_public_organizers(
     let organizers = builder
         .build_query_as::<PublicOrganizerWithStats>()
         .fetch_all(&state.db)
+        .timed(TimingPhase::Database)
         .await?;
 
-    let public_organizers: Vec<PublicOrganizerResponse> = organizers
+    let mut public_organizers: Vec<PublicOrganizerResponse> = organizers
         .into_iter()
         .map(|organizer| PublicOrganizerResponse {
             id: organizer.id,
             name: organizer.name,
             description_de: organizer.description_de,
             description_en: organizer.description_en,
+            description: None,
             website_url: organizer.website_url,
             instagram_url: organizer.instagram_url,
             location: organizer.location,
@@ -214,6 +551,7 @@ pub(crate) async fn list_public_organizers(
             organizer_kind: organizer.organizer_kind,
             active_events_count: organizer.active_events_count,
             activity_score: organizer.activity_score,
+            app_link: organizer_app_link(organizer.id),
         })
         .collect();
 
@@ -222,26 +560,106 @@ pub(crate) async fn list_public_organizers(
     {
         warn!(target: "cache", action = "set", scope = "public_organizers_list", %err, "Failed to store public organizers list in cache");
     }
+    public_organizers.localize(locale);
 
-    Ok(Json(public_organizers))
+    Ok(TimedJson(public_organizers))
+}
+
+async fn fetch_related_events(
+    state: &AppState,
+    event: &PublicEventResponse,
+    channel: PublicChannel,
+) -> Result<PublicRelatedEventsResponse, AppError> {
+    let same_organizer = sqlx::query_as!(
+        PublicEventWithOrganizer,
+        r#"
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.term, e.all_day, e.slug, v.id AS "venue_id?", v.name AS "venue_name?", v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude
+        FROM events e
+        INNER JOIN organizers o ON e.organizer_id = o.id
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE e.organizer_id = $1 AND e.id <> $2
+        AND CASE WHEN $4 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        AND e.cancelled_at IS NULL
+        AND e.end_date_time >= NOW()
+        ORDER BY e.start_date_time ASC
+        LIMIT $3
+        "#,
+        event.organizer_id,
+        event.id,
+        MAX_RELATED_EVENTS,
+        channel == PublicChannel::Web
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    let nearby = sqlx::query_as!(
+        PublicEventWithOrganizer,
+        r#"
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.term, e.all_day, e.slug, v.id AS "venue_id?", v.name AS "venue_name?", v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude
+        FROM events e
+        INNER JOIN organizers o ON e.organizer_id = o.id
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE e.organizer_id <> $1
+        AND o.organizer_kind = $2
+        AND e.audience && $3
+        AND e.start_date_time BETWEEN $4 AND $5
+        AND CASE WHEN $8 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        AND e.cancelled_at IS NULL
+        AND e.end_date_time >= NOW()
+        ORDER BY ABS(EXTRACT(EPOCH FROM (e.start_date_time - $6))) ASC, e.id ASC
+        LIMIT $7
+        "#,
+        event.organizer_id,
+        event.organizer_kind as OrganizerKind,
+        &event.audience as &[EventAudience],
+        event.start_date_time - Duration::days(RELATED_EVENTS_WINDOW_DAYS),
+        event.start_date_time + Duration::days(RELATED_EVENTS_WINDOW_DAYS),
+        event.start_date_time,
+        MAX_RELATED_EVENTS,
+        channel == PublicChannel::Web
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    Ok(PublicRelatedEventsResponse {
+        same_organizer: same_organizer
+            .into_iter()
+            .map(PublicEventResponse::from)
+            .collect(),
+        nearby: nearby.into_iter().map(PublicEventResponse::from).collect(),
+    })
 }
 
 #[utoipa::path(
     get,
     path = "/api/v1/public/events/{id}",
     tag = "Public",
-    params(("id" = i64, Path, description = "Event identifier")),
-    responses((status = 200, description = "Public event details", body = PublicEventResponse), (status = 404, description = "Event not found or not published"))
+    params(("id" = i64, Path, description = "Event identifier"), ChannelQuery, LanguageQuery),
+    responses((status = 200, description = "Public event details", body = PublicEventDetailResponse), (status = 404, description = "Event not found or not published"))
 )]
 #[instrument(skip(state))]
 pub(crate) async fn get_public_event(
     State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
     Path(id): Path<i64>,
-) -> Result<Json<PublicEventResponse>, AppError> {
-    let cache_key = format!("public:events:item:{id}");
+    Query(channel): Query<ChannelQuery>,
+) -> Result<TimedJson<PublicEventDetailResponse>, AppError> {
+    let channel = channel.channel.unwrap_or_default();
+    let cache_key = format!("public:events:item:{id}:{channel:?}");
     if let Some(cache) = &state.cache {
-        match cache.get_json::<PublicEventResponse>(&cache_key).await {
-            Ok(Some(cached)) => return Ok(Json(cached)),
+        match cache
+            .get_json::<PublicEventDetailResponse>(&cache_key)
+            .await
+        {
+            Ok(Some(mut cached)) => {
+                record_event_view(&state, id).await;
+                cached.localize(locale);
+                return Ok(TimedJson(cached));
+            }
             Ok(None) => {}
             Err(err) => {
                 warn!(target: "cache", action = "get", scope = "public_event", event_id = id, %err, "Failed to read public event from cache")
@@ -252,60 +670,148 @@ pub(crate) async fn get_public_event(
     let event = sqlx::query_as!(
         PublicEventWithOrganizer,
         r#"
-        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.term, e.all_day, e.slug, v.id AS "venue_id?", v.name AS "venue_name?", v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude
         FROM events e
         INNER JOIN organizers o ON e.organizer_id = o.id
-        WHERE e.id = $1 AND e.publish_app = true
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE e.id = $1 AND CASE WHEN $2 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
         "#,
-        id
+        id,
+        channel == PublicChannel::Web
     )
     .fetch_optional(&state.db)
+    .timed(TimingPhase::Database)
     .await?;
 
     match event {
         Some(event) => {
-            let public_event = PublicEventResponse {
-                id: event.id,
-                organizer_id: event.organizer_id,
-                organizer_name: event.organizer_name,
-                organizer_kind: event.organizer_kind,
-                title_de: event.title_de,
-                title_en: event.title_en,
-                description_de: event.description_de,
-                description_en: event.description_en,
-                start_date_time: event.start_date_time,
-                end_date_time: event.end_date_time,
-                event_url: event.event_url,
-                location: event.location,
-                publish_web: event.publish_web,
+            record_event_view(&state, id).await;
+            let event = PublicEventResponse::from(event);
+            let mut public_event = PublicEventDetailResponse {
+                agenda: fetch_agenda_items(&state, id).await?,
+                price_tiers: fetch_price_tiers(&state, &[id]).await?,
+                speakers: fetch_event_speakers(&state, id).await?,
+                related: fetch_related_events(&state, &event, channel).await?,
+                event,
             };
             if let Some(cache) = &state.cache
                 && let Err(err) = cache.set_json(&cache_key, &public_event).await
             {
                 warn!(target: "cache", action = "set", scope = "public_event", event_id = id, %err, "Failed to store public event in cache");
             }
-            Ok(Json(public_event))
+            public_event.localize(locale);
+            Ok(TimedJson(public_event))
         }
         None => Err(AppError::not_found("Event not found or not published")),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/series/{id}",
+    tag = "Public",
+    params(("id" = i64, Path, description = "Series identifier"), ChannelQuery, LanguageQuery),
+    responses((status = 200, description = "Event series with its public events", body = PublicEventSeriesResponse), (status = 404, description = "Series not found"))
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_public_series(
+    State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
+    Path(id): Path<i64>,
+    Query(channel): Query<ChannelQuery>,
+) -> Result<TimedJson<PublicEventSeriesResponse>, AppError> {
+    let channel = channel.channel.unwrap_or_default();
+    let cache_key = format!("public:events:series:{id}:{channel:?}");
+    if let Some(cache) = &state.cache {
+        match cache
+            .get_json::<PublicEventSeriesResponse>(&cache_key)
+            .await
+        {
+            Ok(Some(mut cached)) => {
+                cached.localize(locale);
+                return Ok(TimedJson(cached));
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "cache", action = "get", scope = "public_series", series_id = id, %err, "Failed to read public series from cache")
+            }
+        }
+    }
+
+    let series = sqlx::query!(
+        r#"
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en
+        FROM event_series
+        WHERE id = $1
+        "#,
+        id
+    )
+    .fetch_optional(&state.db)
+    .timed(TimingPhase::Database)
+    .await?
+    .ok_or_else(|| AppError::not_found("Series not found"))?;
+
+    let events = sqlx::query_as!(
+        PublicEventWithOrganizer,
+        r#"
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.term, e.all_day, e.slug, v.id AS "venue_id?", v.name AS "venue_name?", v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude
+        FROM events e
+        INNER JOIN organizers o ON e.organizer_id = o.id
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE e.series_id = $1 AND CASE WHEN $2 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        ORDER BY e.start_date_time ASC
+        "#,
+        id,
+        channel == PublicChannel::Web
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    let mut public_series = PublicEventSeriesResponse {
+        id: series.id,
+        organizer_id: series.organizer_id,
+        title_de: series.title_de,
+        title_en: series.title_en,
+        description_de: series.description_de,
+        description_en: series.description_en,
+        title: String::new(),
+        description: None,
+        events: events.into_iter().map(PublicEventResponse::from).collect(),
+    };
+
+    if let Some(cache) = &state.cache
+        && let Err(err) = cache.set_json(&cache_key, &public_series).await
+    {
+        warn!(target: "cache", action = "set", scope = "public_series", series_id = id, %err, "Failed to store public series in cache");
+    }
+    public_series.localize(locale);
+
+    Ok(TimedJson(public_series))
+}
+
 #[utoipa::path(
     get,
     path = "/api/v1/public/organizers/{id}",
     tag = "Public",
-    params(("id" = i64, Path, description = "Organizer identifier")),
+    params(("id" = i64, Path, description = "Organizer identifier"), LanguageQuery),
     responses((status = 200, description = "Public organizer details", body = PublicOrganizerResponse), (status = 404, description = "Organizer not found"))
 )]
 #[instrument(skip(state))]
 pub(crate) async fn get_public_organizer(
     State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
     Path(id): Path<i64>,
-) -> Result<Json<PublicOrganizerResponse>, AppError> {
+) -> Result<TimedJson<PublicOrganizerResponse>, AppError> {
     let cache_key = format!("public:organizers:item:{id}");
     if let Some(cache) = &state.cache {
         match cache.get_json::<PublicOrganizerResponse>(&cache_key).await {
-            Ok(Some(cached)) => return Ok(Json(cached)),
+            Ok(Some(mut cached)) => {
+                cached.localize(locale);
+                return Ok(TimedJson(cached));
+            }
             Ok(None) => {}
             Err(err) => {
                 warn!(target: "cache", action = "get", scope = "public_organizer", organizer_id = id, %err, "Failed to read public organizer from cache")
@@ -337,15 +843,17 @@ pub(crate) async fn get_public_organizer(
         id
     )
     .fetch_optional(&state.db)
+    .timed(TimingPhase::Database)
     .await?;
 
     match organizer {
         Some(organizer) => {
-            let public_organizer = PublicOrganizerResponse {
+            let mut public_organizer = PublicOrganizerResponse {
                 id: organizer.id,
                 name: organizer.name,
                 description_de: organizer.description_de,
                 description_en: organizer.description_en,
+                description: None,
                 website_url: organizer.website_url,
                 instagram_url: organizer.instagram_url,
                 location: organizer.location,
@@ -355,22 +863,481 @@ pub(crate) async fn get_public_organizer(
                 organizer_kind: organizer.organizer_kind,
                 active_events_count: organizer.active_events_count,
                 activity_score: organizer.activity_score,
+                app_link: organizer_app_link(organizer.id),
             };
             if let Some(cache) = &state.cache
                 && let Err(err) = cache.set_json(&cache_key, &public_organizer).await
             {
                 warn!(target: "cache", action = "set", scope = "public_organizer", organizer_id = id, %err, "Failed to store public organizer in cache");
             }
-            Ok(Json(public_organizer))
+            public_organizer.localize(locale);
+            Ok(TimedJson(public_organizer))
         }
         None => Err(AppError::not_found("Organizer not found")),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/featured",
+    tag = "Public",
+    params(ChannelQuery, LanguageQuery),
+    responses((status = 200, description = "Upcoming public events currently pinned by admins", body = [PublicEventResponse]))
+)]
+#[instrument(skip(state))]
+pub(crate) async fn list_featured_public_events(
+    State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
+    Query(channel): Query<ChannelQuery>,
+) -> Result<TimedJson<Vec<PublicEventResponse>>, AppError> {
+    let channel = channel.channel.unwrap_or_default();
+    let cache_key = format!("public:events:featured:{channel:?}");
+    if let Some(cache) = &state.cache {
+        match cache.get_json::<Vec<PublicEventResponse>>(&cache_key).await {
+            Ok(Some(mut cached)) => {
+                cached.localize(locale);
+                return Ok(TimedJson(cached));
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "cache", action = "get", scope = "public_events_featured", %err, "Failed to read featured events from cache")
+            }
+        }
+    }
+
+    let events = sqlx::query_as!(
+        PublicEventWithOrganizer,
+        r#"
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.term, e.all_day, e.slug, v.id AS "venue_id?", v.name AS "venue_name?", v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude
+        FROM events e
+        INNER JOIN organizers o ON e.organizer_id = o.id
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE CASE WHEN $1 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        AND e.featured_until > NOW()
+        AND e.end_date_time >= NOW()
+        ORDER BY e.start_date_time ASC, e.id ASC
+        "#,
+        channel == PublicChannel::Web
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    let mut featured: Vec<PublicEventResponse> =
+        events.into_iter().map(PublicEventResponse::from).collect();
+
+    if let Some(cache) = &state.cache
+        && let Err(err) = cache.set_json(&cache_key, &featured).await
+    {
+        warn!(target: "cache", action = "set", scope = "public_events_featured", %err, "Failed to store featured events in cache");
+    }
+    featured.localize(locale);
+
+    Ok(TimedJson(featured))
+}
+
+const EVENT_SNAPSHOT_PREFIX: &str = "snapshots:public_events";
+
+fn sha256_hex(data: &[u8]) -> String {
+    Sha256::digest(data)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/snapshot",
+    tag = "Public",
+    params(EventSnapshotQuery, ChannelQuery, LanguageQuery),
+    responses((status = 200, description = "Upcoming public events compared against the client's snapshot hash: UNCHANGED, a DELTA against a recently served snapshot, or the FULL list", body = PublicEventSnapshotResponse))
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_public_event_snapshot(
+    State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
+    Query(query): Query<EventSnapshotQuery>,
+    Query(channel): Query<ChannelQuery>,
+) -> Result<TimedJson<PublicEventSnapshotResponse>, AppError> {
+    let channel = channel.channel.unwrap_or_default();
+    let events = sqlx::query_as!(
+        PublicEventWithOrganizer,
+        r#"
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.term, e.all_day, e.slug, v.id AS "venue_id?", v.name AS "venue_name?", v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude
+        FROM events e
+        INNER JOIN organizers o ON e.organizer_id = o.id
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE CASE WHEN $3 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        AND e.end_date_time >= NOW()
+        AND ($1::BIGINT IS NULL OR e.organizer_id = $1)
+        AND ($2::organizer_kind IS NULL OR o.organizer_kind = $2)
+        ORDER BY e.start_date_time ASC, e.id ASC
+        "#,
+        query.organizer_id,
+        query.organizer_kind as Option<OrganizerKind>,
+        channel == PublicChannel::Web
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    let mut events: Vec<PublicEventResponse> =
+        events.into_iter().map(PublicEventResponse::from).collect();
+    events.localize(locale);
+    let mut event_hashes = Vec::with_capacity(events.len());
+    for event in &events {
+        event_hashes.push((event.id, sha256_hex(&serde_json::to_vec(event)?)));
+    }
+    let hash = sha256_hex(
+        event_hashes
+            .iter()
+            .map(|(id, event_hash)| format!("{id}:{event_hash}\n"))
+            .collect::<String>()
+            .as_bytes(),
+    );
+
+    let client_hash = query
+        .hash
+        .as_deref()
+        .map(str::trim)
+        .filter(|value| !value.is_empty());
+    if client_hash == Some(hash.as_str()) {
+        return Ok(TimedJson(PublicEventSnapshotResponse {
+            hash,
+            status: EventSnapshotStatus::Unchanged,
+            events: Vec::new(),
+            removed_event_ids: Vec::new(),
+        }));
+    }
+
+    let mut previous = None;
+    if let Some(cache) = &state.cache {
+        if let Some(client_hash) = client_hash {
+            match cache
+                .get_json::<Vec<(i64, String)>>(&format!("{EVENT_SNAPSHOT_PREFIX}:{client_hash}"))
+                .await
+            {
+                Ok(snapshot) => previous = snapshot,
+                Err(err) => {
+                    warn!(target: "cache", action = "get", scope = "public_event_snapshot", %err, "Failed to read event snapshot from cache")
+                }
+            }
+        }
+        if let Err(err) = cache
+            .set_json(&format!("{EVENT_SNAPSHOT_PREFIX}:{hash}"), &event_hashes)
+            .await
+        {
+            warn!(target: "cache", action = "set", scope = "public_event_snapshot", %err, "Failed to store event snapshot in cache");
+        }
+    }
+
+    let Some(previous) = previous else {
+        return Ok(TimedJson(PublicEventSnapshotResponse {
+            hash,
+            status: EventSnapshotStatus::Full,
+            events,
+            removed_event_ids: Vec::new(),
+        }));
+    };
+
+    let removed_event_ids = previous
+        .iter()
+        .filter(|(id, _)| !event_hashes.iter().any(|(current, _)| current == id))
+        .map(|(id, _)| *id)
+        .collect();
+    let changed_events = events
+        .into_iter()
+        .zip(event_hashes.iter())
+        .filter(|(_, current)| !previous.contains(current))
+        .map(|(event, _)| event)
+        .collect();
+
+    Ok(TimedJson(PublicEventSnapshotResponse {
+        hash,
+        status: EventSnapshotStatus::Delta,
+        events: changed_events,
+        removed_event_ids,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/changes",
+    tag = "Public",
+    params(EventChangesQuery, ChannelQuery, LanguageQuery),
+    responses(
+        (status = 200, description = "Public events created or updated since the given timestamp plus IDs of events that were deleted or are no longer public; pass `until` as the next `since`", body = PublicEventChangesResponse),
+        (status = 400, description = "since lies in the future", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_public_event_changes(
+    State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
+    Query(query): Query<EventChangesQuery>,
+    Query(channel): Query<ChannelQuery>,
+) -> Result<TimedJson<PublicEventChangesResponse>, AppError> {
+    let channel = channel.channel.unwrap_or_default();
+    let until = Utc::now();
+    if query.since > until {
+        return Err(AppError::validation("since must not be in the future"));
+    }
+
+    let touched_ids = sqlx::query_scalar!(
+        "SELECT DISTINCT event_id FROM audit_log WHERE at > $1",
+        query.since
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    let events = sqlx::query_as!(
+        PublicEventWithOrganizer,
+        r#"
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.term, e.all_day, e.slug, v.id AS "venue_id?", v.name AS "venue_name?", v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude
+        FROM events e
+        INNER JOIN organizers o ON e.organizer_id = o.id
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE CASE WHEN $3 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        AND (e.updated_at > $1 OR e.publish_at > $1 OR e.id = ANY($2))
+        ORDER BY e.start_date_time ASC, e.id ASC
+        "#,
+        query.since,
+        &touched_ids,
+        channel == PublicChannel::Web
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    let event_ids: Vec<i64> = events.iter().map(|event| event.id).collect();
+    let created_ids = sqlx::query_scalar!(
+        "SELECT id FROM events WHERE id = ANY($1) AND created_at > $2",
+        &event_ids,
+        query.since
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+    let deleted_event_ids = touched_ids
+        .into_iter()
+        .filter(|id| !event_ids.contains(id))
+        .collect();
+
+    let (mut created, mut updated): (Vec<PublicEventResponse>, Vec<PublicEventResponse>) = events
+        .into_iter()
+        .map(PublicEventResponse::from)
+        .partition(|event| created_ids.contains(&event.id));
+    created.localize(locale);
+    updated.localize(locale);
+
+    Ok(TimedJson(PublicEventChangesResponse {
+        since: query.since,
+        until,
+        created,
+        updated,
+        deleted_event_ids,
+    }))
+}
+
+fn berlin_month_start(year: i32, month: u32) -> Result<DateTime<Utc>, AppError> {
+    let date = NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or_else(|| AppError::validation("invalid year or month"))?;
+    Berlin
+        .from_local_datetime(&date.and_hms_opt(0, 0, 0).unwrap_or_default())
+        .earliest()
+        .map(|start| start.with_timezone(&Utc))
+        .ok_or_else(|| AppError::validation("invalid year or month"))
+}
+
+fn archive_range(
+    year: i32,
+    month: Option<u32>,
+) -> Result<(DateTime<Utc>, DateTime<Utc>), AppError> {
+    match month {
+        Some(month) => {
+            let (next_year, next_month) = if month == 12 {
+                (year + 1, 1)
+            } else {
+                (year, month + 1)
+            };
+            Ok((
+                berlin_month_start(year, month)?,
+                berlin_month_start(next_year, next_month)?,
+            ))
+        }
+        None => Ok((
+            berlin_month_start(year, 1)?,
+            berlin_month_start(year + 1, 1)?,
+        )),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/events/archive",
+    tag = "Public",
+    params(EventArchiveQuery, ChannelQuery, LanguageQuery),
+    responses(
+        (status = 200, description = "Concluded public events grouped by month, most recent first", body = PublicEventArchiveResponse),
+        (status = 400, description = "Invalid year or month", body = ErrorResponse)
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_public_event_archive(
+    State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
+    Query(query): Query<EventArchiveQuery>,
+    Query(channel): Query<ChannelQuery>,
+) -> Result<TimedJson<PublicEventArchiveResponse>, AppError> {
+    let channel = channel.channel.unwrap_or_default();
+    let year = match query.year {
+        Some(year) => year,
+        None if query.month.is_some() => {
+            return Err(AppError::validation("month requires year"));
+        }
+        None => Utc::now().with_timezone(&Berlin).year(),
+    };
+    if !(2000..=9999).contains(&year) {
+        return Err(AppError::validation("year must be between 2000 and 9999"));
+    }
+    if query.month.is_some_and(|month| !(1..=12).contains(&month)) {
+        return Err(AppError::validation("month must be between 1 and 12"));
+    }
+    let (range_start, range_end) = archive_range(year, query.month)?;
+
+    let cache_key = format!(
+        "public:events:archive:{year}:{}:{channel:?}",
+        query
+            .month
+            .map(|month| month.to_string())
+            .unwrap_or_default()
+    );
+    if let Some(cache) = &state.cache {
+        match cache
+            .get_json::<PublicEventArchiveResponse>(&cache_key)
+            .await
+        {
+            Ok(Some(mut cached)) => {
+                cached.localize(locale);
+                return Ok(TimedJson(cached));
+            }
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "cache", action = "get", scope = "public_event_archive", %err, "Failed to read public event archive from cache")
+            }
+        }
+    }
+
+    let events = sqlx::query_as!(
+        PublicEventWithOrganizer,
+        r#"
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, o.organizer_kind as "organizer_kind: OrganizerKind", o.event_footer_de AS organizer_event_footer_de, o.event_footer_en AS organizer_event_footer_en, e.title_de, e.title_en, e.description_de, e.description_en, e.start_date_time, e.end_date_time, e.event_url, e.location, e.publish_web, e.max_participants, e.cancelled_at, e.series_id, e.custom_fields, e.price_cents, e.currency, e.free_entry, e.audience as "audience: Vec<EventAudience>", e.term, e.all_day, e.slug, v.id AS "venue_id?", v.name AS "venue_name?", v.building AS venue_building, v.room AS venue_room, v.latitude AS venue_latitude, v.longitude AS venue_longitude
+        FROM events e
+        INNER JOIN organizers o ON e.organizer_id = o.id
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE CASE WHEN $3 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        AND e.cancelled_at IS NULL
+        AND e.end_date_time < NOW()
+        AND e.start_date_time >= $1
+        AND e.start_date_time < $2
+        ORDER BY e.start_date_time DESC
+        "#,
+        range_start,
+        range_end,
+        channel == PublicChannel::Web
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    let mut months: Vec<PublicEventArchiveMonth> = Vec::new();
+    for event in events.into_iter().map(PublicEventResponse::from) {
+        let local_start = event.start_date_time.with_timezone(&Berlin);
+        let (event_year, event_month) = (local_start.year(), local_start.month());
+        match months.last_mut() {
+            Some(group) if group.year == event_year && group.month == event_month => {
+                group.event_count += 1;
+                group.events.push(event);
+            }
+            _ => months.push(PublicEventArchiveMonth {
+                year: event_year,
+                month: event_month,
+                event_count: 1,
+                events: vec![event],
+            }),
+        }
+    }
+
+    let mut archive = PublicEventArchiveResponse {
+        year,
+        month: query.month,
+        months,
+    };
+
+    if let Some(cache) = &state.cache
+        && let Err(err) = cache.set_json(&cache_key, &archive).await
+    {
+        warn!(target: "cache", action = "set", scope = "public_event_archive", %err, "Failed to store public event archive in cache");
+    }
+    archive.localize(locale);
+
+    Ok(TimedJson(archive))
+}
+
 pub(crate) fn router() -> Router<AppState> {
     Router::new()
         .route("/events", get(list_public_events))
+        .route("/events/archive", get(get_public_event_archive))
+        .route("/events/featured", get(list_featured_public_events))
+        .route("/events/snapshot", get(get_public_event_snapshot))
+        .route("/events/changes", get(get_public_event_changes))
         .route("/events/{id}", get(get_public_event))
+        .route("/series/{id}", get(get_public_series))
         .route("/organizers", get(list_public_organizers))
         .route("/organizers/{id}", get(get_public_organizer))
+        .route("/organizers/{id}/events", get(list_public_organizer_events))
+        .merge(super::event_slugs::public_router())
+        .merge(super::public_search::public_router())
+        .merge(super::event_og_image::public_router())
+        .layer(middleware::from_fn(i18n::negotiate_content_language))
+        .merge(super::event_stream::public_router())
+        .merge(super::registrations::public_router())
+        .merge(super::subscriptions::public_router())
+        .merge(super::custom_fields::public_router())
+        .merge(super::academic_periods::public_router())
+        .merge(super::newsletter::public_router())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_requested_language_with_fallback() {
+        assert_eq!(
+            resolve_title(Locale::En, "Sommerfest", "Summer party"),
+            "Summer party"
+        );
+        assert_eq!(resolve_title(Locale::En, "Sommerfest", " "), "Sommerfest");
+        assert_eq!(
+            resolve_title(Locale::De, "Sommerfest", "Summer party"),
+            "Sommerfest"
+        );
+
+        let german = Some("Beschreibung".to_string());
+        assert_eq!(
+            resolve_description(Locale::En, german.as_ref(), None),
+            german
+        );
+        assert_eq!(
+            resolve_description(Locale::De, None, Some(&"Text".to_string())),
+            Some("Text".to_string())
+        );
+        assert_eq!(resolve_description(Locale::En, None, None), None);
+    }
 }
@@ -0,0 +1,121 @@
+use axum::{
+    Extension, Json, Router,
+    extract::{Query, State},
+    routing::get,
+};
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    dto::{LanguageQuery, PublicChannel, PublicSearchQuery},
+    error::AppError,
+    i18n::Locale,
+    responses::{ErrorResponse, PublicSearchResponse, PublicSearchResult, PublicSearchResultKind},
+};
+
+use super::{events::search_term, public_events::resolve_title};
+
+const DEFAULT_SEARCH_LIMIT: i64 = 20;
+const MAX_SEARCH_LIMIT: i64 = 50;
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/search",
+    tag = "Public",
+    params(PublicSearchQuery, LanguageQuery),
+    responses(
+        (status = 200, description = "Events and organizers matching the query, best match first", body = PublicSearchResponse),
+        (status = 400, description = "Missing or too long query", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn public_search(
+    State(state): State<AppState>,
+    Extension(locale): Extension<Locale>,
+    Query(query): Query<PublicSearchQuery>,
+) -> Result<Json<PublicSearchResponse>, AppError> {
+    let Some(search) = search_term(Some(&query.q))? else {
+        return Err(AppError::validation("q must not be empty"));
+    };
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_SEARCH_LIMIT)
+        .clamp(1, MAX_SEARCH_LIMIT);
+
+    let events = sqlx::query!(
+        r#"
+        SELECT e.id, e.title_de, e.title_en, e.slug, e.start_date_time, o.name AS organizer_name,
+               GREATEST(
+                   similarity(e.title_de, $1),
+                   similarity(e.title_en, $1),
+                   word_similarity($1, e.title_de),
+                   word_similarity($1, e.title_en),
+                   word_similarity($1, COALESCE(e.description_de, '')) * 0.8::real,
+                   word_similarity($1, COALESCE(e.description_en, '')) * 0.8::real
+               ) AS "score!"
+        FROM events e
+        INNER JOIN organizers o ON o.id = e.organizer_id
+        WHERE CASE WHEN $3 THEN e.publish_web ELSE e.publish_app END AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+          AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+          AND (
+              e.title_de % $1 OR e.title_en % $1
+              OR $1 <% e.title_de OR $1 <% e.title_en
+              OR $1 <% e.description_de OR $1 <% e.description_en
+          )
+        ORDER BY 7 DESC, e.start_date_time DESC
+        LIMIT $2
+        "#,
+        search,
+        limit,
+        query.channel.unwrap_or_default() == PublicChannel::Web
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let organizers = sqlx::query!(
+        r#"
+        SELECT id, name, GREATEST(similarity(name, $1), word_similarity($1, name)) AS "score!"
+        FROM organizers
+        WHERE name % $1 OR $1 <% name
+        ORDER BY 3 DESC, name
+        LIMIT $2
+        "#,
+        search,
+        limit
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut results: Vec<PublicSearchResult> = events
+        .into_iter()
+        .map(|event| PublicSearchResult {
+            kind: PublicSearchResultKind::Event,
+            id: event.id,
+            title: resolve_title(locale, &event.title_de, &event.title_en),
+            slug: event.slug,
+            start_date_time: Some(event.start_date_time),
+            organizer_name: Some(event.organizer_name),
+            score: event.score,
+        })
+        .chain(organizers.into_iter().map(|organizer| PublicSearchResult {
+            kind: PublicSearchResultKind::Organizer,
+            id: organizer.id,
+            title: organizer.name,
+            slug: None,
+            start_date_time: None,
+            organizer_name: None,
+            score: organizer.score,
+        }))
+        .collect();
+    results.sort_by(|a, b| b.score.total_cmp(&a.score));
+    results.truncate(usize::try_from(limit).unwrap_or_default());
+
+    Ok(Json(PublicSearchResponse {
+        query: search.to_string(),
+        results,
+    }))
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new().route("/search", get(public_search))
+}
@@ -0,0 +1,474 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use chrono::Utc;
+use futures_util::TryStreamExt;
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::{CheckInRequest, CreateRegistrationRequest},
+    error::AppError,
+    export::stream_export,
+    models::Registration,
+    responses::{
+        CheckInResponse, ErrorResponse, EventRegistrationsResponse, EventStatisticsResponse,
+        PublicRegistrationResponse,
+    },
+};
+
+use super::shared::{current_user_from_headers, generate_setup_token_value, normalize_email};
+
+const MAX_REGISTRATION_NAME_LEN: usize = 200;
+const CHECK_IN_PAYLOAD_PREFIX: &str = "cle-checkin";
+
+fn check_in_payload(event_id: i64, code: &str) -> String {
+    format!("{CHECK_IN_PAYLOAD_PREFIX}:{event_id}:{code}")
+}
+
+fn parse_check_in_code(event_id: i64, raw: &str) -> Result<String, AppError> {
+    let raw = raw.trim();
+    let code = match raw.strip_prefix(CHECK_IN_PAYLOAD_PREFIX) {
+        Some(rest) => {
+            let (payload_event_id, code) = rest
+                .strip_prefix(':')
+                .and_then(|rest| rest.split_once(':'))
+                .ok_or_else(|| AppError::validation("invalid check-in code"))?;
+            if payload_event_id.parse::<i64>().ok() != Some(event_id) {
+                return Err(AppError::validation(
+                    "check-in code belongs to another event",
+                ));
+            }
+            code
+        }
+        None => raw,
+    };
+    if code.is_empty() {
+        return Err(AppError::validation("invalid check-in code"));
+    }
+    Ok(code.to_string())
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/public/events/{id}/registrations",
+    tag = "Public",
+    params(("id" = i64, Path, description = "Event identifier")),
+    request_body = CreateRegistrationRequest,
+    responses(
+        (status = 201, description = "Registration created", body = PublicRegistrationResponse),
+        (status = 400, description = "Invalid request or registration closed", body = ErrorResponse),
+        (status = 404, description = "Event not found or not published", body = ErrorResponse),
+        (status = 409, description = "Event is fully booked or email already registered", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, payload))]
+pub(crate) async fn create_registration(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(payload): Json<CreateRegistrationRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let name = payload.name.trim();
+    if name.is_empty() {
+        return Err(AppError::validation("name is required"));
+    }
+    if name.chars().count() > MAX_REGISTRATION_NAME_LEN {
+        return Err(AppError::validation(format!(
+            "name must be at most {MAX_REGISTRATION_NAME_LEN} characters"
+        )));
+    }
+    let email = normalize_email(&payload.email)?;
+
+    let mut transaction = state.db.begin().await?;
+
+    let event = sqlx::query!(
+        r#"
+        SELECT e.end_date_time, e.max_participants, e.cancelled_at,
+               CASE WHEN v.close_registration_at_capacity THEN v.capacity END AS venue_capacity
+        FROM events e
+        LEFT JOIN venues v ON v.id = e.venue_id
+        WHERE e.id = $1 AND e.publish_app = true AND e.status = 'PUBLISHED'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        FOR UPDATE OF e
+        "#,
+        id
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
+
+    let Some(event) = event else {
+        return Err(AppError::not_found("Event not found or not published"));
+    };
+
+    if event.cancelled_at.is_some() {
+        return Err(AppError::validation("event has been cancelled"));
+    }
+
+    if event.end_date_time < Utc::now() {
+        return Err(AppError::validation(
+            "registration is closed for this event",
+        ));
+    }
+
+    let registration_limit = [event.max_participants, event.venue_capacity]
+        .into_iter()
+        .flatten()
+        .min();
+    if let Some(registration_limit) = registration_limit {
+        let registration_count = sqlx::query_scalar!(
+            r#"SELECT COUNT(*) AS "count!" FROM registrations WHERE event_id = $1"#,
+            id
+        )
+        .fetch_one(&mut *transaction)
+        .await?;
+
+        if registration_count >= i64::from(registration_limit) {
+            return Err(AppError::conflict("event is fully booked"));
+        }
+    }
+
+    let registration = sqlx::query_as!(
+        Registration,
+        r#"
+        INSERT INTO registrations (event_id, name, email, check_in_code)
+        VALUES ($1, $2, $3, $4)
+        ON CONFLICT (event_id, LOWER(email)) DO NOTHING
+        RETURNING id, event_id, name, email, check_in_code, checked_in_at, created_at
+        "#,
+        id,
+        name,
+        &email,
+        generate_setup_token_value()
+    )
+    .fetch_optional(&mut *transaction)
+    .await?;
+
+    let Some(registration) = registration else {
+        return Err(AppError::conflict(
+            "this email is already registered for the event",
+        ));
+    };
+
+    transaction.commit().await?;
+
+    info!(
+        target: "registrations",
+        action = "create",
+        event_id = id,
+        registration_id = registration.id,
+        "Event registration created"
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(PublicRegistrationResponse {
+            id: registration.id,
+            event_id: registration.event_id,
+            name: registration.name,
+            check_in_payload: check_in_payload(registration.event_id, &registration.check_in_code),
+            check_in_code: registration.check_in_code,
+            created_at: registration.created_at,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/registrations",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Registrations for the event", body = EventRegistrationsResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_event_registrations(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<EventRegistrationsResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let event = sqlx::query!(
+        r#"
+        SELECT organizer_id, max_participants
+        FROM events
+        WHERE id = $1
+        "#,
+        id
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let Some(event) = event else {
+        return Err(AppError::not_found("event not found"));
+    };
+
+    if !user.is_admin() && user.organizer_id() != Some(event.organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot view another organizer's registrations",
+        ));
+    }
+
+    let registrations = sqlx::query_as!(
+        Registration,
+        r#"
+        SELECT id, event_id, name, email, check_in_code, checked_in_at, created_at
+        FROM registrations
+        WHERE event_id = $1
+        ORDER BY created_at ASC
+        "#,
+        id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(EventRegistrationsResponse {
+        event_id: id,
+        max_participants: event.max_participants,
+        registration_count: registrations.len() as i64,
+        registrations,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/registrations/export",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Registrations for the event streamed as CSV", content_type = "text/csv"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn export_event_registrations(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Response, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let organizer_id = sqlx::query_scalar!("SELECT organizer_id FROM events WHERE id = $1", id)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::not_found("event not found"))?;
+
+    if !user.is_admin() && user.organizer_id() != Some(organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot view another organizer's registrations",
+        ));
+    }
+
+    let db = state.db.clone();
+    let filename = format!("event-{id}-registrations.csv");
+    stream_export(
+        "text/csv; charset=utf-8",
+        &filename,
+        move |mut writer| async move {
+            writer
+                .write_csv_row(&["id", "name", "email", "created_at", "checked_in_at"])
+                .await?;
+
+            let mut rows = sqlx::query_as!(
+                Registration,
+                r#"
+            SELECT id, event_id, name, email, check_in_code, checked_in_at, created_at
+            FROM registrations
+            WHERE event_id = $1
+            ORDER BY created_at ASC
+            "#,
+                id
+            )
+            .fetch(&db);
+
+            while let Some(registration) = rows.try_next().await? {
+                writer
+                    .write_csv_row(&[
+                        registration.id.to_string(),
+                        registration.name,
+                        registration.email,
+                        registration.created_at.to_rfc3339(),
+                        registration
+                            .checked_in_at
+                            .map(|checked_in_at| checked_in_at.to_rfc3339())
+                            .unwrap_or_default(),
+                    ])
+                    .await?;
+            }
+
+            Ok(writer)
+        },
+    )
+}
+
+async fn ensure_event_access(
+    state: &AppState,
+    headers: &HeaderMap,
+    event_id: i64,
+) -> Result<i64, AppError> {
+    let user = current_user_from_headers(headers, state).await?;
+
+    let organizer_id =
+        sqlx::query_scalar!("SELECT organizer_id FROM events WHERE id = $1", event_id)
+            .fetch_optional(&state.db)
+            .await?
+            .ok_or_else(|| AppError::not_found("event not found"))?;
+
+    if !user.is_admin() && user.organizer_id() != Some(organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot view another organizer's registrations",
+        ));
+    }
+
+    Ok(user.account_id)
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/check-in",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    request_body = CheckInRequest,
+    responses(
+        (status = 200, description = "Registration checked in; accepts the raw code or the QR payload", body = CheckInResponse),
+        (status = 400, description = "Invalid check-in code", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event or check-in code not found", body = ErrorResponse),
+        (status = 409, description = "Registration already checked in", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn check_in_registration(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<CheckInRequest>,
+) -> Result<Json<CheckInResponse>, AppError> {
+    let account_id = ensure_event_access(&state, &headers, id).await?;
+    let code = parse_check_in_code(id, &payload.code)?;
+
+    let mut transaction = state.db.begin().await?;
+
+    let registration = sqlx::query!(
+        r#"
+        SELECT id, name, checked_in_at
+        FROM registrations
+        WHERE event_id = $1 AND check_in_code = $2
+        FOR UPDATE
+        "#,
+        id,
+        &code
+    )
+    .fetch_optional(&mut *transaction)
+    .await?
+    .ok_or_else(|| AppError::not_found("check-in code not found"))?;
+
+    if registration.checked_in_at.is_some() {
+        return Err(AppError::conflict("registration already checked in"));
+    }
+
+    let checked_in_at = sqlx::query_scalar!(
+        r#"
+        UPDATE registrations
+        SET checked_in_at = NOW(), checked_in_by = $2
+        WHERE id = $1
+        RETURNING checked_in_at AS "checked_in_at!"
+        "#,
+        registration.id,
+        account_id
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+
+    let checked_in_count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM registrations WHERE event_id = $1 AND checked_in_at IS NOT NULL"#,
+        id
+    )
+    .fetch_one(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    info!(
+        target: "registrations",
+        action = "check_in",
+        event_id = id,
+        registration_id = registration.id,
+        checked_in_by = account_id,
+        "Registration checked in"
+    );
+
+    Ok(Json(CheckInResponse {
+        registration_id: registration.id,
+        event_id: id,
+        name: registration.name,
+        checked_in_at,
+        checked_in_count,
+    }))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/statistics",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Aggregate registration, attendance and subscriber counts", body = EventStatisticsResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_event_statistics(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<EventStatisticsResponse>, AppError> {
+    ensure_event_access(&state, &headers, id).await?;
+
+    let statistics = sqlx::query!(
+        r#"
+        SELECT
+            e.max_participants,
+            (SELECT COUNT(*) FROM registrations r WHERE r.event_id = e.id) AS "registration_count!",
+            (SELECT COUNT(*) FROM registrations r WHERE r.event_id = e.id AND r.checked_in_at IS NOT NULL) AS "checked_in_count!",
+            (SELECT COUNT(*) FROM event_subscriptions s WHERE s.event_id = e.id) AS "subscriber_count!"
+        FROM events e
+        WHERE e.id = $1
+        "#,
+        id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(Json(EventStatisticsResponse {
+        event_id: id,
+        max_participants: statistics.max_participants,
+        registration_count: statistics.registration_count,
+        checked_in_count: statistics.checked_in_count,
+        subscriber_count: statistics.subscriber_count,
+    }))
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new().route("/events/{id}/registrations", post(create_registration))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/{id}/registrations", get(list_event_registrations))
+        .route(
+            "/{id}/registrations/export",
+            get(export_event_registrations),
+        )
+        .route("/{id}/check-in", post(check_in_registration))
+        .route("/{id}/statistics", get(get_event_statistics))
+}
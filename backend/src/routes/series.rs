@@ -0,0 +1,174 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{delete, get},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::{CreateEventSeriesRequest, OrganizerFilterQuery},
+    error::AppError,
+    models::EventSeries,
+    responses::ErrorResponse,
+};
+
+use super::{events::invalidate_public_event_caches, shared::current_user_from_headers};
+
+const MAX_SERIES_TITLE_LEN: usize = 200;
+
+fn required_title(value: &str, field: &str) -> Result<String, AppError> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err(AppError::validation(format!("{field} is required")));
+    }
+    if value.chars().count() > MAX_SERIES_TITLE_LEN {
+        return Err(AppError::validation(format!(
+            "{field} must be at most {MAX_SERIES_TITLE_LEN} characters"
+        )));
+    }
+    Ok(value.to_string())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/series",
+    tag = "Events",
+    params(OrganizerFilterQuery),
+    responses(
+        (status = 200, description = "Event series of the organizer", body = [EventSeries]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_series(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<OrganizerFilterQuery>,
+) -> Result<Json<Vec<EventSeries>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let organizer_id = if user.is_admin() {
+        query.organizer_id
+    } else {
+        Some(
+            user.organizer_id()
+                .ok_or_else(|| AppError::unauthorized("organizer account required"))?,
+        )
+    };
+
+    let series = sqlx::query_as!(
+        EventSeries,
+        r#"
+        SELECT id, organizer_id, title_de, title_en, description_de, description_en, created_at, updated_at
+        FROM event_series
+        WHERE ($1::bigint IS NULL OR organizer_id = $1)
+        ORDER BY created_at DESC
+        "#,
+        organizer_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(series))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/series",
+    tag = "Events",
+    request_body = CreateEventSeriesRequest,
+    responses(
+        (status = 201, description = "Event series created", body = EventSeries),
+        (status = 400, description = "Invalid series", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_series(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateEventSeriesRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = user
+        .organizer_id()
+        .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+
+    let title_de = required_title(&payload.title_de, "title_de")?;
+    let title_en = required_title(&payload.title_en, "title_en")?;
+
+    let series = sqlx::query_as!(
+        EventSeries,
+        r#"
+        INSERT INTO event_series (organizer_id, title_de, title_en, description_de, description_en)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, organizer_id, title_de, title_en, description_de, description_en, created_at, updated_at
+        "#,
+        organizer_id,
+        title_de,
+        title_en,
+        payload.description_de,
+        payload.description_en
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(
+        target: "series",
+        action = "create",
+        organizer_id,
+        series_id = series.id,
+        "Event series created"
+    );
+
+    Ok((StatusCode::CREATED, Json(series)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/series/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Series identifier")),
+    responses(
+        (status = 204, description = "Series removed; its events are kept"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Series not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_series(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let organizer_id =
+        sqlx::query_scalar!("SELECT organizer_id FROM event_series WHERE id = $1", id)
+            .fetch_optional(&state.db)
+            .await?
+            .ok_or_else(|| AppError::not_found("series not found"))?;
+
+    if !user.is_admin() && user.organizer_id() != Some(organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot delete another organizer's series",
+        ));
+    }
+
+    sqlx::query!("DELETE FROM event_series WHERE id = $1", id)
+        .execute(&state.db)
+        .await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_series).post(create_series))
+        .route("/{id}", delete(delete_series))
+}
@@ -1,5 +1,11 @@
-use axum::http::HeaderMap;
+use std::{marker::PhantomData, str::FromStr};
+
+use axum::{
+    extract::FromRequestParts,
+    http::{HeaderMap, Uri, request::Parts},
+};
 use cookie::Cookie;
+use lettre::message::Mailbox;
 use tracing::warn;
 use uuid::Uuid;
 
@@ -7,11 +13,14 @@ use crate::{
     api_token,
     app_state::AppState,
     error::AppError,
-    models::{AccountType, OrganizerKind},
+    models::{AccountType, ApiTokenScope, OrganizerKind},
+    request_context::record_acting_account,
 };
 
 pub(crate) use crate::authed_user::AuthedUser;
 
+const MAX_URL_LEN: usize = 2048;
+
 #[derive(Clone, Copy)]
 pub(crate) enum SessionOrganizerKindScope {
     All,
@@ -48,12 +57,61 @@ pub(crate) async fn session_organizer_kind_scope(
     Err(AppError::validation("unsupported account type"))
 }
 
+pub(crate) trait RequiredScope {
+    const SCOPE: ApiTokenScope;
+}
+
+pub(crate) struct EventsRead;
+
+impl RequiredScope for EventsRead {
+    const SCOPE: ApiTokenScope = ApiTokenScope::EventsRead;
+}
+
+pub(crate) struct EventsWrite;
+
+impl RequiredScope for EventsWrite {
+    const SCOPE: ApiTokenScope = ApiTokenScope::EventsWrite;
+}
+
+pub(crate) struct ScopedUser<S>(pub(crate) AuthedUser, pub(crate) PhantomData<S>);
+
+impl<S: RequiredScope> FromRequestParts<AppState> for ScopedUser<S> {
+    type Rejection = AppError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &AppState) -> Result<Self, AppError> {
+        let user = current_user_with_scope(&parts.headers, state, S::SCOPE).await?;
+        Ok(Self(user, PhantomData))
+    }
+}
+
 pub(crate) async fn current_user_from_headers(
     headers: &HeaderMap,
     state: &AppState,
+) -> Result<AuthedUser, AppError> {
+    current_user_with_scope(headers, state, ApiTokenScope::Full).await
+}
+
+pub(crate) async fn session_user_from_headers(
+    headers: &HeaderMap,
+    state: &AppState,
+) -> Result<AuthedUser, AppError> {
+    if bearer_token(headers).is_some() {
+        return Err(AppError::unauthorized(
+            "API tokens cannot be used for this endpoint",
+        ));
+    }
+    current_user_from_headers(headers, state).await
+}
+
+pub(crate) async fn current_user_with_scope(
+    headers: &HeaderMap,
+    state: &AppState,
+    scope: ApiTokenScope,
 ) -> Result<AuthedUser, AppError> {
     if let Some(raw) = bearer_token(headers) {
-        return api_token::authed_user_from_bearer(raw, state).await;
+        let user = api_token::authed_user_from_bearer(raw, state, scope).await?;
+        record_acting_account(user.account_id);
+        return Ok(user);
     }
 
     let Some(session_id) = get_cookie(headers, "session_id") else {
@@ -79,6 +137,7 @@ pub(crate) async fn current_user_from_headers(
         return Err(AppError::unauthorized("invalid or expired session"));
     };
 
+    record_acting_account(row.id);
     Ok(AuthedUser {
         account_id: row.id,
         account_type: row.account_type,
@@ -86,7 +145,7 @@ pub(crate) async fn current_user_from_headers(
     })
 }
 
-fn bearer_token(headers: &HeaderMap) -> Option<&str> {
+pub(crate) fn bearer_token(headers: &HeaderMap) -> Option<&str> {
     let hv = headers
         .get(axum::http::header::AUTHORIZATION)?
         .to_str()
@@ -124,6 +183,14 @@ pub(crate) async fn refresh_organizer_activity_stats(state: &AppState) {
     }
 }
 
+pub(crate) fn frontend_base_url() -> String {
+    std::env::var("BASE_URL")
+        .ok()
+        .map(|value| value.trim().trim_end_matches('/').to_string())
+        .filter(|value| !value.is_empty())
+        .unwrap_or_else(|| "http://localhost:3000".to_string())
+}
+
 pub(crate) fn session_cookie_attributes() -> String {
     let secure = std::env::var("SESSION_COOKIE_SECURE")
         .ok()
@@ -143,3 +210,47 @@ pub(crate) fn generate_setup_token_value() -> String {
     OsRng.fill_bytes(&mut bytes);
     general_purpose::URL_SAFE_NO_PAD.encode(bytes)
 }
+
+pub(crate) fn normalize_email(raw: &str) -> Result<String, AppError> {
+    let trimmed = raw.trim();
+    if trimmed.is_empty() {
+        return Err(AppError::validation("email must not be empty"));
+    }
+    let wrapped = format!("n <{trimmed}>");
+    Mailbox::from_str(&wrapped).map_err(|_| AppError::validation("invalid email address"))?;
+    Ok(trimmed.to_string())
+}
+
+pub(crate) fn validate_http_url(raw: &str, field: &str) -> Result<String, AppError> {
+    let url = raw.trim();
+    if url.is_empty() || url.len() > MAX_URL_LEN {
+        return Err(AppError::validation(format!(
+            "{field} must be between 1 and {MAX_URL_LEN} characters"
+        )));
+    }
+    let uri = url
+        .parse::<Uri>()
+        .map_err(|_| AppError::validation(format!("{field} must be a valid URL")))?;
+    let scheme_ok = matches!(uri.scheme_str(), Some("http") | Some("https"));
+    if !scheme_ok || uri.host().is_none() {
+        return Err(AppError::validation(format!(
+            "{field} must be an absolute http(s) URL"
+        )));
+    }
+    Ok(url.to_string())
+}
+
+pub(crate) fn append_event_footer(
+    description: Option<String>,
+    footer: Option<&str>,
+) -> Option<String> {
+    let Some(footer) = footer.map(str::trim).filter(|footer| !footer.is_empty()) else {
+        return description;
+    };
+    match description {
+        Some(description) if !description.trim().is_empty() => {
+            Some(format!("{}\n\n{footer}", description.trim_end()))
+        }
+        _ => Some(footer.to_string()),
+    }
+}
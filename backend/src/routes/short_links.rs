@@ -0,0 +1,277 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::{IntoResponse, Redirect},
+    routing::{delete, get},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState, dto::CreateShortLinkRequest, error::AppError, models::ShortLink,
+    responses::ErrorResponse,
+};
+
+use super::shared::{AuthedUser, current_user_from_headers, frontend_base_url, validate_http_url};
+
+const MIN_ALIAS_LEN: usize = 3;
+const MAX_ALIAS_LEN: usize = 64;
+const RESERVED_ALIASES: &[&str] = &["admin", "api", "go", "login", "mcp"];
+
+fn normalize_alias(raw: &str) -> Result<String, AppError> {
+    let alias = raw.trim().to_lowercase();
+    let length = alias.chars().count();
+    if !(MIN_ALIAS_LEN..=MAX_ALIAS_LEN).contains(&length) {
+        return Err(AppError::validation(format!(
+            "alias must be between {MIN_ALIAS_LEN} and {MAX_ALIAS_LEN} characters"
+        )));
+    }
+    if !alias
+        .chars()
+        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+    {
+        return Err(AppError::validation(
+            "alias may only contain letters, digits and hyphens",
+        ));
+    }
+    if alias.starts_with('-') || alias.ends_with('-') {
+        return Err(AppError::validation(
+            "alias must not start or end with a hyphen",
+        ));
+    }
+    if RESERVED_ALIASES.contains(&alias.as_str()) {
+        return Err(AppError::validation("alias is reserved"));
+    }
+    Ok(alias)
+}
+
+async fn owning_organizer_for_event(
+    state: &AppState,
+    user: &AuthedUser,
+    event_id: i64,
+) -> Result<i64, AppError> {
+    let organizer_id =
+        sqlx::query_scalar!("SELECT organizer_id FROM events WHERE id = $1", event_id)
+            .fetch_optional(&state.db)
+            .await?;
+
+    let Some(organizer_id) = organizer_id else {
+        return Err(AppError::not_found("event not found"));
+    };
+
+    if !user.is_admin() && user.organizer_id() != Some(organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot create short links for another organizer's event",
+        ));
+    }
+
+    Ok(organizer_id)
+}
+
+#[utoipa::path(
+    get,
+    path = "/go/{alias}",
+    tag = "Short Links",
+    params(("alias" = String, Path, description = "Short link alias")),
+    responses(
+        (status = 307, description = "Redirect to the link target"),
+        (status = 404, description = "Short link not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn follow_short_link(
+    State(state): State<AppState>,
+    Path(alias): Path<String>,
+) -> Result<Redirect, AppError> {
+    let alias = alias.trim().to_lowercase();
+
+    let target_url = sqlx::query_scalar!(
+        r#"
+        UPDATE short_links
+        SET click_count = click_count + 1
+        WHERE alias = $1
+        RETURNING target_url
+        "#,
+        alias
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let Some(target_url) = target_url else {
+        return Err(AppError::not_found("Short link not found"));
+    };
+
+    Ok(Redirect::temporary(&target_url))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/short-links",
+    tag = "Short Links",
+    responses(
+        (status = 200, description = "Short links visible to the current account", body = [ShortLink]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_short_links(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<ShortLink>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let links = if user.is_admin() {
+        sqlx::query_as!(
+            ShortLink,
+            r#"
+            SELECT id, alias, target_url, event_id, organizer_id, click_count, created_at, updated_at
+            FROM short_links
+            ORDER BY created_at DESC
+            "#
+        )
+        .fetch_all(&state.db)
+        .await?
+    } else {
+        let organizer_id = user
+            .organizer_id()
+            .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+        sqlx::query_as!(
+            ShortLink,
+            r#"
+            SELECT id, alias, target_url, event_id, organizer_id, click_count, created_at, updated_at
+            FROM short_links
+            WHERE organizer_id = $1
+            ORDER BY created_at DESC
+            "#,
+            organizer_id
+        )
+        .fetch_all(&state.db)
+        .await?
+    };
+
+    Ok(Json(links))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/short-links",
+    tag = "Short Links",
+    request_body = CreateShortLinkRequest,
+    responses(
+        (status = 201, description = "Short link created", body = ShortLink),
+        (status = 400, description = "Invalid alias or target", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Alias already taken", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_short_link(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateShortLinkRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() && user.organizer_id().is_none() {
+        return Err(AppError::unauthorized("organizer account required"));
+    }
+
+    let alias = normalize_alias(&payload.alias)?;
+
+    let organizer_id = match payload.event_id {
+        Some(event_id) => Some(owning_organizer_for_event(&state, &user, event_id).await?),
+        None => user.organizer_id(),
+    };
+
+    let target_url = match (payload.target_url.as_deref(), payload.event_id) {
+        (Some(target_url), _) => validate_http_url(target_url, "target_url")?,
+        (None, Some(event_id)) => format!("{}/e/{event_id}", frontend_base_url()),
+        (None, None) => {
+            return Err(AppError::validation(
+                "target_url is required when no event_id is given",
+            ));
+        }
+    };
+
+    let link = sqlx::query_as!(
+        ShortLink,
+        r#"
+        INSERT INTO short_links (alias, target_url, event_id, organizer_id, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        ON CONFLICT (alias) DO NOTHING
+        RETURNING id, alias, target_url, event_id, organizer_id, click_count, created_at, updated_at
+        "#,
+        alias,
+        target_url,
+        payload.event_id,
+        organizer_id,
+        user.account_id
+    )
+    .fetch_optional(&state.db)
+    .await?;
+
+    let Some(link) = link else {
+        return Err(AppError::conflict("alias is already taken"));
+    };
+
+    info!(
+        target: "short_links",
+        action = "create",
+        short_link_id = link.id,
+        alias = %link.alias,
+        "Short link created"
+    );
+
+    Ok((StatusCode::CREATED, Json(link)))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/short-links/{id}",
+    tag = "Short Links",
+    params(("id" = i64, Path, description = "Short link identifier")),
+    responses(
+        (status = 204, description = "Short link removed"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Short link not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_short_link(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let owner = sqlx::query!("SELECT organizer_id FROM short_links WHERE id = $1", id)
+        .fetch_optional(&state.db)
+        .await?;
+
+    let Some(owner) = owner else {
+        return Err(AppError::not_found("Short link not found"));
+    };
+
+    if !user.is_admin()
+        && (owner.organizer_id.is_none() || user.organizer_id() != owner.organizer_id)
+    {
+        return Err(AppError::unauthorized(
+            "cannot delete another organizer's short link",
+        ));
+    }
+
+    sqlx::query!("DELETE FROM short_links WHERE id = $1", id)
+        .execute(&state.db)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_short_links).post(create_short_link))
+        .route("/{id}", delete(delete_short_link))
+}
+
+pub(crate) fn redirect_router() -> Router<AppState> {
+    Router::new().route("/go/{alias}", get(follow_short_link))
+}
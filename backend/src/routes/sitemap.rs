@@ -0,0 +1,168 @@
+use std::{env, sync::LazyLock};
+
+use axum::{
+    Router,
+    extract::State,
+    http::header,
+    response::{IntoResponse, Response},
+    routing::get,
+};
+use chrono::{DateTime, SecondsFormat, Utc};
+use tracing::{instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    error::AppError,
+    og_image::escape_xml,
+    timings::{Timed, TimingPhase},
+};
+
+use super::shared::frontend_base_url;
+
+const SITEMAP_CACHE_KEY: &str = "public:events:sitemap";
+const MAX_SITEMAP_URLS: i64 = 50_000;
+
+static TEMPLATES: LazyLock<SitemapTemplates> = LazyLock::new(SitemapTemplates::from_env);
+
+struct SitemapTemplates {
+    event: String,
+    organizer: Option<String>,
+}
+
+impl SitemapTemplates {
+    fn from_env() -> Self {
+        let template = |name: &str| {
+            env::var(name)
+                .ok()
+                .map(|value| value.trim().to_string())
+                .filter(|value| value.contains("{id}") || value.contains("{slug}"))
+        };
+        Self {
+            event: template("SITEMAP_EVENT_URL_TEMPLATE")
+                .unwrap_or_else(|| format!("{}/e/{{id}}", frontend_base_url())),
+            organizer: template("SITEMAP_ORGANIZER_URL_TEMPLATE"),
+        }
+    }
+}
+
+fn render_url(template: &str, id: i64, slug: Option<&str>) -> String {
+    let id = id.to_string();
+    template
+        .replace("{slug}", slug.unwrap_or(&id))
+        .replace("{id}", &id)
+}
+
+fn push_url(xml: &mut String, loc: &str, lastmod: DateTime<Utc>) {
+    xml.push_str("  <url><loc>");
+    xml.push_str(&escape_xml(loc));
+    xml.push_str("</loc><lastmod>");
+    xml.push_str(&lastmod.to_rfc3339_opts(SecondsFormat::Secs, true));
+    xml.push_str("</lastmod></url>\n");
+}
+
+fn xml_response(xml: String) -> Response {
+    (
+        [(header::CONTENT_TYPE, "application/xml; charset=utf-8")],
+        xml,
+    )
+        .into_response()
+}
+
+#[utoipa::path(
+    get,
+    path = "/sitemap.xml",
+    tag = "Public",
+    responses(
+        (status = 200, description = "Sitemap of public event and organizer pages with lastmod timestamps", content_type = "application/xml", body = String),
+    )
+)]
+#[instrument(skip(state))]
+pub(crate) async fn get_sitemap(State(state): State<AppState>) -> Result<Response, AppError> {
+    if let Some(cache) = &state.cache {
+        match cache.get_string(SITEMAP_CACHE_KEY).await {
+            Ok(Some(xml)) => return Ok(xml_response(xml)),
+            Ok(None) => {}
+            Err(err) => {
+                warn!(target: "cache", action = "get", scope = "sitemap", %err, "Failed to read sitemap from cache")
+            }
+        }
+    }
+
+    let events = sqlx::query!(
+        r#"
+        SELECT e.id, e.slug, e.updated_at
+        FROM events e
+        WHERE e.publish_web = true AND e.status = 'PUBLISHED' AND e.visibility = 'PUBLIC'
+        AND (e.publish_at IS NULL OR e.publish_at <= NOW())
+        ORDER BY e.updated_at DESC
+        LIMIT $1
+        "#,
+        MAX_SITEMAP_URLS
+    )
+    .fetch_all(&state.db)
+    .timed(TimingPhase::Database)
+    .await?;
+
+    let mut xml = String::from(
+        "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n<urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
+    );
+    for event in &events {
+        push_url(
+            &mut xml,
+            &render_url(&TEMPLATES.event, event.id, event.slug.as_deref()),
+            event.updated_at,
+        );
+    }
+
+    if let Some(template) = &TEMPLATES.organizer {
+        let organizers = sqlx::query!(
+            r#"
+            SELECT id, updated_at
+            FROM organizers
+            ORDER BY updated_at DESC
+            LIMIT $1
+            "#,
+            MAX_SITEMAP_URLS - events.len() as i64
+        )
+        .fetch_all(&state.db)
+        .timed(TimingPhase::Database)
+        .await?;
+        for organizer in organizers {
+            push_url(
+                &mut xml,
+                &render_url(template, organizer.id, None),
+                organizer.updated_at,
+            );
+        }
+    }
+    xml.push_str("</urlset>\n");
+
+    if let Some(cache) = &state.cache
+        && let Err(err) = cache.set_string(SITEMAP_CACHE_KEY, &xml).await
+    {
+        warn!(target: "cache", action = "set", scope = "sitemap", %err, "Failed to store sitemap in cache");
+    }
+
+    Ok(xml_response(xml))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/sitemap.xml", get(get_sitemap))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn renders_slug_with_id_fallback() {
+        assert_eq!(
+            render_url("https://events.thi.de/e/{slug}", 7, Some("sommerfest-2026")),
+            "https://events.thi.de/e/sommerfest-2026"
+        );
+        assert_eq!(
+            render_url("https://events.thi.de/e/{slug}", 7, None),
+            "https://events.thi.de/e/7"
+        );
+    }
+}
@@ -0,0 +1,407 @@
+use std::collections::HashSet;
+
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{get, put},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::{OrganizerFilterQuery, SetEventSpeakersRequest, SpeakerRequest},
+    error::AppError,
+    models::Speaker,
+    responses::ErrorResponse,
+};
+
+use super::{
+    events::invalidate_public_event_caches,
+    shared::{AuthedUser, current_user_from_headers, validate_http_url},
+};
+
+const MAX_SPEAKER_NAME_LEN: usize = 200;
+const MAX_SPEAKER_BIO_LEN: usize = 5000;
+const MAX_SPEAKER_LINKS: usize = 10;
+
+struct ValidatedSpeaker {
+    name: String,
+    bio: Option<String>,
+    photo_url: Option<String>,
+    links: Vec<String>,
+}
+
+fn validate_speaker(payload: SpeakerRequest) -> Result<ValidatedSpeaker, AppError> {
+    let name = payload.name.trim();
+    if name.is_empty() {
+        return Err(AppError::validation("name is required"));
+    }
+    if name.chars().count() > MAX_SPEAKER_NAME_LEN {
+        return Err(AppError::validation(format!(
+            "name must be at most {MAX_SPEAKER_NAME_LEN} characters"
+        )));
+    }
+
+    let bio = payload
+        .bio
+        .map(|bio| bio.trim().to_string())
+        .filter(|bio| !bio.is_empty());
+    if let Some(bio) = &bio
+        && bio.chars().count() > MAX_SPEAKER_BIO_LEN
+    {
+        return Err(AppError::validation(format!(
+            "bio must be at most {MAX_SPEAKER_BIO_LEN} characters"
+        )));
+    }
+
+    let photo_url = payload
+        .photo_url
+        .filter(|url| !url.trim().is_empty())
+        .map(|url| validate_http_url(&url, "photo_url"))
+        .transpose()?;
+
+    if payload.links.len() > MAX_SPEAKER_LINKS {
+        return Err(AppError::validation(format!(
+            "a speaker may have at most {MAX_SPEAKER_LINKS} links"
+        )));
+    }
+    let links = payload
+        .links
+        .iter()
+        .map(|link| validate_http_url(link, "links"))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(ValidatedSpeaker {
+        name: name.to_string(),
+        bio,
+        photo_url,
+        links,
+    })
+}
+
+async fn speaker_owner(state: &AppState, user: &AuthedUser, id: i64) -> Result<i64, AppError> {
+    let organizer_id = sqlx::query_scalar!("SELECT organizer_id FROM speakers WHERE id = $1", id)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::not_found("speaker not found"))?;
+
+    if !user.is_admin() && user.organizer_id() != Some(organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot manage another organizer's speakers",
+        ));
+    }
+
+    Ok(organizer_id)
+}
+
+async fn event_owner(state: &AppState, user: &AuthedUser, event_id: i64) -> Result<i64, AppError> {
+    let organizer_id =
+        sqlx::query_scalar!("SELECT organizer_id FROM events WHERE id = $1", event_id)
+            .fetch_optional(&state.db)
+            .await?
+            .ok_or_else(|| AppError::not_found("event not found"))?;
+
+    if !user.is_admin() && user.organizer_id() != Some(organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot manage another organizer's event speakers",
+        ));
+    }
+
+    Ok(organizer_id)
+}
+
+pub(crate) async fn fetch_event_speakers(
+    state: &AppState,
+    event_id: i64,
+) -> Result<Vec<Speaker>, AppError> {
+    let speakers = sqlx::query_as!(
+        Speaker,
+        r#"
+        SELECT s.id, s.organizer_id, s.name, s.bio, s.photo_url, s.links, s.created_at, s.updated_at
+        FROM event_speakers es
+        INNER JOIN speakers s ON s.id = es.speaker_id
+        WHERE es.event_id = $1
+        ORDER BY es.position ASC, s.name ASC
+        "#,
+        event_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(speakers)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/speakers",
+    tag = "Speakers",
+    params(OrganizerFilterQuery),
+    responses(
+        (status = 200, description = "Speakers of the organizer", body = [Speaker]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_speakers(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<OrganizerFilterQuery>,
+) -> Result<Json<Vec<Speaker>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let organizer_id = if user.is_admin() {
+        query.organizer_id
+    } else {
+        Some(
+            user.organizer_id()
+                .ok_or_else(|| AppError::unauthorized("organizer account required"))?,
+        )
+    };
+
+    let speakers = sqlx::query_as!(
+        Speaker,
+        r#"
+        SELECT id, organizer_id, name, bio, photo_url, links, created_at, updated_at
+        FROM speakers
+        WHERE ($1::bigint IS NULL OR organizer_id = $1)
+        ORDER BY name ASC
+        "#,
+        organizer_id
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(speakers))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/speakers",
+    tag = "Speakers",
+    request_body = SpeakerRequest,
+    responses(
+        (status = 201, description = "Speaker created", body = Speaker),
+        (status = 400, description = "Invalid speaker", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_speaker(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<SpeakerRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = user
+        .organizer_id()
+        .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+    let speaker = validate_speaker(payload)?;
+
+    let speaker = sqlx::query_as!(
+        Speaker,
+        r#"
+        INSERT INTO speakers (organizer_id, name, bio, photo_url, links)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, organizer_id, name, bio, photo_url, links, created_at, updated_at
+        "#,
+        organizer_id,
+        speaker.name,
+        speaker.bio,
+        speaker.photo_url,
+        &speaker.links
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(
+        target: "speakers",
+        action = "create",
+        organizer_id,
+        speaker_id = speaker.id,
+        "Speaker created"
+    );
+
+    Ok((StatusCode::CREATED, Json(speaker)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/speakers/{id}",
+    tag = "Speakers",
+    params(("id" = i64, Path, description = "Speaker identifier")),
+    request_body = SpeakerRequest,
+    responses(
+        (status = 200, description = "Speaker updated", body = Speaker),
+        (status = 400, description = "Invalid speaker", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Speaker not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_speaker(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<SpeakerRequest>,
+) -> Result<Json<Speaker>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    speaker_owner(&state, &user, id).await?;
+    let speaker = validate_speaker(payload)?;
+
+    let speaker = sqlx::query_as!(
+        Speaker,
+        r#"
+        UPDATE speakers
+        SET name = $2, bio = $3, photo_url = $4, links = $5, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, organizer_id, name, bio, photo_url, links, created_at, updated_at
+        "#,
+        id,
+        speaker.name,
+        speaker.bio,
+        speaker.photo_url,
+        &speaker.links
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    Ok(Json(speaker))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/speakers/{id}",
+    tag = "Speakers",
+    params(("id" = i64, Path, description = "Speaker identifier")),
+    responses(
+        (status = 204, description = "Speaker removed"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Speaker not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_speaker(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    speaker_owner(&state, &user, id).await?;
+
+    sqlx::query!("DELETE FROM speakers WHERE id = $1", id)
+        .execute(&state.db)
+        .await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/speakers",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Speakers attached to the event", body = [Speaker]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_event_speakers(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<Speaker>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    event_owner(&state, &user, id).await?;
+
+    Ok(Json(fetch_event_speakers(&state, id).await?))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/events/{id}/speakers",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    request_body = SetEventSpeakersRequest,
+    responses(
+        (status = 200, description = "Speakers attached to the event", body = [Speaker]),
+        (status = 400, description = "Unknown speaker for this organizer", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn set_event_speakers(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<SetEventSpeakersRequest>,
+) -> Result<Json<Vec<Speaker>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = event_owner(&state, &user, id).await?;
+
+    let mut speaker_ids = payload.speaker_ids;
+    let mut seen = HashSet::new();
+    speaker_ids.retain(|speaker_id| seen.insert(*speaker_id));
+
+    let matching = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM speakers WHERE id = ANY($1) AND organizer_id = $2"#,
+        &speaker_ids,
+        organizer_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    if matching != speaker_ids.len() as i64 {
+        return Err(AppError::validation(
+            "speakers must belong to the event's organizer",
+        ));
+    }
+
+    let positions: Vec<i32> = (0..speaker_ids.len() as i32).collect();
+
+    let mut transaction = state.db.begin().await?;
+
+    sqlx::query!("DELETE FROM event_speakers WHERE event_id = $1", id)
+        .execute(&mut *transaction)
+        .await?;
+
+    sqlx::query!(
+        r#"
+        INSERT INTO event_speakers (event_id, speaker_id, position)
+        SELECT $1, speaker_id, position
+        FROM UNNEST($2::bigint[], $3::int[]) AS t(speaker_id, position)
+        "#,
+        id,
+        &speaker_ids,
+        &positions
+    )
+    .execute(&mut *transaction)
+    .await?;
+
+    transaction.commit().await?;
+
+    invalidate_public_event_caches(&state).await;
+
+    Ok(Json(fetch_event_speakers(&state, id).await?))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_speakers).post(create_speaker))
+        .route("/{id}", put(update_speaker).delete(delete_speaker))
+}
+
+pub(crate) fn event_router() -> Router<AppState> {
+    Router::new().route(
+        "/{id}/speakers",
+        get(list_event_speakers).put(set_event_speakers),
+    )
+}
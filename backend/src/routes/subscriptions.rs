@@ -0,0 +1,365 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use chrono::{DateTime, Utc};
+use chrono_tz::Europe::Berlin;
+use tracing::{info, instrument, warn};
+
+use crate::{
+    app_state::AppState,
+    dto::CreateEventSubscriptionRequest,
+    error::AppError,
+    models::{Event, EventStatus},
+    request_context::spawn_with_context,
+    responses::{ErrorResponse, EventSubscriberCountResponse, PublicEventSubscriptionResponse},
+};
+
+use super::shared::{current_user_from_headers, generate_setup_token_value, normalize_email};
+
+const CONFIRMATION_RESEND_MINUTES: i32 = 10;
+
+fn format_local(value: DateTime<Utc>) -> String {
+    value
+        .with_timezone(&Berlin)
+        .format("%d.%m.%Y, %H:%M Uhr")
+        .to_string()
+}
+
+fn describe_changes(before: &Event, after: &Event) -> Vec<String> {
+    let mut changes = Vec::new();
+
+    if before.cancelled_at.is_none() && after.cancelled_at.is_some() {
+        changes.push("Die Veranstaltung wurde abgesagt.".to_string());
+        return changes;
+    }
+
+    if before.start_date_time != after.start_date_time
+        || before.end_date_time != after.end_date_time
+    {
+        changes.push(format!(
+            "Neue Zeit: {} bis {}",
+            format_local(after.start_date_time),
+            format_local(after.end_date_time)
+        ));
+    }
+
+    if before.location != after.location {
+        match after.location.as_deref() {
+            Some(location) => changes.push(format!("Neuer Ort: {location}")),
+            None => changes.push("Der Ort wurde entfernt.".to_string()),
+        }
+    }
+
+    changes
+}
+
+pub(crate) async fn notify_event_subscribers(state: &AppState, before: &Event, after: &Event) {
+    if after.status != EventStatus::Published {
+        return;
+    }
+
+    let changes = describe_changes(before, after);
+    if changes.is_empty() {
+        return;
+    }
+
+    let Some(email_client) = state.email.clone() else {
+        warn!("email client not configured; event change notifications not sent");
+        return;
+    };
+
+    let subscribers = match sqlx::query!(
+        "SELECT email, token FROM event_subscriptions WHERE event_id = $1 AND confirmed_at IS NOT NULL",
+        after.id
+    )
+    .fetch_all(&state.db)
+    .await
+    {
+        Ok(subscribers) => subscribers,
+        Err(err) => {
+            warn!(event_id = after.id, %err, "Failed to load event subscribers");
+            return;
+        }
+    };
+
+    if subscribers.is_empty() {
+        return;
+    }
+
+    let event_id = after.id;
+    let event_title = after.title_de.clone();
+    spawn_with_context(async move {
+        let mut failed = 0usize;
+        for subscriber in &subscribers {
+            if let Err(err) = email_client
+                .send_event_change_notification(
+                    &subscriber.email,
+                    event_id,
+                    &event_title,
+                    &changes,
+                    &subscriber.token,
+                )
+                .await
+            {
+                failed += 1;
+                warn!(event_id, %err, "Failed to send event change notification");
+            }
+        }
+        info!(
+            target: "subscriptions",
+            action = "notify",
+            event_id,
+            recipients = subscribers.len(),
+            failed,
+            "Event change notifications sent"
+        );
+    });
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/public/events/{id}/subscriptions",
+    tag = "Public",
+    params(("id" = i64, Path, description = "Event identifier")),
+    request_body = CreateEventSubscriptionRequest,
+    responses(
+        (status = 202, description = "Confirmation link sent to the email address", body = PublicEventSubscriptionResponse),
+        (status = 400, description = "Invalid email address", body = ErrorResponse),
+        (status = 404, description = "Event not found or not published", body = ErrorResponse),
+        (status = 409, description = "Email already subscribed or confirmation recently sent", body = ErrorResponse),
+        (status = 503, description = "Email delivery unavailable", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, payload))]
+pub(crate) async fn create_event_subscription(
+    State(state): State<AppState>,
+    Path(id): Path<i64>,
+    Json(payload): Json<CreateEventSubscriptionRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let email = normalize_email(&payload.email)?;
+
+    let Some(email_client) = &state.email else {
+        return Err(AppError::service_unavailable(
+            "email delivery is not configured",
+        ));
+    };
+
+    let event_title = sqlx::query_scalar!(
+        r#"
+        SELECT title_de FROM events
+        WHERE id = $1 AND publish_app = true AND status = 'PUBLISHED' AND cancelled_at IS NULL
+        AND (publish_at IS NULL OR publish_at <= NOW())
+        "#,
+        id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("Event not found or not published"))?;
+
+    let subscription = sqlx::query!(
+        r#"
+        INSERT INTO event_subscriptions (event_id, email, token)
+        VALUES ($1, $2, $3)
+        ON CONFLICT (event_id, LOWER(email)) DO UPDATE
+        SET confirmation_sent_at = NOW()
+        WHERE event_subscriptions.confirmed_at IS NULL
+          AND event_subscriptions.confirmation_sent_at < NOW() - make_interval(mins => $4)
+        RETURNING token, created_at
+        "#,
+        id,
+        &email,
+        generate_setup_token_value(),
+        CONFIRMATION_RESEND_MINUTES
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| {
+        AppError::conflict(
+            "this email is already subscribed to the event or a confirmation was sent recently",
+        )
+    })?;
+
+    if let Err(err) = email_client
+        .send_event_subscription_confirmation(&email, id, &event_title, &subscription.token)
+        .await
+    {
+        warn!(event_id = id, %err, "Failed to send subscription confirmation");
+        sqlx::query!(
+            "DELETE FROM event_subscriptions WHERE token = $1 AND confirmed_at IS NULL",
+            &subscription.token
+        )
+        .execute(&state.db)
+        .await?;
+        return Err(AppError::service_unavailable(
+            "confirmation email could not be sent",
+        ));
+    }
+
+    info!(
+        target: "subscriptions",
+        action = "create",
+        event_id = id,
+        "Event subscription confirmation sent"
+    );
+
+    Ok((
+        StatusCode::ACCEPTED,
+        Json(PublicEventSubscriptionResponse {
+            event_id: id,
+            created_at: subscription.created_at,
+        }),
+    ))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/subscriptions/{token}/confirm",
+    tag = "Public",
+    params(("token" = String, Path, description = "Subscription token")),
+    responses(
+        (status = 200, description = "Subscription confirmed", body = String, content_type = "text/plain"),
+        (status = 404, description = "Subscription not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, token))]
+pub(crate) async fn confirm_event_subscription(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<&'static str, AppError> {
+    let result = sqlx::query!(
+        "UPDATE event_subscriptions SET confirmed_at = COALESCE(confirmed_at, NOW()) WHERE token = $1",
+        &token
+    )
+    .execute(&state.db)
+    .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("Subscription not found"));
+    }
+
+    info!(
+        target: "subscriptions",
+        action = "confirm",
+        "Event subscription confirmed"
+    );
+
+    Ok("Du erhältst ab jetzt Benachrichtigungen zu Änderungen an dieser Veranstaltung.")
+}
+
+async fn remove_subscription(state: &AppState, token: &str) -> Result<(), AppError> {
+    let result = sqlx::query!("DELETE FROM event_subscriptions WHERE token = $1", token)
+        .execute(&state.db)
+        .await?;
+
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("Subscription not found"));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/public/subscriptions/{token}",
+    tag = "Public",
+    params(("token" = String, Path, description = "Subscription token")),
+    responses(
+        (status = 204, description = "Subscription removed"),
+        (status = 404, description = "Subscription not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, token))]
+pub(crate) async fn delete_event_subscription(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<StatusCode, AppError> {
+    remove_subscription(&state, &token).await?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/public/subscriptions/{token}/unsubscribe",
+    tag = "Public",
+    params(("token" = String, Path, description = "Subscription token")),
+    responses(
+        (status = 200, description = "Subscription removed", body = String, content_type = "text/plain"),
+        (status = 404, description = "Subscription not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, token))]
+pub(crate) async fn unsubscribe_via_link(
+    State(state): State<AppState>,
+    Path(token): Path<String>,
+) -> Result<&'static str, AppError> {
+    remove_subscription(&state, &token).await?;
+    Ok("Du erhältst keine Benachrichtigungen mehr zu dieser Veranstaltung.")
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/{id}/subscriptions",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier")),
+    responses(
+        (status = 200, description = "Number of subscribers for the event", body = EventSubscriberCountResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_event_subscriber_count(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<EventSubscriberCountResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let organizer_id = sqlx::query_scalar!("SELECT organizer_id FROM events WHERE id = $1", id)
+        .fetch_optional(&state.db)
+        .await?
+        .ok_or_else(|| AppError::not_found("event not found"))?;
+
+    if !user.is_admin() && user.organizer_id() != Some(organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot view another organizer's subscribers",
+        ));
+    }
+
+    let subscriber_count = sqlx::query_scalar!(
+        r#"SELECT COUNT(*) AS "count!" FROM event_subscriptions WHERE event_id = $1 AND confirmed_at IS NOT NULL"#,
+        id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    Ok(Json(EventSubscriberCountResponse {
+        event_id: id,
+        subscriber_count,
+    }))
+}
+
+pub(crate) fn public_router() -> Router<AppState> {
+    Router::new()
+        .route(
+            "/events/{id}/subscriptions",
+            post(create_event_subscription),
+        )
+        .route("/subscriptions/{token}", delete(delete_event_subscription))
+        .route(
+            "/subscriptions/{token}/confirm",
+            get(confirm_event_subscription),
+        )
+        .route(
+            "/subscriptions/{token}/unsubscribe",
+            get(unsubscribe_via_link),
+        )
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/{id}/subscriptions", get(get_event_subscriber_count))
+}
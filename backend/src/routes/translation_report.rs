@@ -0,0 +1,173 @@
+use axum::{
+    Json, Router,
+    extract::{Query, State},
+    http::HeaderMap,
+    routing::get,
+};
+use chrono::{Duration, Utc};
+use tracing::instrument;
+
+use crate::{
+    app_state::AppState,
+    dto::TranslationReportQuery,
+    error::AppError,
+    responses::{
+        ErrorResponse, EventTranslationReportEntry, OrganizerTranslationReportEntry,
+        TranslationIssue, TranslationReportResponse,
+    },
+};
+
+use super::shared::current_user_from_headers;
+
+const DEFAULT_REPORT_WEEKS: i64 = 4;
+const MAX_REPORT_WEEKS: i64 = 26;
+const MIN_COMPARABLE_DESCRIPTION_LEN: usize = 80;
+const MIN_DESCRIPTION_LENGTH_RATIO: f64 = 0.4;
+
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+fn title_issue(title_de: &str, title_en: &str) -> Option<TranslationIssue> {
+    let title_de = title_de.trim();
+    let title_en = title_en.trim();
+    if title_en.is_empty() {
+        Some(TranslationIssue::MissingTitle)
+    } else if title_en.eq_ignore_ascii_case(title_de) && title_de.split_whitespace().count() > 2 {
+        Some(TranslationIssue::UntranslatedTitle)
+    } else {
+        None
+    }
+}
+
+fn description_issue(
+    description_de: Option<&str>,
+    description_en: Option<&str>,
+) -> Option<TranslationIssue> {
+    let description_de = non_empty(description_de)?;
+    let Some(description_en) = non_empty(description_en) else {
+        return Some(TranslationIssue::MissingDescription);
+    };
+    if description_en == description_de {
+        return Some(TranslationIssue::UntranslatedDescription);
+    }
+
+    let length_de = description_de.chars().count();
+    let length_en = description_en.chars().count();
+    if length_de >= MIN_COMPARABLE_DESCRIPTION_LEN
+        && (length_en as f64) < (length_de as f64) * MIN_DESCRIPTION_LENGTH_RATIO
+    {
+        return Some(TranslationIssue::ShortDescription);
+    }
+    None
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/admin/translation-report",
+    tag = "Admin",
+    params(TranslationReportQuery),
+    responses(
+        (status = 200, description = "Upcoming events and organizers with missing or suspicious English translations", body = TranslationReportResponse),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    ),
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn get_translation_report(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<TranslationReportQuery>,
+) -> Result<Json<TranslationReportResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let weeks = query.weeks.unwrap_or(DEFAULT_REPORT_WEEKS);
+    if !(1..=MAX_REPORT_WEEKS).contains(&weeks) {
+        return Err(AppError::validation(format!(
+            "weeks must be between 1 and {MAX_REPORT_WEEKS}"
+        )));
+    }
+
+    let window_start = Utc::now();
+    let window_end = window_start + Duration::weeks(weeks);
+
+    let events = sqlx::query!(
+        r#"
+        SELECT e.id, e.organizer_id, o.name AS organizer_name, e.title_de, e.title_en,
+               e.description_de, e.description_en, e.start_date_time
+        FROM events e
+        JOIN organizers o ON e.organizer_id = o.id
+        WHERE e.status = 'PUBLISHED'
+          AND e.cancelled_at IS NULL
+          AND e.start_date_time >= $1
+          AND e.start_date_time < $2
+        ORDER BY e.start_date_time ASC, e.id ASC
+        "#,
+        window_start,
+        window_end
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let events = events
+        .into_iter()
+        .filter_map(|event| {
+            let issues: Vec<TranslationIssue> = [
+                title_issue(&event.title_de, &event.title_en),
+                description_issue(
+                    event.description_de.as_deref(),
+                    event.description_en.as_deref(),
+                ),
+            ]
+            .into_iter()
+            .flatten()
+            .collect();
+            if issues.is_empty() {
+                return None;
+            }
+            Some(EventTranslationReportEntry {
+                event_id: event.id,
+                organizer_id: event.organizer_id,
+                organizer_name: event.organizer_name,
+                title_de: event.title_de,
+                start_date_time: event.start_date_time,
+                issues,
+            })
+        })
+        .collect();
+
+    let organizers = sqlx::query!(
+        "SELECT id, name, description_de, description_en FROM organizers ORDER BY name ASC"
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let organizers = organizers
+        .into_iter()
+        .filter_map(|organizer| {
+            let issue = description_issue(
+                organizer.description_de.as_deref(),
+                organizer.description_en.as_deref(),
+            )?;
+            Some(OrganizerTranslationReportEntry {
+                organizer_id: organizer.id,
+                name: organizer.name,
+                issues: vec![issue],
+            })
+        })
+        .collect();
+
+    Ok(Json(TranslationReportResponse {
+        window_start,
+        window_end,
+        events,
+        organizers,
+    }))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/translation-report", get(get_translation_report))
+}
@@ -0,0 +1,134 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::HeaderMap,
+    routing::post,
+};
+use tracing::{error, info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::SuggestTranslationQuery,
+    error::AppError,
+    models::Event,
+    responses::{ErrorResponse, TranslationSuggestionResponse},
+    translation::TranslationLanguage,
+};
+
+use super::{events::get_event_with_user, shared::current_user_from_headers};
+
+fn is_blank(value: Option<&str>) -> bool {
+    value.is_none_or(|value| value.trim().is_empty())
+}
+
+fn missing_language(event: &Event) -> TranslationLanguage {
+    let missing_en = event.title_en.trim().is_empty()
+        || (!is_blank(event.description_de.as_deref())
+            && is_blank(event.description_en.as_deref()));
+    let missing_de = event.title_de.trim().is_empty()
+        || (!is_blank(event.description_en.as_deref())
+            && is_blank(event.description_de.as_deref()));
+
+    if missing_de && !missing_en {
+        TranslationLanguage::De
+    } else {
+        TranslationLanguage::En
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/events/{id}/suggest-translation",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Event identifier"), SuggestTranslationQuery),
+    responses(
+        (status = 200, description = "Machine-translated draft of the missing language; nothing is saved", body = TranslationSuggestionResponse),
+        (status = 400, description = "Nothing to translate", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Event not found", body = ErrorResponse),
+        (status = 503, description = "Translation service not configured or unavailable", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn suggest_translation(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Query(query): Query<SuggestTranslationQuery>,
+) -> Result<Json<TranslationSuggestionResponse>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let event = get_event_with_user(&state, &user, id).await?;
+    if !user.is_admin() && user.organizer_id() != Some(event.organizer_id) {
+        return Err(AppError::unauthorized(
+            "cannot translate another organizer's event",
+        ));
+    }
+
+    let Some(translator) = &state.translator else {
+        return Err(AppError::service_unavailable(
+            "translation service not configured",
+        ));
+    };
+
+    let target_language = query
+        .target_language
+        .unwrap_or_else(|| missing_language(&event));
+    let (source_language, source_title, source_description) = match target_language {
+        TranslationLanguage::En => (
+            TranslationLanguage::De,
+            event.title_de,
+            event.description_de,
+        ),
+        TranslationLanguage::De => (
+            TranslationLanguage::En,
+            event.title_en,
+            event.description_en,
+        ),
+    };
+
+    let source_title = source_title.trim().to_string();
+    if source_title.is_empty() {
+        return Err(AppError::validation("nothing to translate"));
+    }
+    let source_description = source_description
+        .map(|description| description.trim().to_string())
+        .filter(|description| !description.is_empty());
+
+    let mut texts = vec![source_title];
+    if let Some(description) = source_description {
+        texts.push(description);
+    }
+
+    let mut translated = translator
+        .translate(&texts, source_language, target_language)
+        .await
+        .map_err(|err| {
+            error!(target: "translation", action = "suggest", event_id = id, %err, "Translation request failed");
+            AppError::service_unavailable("translation service failed")
+        })?
+        .into_iter();
+
+    let title = translated.next().unwrap_or_default();
+    let description = translated.next();
+
+    info!(
+        target: "translation",
+        action = "suggest",
+        event_id = id,
+        provider = translator.provider_name(),
+        "Translation suggestion generated"
+    );
+
+    Ok(Json(TranslationSuggestionResponse {
+        event_id: id,
+        provider: translator.provider_name().to_string(),
+        source_language,
+        target_language,
+        title,
+        description,
+    }))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new().route("/{id}/suggest-translation", post(suggest_translation))
+}
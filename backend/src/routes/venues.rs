@@ -0,0 +1,366 @@
+use axum::{
+    Json, Router,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
+    routing::{get, put},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    app_state::AppState,
+    dto::{LocationSuggestQuery, VenueRequest},
+    error::AppError,
+    models::Venue,
+    responses::{ErrorResponse, LocationSuggestion},
+};
+
+use super::{events::invalidate_public_event_caches, shared::current_user_from_headers};
+
+const MAX_VENUE_NAME_LEN: usize = 200;
+const MAX_VENUE_DETAIL_LEN: usize = 100;
+const MAX_VENUE_CAPACITY: i32 = 100_000;
+const MAX_LOCATION_QUERY_LEN: usize = 100;
+const DEFAULT_LOCATION_SUGGESTIONS: i64 = 10;
+const MAX_LOCATION_SUGGESTIONS: i64 = 50;
+
+struct NormalizedVenue {
+    name: String,
+    building: Option<String>,
+    room: Option<String>,
+    latitude: Option<f64>,
+    longitude: Option<f64>,
+    capacity: Option<i32>,
+    close_registration_at_capacity: bool,
+}
+
+fn normalize_detail(raw: Option<String>, field: &str) -> Result<Option<String>, AppError> {
+    let Some(value) = raw.map(|value| value.trim().to_string()) else {
+        return Ok(None);
+    };
+    if value.chars().count() > MAX_VENUE_DETAIL_LEN {
+        return Err(AppError::validation(format!(
+            "{field} must be at most {MAX_VENUE_DETAIL_LEN} characters"
+        )));
+    }
+    Ok((!value.is_empty()).then_some(value))
+}
+
+fn normalize_venue(payload: VenueRequest) -> Result<NormalizedVenue, AppError> {
+    let name = payload.name.trim().to_string();
+    if name.is_empty() || name.chars().count() > MAX_VENUE_NAME_LEN {
+        return Err(AppError::validation(format!(
+            "name must be between 1 and {MAX_VENUE_NAME_LEN} characters"
+        )));
+    }
+
+    match (payload.latitude, payload.longitude) {
+        (None, None) => {}
+        (Some(latitude), Some(longitude)) => {
+            if !(-90.0..=90.0).contains(&latitude) {
+                return Err(AppError::validation("latitude must be between -90 and 90"));
+            }
+            if !(-180.0..=180.0).contains(&longitude) {
+                return Err(AppError::validation(
+                    "longitude must be between -180 and 180",
+                ));
+            }
+        }
+        _ => {
+            return Err(AppError::validation(
+                "latitude and longitude must be provided together",
+            ));
+        }
+    }
+
+    if payload
+        .capacity
+        .is_some_and(|capacity| !(1..=MAX_VENUE_CAPACITY).contains(&capacity))
+    {
+        return Err(AppError::validation(format!(
+            "capacity must be between 1 and {MAX_VENUE_CAPACITY}"
+        )));
+    }
+    if payload.close_registration_at_capacity && payload.capacity.is_none() {
+        return Err(AppError::validation(
+            "close_registration_at_capacity requires a capacity",
+        ));
+    }
+
+    Ok(NormalizedVenue {
+        name,
+        building: normalize_detail(payload.building, "building")?,
+        room: normalize_detail(payload.room, "room")?,
+        latitude: payload.latitude,
+        longitude: payload.longitude,
+        capacity: payload.capacity,
+        close_registration_at_capacity: payload.close_registration_at_capacity,
+    })
+}
+
+pub(crate) async fn venue_location_label(
+    state: &AppState,
+    venue_id: Option<i64>,
+) -> Result<Option<String>, AppError> {
+    let Some(venue_id) = venue_id else {
+        return Ok(None);
+    };
+
+    let venue = sqlx::query!(
+        "SELECT name, building, room FROM venues WHERE id = $1",
+        venue_id
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::validation("venue not found"))?;
+
+    let label = [Some(venue.name), venue.building, venue.room]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>()
+        .join(", ");
+    Ok(Some(label))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/venues",
+    tag = "Events",
+    responses(
+        (status = 200, description = "Venues events can be assigned to", body = [Venue]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_venues(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<Venue>>, AppError> {
+    current_user_from_headers(&headers, &state).await?;
+
+    let venues = sqlx::query_as!(
+        Venue,
+        r#"
+        SELECT id, name, building, room, latitude, longitude, capacity, close_registration_at_capacity, created_at, updated_at
+        FROM venues
+        ORDER BY name ASC, building ASC NULLS FIRST, room ASC NULLS FIRST, id ASC
+        "#
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(venues))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/venues",
+    tag = "Events",
+    request_body = VenueRequest,
+    responses(
+        (status = 201, description = "Venue created", body = Venue),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 409, description = "Venue already exists", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_venue(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<VenueRequest>,
+) -> Result<(StatusCode, Json<Venue>), AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let venue = normalize_venue(payload)?;
+    let venue = sqlx::query_as!(
+        Venue,
+        r#"
+        INSERT INTO venues (name, building, room, latitude, longitude, capacity, close_registration_at_capacity)
+        VALUES ($1, $2, $3, $4, $5, $6, $7)
+        RETURNING id, name, building, room, latitude, longitude, capacity, close_registration_at_capacity, created_at, updated_at
+        "#,
+        venue.name,
+        venue.building,
+        venue.room,
+        venue.latitude,
+        venue.longitude,
+        venue.capacity,
+        venue.close_registration_at_capacity
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(target: "venues", action = "create", venue_id = venue.id, "Venue created");
+
+    Ok((StatusCode::CREATED, Json(venue)))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/venues/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Venue identifier")),
+    request_body = VenueRequest,
+    responses(
+        (status = 200, description = "Venue updated", body = Venue),
+        (status = 400, description = "Invalid request", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_venue(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<VenueRequest>,
+) -> Result<Json<Venue>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let venue = normalize_venue(payload)?;
+    let venue = sqlx::query_as!(
+        Venue,
+        r#"
+        UPDATE venues
+        SET name = $2, building = $3, room = $4, latitude = $5, longitude = $6, capacity = $7, close_registration_at_capacity = $8, updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, name, building, room, latitude, longitude, capacity, close_registration_at_capacity, created_at, updated_at
+        "#,
+        id,
+        venue.name,
+        venue.building,
+        venue.room,
+        venue.latitude,
+        venue.longitude,
+        venue.capacity,
+        venue.close_registration_at_capacity
+    )
+    .fetch_optional(&state.db)
+    .await?
+    .ok_or_else(|| AppError::not_found("venue not found"))?;
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(target: "venues", action = "update", venue_id = id, "Venue updated");
+
+    Ok(Json(venue))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/venues/{id}",
+    tag = "Events",
+    params(("id" = i64, Path, description = "Venue identifier")),
+    responses(
+        (status = 204, description = "Venue deleted and detached from its events"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Venue not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_venue(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    if !user.is_admin() {
+        return Err(AppError::unauthorized("insufficient permissions"));
+    }
+
+    let result = sqlx::query!("DELETE FROM venues WHERE id = $1", id)
+        .execute(&state.db)
+        .await?;
+    if result.rows_affected() == 0 {
+        return Err(AppError::not_found("venue not found"));
+    }
+
+    invalidate_public_event_caches(&state).await;
+
+    info!(target: "venues", action = "delete", venue_id = id, "Venue deleted");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/events/locations/suggest",
+    tag = "Events",
+    params(LocationSuggestQuery),
+    responses(
+        (status = 200, description = "Shared venues and locations previously used by the organizer matching the query", body = [LocationSuggestion]),
+        (status = 400, description = "Invalid query", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn suggest_locations(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Query(query): Query<LocationSuggestQuery>,
+) -> Result<Json<Vec<LocationSuggestion>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    let organizer_id = match user.organizer_id() {
+        Some(organizer_id) => Some(organizer_id),
+        None if user.is_admin() => query.organizer_id,
+        None => return Err(AppError::unauthorized("organizer account required")),
+    };
+
+    let q = query.q.trim();
+    if q.chars().count() > MAX_LOCATION_QUERY_LEN {
+        return Err(AppError::validation(format!(
+            "q must be at most {MAX_LOCATION_QUERY_LEN} characters"
+        )));
+    }
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_LOCATION_SUGGESTIONS)
+        .clamp(1, MAX_LOCATION_SUGGESTIONS);
+
+    let suggestions = sqlx::query_as!(
+        LocationSuggestion,
+        r#"
+        SELECT label AS "label!", venue_id, uses AS "uses!"
+        FROM (
+            SELECT concat_ws(', ', name, building, room) AS label, id AS venue_id,
+                   (SELECT COUNT(*) FROM events e WHERE e.venue_id = venues.id AND ($1::bigint IS NULL OR e.organizer_id = $1)) AS uses
+            FROM venues
+            WHERE strpos(LOWER(concat_ws(', ', name, building, room)), LOWER($2)) > 0
+            UNION ALL
+            SELECT (ARRAY_AGG(TRIM(location) ORDER BY start_date_time DESC))[1] AS label, NULL::bigint AS venue_id, COUNT(*) AS uses
+            FROM events
+            WHERE ($1::bigint IS NULL OR organizer_id = $1)
+              AND venue_id IS NULL
+              AND location IS NOT NULL
+              AND TRIM(location) <> ''
+              AND strpos(LOWER(location), LOWER($2)) > 0
+            GROUP BY LOWER(TRIM(location))
+        ) suggestions
+        ORDER BY venue_id IS NULL, uses DESC, label ASC
+        LIMIT $3
+        "#,
+        organizer_id,
+        q,
+        limit
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(suggestions))
+}
+
+pub(crate) fn event_router() -> Router<AppState> {
+    Router::new().route("/locations/suggest", get(suggest_locations))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_venues).post(create_venue))
+        .route("/{id}", put(update_venue).delete(delete_venue))
+}
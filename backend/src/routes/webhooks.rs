@@ -0,0 +1,322 @@
+use axum::{
+    Json, Router,
+    extract::{Path, State},
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    routing::{get, put},
+};
+use tracing::{info, instrument};
+
+use crate::{
+    api_token,
+    app_state::AppState,
+    dto::{CreateWebhookRequest, UpdateWebhookRequest},
+    error::AppError,
+    models::{Webhook, WebhookDelivery, WebhookDeliveryStatus, WebhookEventType},
+    responses::{ErrorResponse, WebhookCreatedResponse},
+    webhooks::ensure_public_webhook_url,
+};
+
+use super::shared::{AuthedUser, current_user_from_headers, validate_http_url};
+
+const WEBHOOK_SECRET_PREFIX: &str = "whsec_";
+const DELIVERY_HISTORY_LIMIT: i64 = 100;
+
+fn normalize_event_types(
+    event_types: Vec<WebhookEventType>,
+) -> Result<Vec<WebhookEventType>, AppError> {
+    let mut normalized = Vec::with_capacity(event_types.len());
+    for event_type in event_types {
+        if !normalized.contains(&event_type) {
+            normalized.push(event_type);
+        }
+    }
+    if normalized.is_empty() {
+        return Err(AppError::validation(
+            "event_types must contain at least one event type",
+        ));
+    }
+    Ok(normalized)
+}
+
+async fn owned_webhook(state: &AppState, user: &AuthedUser, id: i64) -> Result<(), AppError> {
+    let owner = sqlx::query!("SELECT organizer_id FROM webhooks WHERE id = $1", id)
+        .fetch_optional(&state.db)
+        .await?;
+
+    let Some(owner) = owner else {
+        return Err(AppError::not_found("Webhook not found"));
+    };
+
+    if !user.is_admin()
+        && (owner.organizer_id.is_none() || user.organizer_id() != owner.organizer_id)
+    {
+        return Err(AppError::unauthorized(
+            "cannot manage another organizer's webhook",
+        ));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks",
+    tag = "Webhooks",
+    responses(
+        (status = 200, description = "Webhooks visible to the current account", body = [Webhook]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_webhooks(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+) -> Result<Json<Vec<Webhook>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let webhooks = if user.is_admin() {
+        sqlx::query_as!(
+            Webhook,
+            r#"
+            SELECT id, organizer_id, url, event_types as "event_types: Vec<WebhookEventType>",
+                   active, created_at, updated_at
+            FROM webhooks
+            ORDER BY created_at DESC
+            "#
+        )
+        .fetch_all(&state.db)
+        .await?
+    } else {
+        let organizer_id = user
+            .organizer_id()
+            .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+        sqlx::query_as!(
+            Webhook,
+            r#"
+            SELECT id, organizer_id, url, event_types as "event_types: Vec<WebhookEventType>",
+                   active, created_at, updated_at
+            FROM webhooks
+            WHERE organizer_id = $1
+            ORDER BY created_at DESC
+            "#,
+            organizer_id
+        )
+        .fetch_all(&state.db)
+        .await?
+    };
+
+    Ok(Json(webhooks))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/v1/webhooks",
+    tag = "Webhooks",
+    request_body = CreateWebhookRequest,
+    responses(
+        (status = 201, description = "Webhook created; the signing secret is only returned once", body = WebhookCreatedResponse),
+        (status = 400, description = "Invalid URL or event types", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn create_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Json(payload): Json<CreateWebhookRequest>,
+) -> Result<impl IntoResponse, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+
+    let organizer_id = if user.is_admin() {
+        payload.organizer_id
+    } else {
+        let organizer_id = user
+            .organizer_id()
+            .ok_or_else(|| AppError::unauthorized("organizer account required"))?;
+        if payload
+            .organizer_id
+            .is_some_and(|requested| requested != organizer_id)
+        {
+            return Err(AppError::unauthorized(
+                "cannot create webhooks for another organizer",
+            ));
+        }
+        Some(organizer_id)
+    };
+
+    let url = validate_http_url(&payload.url, "url")?;
+    ensure_public_webhook_url(&url).await?;
+    let event_types = normalize_event_types(payload.event_types)?;
+    let secret = api_token::generate_prefixed_token(WEBHOOK_SECRET_PREFIX);
+
+    let webhook = sqlx::query_as!(
+        Webhook,
+        r#"
+        INSERT INTO webhooks (organizer_id, url, secret, event_types, created_by)
+        VALUES ($1, $2, $3, $4, $5)
+        RETURNING id, organizer_id, url, event_types as "event_types: Vec<WebhookEventType>",
+                  active, created_at, updated_at
+        "#,
+        organizer_id,
+        url,
+        secret,
+        event_types as Vec<WebhookEventType>,
+        user.account_id
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(
+        target: "webhooks",
+        action = "create",
+        webhook_id = webhook.id,
+        organizer_id = ?webhook.organizer_id,
+        "Webhook created"
+    );
+
+    Ok((
+        StatusCode::CREATED,
+        Json(WebhookCreatedResponse { webhook, secret }),
+    ))
+}
+
+#[utoipa::path(
+    put,
+    path = "/api/v1/webhooks/{id}",
+    tag = "Webhooks",
+    params(("id" = i64, Path, description = "Webhook identifier")),
+    request_body = UpdateWebhookRequest,
+    responses(
+        (status = 200, description = "Webhook updated", body = Webhook),
+        (status = 400, description = "Invalid URL or event types", body = ErrorResponse),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers, payload))]
+pub(crate) async fn update_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+    Json(payload): Json<UpdateWebhookRequest>,
+) -> Result<Json<Webhook>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    owned_webhook(&state, &user, id).await?;
+
+    let url = payload
+        .url
+        .as_deref()
+        .map(|url| validate_http_url(url, "url"))
+        .transpose()?;
+    if let Some(url) = &url {
+        ensure_public_webhook_url(url).await?;
+    }
+    let event_types = payload.event_types.map(normalize_event_types).transpose()?;
+
+    let webhook = sqlx::query_as!(
+        Webhook,
+        r#"
+        UPDATE webhooks
+        SET url = COALESCE($2, url),
+            event_types = COALESCE($3, event_types),
+            active = COALESCE($4, active),
+            updated_at = NOW()
+        WHERE id = $1
+        RETURNING id, organizer_id, url, event_types as "event_types: Vec<WebhookEventType>",
+                  active, created_at, updated_at
+        "#,
+        id,
+        url,
+        event_types as Option<Vec<WebhookEventType>>,
+        payload.active
+    )
+    .fetch_one(&state.db)
+    .await?;
+
+    info!(
+        target: "webhooks",
+        action = "update",
+        webhook_id = webhook.id,
+        active = webhook.active,
+        "Webhook updated"
+    );
+
+    Ok(Json(webhook))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/v1/webhooks/{id}",
+    tag = "Webhooks",
+    params(("id" = i64, Path, description = "Webhook identifier")),
+    responses(
+        (status = 204, description = "Webhook removed together with its delivery log"),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn delete_webhook(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<StatusCode, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    owned_webhook(&state, &user, id).await?;
+
+    sqlx::query!("DELETE FROM webhooks WHERE id = $1", id)
+        .execute(&state.db)
+        .await?;
+
+    info!(target: "webhooks", action = "delete", webhook_id = id, "Webhook deleted");
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/v1/webhooks/{id}/deliveries",
+    tag = "Webhooks",
+    params(("id" = i64, Path, description = "Webhook identifier")),
+    responses(
+        (status = 200, description = "Most recent deliveries for the webhook", body = [WebhookDelivery]),
+        (status = 401, description = "Unauthorized", body = ErrorResponse),
+        (status = 404, description = "Webhook not found", body = ErrorResponse),
+    )
+)]
+#[instrument(skip(state, headers))]
+pub(crate) async fn list_webhook_deliveries(
+    State(state): State<AppState>,
+    headers: HeaderMap,
+    Path(id): Path<i64>,
+) -> Result<Json<Vec<WebhookDelivery>>, AppError> {
+    let user = current_user_from_headers(&headers, &state).await?;
+    owned_webhook(&state, &user, id).await?;
+
+    let deliveries = sqlx::query_as!(
+        WebhookDelivery,
+        r#"
+        SELECT id, webhook_id, event_type as "event_type: WebhookEventType", event_id, payload,
+               status as "status: WebhookDeliveryStatus", attempts, next_attempt_at,
+               last_status_code, last_error, delivered_at, created_at
+        FROM webhook_deliveries
+        WHERE webhook_id = $1
+        ORDER BY created_at DESC
+        LIMIT $2
+        "#,
+        id,
+        DELIVERY_HISTORY_LIMIT
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    Ok(Json(deliveries))
+}
+
+pub(crate) fn router() -> Router<AppState> {
+    Router::new()
+        .route("/", get(list_webhooks).post(create_webhook))
+        .route("/{id}", put(update_webhook).delete(delete_webhook))
+        .route("/{id}/deliveries", get(list_webhook_deliveries))
+}
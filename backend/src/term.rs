@@ -0,0 +1,77 @@
+use chrono::{DateTime, Datelike, Utc};
+use chrono_tz::Europe::Berlin;
+
+use crate::error::AppError;
+
+const SUMMER_TERM_START: (u32, u32) = (3, 15);
+const WINTER_TERM_START: (u32, u32) = (10, 1);
+
+fn short_year(year: i32) -> i32 {
+    year.rem_euclid(100)
+}
+
+fn winter_term(start_year: i32) -> String {
+    format!(
+        "WS{:02}/{:02}",
+        short_year(start_year),
+        short_year(start_year + 1)
+    )
+}
+
+pub fn term_for(start_date_time: DateTime<Utc>) -> String {
+    let date = start_date_time.with_timezone(&Berlin).date_naive();
+    let month_day = (date.month(), date.day());
+    if month_day < SUMMER_TERM_START {
+        winter_term(date.year() - 1)
+    } else if month_day < WINTER_TERM_START {
+        format!("SS{:02}", short_year(date.year()))
+    } else {
+        winter_term(date.year())
+    }
+}
+
+pub fn normalize_term(raw: &str) -> Result<String, AppError> {
+    let value = raw.trim().to_ascii_uppercase();
+    let digits = |part: &str| {
+        (part.len() == 2 && part.bytes().all(|byte| byte.is_ascii_digit()))
+            .then(|| part.parse::<i32>().ok())
+            .flatten()
+    };
+    let valid = if let Some(year) = value.strip_prefix("SS") {
+        digits(year).is_some()
+    } else if let Some((first, second)) = value
+        .strip_prefix("WS")
+        .and_then(|years| years.split_once('/'))
+    {
+        matches!(
+            (digits(first), digits(second)),
+            (Some(first), Some(second)) if (first + 1) % 100 == second
+        )
+    } else {
+        false
+    };
+
+    if valid {
+        Ok(value)
+    } else {
+        Err(AppError::validation("term must look like SS26 or WS25/26"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn derives_terms_from_berlin_dates() {
+        let at = |value: &str| value.parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(term_for(at("2026-01-20T10:00:00Z")), "WS25/26");
+        assert_eq!(term_for(at("2026-03-14T23:30:00Z")), "SS26");
+        assert_eq!(term_for(at("2026-09-30T21:59:00Z")), "SS26");
+        assert_eq!(term_for(at("2026-09-30T22:00:00Z")), "WS26/27");
+        assert_eq!(term_for(at("2099-11-02T12:00:00Z")), "WS99/00");
+        assert_eq!(normalize_term(" ws25/26 ").unwrap(), "WS25/26");
+        assert!(normalize_term("WS25/27").is_err());
+        assert!(normalize_term("SS2026").is_err());
+    }
+}
@@ -0,0 +1,123 @@
+use std::{
+    future::Future,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::{
+    Json,
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimingPhase {
+    Cache,
+    Database,
+    Serialization,
+}
+
+impl TimingPhase {
+    fn metric_name(self) -> &'static str {
+        match self {
+            Self::Cache => "cache",
+            Self::Database => "db",
+            Self::Serialization => "serialize",
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct PhaseTotal {
+    elapsed: Duration,
+    count: u32,
+}
+
+#[derive(Debug, Default)]
+struct Totals {
+    cache: PhaseTotal,
+    database: PhaseTotal,
+    serialization: PhaseTotal,
+}
+
+impl Totals {
+    fn phase_mut(&mut self, phase: TimingPhase) -> &mut PhaseTotal {
+        match phase {
+            TimingPhase::Cache => &mut self.cache,
+            TimingPhase::Database => &mut self.database,
+            TimingPhase::Serialization => &mut self.serialization,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TimingRecorder {
+    totals: Arc<Mutex<Totals>>,
+}
+
+tokio::task_local! {
+    static RECORDER: TimingRecorder;
+}
+
+impl TimingRecorder {
+    pub async fn scope<F: Future>(&self, future: F) -> F::Output {
+        RECORDER.scope(self.clone(), future).await
+    }
+
+    pub fn header_value(&self, total: Duration) -> String {
+        let totals = self.totals.lock().unwrap_or_else(|err| err.into_inner());
+        let mut parts: Vec<String> = [
+            (TimingPhase::Cache, totals.cache),
+            (TimingPhase::Database, totals.database),
+            (TimingPhase::Serialization, totals.serialization),
+        ]
+        .into_iter()
+        .map(|(phase, recorded)| {
+            format!(
+                "{};dur={:.2};count={}",
+                phase.metric_name(),
+                recorded.elapsed.as_secs_f64() * 1000.0,
+                recorded.count
+            )
+        })
+        .collect();
+        parts.push(format!("total;dur={:.2}", total.as_secs_f64() * 1000.0));
+        parts.join(", ")
+    }
+}
+
+pub fn record(phase: TimingPhase, elapsed: Duration) {
+    let _ = RECORDER.try_with(|recorder| {
+        let mut totals = recorder
+            .totals
+            .lock()
+            .unwrap_or_else(|err| err.into_inner());
+        let total = totals.phase_mut(phase);
+        total.elapsed += elapsed;
+        total.count += 1;
+    });
+}
+
+pub trait Timed: Future + Sized {
+    fn timed(self, phase: TimingPhase) -> impl Future<Output = Self::Output> {
+        async move {
+            let started = Instant::now();
+            let output = self.await;
+            record(phase, started.elapsed());
+            output
+        }
+    }
+}
+
+impl<F: Future> Timed for F {}
+
+pub struct TimedJson<T>(pub T);
+
+impl<T: Serialize> IntoResponse for TimedJson<T> {
+    fn into_response(self) -> Response {
+        let started = Instant::now();
+        let response = Json(self.0).into_response();
+        record(TimingPhase::Serialization, started.elapsed());
+        response
+    }
+}
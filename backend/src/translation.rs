@@ -0,0 +1,256 @@
+use std::{env, str::FromStr, time::Duration};
+
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use thiserror::Error;
+use utoipa::ToSchema;
+
+const DEFAULT_DEEPL_URL: &str = "https://api-free.deepl.com/v2/translate";
+const DEFAULT_TRANSLATION_TIMEOUT_SECONDS: u64 = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum TranslationLanguage {
+    De,
+    En,
+}
+
+impl TranslationLanguage {
+    fn deepl_code(self, target: bool) -> &'static str {
+        match (self, target) {
+            (TranslationLanguage::De, _) => "DE",
+            (TranslationLanguage::En, true) => "EN-GB",
+            (TranslationLanguage::En, false) => "EN",
+        }
+    }
+
+    fn english_name(self) -> &'static str {
+        match self {
+            TranslationLanguage::De => "German",
+            TranslationLanguage::En => "English",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TranslationProvider {
+    DeepL,
+    OpenAi,
+}
+
+impl FromStr for TranslationProvider {
+    type Err = TranslationError;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "deepl" => Ok(TranslationProvider::DeepL),
+            "openai" | "llm" => Ok(TranslationProvider::OpenAi),
+            other => Err(TranslationError::InvalidConfig(format!(
+                "unsupported TRANSLATION_PROVIDER {other}"
+            ))),
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum TranslationError {
+    #[error("Invalid translation configuration: {0}")]
+    InvalidConfig(String),
+    #[error("Translation request failed: {0}")]
+    Request(#[from] reqwest::Error),
+    #[error("Translation provider returned an unexpected response: {0}")]
+    UnexpectedResponse(String),
+}
+
+#[derive(Clone)]
+pub struct TranslationClient {
+    http: reqwest::Client,
+    provider: TranslationProvider,
+    api_url: String,
+    api_key: String,
+    model: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLResponse {
+    translations: Vec<DeepLTranslation>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DeepLTranslation {
+    text: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionResponse {
+    choices: Vec<ChatCompletionChoice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionChoice {
+    message: ChatCompletionMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatCompletionMessage {
+    content: String,
+}
+
+impl TranslationClient {
+    pub fn from_env() -> Result<Option<Self>, TranslationError> {
+        let Some(api_key) = env::var("TRANSLATION_API_KEY")
+            .ok()
+            .filter(|value| !value.trim().is_empty())
+        else {
+            return Ok(None);
+        };
+
+        let provider = env::var("TRANSLATION_PROVIDER")
+            .unwrap_or_else(|_| "deepl".to_string())
+            .parse::<TranslationProvider>()?;
+        let api_url = match (env::var("TRANSLATION_API_URL").ok(), provider) {
+            (Some(url), _) if !url.trim().is_empty() => url.trim().to_string(),
+            (_, TranslationProvider::DeepL) => DEFAULT_DEEPL_URL.to_string(),
+            (_, TranslationProvider::OpenAi) => {
+                return Err(TranslationError::InvalidConfig(
+                    "TRANSLATION_API_URL is required for the openai provider".into(),
+                ));
+            }
+        };
+        let model = env::var("TRANSLATION_MODEL")
+            .ok()
+            .filter(|value| !value.trim().is_empty());
+        if provider == TranslationProvider::OpenAi && model.is_none() {
+            return Err(TranslationError::InvalidConfig(
+                "TRANSLATION_MODEL is required for the openai provider".into(),
+            ));
+        }
+
+        let timeout = env::var("TRANSLATION_TIMEOUT_SECONDS")
+            .ok()
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(DEFAULT_TRANSLATION_TIMEOUT_SECONDS);
+        let http = reqwest::Client::builder()
+            .timeout(Duration::from_secs(timeout))
+            .build()?;
+
+        Ok(Some(Self {
+            http,
+            provider,
+            api_url,
+            api_key: api_key.trim().to_string(),
+            model,
+        }))
+    }
+
+    pub fn provider_name(&self) -> &'static str {
+        match self.provider {
+            TranslationProvider::DeepL => "deepl",
+            TranslationProvider::OpenAi => "openai",
+        }
+    }
+
+    pub async fn translate(
+        &self,
+        texts: &[String],
+        source: TranslationLanguage,
+        target: TranslationLanguage,
+    ) -> Result<Vec<String>, TranslationError> {
+        if texts.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let translated = match self.provider {
+            TranslationProvider::DeepL => self.translate_deepl(texts, source, target).await?,
+            TranslationProvider::OpenAi => self.translate_chat(texts, source, target).await?,
+        };
+
+        if translated.len() != texts.len() {
+            return Err(TranslationError::UnexpectedResponse(format!(
+                "expected {} translations, got {}",
+                texts.len(),
+                translated.len()
+            )));
+        }
+        Ok(translated)
+    }
+
+    async fn translate_deepl(
+        &self,
+        texts: &[String],
+        source: TranslationLanguage,
+        target: TranslationLanguage,
+    ) -> Result<Vec<String>, TranslationError> {
+        let response: DeepLResponse = self
+            .http
+            .post(&self.api_url)
+            .header("Authorization", format!("DeepL-Auth-Key {}", self.api_key))
+            .json(&json!({
+                "text": texts,
+                "source_lang": source.deepl_code(false),
+                "target_lang": target.deepl_code(true),
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(response
+            .translations
+            .into_iter()
+            .map(|translation| translation.text)
+            .collect())
+    }
+
+    async fn translate_chat(
+        &self,
+        texts: &[String],
+        source: TranslationLanguage,
+        target: TranslationLanguage,
+    ) -> Result<Vec<String>, TranslationError> {
+        let instructions = format!(
+            "Translate each string of the JSON array from {} to {} for a university event listing. \
+             Keep line breaks, URLs and names unchanged. Reply with a JSON array of strings only, \
+             in the same order.",
+            source.english_name(),
+            target.english_name()
+        );
+        let input = serde_json::to_string(texts)
+            .map_err(|err| TranslationError::UnexpectedResponse(err.to_string()))?;
+
+        let response: ChatCompletionResponse = self
+            .http
+            .post(&self.api_url)
+            .bearer_auth(&self.api_key)
+            .json(&json!({
+                "model": self.model,
+                "temperature": 0.2,
+                "messages": [
+                    { "role": "system", "content": instructions },
+                    { "role": "user", "content": input },
+                ],
+            }))
+            .send()
+            .await?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let content = response
+            .choices
+            .into_iter()
+            .next()
+            .map(|choice| choice.message.content)
+            .ok_or_else(|| TranslationError::UnexpectedResponse("no choices returned".into()))?;
+        let content = content
+            .trim()
+            .trim_start_matches("```json")
+            .trim_start_matches("```")
+            .trim_end_matches("```")
+            .trim();
+
+        serde_json::from_str(content)
+            .map_err(|err| TranslationError::UnexpectedResponse(err.to_string()))
+    }
+}
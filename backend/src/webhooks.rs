@@ -0,0 +1,434 @@
+use std::{
+    env,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+
+use chrono::Utc;
+use hmac::{Hmac, Mac};
+use reqwest::{
+    Client, Url,
+    dns::{Addrs, Name, Resolve, Resolving},
+    redirect::Policy,
+};
+use serde_json::{Value, json};
+use sha2::Sha256;
+use sqlx::{Postgres, Transaction};
+use tokio::{
+    net::lookup_host,
+    time::{MissedTickBehavior, interval},
+};
+use tracing::{info, warn};
+
+use crate::{
+    app_state::AppState,
+    error::AppError,
+    models::{AuditType, Event, WebhookEventType},
+};
+
+type HmacSha256 = Hmac<Sha256>;
+
+const DEFAULT_INTERVAL_SECONDS: u64 = 15;
+const DEFAULT_TIMEOUT_SECONDS: u64 = 10;
+const DEFAULT_MAX_ATTEMPTS: i32 = 8;
+const BATCH_SIZE: i64 = 50;
+const LEASE_SECONDS: i64 = 300;
+const BASE_BACKOFF_SECONDS: i64 = 30;
+const MAX_BACKOFF_SECONDS: i64 = 6 * 3600;
+pub const SIGNATURE_HEADER: &str = "x-webhook-signature";
+
+#[derive(Debug, Clone)]
+pub struct WebhookSettings {
+    pub interval: Option<Duration>,
+    pub timeout: Duration,
+    pub max_attempts: i32,
+}
+
+impl WebhookSettings {
+    pub fn from_env() -> Self {
+        let number = |name: &str| {
+            env::var(name)
+                .ok()
+                .and_then(|value| value.trim().parse::<u64>().ok())
+        };
+        let interval = match number("WEBHOOK_DISPATCH_INTERVAL_SECONDS") {
+            Some(0) => None,
+            Some(seconds) => Some(Duration::from_secs(seconds)),
+            None => Some(Duration::from_secs(DEFAULT_INTERVAL_SECONDS)),
+        };
+        let timeout = number("WEBHOOK_TIMEOUT_SECONDS")
+            .filter(|seconds| *seconds > 0)
+            .unwrap_or(DEFAULT_TIMEOUT_SECONDS);
+        let max_attempts = number("WEBHOOK_MAX_ATTEMPTS")
+            .and_then(|attempts| i32::try_from(attempts).ok())
+            .filter(|attempts| *attempts > 0)
+            .unwrap_or(DEFAULT_MAX_ATTEMPTS);
+
+        Self {
+            interval,
+            timeout: Duration::from_secs(timeout),
+            max_attempts,
+        }
+    }
+}
+
+fn is_public_ip(ip: IpAddr) -> bool {
+    match ip {
+        IpAddr::V4(ip) => is_public_ipv4(ip),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(mapped) => is_public_ipv4(mapped),
+            None => is_public_ipv6(ip),
+        },
+    }
+}
+
+fn is_public_ipv4(ip: Ipv4Addr) -> bool {
+    let [a, b, ..] = ip.octets();
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_private()
+        || ip.is_link_local()
+        || ip.is_broadcast()
+        || ip.is_documentation()
+        || ip.is_multicast()
+        || a == 0
+        || a >= 240
+        || (a == 100 && (64..128).contains(&b))
+        || (a == 198 && (18..20).contains(&b)))
+}
+
+fn is_public_ipv6(ip: Ipv6Addr) -> bool {
+    let first = ip.segments()[0];
+    !(ip.is_unspecified()
+        || ip.is_loopback()
+        || ip.is_multicast()
+        || (first & 0xfe00) == 0xfc00
+        || (first & 0xffc0) == 0xfe80
+        || (first == 0x2001 && ip.segments()[1] == 0x0db8)
+        || (first == 0x0064 && ip.segments()[1] == 0xff9b))
+}
+
+fn webhook_target(raw: &str) -> Option<(String, u16)> {
+    let url = Url::parse(raw).ok()?;
+    if url.scheme() != "https" {
+        return None;
+    }
+    let host = url
+        .host_str()?
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .to_ascii_lowercase();
+    if host == "localhost" || host.ends_with(".localhost") {
+        return None;
+    }
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return is_public_ip(ip).then_some((host, url.port_or_known_default()?));
+    }
+    Some((host, url.port_or_known_default()?))
+}
+
+async fn resolves_to_public_addresses(host: &str, port: u16) -> bool {
+    match lookup_host((host, port)).await {
+        Ok(addrs) => {
+            let addrs: Vec<SocketAddr> = addrs.collect();
+            !addrs.is_empty() && addrs.iter().all(|addr| is_public_ip(addr.ip()))
+        }
+        Err(_) => false,
+    }
+}
+
+pub(crate) async fn ensure_public_webhook_url(url: &str) -> Result<(), AppError> {
+    let Some((host, port)) = webhook_target(url) else {
+        return Err(AppError::validation(
+            "url must be an https URL pointing to a public host",
+        ));
+    };
+    if !resolves_to_public_addresses(&host, port).await {
+        return Err(AppError::validation(
+            "url must resolve to public IP addresses only",
+        ));
+    }
+    Ok(())
+}
+
+struct PublicOnlyResolver;
+
+impl Resolve for PublicOnlyResolver {
+    fn resolve(&self, name: Name) -> Resolving {
+        let host = name.as_str().to_string();
+        Box::pin(async move {
+            let addrs: Vec<SocketAddr> = lookup_host((host.as_str(), 0)).await?.collect();
+            if addrs.is_empty() || !addrs.iter().all(|addr| is_public_ip(addr.ip())) {
+                return Err(format!("{host} resolves to a non-public address").into());
+            }
+            Ok(Box::new(addrs.into_iter()) as Addrs)
+        })
+    }
+}
+
+fn delivery_error_class(err: &reqwest::Error) -> &'static str {
+    if err.is_timeout() {
+        "timeout"
+    } else if err.is_connect() {
+        "connection failed"
+    } else {
+        "request failed"
+    }
+}
+
+pub(crate) fn webhook_event_type(
+    audit_type: &AuditType,
+    old_data: Option<&Event>,
+    new_data: Option<&Event>,
+) -> WebhookEventType {
+    match audit_type {
+        AuditType::Create => WebhookEventType::Created,
+        AuditType::Delete => WebhookEventType::Deleted,
+        AuditType::Update => {
+            let was_cancelled = old_data.is_some_and(|event| event.cancelled_at.is_some());
+            let is_cancelled = new_data.is_some_and(|event| event.cancelled_at.is_some());
+            if is_cancelled && !was_cancelled {
+                WebhookEventType::Cancelled
+            } else {
+                WebhookEventType::Updated
+            }
+        }
+    }
+}
+
+pub(crate) async fn enqueue_event_webhooks(
+    transaction: &mut Transaction<'_, Postgres>,
+    event_type: WebhookEventType,
+    event_id: i64,
+    organizer_id: i64,
+    old_data: Option<&Value>,
+    new_data: Option<&Value>,
+) -> Result<(), AppError> {
+    let payload = json!({
+        "type": event_type.as_str(),
+        "event_id": event_id,
+        "organizer_id": organizer_id,
+        "occurred_at": Utc::now(),
+        "data": new_data.or(old_data),
+        "previous": old_data,
+    });
+
+    sqlx::query!(
+        r#"
+        INSERT INTO webhook_deliveries (webhook_id, event_type, event_id, payload)
+        SELECT id, $1, $2, $3
+        FROM webhooks
+        WHERE active AND $1 = ANY(event_types)
+        AND (organizer_id IS NULL OR organizer_id = $4)
+        "#,
+        event_type as WebhookEventType,
+        event_id,
+        payload,
+        organizer_id
+    )
+    .execute(&mut **transaction)
+    .await?;
+
+    Ok(())
+}
+
+pub fn signature(secret: &str, timestamp: i64, body: &[u8]) -> String {
+    let mut mac = HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts any key");
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+    let digest: String = mac
+        .finalize()
+        .into_bytes()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect();
+    format!("t={timestamp},v1={digest}")
+}
+
+fn backoff(attempts: i32) -> chrono::Duration {
+    let exponent = u32::try_from(attempts.saturating_sub(1))
+        .unwrap_or(0)
+        .min(20);
+    let seconds = BASE_BACKOFF_SECONDS
+        .saturating_mul(1_i64 << exponent)
+        .min(MAX_BACKOFF_SECONDS);
+    chrono::Duration::seconds(seconds)
+}
+
+pub fn spawn_webhook_dispatcher(state: AppState, settings: WebhookSettings) {
+    let Some(every) = settings.interval else {
+        return;
+    };
+    let http = match Client::builder()
+        .timeout(settings.timeout)
+        .redirect(Policy::none())
+        .dns_resolver(Arc::new(PublicOnlyResolver))
+        .user_agent(concat!("campus-life-events/", env!("CARGO_PKG_VERSION")))
+        .build()
+    {
+        Ok(http) => http,
+        Err(err) => {
+            warn!(target: "webhooks", action = "init", %err, "Failed to build webhook HTTP client");
+            return;
+        }
+    };
+
+    tokio::spawn(async move {
+        let mut ticker = interval(every);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+        loop {
+            ticker.tick().await;
+            if let Err(err) = dispatch_due_deliveries(&state, &http, settings.max_attempts).await {
+                warn!(target: "webhooks", action = "dispatch", %err, "Webhook dispatch run failed");
+            }
+        }
+    });
+}
+
+async fn dispatch_due_deliveries(
+    state: &AppState,
+    http: &Client,
+    max_attempts: i32,
+) -> Result<(), AppError> {
+    let deliveries = sqlx::query!(
+        r#"
+        UPDATE webhook_deliveries d
+        SET next_attempt_at = NOW() + make_interval(secs => $2)
+        FROM webhooks w
+        WHERE w.id = d.webhook_id AND d.id IN (
+            SELECT id FROM webhook_deliveries
+            WHERE status = 'PENDING' AND next_attempt_at <= NOW()
+            ORDER BY next_attempt_at
+            LIMIT $1
+            FOR UPDATE SKIP LOCKED
+        )
+        RETURNING d.id, d.webhook_id, d.event_type as "event_type: WebhookEventType", d.payload,
+                  d.attempts, w.url, w.secret
+        "#,
+        BATCH_SIZE,
+        LEASE_SECONDS as f64
+    )
+    .fetch_all(&state.db)
+    .await?;
+
+    let mut failed = 0usize;
+    for delivery in &deliveries {
+        let body = serde_json::to_vec(&delivery.payload)?;
+        let timestamp = Utc::now().timestamp();
+        let result = if webhook_target(&delivery.url).is_none() {
+            Err(None)
+        } else {
+            http.post(&delivery.url)
+                .header("Content-Type", "application/json")
+                .header("X-Webhook-Event", delivery.event_type.as_str())
+                .header("X-Webhook-Delivery", delivery.id.to_string())
+                .header(
+                    SIGNATURE_HEADER,
+                    signature(&delivery.secret, timestamp, &body),
+                )
+                .body(body)
+                .send()
+                .await
+                .map_err(Some)
+        };
+
+        let (status_code, error) = match result {
+            Ok(response) if response.status().is_success() => {
+                sqlx::query!(
+                    r#"
+                    UPDATE webhook_deliveries
+                    SET status = 'DELIVERED', attempts = attempts + 1, last_status_code = $2,
+                        last_error = NULL, delivered_at = NOW()
+                    WHERE id = $1
+                    "#,
+                    delivery.id,
+                    i32::from(response.status().as_u16())
+                )
+                .execute(&state.db)
+                .await?;
+                continue;
+            }
+            Ok(response) => (
+                Some(i32::from(response.status().as_u16())),
+                format!("unexpected status {}", response.status().as_u16()),
+            ),
+            Err(Some(err)) => {
+                warn!(target: "webhooks", action = "deliver", delivery_id = delivery.id, webhook_id = delivery.webhook_id, %err, "Webhook request failed");
+                (None, delivery_error_class(&err).to_string())
+            }
+            Err(None) => (None, "destination not allowed".to_string()),
+        };
+
+        failed += 1;
+        let attempts = delivery.attempts + 1;
+        let exhausted = attempts >= max_attempts;
+        sqlx::query!(
+            r#"
+            UPDATE webhook_deliveries
+            SET status = CASE WHEN $5 THEN 'FAILED'::webhook_delivery_status ELSE status END,
+                attempts = $2, last_status_code = $3, last_error = $4, next_attempt_at = $6
+            WHERE id = $1
+            "#,
+            delivery.id,
+            attempts,
+            status_code,
+            error,
+            exhausted,
+            Utc::now() + backoff(attempts)
+        )
+        .execute(&state.db)
+        .await?;
+        if exhausted {
+            warn!(target: "webhooks", action = "give_up", delivery_id = delivery.id, webhook_id = delivery.webhook_id, attempts, "Webhook delivery failed permanently");
+        }
+    }
+
+    if !deliveries.is_empty() {
+        info!(
+            target: "webhooks",
+            action = "dispatch",
+            attempted = deliveries.len(),
+            failed,
+            "Webhook dispatch run finished"
+        );
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signs_timestamp_and_body_and_backs_off_exponentially() {
+        let signed = signature("whsec_test", 1_700_000_000, br#"{"type":"event.created"}"#);
+        assert!(signed.starts_with("t=1700000000,v1="));
+        assert_eq!(signed.len(), "t=1700000000,v1=".len() + 64);
+        assert_ne!(
+            signed,
+            signature("whsec_other", 1_700_000_000, br#"{"type":"event.created"}"#)
+        );
+
+        assert_eq!(backoff(1), chrono::Duration::seconds(30));
+        assert_eq!(backoff(3), chrono::Duration::seconds(120));
+        assert_eq!(backoff(30), chrono::Duration::seconds(MAX_BACKOFF_SECONDS));
+    }
+
+    #[test]
+    fn only_targets_public_https_hosts() {
+        assert!(webhook_target("https://hooks.example.org/cle").is_some());
+        assert!(webhook_target("https://93.184.216.34/hook").is_some());
+        assert!(webhook_target("http://hooks.example.org/cle").is_none());
+        assert!(webhook_target("https://localhost:8080/").is_none());
+        assert!(webhook_target("https://127.0.0.1/").is_none());
+        assert!(webhook_target("https://10.0.0.5/").is_none());
+        assert!(webhook_target("https://169.254.169.254/latest/meta-data").is_none());
+        assert!(webhook_target("https://[::1]/").is_none());
+        assert!(webhook_target("https://[fd00::1]/").is_none());
+        assert!(webhook_target("https://[fe80::1]/").is_none());
+        assert!(webhook_target("https://[::ffff:192.168.0.1]/").is_none());
+    }
+}
@@ -1,15 +1,21 @@
 'use client'
 
 import { AlertCircle, CheckCircle } from 'lucide-react'
+import { useQuery } from '@tanstack/react-query'
 import { useRouter } from 'next/navigation'
 import { useId, useState } from 'react'
-import { requestPasswordReset } from '@/client'
 import { AuthCard, AuthLayout, AuthLeftSide } from '@/components/auth'
+import { CaptchaWidget } from '@/components/captcha-widget'
 import { Alert, AlertDescription, AlertTitle } from '@/components/ui/alert'
 import { Button } from '@/components/ui/button'
 import { CardContent, CardHeader, CardTitle } from '@/components/ui/card'
 import { Input } from '@/components/ui/input'
 import { Label } from '@/components/ui/label'
+import {
+	CAPTCHA_REQUIRED_MESSAGES,
+	getCaptchaConfig,
+	requestPasswordReset
+} from '@/lib/auth'
 
 function getErrorMessage(error: unknown): string {
 	if (
@@ -31,6 +37,14 @@ export default function ForgotPasswordPage() {
 	const [loading, setLoading] = useState(false)
 	const [error, setError] = useState<string | null>(null)
 	const [success, setSuccess] = useState(false)
+	const [captchaToken, setCaptchaToken] = useState<string | null>(null)
+	const [captchaKey, setCaptchaKey] = useState(0)
+	const { data: captchaConfig } = useQuery({
+		queryKey: ['auth', 'captcha'],
+		queryFn: getCaptchaConfig,
+		staleTime: Number.POSITIVE_INFINITY
+	})
+	const captchaEnabled = Boolean(captchaConfig?.enabled)
 
 	async function onSubmit(e: React.FormEvent) {
 		e.preventDefault()
@@ -39,13 +53,18 @@ export default function ForgotPasswordPage() {
 		setSuccess(false)
 
 		try {
-			await requestPasswordReset({
-				body: { email }
-			})
+			await requestPasswordReset(email, captchaToken)
 			setSuccess(true)
 		} catch (err) {
-			setError(getErrorMessage(err))
+			const message = getErrorMessage(err)
+			setError(
+				CAPTCHA_REQUIRED_MESSAGES.includes(message.toLowerCase())
+					? 'Bitte bestätige zuerst, dass du kein Roboter bist.'
+					: message
+			)
 		} finally {
+			setCaptchaToken(null)
+			setCaptchaKey((key) => key + 1)
 			setLoading(false)
 		}
 	}
@@ -129,8 +148,19 @@ export default function ForgotPasswordPage() {
 								autoFocus
 							/>
 						</div>
+						{captchaEnabled && captchaConfig && (
+							<CaptchaWidget
+								key={captchaKey}
+								config={captchaConfig}
+								onToken={setCaptchaToken}
+							/>
+						)}
 						<div className="flex flex-col gap-2">
-							<Button type="submit" className="w-full" disabled={loading}>
+							<Button
+								type="submit"
+								className="w-full"
+								disabled={loading || (captchaEnabled && !captchaToken)}
+							>
 								{loading ? 'Senden...' : 'Link senden'}
 							</Button>
 							<Button
@@ -1,6 +1,6 @@
 'use client'
 
-import { useQueryClient } from '@tanstack/react-query'
+import { useQuery, useQueryClient } from '@tanstack/react-query'
 import { AlertTriangle } from 'lucide-react'
 import Link from 'next/link'
 import { useRouter } from 'next/navigation'
@@ -8,12 +8,17 @@ import { useId, useState } from 'react'
 import { AnimateIcon } from '@/components/animate-ui/icons/icon'
 import { LogIn } from '@/components/animate-ui/icons/log-in'
 import { AuthCard, AuthLayout, AuthLeftSide } from '@/components/auth'
+import { CaptchaWidget } from '@/components/captcha-widget'
 import { Alert, AlertDescription, AlertTitle } from '@/components/ui/alert'
 import { Button } from '@/components/ui/button'
 import { CardContent, CardHeader, CardTitle } from '@/components/ui/card'
 import { Input } from '@/components/ui/input'
 import { Label } from '@/components/ui/label'
-import { login } from '@/lib/auth'
+import {
+	CAPTCHA_REQUIRED_MESSAGES,
+	getCaptchaConfig,
+	login
+} from '@/lib/auth'
 
 function getErrorMessage(error: unknown): string {
 	if (
@@ -37,20 +42,39 @@ export default function LoginPage() {
 	const [password, setPassword] = useState('')
 	const [loading, setLoading] = useState(false)
 	const [error, setError] = useState<string | null>(null)
+	const [captchaRequired, setCaptchaRequired] = useState(false)
+	const [captchaToken, setCaptchaToken] = useState<string | null>(null)
+	const [captchaKey, setCaptchaKey] = useState(0)
+	const { data: captchaConfig } = useQuery({
+		queryKey: ['auth', 'captcha'],
+		queryFn: getCaptchaConfig,
+		staleTime: Number.POSITIVE_INFINITY
+	})
+	const showCaptcha = captchaRequired && Boolean(captchaConfig?.enabled)
 
 	async function onSubmit(e: React.FormEvent) {
 		e.preventDefault()
 		setLoading(true)
 		setError(null)
 		try {
-			await login({ email, password })
+			await login({ email, password }, showCaptcha ? captchaToken : null)
 			await queryClient.invalidateQueries({
 				queryKey: ['auth', 'me'],
 				refetchType: 'all'
 			})
 			router.push('/')
 		} catch (err) {
-			setError(getErrorMessage(err))
+			const message = getErrorMessage(err)
+			if (CAPTCHA_REQUIRED_MESSAGES.includes(message.toLowerCase())) {
+				setCaptchaRequired(true)
+				setError('Bitte bestätige zuerst, dass du kein Roboter bist.')
+			} else {
+				setError(message)
+			}
+			if (showCaptcha) {
+				setCaptchaToken(null)
+				setCaptchaKey((key) => key + 1)
+			}
 		} finally {
 			setLoading(false)
 		}
@@ -97,8 +121,19 @@ export default function LoginPage() {
 								required
 							/>
 						</div>
+						{showCaptcha && captchaConfig && (
+							<CaptchaWidget
+								key={captchaKey}
+								config={captchaConfig}
+								onToken={setCaptchaToken}
+							/>
+						)}
 						<AnimateIcon animateOnHover animateOnTap>
-							<Button type="submit" className="w-full" disabled={loading}>
+							<Button
+								type="submit"
+								className="w-full"
+								disabled={loading || (showCaptcha && !captchaToken)}
+							>
 								{loading ? 'Einloggen...' : 'Einloggen'}
 								<LogIn className="h-4 w-4" />
 							</Button>
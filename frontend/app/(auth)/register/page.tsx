@@ -16,7 +16,11 @@ import {
 	TooltipProvider,
 	TooltipTrigger
 } from '@/components/ui/tooltip'
-import { initAccount, lookupSetupToken } from '@/lib/auth'
+import {
+	initAccount,
+	lookupSetupToken,
+	requestEmailVerification
+} from '@/lib/auth'
 import {
 	getPasswordPolicyError,
 	PASSWORD_POLICY_SUMMARY
@@ -36,10 +40,29 @@ function friendlyTokenError(message: string): string {
 	}
 }
 
+function friendlyVerificationError(message: string): string {
+	const normalized = message.toLowerCase()
+	if (normalized.startsWith('a verification code was sent recently')) {
+		return 'Es wurde gerade erst ein Code gesendet. Bitte warte einen Moment, bevor du einen neuen anforderst.'
+	}
+	if (normalized.startsWith('email verification required')) {
+		return 'Bitte fordere einen Bestätigungscode an und gib ihn ein.'
+	}
+	switch (normalized) {
+		case 'invalid or expired verification code':
+			return 'Der Bestätigungscode ist ungültig oder abgelaufen.'
+		case 'failed to send verification code':
+			return 'Der Bestätigungscode konnte nicht gesendet werden. Bitte versuche es später erneut.'
+		default:
+			return message
+	}
+}
+
 function RegisterForm({ token }: { token: string }) {
 	const router = useRouter()
 	const queryClient = useQueryClient()
 	const emailId = useId()
+	const codeId = useId()
 	const passwordId = useId()
 	const password2Id = useId()
 	const [clubName, setClubName] = useState<string | null>(null)
@@ -48,17 +71,24 @@ function RegisterForm({ token }: { token: string }) {
 	>(token ? 'loading' : 'idle')
 	const [tokenError, setTokenError] = useState<string | null>(null)
 	const [email, setEmail] = useState('')
+	const [verificationRequired, setVerificationRequired] = useState(false)
+	const [verificationCode, setVerificationCode] = useState('')
+	const [codeSending, setCodeSending] = useState(false)
+	const [codeSent, setCodeSent] = useState(false)
 	const [password, setPassword] = useState('')
 	const [password2, setPassword2] = useState('')
 	const [loading, setLoading] = useState(false)
 	const [error, setError] = useState<string | null>(null)
 	const passwordPolicyError = password ? getPasswordPolicyError(password) : null
 	const passwordsMismatch = password2.length > 0 && password !== password2
+	const codeMissing =
+		verificationRequired && verificationCode.trim().length !== 6
 	const submitDisabled =
 		loading ||
 		tokenStatus !== 'valid' ||
 		Boolean(passwordPolicyError) ||
-		passwordsMismatch
+		passwordsMismatch ||
+		codeMissing
 
 	useEffect(() => {
 		if (!token) {
@@ -78,6 +108,7 @@ function RegisterForm({ token }: { token: string }) {
 				if (cancelled) return
 				setClubName(data.account_name)
 				setEmail(data.email)
+				setVerificationRequired(data.email_verification_required)
 				setTokenStatus('valid')
 			})
 			.catch((err) => {
@@ -151,6 +182,23 @@ function RegisterForm({ token }: { token: string }) {
 		)
 	}
 
+	async function onRequestCode() {
+		setError(null)
+		setCodeSending(true)
+		try {
+			await requestEmailVerification(token)
+			setCodeSent(true)
+		} catch (err: unknown) {
+			const message =
+				err instanceof Error
+					? err.message
+					: 'Bestätigungscode konnte nicht gesendet werden'
+			setError(friendlyVerificationError(message))
+		} finally {
+			setCodeSending(false)
+		}
+	}
+
 	async function onSubmit(e: React.FormEvent) {
 		e.preventDefault()
 		setError(null)
@@ -174,9 +222,19 @@ function RegisterForm({ token }: { token: string }) {
 			setError(message)
 			return
 		}
+		if (codeMissing) {
+			setError('Bitte gib den sechsstelligen Bestätigungscode ein.')
+			return
+		}
 		setLoading(true)
 		try {
-			await initAccount({ token, password })
+			await initAccount({
+				token,
+				password,
+				verification_code: verificationRequired
+					? verificationCode.trim()
+					: undefined
+			})
 			await queryClient.invalidateQueries({
 				queryKey: ['auth', 'me'],
 				refetchType: 'all'
@@ -185,7 +243,7 @@ function RegisterForm({ token }: { token: string }) {
 		} catch (err: unknown) {
 			const message =
 				err instanceof Error ? err.message : 'Einrichtung fehlgeschlagen'
-			setError(message)
+			setError(friendlyVerificationError(message))
 		} finally {
 			setLoading(false)
 		}
@@ -265,6 +323,42 @@ function RegisterForm({ token }: { token: string }) {
 									required
 								/>
 							</div>
+							{verificationRequired && (
+								<div className="space-y-2">
+									<Label htmlFor={codeId}>Bestätigungscode</Label>
+									<div className="flex gap-2">
+										<Input
+											id={codeId}
+											inputMode="numeric"
+											autoComplete="one-time-code"
+											maxLength={6}
+											value={verificationCode}
+											onChange={(e) =>
+												setVerificationCode(e.target.value.replace(/\D/g, ''))
+											}
+											placeholder="123456"
+											required
+										/>
+										<Button
+											type="button"
+											variant="outline"
+											onClick={onRequestCode}
+											disabled={codeSending || tokenStatus !== 'valid'}
+										>
+											{codeSending
+												? 'Wird gesendet...'
+												: codeSent
+													? 'Erneut senden'
+													: 'Code senden'}
+										</Button>
+									</div>
+									<p className="text-xs text-muted-foreground">
+										{codeSent
+											? 'Wir haben dir einen Code an deine E-Mail-Adresse gesendet. Er ist 15 Minuten gültig.'
+											: 'Zur Bestätigung deiner E-Mail-Adresse senden wir dir einen sechsstelligen Code.'}
+									</p>
+								</div>
+							)}
 							<div className="space-y-2">
 								<Label htmlFor={passwordId}>Passwort</Label>
 								<Input
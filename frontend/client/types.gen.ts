@@ -91,6 +91,17 @@ export type CreateOrganizerRequest = {
     organizer_kind?: OrganizerKind;
 };
 
+export type CaptchaConfigResponse = {
+    enabled: boolean;
+    provider?: string | null;
+    site_key?: string | null;
+};
+
+export type EmailVerificationSentResponse = {
+    email: string;
+    expires_at: string;
+};
+
 export type ErrorResponse = {
     message: string;
 };
@@ -158,6 +169,7 @@ export type IcalEventResponse = {
 export type InitAccountRequest = {
     password: string;
     token: string;
+    verification_code?: string | null;
 };
 
 export type InviteAdminRequest = {
@@ -304,6 +316,7 @@ export type SetupTokenInfoResponse = {
     account_name: string;
     account_type: AccountType;
     email: string;
+    email_verification_required: boolean;
 };
 
 export type SetupTokenLookupRequest = {
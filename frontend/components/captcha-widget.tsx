@@ -0,0 +1,96 @@
+'use client'
+
+import { useEffect, useRef } from 'react'
+import type { CaptchaConfigResponse } from '@/client/types.gen'
+
+type CaptchaRenderOptions = {
+	sitekey: string
+	callback: (token: string) => void
+	'expired-callback': () => void
+	'error-callback': () => void
+}
+
+type CaptchaApi = {
+	render: (container: HTMLElement, options: CaptchaRenderOptions) => string
+	remove?: (widgetId: string) => void
+}
+
+declare global {
+	interface Window {
+		turnstile?: CaptchaApi
+		hcaptcha?: CaptchaApi
+	}
+}
+
+const SCRIPTS: Record<string, string> = {
+	turnstile:
+		'https://challenges.cloudflare.com/turnstile/v0/api.js?render=explicit',
+	hcaptcha: 'https://js.hcaptcha.com/1/api.js?render=explicit'
+}
+
+const loaders = new Map<string, Promise<void>>()
+
+function loadScript(src: string): Promise<void> {
+	const existing = loaders.get(src)
+	if (existing) return existing
+	const loader = new Promise<void>((resolve, reject) => {
+		const script = document.createElement('script')
+		script.src = src
+		script.async = true
+		script.defer = true
+		script.onload = () => resolve()
+		script.onerror = () => {
+			loaders.delete(src)
+			reject(new Error('CAPTCHA konnte nicht geladen werden'))
+		}
+		document.head.appendChild(script)
+	})
+	loaders.set(src, loader)
+	return loader
+}
+
+function captchaApi(provider: string): CaptchaApi | undefined {
+	return provider === 'hcaptcha' ? window.hcaptcha : window.turnstile
+}
+
+export function CaptchaWidget({
+	config,
+	onToken
+}: {
+	config: CaptchaConfigResponse
+	onToken: (token: string | null) => void
+}) {
+	const container = useRef<HTMLDivElement>(null)
+	const onTokenRef = useRef(onToken)
+	onTokenRef.current = onToken
+	const provider = config.provider ?? 'turnstile'
+	const siteKey = config.site_key ?? ''
+
+	useEffect(() => {
+		const src = SCRIPTS[provider]
+		if (!src || !siteKey) return
+		let cancelled = false
+		let widgetId: string | undefined
+
+		loadScript(src)
+			.then(() => {
+				const api = captchaApi(provider)
+				if (cancelled || !api || !container.current) return
+				widgetId = api.render(container.current, {
+					sitekey: siteKey,
+					callback: (token) => onTokenRef.current(token),
+					'expired-callback': () => onTokenRef.current(null),
+					'error-callback': () => onTokenRef.current(null)
+				})
+			})
+			.catch(() => onTokenRef.current(null))
+
+		return () => {
+			cancelled = true
+			if (widgetId) captchaApi(provider)?.remove?.(widgetId)
+			onTokenRef.current(null)
+		}
+	}, [provider, siteKey])
+
+	return <div ref={container} className="flex justify-center" />
+}
@@ -1,19 +1,44 @@
-import type { SetupTokenInfoResponse } from '@/client/types.gen'
+import type {
+	CaptchaConfigResponse,
+	EmailVerificationSentResponse,
+	SetupTokenInfoResponse
+} from '@/client/types.gen'
+
+export const CAPTCHA_REQUIRED_MESSAGES = [
+	'captcha verification required',
+	'captcha verification failed'
+]
 
 export type LoginPayload = { email: string; password: string }
 export type InitAccountPayload = {
 	token: string
 	password: string
+	verification_code?: string
 }
 export type ChangePasswordPayload = {
 	current_password: string
 	new_password: string
 }
 
-export async function login(payload: LoginPayload) {
+function captchaHeaders(captchaToken?: string | null): HeadersInit {
+	return captchaToken
+		? { 'Content-Type': 'application/json', 'X-Captcha-Token': captchaToken }
+		: { 'Content-Type': 'application/json' }
+}
+
+export async function getCaptchaConfig(): Promise<CaptchaConfigResponse> {
+	const res = await fetch('/api/v1/auth/captcha', { credentials: 'include' })
+	if (!res.ok) return { enabled: false }
+	return res.json()
+}
+
+export async function login(
+	payload: LoginPayload,
+	captchaToken?: string | null
+) {
 	const res = await fetch('/api/v1/auth/login', {
 		method: 'POST',
-		headers: { 'Content-Type': 'application/json' },
+		headers: captchaHeaders(captchaToken),
 		body: JSON.stringify(payload),
 		credentials: 'include'
 	})
@@ -24,6 +49,23 @@ export async function login(payload: LoginPayload) {
 	return res.json()
 }
 
+export async function requestPasswordReset(
+	email: string,
+	captchaToken?: string | null
+) {
+	const res = await fetch('/api/v1/auth/request-password-reset', {
+		method: 'POST',
+		headers: captchaHeaders(captchaToken),
+		body: JSON.stringify({ email }),
+		credentials: 'include'
+	})
+	if (!res.ok) {
+		const msg = await safeError(res)
+		throw new Error(msg || 'Password reset request failed')
+	}
+	return res.json()
+}
+
 export async function initAccount(payload: InitAccountPayload) {
 	const res = await fetch('/api/v1/auth/init', {
 		method: 'POST',
@@ -38,6 +80,22 @@ export async function initAccount(payload: InitAccountPayload) {
 	return res.json()
 }
 
+export async function requestEmailVerification(
+	token: string
+): Promise<EmailVerificationSentResponse> {
+	const res = await fetch('/api/v1/auth/init/verify-email', {
+		method: 'POST',
+		headers: { 'Content-Type': 'application/json' },
+		body: JSON.stringify({ token }),
+		credentials: 'include'
+	})
+	if (!res.ok) {
+		const msg = await safeError(res)
+		throw new Error(msg || 'Sending verification code failed')
+	}
+	return res.json()
+}
+
 export async function lookupSetupToken(
 	token: string
 ): Promise<SetupTokenInfoResponse> {